@@ -30,6 +30,7 @@ mod ast;
 mod logger;
 
 use air::execute_air;
+use air::execute_precompiled_air;
 use air::InterpreterOutcome;
 use air::RunParameters;
 use marine_rs_sdk::marine;
@@ -52,6 +53,17 @@ pub fn invoke(
     execute_air(air, prev_data, data, params, call_results)
 }
 
+#[marine]
+pub fn invoke_precompiled(
+    air: Vec<u8>,
+    prev_data: Vec<u8>,
+    data: Vec<u8>,
+    params: RunParameters,
+    call_results: Vec<u8>,
+) -> InterpreterOutcome {
+    execute_precompiled_air(air, prev_data, data, params, call_results)
+}
+
 #[marine]
 pub fn invoke_tracing(
     air: String,
@@ -66,6 +78,18 @@ pub fn invoke_tracing(
     execute_air(air, prev_data, data, params, call_results)
 }
 
+/// Returns a JSON report about provenance of states of the result trace produced by the last
+/// invocation, it's empty if tracking wasn't enabled by run parameters.
+#[marine]
+pub fn merge_provenance() -> Vec<u8> {
+    air::take_merge_provenance()
+}
+
+#[marine]
+pub fn binary_air_version() -> u32 {
+    air::parser::BINARY_AIR_VERSION
+}
+
 #[marine]
 pub fn ast(script: String) -> String {
     ast::ast(script)
@@ -30,6 +30,7 @@ mod ast;
 mod logger;
 
 use air::execute_air;
+use air::execute_precompiled_air;
 use air::RunParameters;
 
 use log::LevelFilter;
@@ -70,6 +71,26 @@ pub fn invoke(
     serde_json::to_string(&outcome).expect("Cannot parse InterpreterOutcome")
 }
 
+#[wasm_bindgen]
+pub fn invoke_precompiled(
+    air: Vec<u8>,
+    prev_data: Vec<u8>,
+    data: Vec<u8>,
+    params: Vec<u8>,
+    call_results: Vec<u8>,
+    log_level: &str,
+) -> String {
+    use std::str::FromStr;
+
+    let log_level = log::LevelFilter::from_str(log_level).unwrap_or(DEFAULT_LOG_LEVEL);
+    log::set_max_level(log_level);
+
+    let params: RunParameters = serde_json::from_slice(&params).expect("cannot parse RunParameters");
+
+    let outcome = execute_precompiled_air(air, prev_data, data, params, call_results);
+    serde_json::to_string(&outcome).expect("Cannot parse InterpreterOutcome")
+}
+
 #[wasm_bindgen]
 pub fn ast(script: String) -> String {
     ast::ast(script)
@@ -0,0 +1,94 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::CanonStream;
+use super::ValueAggregate;
+use crate::JValue;
+
+use polyplets::SecurityTetraplet;
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::rc::Rc;
+
+pub(crate) const STREAM_MAP_KEY_FIELD_NAME: &str = "key";
+pub(crate) const STREAM_MAP_VALUE_FIELD_NAME: &str = "value";
+
+/// Canon stream map is a canonicalized stream map, it contains key-value pairs in the order
+/// they were appended to a stream map. Since a stream map is append-only, the first value
+/// appended with a key is the one this key is associated with.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct CanonStreamMap {
+    canon_stream: CanonStream,
+}
+
+impl CanonStreamMap {
+    pub(crate) fn from_canon_stream(canon_stream: CanonStream) -> Self {
+        Self { canon_stream }
+    }
+
+    pub(crate) fn as_jvalue(&self) -> JValue {
+        let mut map = serde_json::Map::new();
+        for (key, value) in self.iter() {
+            let value = value
+                .result
+                .get(STREAM_MAP_VALUE_FIELD_NAME)
+                .cloned()
+                .unwrap_or_default();
+            map.insert(key.to_string(), value);
+        }
+
+        JValue::Object(map)
+    }
+
+    /// Returns key-value pairs that determine values of this map.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &ValueAggregate)> {
+        let mut met_keys = std::collections::HashSet::new();
+
+        self.canon_stream.iter().filter_map(move |value| {
+            let key = value.result.get(STREAM_MAP_KEY_FIELD_NAME)?.as_str()?;
+            if met_keys.insert(key) {
+                Some((key, value))
+            } else {
+                None
+            }
+        })
+    }
+
+    pub(crate) fn tetraplet(&self) -> &Rc<SecurityTetraplet> {
+        self.canon_stream.tetraplet()
+    }
+}
+
+/// Creates a stream map entry from a key and a value.
+pub(crate) fn stream_map_entry(key: &str, value: &JValue) -> JValue {
+    serde_json::json!({
+        STREAM_MAP_KEY_FIELD_NAME: key,
+        STREAM_MAP_VALUE_FIELD_NAME: value,
+    })
+}
+
+use std::fmt;
+
+impl fmt::Display for CanonStreamMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (key, value) in self.iter() {
+            write!(f, "{key}: {value}, ")?;
+        }
+        write!(f, "}}")
+    }
+}
@@ -49,7 +49,8 @@ impl JValuable for &CanonStream {
                 let resolved_call = self.nth(idx).expect(crate::execution_step::TETRAPLET_IDX_CORRECT);
                 resolved_call.tetraplet.as_ref().clone()
             }
-            None => SecurityTetraplet::new(exec_ctx.run_parameters.current_peer_id.to_string(), "", "", ""),
+            // a result selected from several values keeps provenance of the whole canon stream
+            None => self.tetraplet().as_ref().clone(),
         };
         let tetraplet = populate_tetraplet_with_lambda(tetraplet, lambda);
 
@@ -0,0 +1,66 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::select_by_lambda_from_scalar;
+use super::ExecutionResult;
+use super::JValuable;
+use crate::execution_step::boxed_value::populate_tetraplet_with_lambda;
+use crate::execution_step::boxed_value::CanonStreamMap;
+use crate::execution_step::ExecutionCtx;
+use crate::execution_step::RcSecurityTetraplets;
+use crate::JValue;
+use crate::LambdaAST;
+use crate::SecurityTetraplet;
+
+use std::borrow::Cow;
+
+impl JValuable for &CanonStreamMap {
+    fn apply_lambda(&self, lambda: &LambdaAST<'_>, exec_ctx: &ExecutionCtx<'_>) -> ExecutionResult<Cow<'_, JValue>> {
+        let map = CanonStreamMap::as_jvalue(self);
+        let select_result = select_by_lambda_from_scalar(&map, lambda, exec_ctx)?;
+
+        Ok(Cow::Owned(select_result.into_owned()))
+    }
+
+    fn apply_lambda_with_tetraplets(
+        &self,
+        lambda: &LambdaAST<'_>,
+        exec_ctx: &ExecutionCtx<'_>,
+    ) -> ExecutionResult<(Cow<'_, JValue>, SecurityTetraplet)> {
+        let map = CanonStreamMap::as_jvalue(self);
+        let select_result = select_by_lambda_from_scalar(&map, lambda, exec_ctx)?;
+
+        let tetraplet = self.tetraplet().as_ref().clone();
+        let tetraplet = populate_tetraplet_with_lambda(tetraplet, lambda);
+
+        Ok((Cow::Owned(select_result.into_owned()), tetraplet))
+    }
+
+    fn as_jvalue(&self) -> Cow<'_, JValue> {
+        let jvalue = CanonStreamMap::as_jvalue(self);
+        Cow::Owned(jvalue)
+    }
+
+    fn into_jvalue(self: Box<Self>) -> JValue {
+        CanonStreamMap::as_jvalue(&self)
+    }
+
+    fn as_tetraplets(&self) -> RcSecurityTetraplets {
+        self.iter()
+            .map(|(_, value)| value.tetraplet.clone())
+            .collect::<Vec<_>>()
+    }
+}
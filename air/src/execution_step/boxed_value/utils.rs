@@ -14,6 +14,7 @@
  * limitations under the License.
  */
 
+use air_lambda_ast::Functor;
 use air_lambda_ast::LambdaAST;
 use polyplets::SecurityTetraplet;
 
@@ -22,7 +23,8 @@ pub(crate) fn populate_tetraplet_with_lambda(
     lambda: &LambdaAST<'_>,
 ) -> SecurityTetraplet {
     match lambda {
-        LambdaAST::ValuePath(_) => {
+        // these functors select an existing element, so it keeps provenance of the original value
+        LambdaAST::ValuePath(_) | LambdaAST::Functor(Functor::First | Functor::Last) => {
             tetraplet.add_lambda(&lambda.to_string());
             tetraplet
         }
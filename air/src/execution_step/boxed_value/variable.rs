@@ -31,6 +31,9 @@ pub(crate) enum Variable<'i> {
     CanonStream {
         name: &'i str,
     },
+    CanonStreamMap {
+        name: &'i str,
+    },
 }
 
 impl<'i> Variable<'i> {
@@ -41,6 +44,10 @@ impl<'i> Variable<'i> {
     pub(crate) fn canon_stream(name: &'i str) -> Self {
         Self::CanonStream { name }
     }
+
+    pub(crate) fn canon_stream_map(name: &'i str) -> Self {
+        Self::CanonStreamMap { name }
+    }
 }
 
 impl<'i> From<&ast::ImmutableVariable<'i>> for Variable<'i> {
@@ -50,6 +57,7 @@ impl<'i> From<&ast::ImmutableVariable<'i>> for Variable<'i> {
         match ast_variable {
             Scalar(scalar) => Self::scalar(scalar.name),
             CanonStream(canon_stream) => Self::canon_stream(canon_stream.name),
+            CanonStreamMap(canon_stream_map) => Self::canon_stream_map(canon_stream_map.name),
         }
     }
 }
@@ -61,6 +69,7 @@ impl<'i> From<&ast::ImmutableVariableWithLambda<'i>> for Variable<'i> {
         match ast_variable {
             Scalar(scalar) => Self::scalar(scalar.name),
             CanonStream(canon_stream) => Self::canon_stream(canon_stream.name),
+            CanonStreamMap(canon_stream_map) => Self::canon_stream_map(canon_stream_map.name),
         }
     }
 }
@@ -21,6 +21,9 @@ use crate::execution_step::lambda_applier::LambdaError;
 use crate::JValue;
 use crate::ToErrorCode;
 
+use air_lambda_ast::Functor;
+use air_parser::ast::ComparisonOperator;
+
 use strum::IntoEnumIterator;
 use strum_macros::EnumDiscriminants;
 use strum_macros::EnumIter;
@@ -93,6 +96,64 @@ pub enum CatchableError {
         variable_name: String,
         actual_value: JValue,
     },
+
+    /// A key of a stream map could be only a string or an integer.
+    #[error("stream map key must be a string or an integer, but '{0}' is provided")]
+    StreamMapKeyHasInvalidType(JValue),
+
+    /// This error type is occurred when the keys functor applied to a value of non-object type.
+    #[error("the keys functor could applied only to an object, but it's applied to '{0}'")]
+    KeysFunctorAppliedToNotObject(JValue),
+
+    /// This error type is occurred when the values functor applied to a value of non-object type.
+    #[error("the values functor could applied only to an object, but it's applied to '{0}'")]
+    ValuesFunctorAppliedToNotObject(JValue),
+
+    /// This error type is occurred when the is_empty functor applied to a value
+    /// that is neither an array nor an object.
+    #[error("the is_empty functor could applied only to an array-like value or an object, but it's applied to '{0}'")]
+    IsEmptyFunctorAppliedToNotCollection(JValue),
+
+    /// This error type is occurred when the first or last functor applied to a value of non-array type.
+    #[error("the {functor} functor could applied only to an array-like value, but it's applied to '{value}'")]
+    ElementFunctorAppliedToNotArray { functor: Functor, value: JValue },
+
+    /// This error type is occurred when the first or last functor applied to an empty array-like value.
+    #[error("the {0} functor could applied only to a non-empty array-like value")]
+    ElementFunctorAppliedToEmptyArray(Functor),
+
+    /// This error type is produced by a comparison instruction to notify xor that compared values
+    /// don't satisfy the comparison.
+    #[error("{0} is used without corresponding xor")]
+    ComparisonNotSatisfied(ComparisonOperator),
+
+    /// This error type is produced by a comparison instruction if values can't be ordered,
+    /// only two numbers or two strings are comparable.
+    #[error("values '{left}' and '{right}' can't be ordered, only two numbers or two strings are comparable")]
+    IncomparableValues { left: JValue, right: JValue },
+
+    /// This error type is produced by a member instruction to notify xor that a collection
+    /// doesn't contain a value.
+    #[error("member is used without corresponding xor")]
+    MemberValueNotFound,
+
+    /// This error type is produced by a member instruction if its collection isn't an array-like value.
+    #[error("member could be applied only to an array-like collection, but '{0}' is provided")]
+    MemberCollectionIsNotArray(JValue),
+
+    /// This error type is produced by a ttl_guard instruction if less than the required time
+    /// remains before the particle TTL expires.
+    #[error("ttl_guard requires at least {required} ms of remaining ttl, but only {remaining} ms remain")]
+    NotEnoughRemainingTtl { remaining: u64, required: u64 },
+
+    /// This error type is produced by a ttl_guard instruction if its argument isn't a non-negative integer.
+    #[error("ttl_guard could be applied only to a non-negative integer, but '{0}' is provided")]
+    TtlGuardArgumentIsNotNumber(JValue),
+
+    /// This error type is produced by an ap instruction if its array or object literal contains
+    /// a non-literal element, because a tetraplet of such element can't be kept.
+    #[error("array and object literals in ap could contain only literals, but '{0}' is provided")]
+    ApLiteralWithNonLiteralElement(String),
 }
 
 impl From<LambdaError> for Rc<CatchableError> {
@@ -118,7 +179,10 @@ impl LastErrorAffectable for CatchableError {
     fn affects_last_error(&self) -> bool {
         !matches!(
             self,
-            CatchableError::MatchValuesNotEqual | CatchableError::MismatchValuesEqual
+            CatchableError::MatchValuesNotEqual
+                | CatchableError::MismatchValuesEqual
+                | CatchableError::ComparisonNotSatisfied(_)
+                | CatchableError::MemberValueNotFound
         )
     }
 }
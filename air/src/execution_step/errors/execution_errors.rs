@@ -48,7 +48,10 @@ impl ExecutionError {
         match self {
             ExecutionError::Catchable(catchable) => matches!(
                 catchable.as_ref(),
-                CatchableError::MatchValuesNotEqual | CatchableError::MismatchValuesEqual
+                CatchableError::MatchValuesNotEqual
+                    | CatchableError::MismatchValuesEqual
+                    | CatchableError::ComparisonNotSatisfied(_)
+                    | CatchableError::MemberValueNotFound
             ),
             _ => false,
         }
@@ -20,8 +20,9 @@ use crate::JValue;
 use crate::ToErrorCode;
 
 use air_interpreter_cid::CidCalculationError;
+use air_interpreter_data::CallResult;
 use air_interpreter_data::TracePos;
-use air_interpreter_data::ValueRef;
+use air_interpreter_signatures::SignatureVerificationError;
 use air_trace_handler::merger::MergerApResult;
 use air_trace_handler::GenerationCompatificationError;
 use air_trace_handler::TraceHandlerError;
@@ -69,8 +70,8 @@ pub enum UncatchableError {
 
     /// Errors occurred when result from data doesn't match to a call instruction, f.e. a call
     /// could be applied to a stream, but result doesn't contain generation in a source position.
-    #[error("call result value {0:?} doesn't match with corresponding instruction")]
-    CallResultNotCorrespondToInstr(ValueRef),
+    #[error("call result {0:?} doesn't match with corresponding instruction")]
+    CallResultNotCorrespondToInstr(CallResult),
 
     /// Variable shadowing is not allowed, usually it's thrown when a AIR tries to assign value
     /// for a variable not in a fold block or in a global scope but not right after new.
@@ -109,6 +110,10 @@ pub enum UncatchableError {
              stream is {stream:?}"
     )]
     StreamDontHaveSuchGeneration { stream: Stream, generation: Generation },
+
+    /// A state from the current data isn't signed by a peer produced it.
+    #[error("current data contains a state that isn't signed properly: {0}")]
+    SignatureVerificationFailed(SignatureVerificationError),
 }
 
 impl ToErrorCode for UncatchableError {
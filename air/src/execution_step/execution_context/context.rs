@@ -24,16 +24,24 @@ use crate::JValue;
 use crate::UncatchableError;
 
 use air_execution_info_collector::InstructionTracker;
+use air_interpreter_cid::CidHashAlgorithm;
 use air_interpreter_cid::CID;
+use air_interpreter_data::CallResult;
 use air_interpreter_data::CanonCidAggregate;
 use air_interpreter_data::CidInfo;
 use air_interpreter_data::CidTracker;
 use air_interpreter_data::GlobalStreamGens;
 use air_interpreter_data::RestrictedStreamGens;
+use air_interpreter_data::Sender;
 use air_interpreter_data::TracePos;
+use air_interpreter_data::ValueRef;
 use air_interpreter_interface::*;
+use air_interpreter_signatures::KeyPair;
+use air_interpreter_signatures::SignatureStore;
+use air_interpreter_signatures::SignatureTracker;
 use polyplets::SecurityTetraplet;
 
+use std::borrow::Cow;
 use std::rc::Rc;
 
 /// Contains all necessary state needed to execute AIR script.
@@ -77,6 +85,15 @@ pub(crate) struct ExecutionCtx<'i> {
 
     /// CID-to-something trackers.
     pub(crate) cid_state: ExecutionCidState,
+
+    /// Key pair of the current peer used to sign produced states, signing is disabled without it.
+    pub(crate) key_pair: Option<KeyPair>,
+
+    /// Collects CIDs of states produced by the current peer.
+    pub(crate) signature_tracker: SignatureTracker,
+
+    /// Signatures of states from both prev and current data.
+    pub(crate) signature_store: SignatureStore,
 }
 
 impl<'i> ExecutionCtx<'i> {
@@ -85,8 +102,11 @@ impl<'i> ExecutionCtx<'i> {
         current_ingredients: ExecCtxIngredients,
         call_results: CallResults,
         run_parameters: RunParameters,
+        data_format: DataFormat,
+        cid_hash_algorithm: CidHashAlgorithm,
+        key_pair: Option<KeyPair>,
     ) -> Self {
-        let run_parameters = RcRunParameters::from_run_parameters(run_parameters);
+        let run_parameters = RcRunParameters::from_run_parameters(run_parameters, data_format);
         let streams = Streams::from_data(
             prev_ingredients.global_streams,
             current_ingredients.global_streams,
@@ -94,7 +114,13 @@ impl<'i> ExecutionCtx<'i> {
             current_ingredients.restricted_streams,
         );
 
-        let cid_state = ExecutionCidState::from_cid_info(prev_ingredients.cid_info, current_ingredients.cid_info);
+        let cid_state = ExecutionCidState::from_cid_info(
+            prev_ingredients.cid_info,
+            current_ingredients.cid_info,
+            cid_hash_algorithm,
+        );
+
+        let signature_store = SignatureStore::merge(prev_ingredients.signatures, current_ingredients.signatures);
 
         Self {
             run_parameters,
@@ -103,6 +129,8 @@ impl<'i> ExecutionCtx<'i> {
             call_results,
             streams,
             cid_state,
+            key_pair,
+            signature_store,
             ..<_>::default()
         }
     }
@@ -111,10 +139,60 @@ impl<'i> ExecutionCtx<'i> {
         self.last_error_descriptor.last_error()
     }
 
+    pub(crate) fn scoped_error(&self) -> &LastError {
+        self.last_error_descriptor.scoped_error()
+    }
+
     pub(crate) fn next_call_request_id(&mut self) -> u32 {
         self.last_call_request_id += 1;
         self.last_call_request_id
     }
+
+    /// Registers a state produced by the current peer to sign it, if signing is enabled.
+    pub(crate) fn register_produced_state(&mut self, state: &impl serde::Serialize) -> Result<(), UncatchableError> {
+        if self.key_pair.is_some() {
+            self.signature_tracker.register_state(state)?;
+        }
+
+        Ok(())
+    }
+
+    /// Registers a call state produced by the current peer to sign it, if signing is enabled.
+    pub(crate) fn register_produced_call(&mut self, call_result: &CallResult) -> Result<(), UncatchableError> {
+        self.register_produced_state(&signed_call_state(call_result))
+    }
+
+    /// Checks that a call state met only in the current data is signed by the peer produced it,
+    /// that is the sender for a sent request and the peer executed the call otherwise.
+    /// Unsigned states of peers without any signature are accepted, unless signatures are required.
+    pub(crate) fn verify_call_state(
+        &self,
+        call_result: &CallResult,
+        call_peer_id: &str,
+    ) -> Result<(), UncatchableError> {
+        let producer_id = match call_result {
+            CallResult::RequestSentBy(Sender::PeerId(peer_id))
+            | CallResult::RequestSentBy(Sender::PeerIdWithCallId { peer_id, .. }) => peer_id.as_str(),
+            _ => call_peer_id,
+        };
+
+        self.signature_store
+            .verify_state(
+                producer_id,
+                &signed_call_state(call_result),
+                self.run_parameters.require_signatures,
+            )
+            .map_err(UncatchableError::SignatureVerificationFailed)
+    }
+}
+
+/// Generations of stream values are recalculated by each peer merging them,
+/// so call states are signed with a zero generation.
+fn signed_call_state(call_result: &CallResult) -> Cow<'_, CallResult> {
+    match call_result {
+        CallResult::Executed(ValueRef::Stream { cid, .. }) => Cow::Owned(CallResult::executed_stream(cid.clone(), 0)),
+        call_result => Cow::Borrowed(call_result),
+    }
 }
 
 impl ExecutionCtx<'_> {
@@ -142,6 +220,7 @@ pub(crate) struct ExecCtxIngredients {
     pub(crate) last_call_request_id: u32,
     pub(crate) restricted_streams: RestrictedStreamGens,
     pub(crate) cid_info: CidInfo,
+    pub(crate) signatures: SignatureStore,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -152,14 +231,23 @@ pub struct ExecutionCidState {
 }
 
 impl ExecutionCidState {
-    fn from_cid_info(prev_cid_info: CidInfo, current_cid_info: CidInfo) -> Self {
+    fn from_cid_info(prev_cid_info: CidInfo, current_cid_info: CidInfo, hash_algorithm: CidHashAlgorithm) -> Self {
         Self {
-            value_tracker: CidTracker::from_cid_stores(prev_cid_info.value_store, current_cid_info.value_store),
+            value_tracker: CidTracker::from_cid_stores(
+                prev_cid_info.value_store,
+                current_cid_info.value_store,
+                hash_algorithm,
+            ),
             tetraplet_tracker: CidTracker::from_cid_stores(
                 prev_cid_info.tetraplet_store,
                 current_cid_info.tetraplet_store,
+                hash_algorithm,
+            ),
+            canon_tracker: CidTracker::from_cid_stores(
+                prev_cid_info.canon_store,
+                current_cid_info.canon_store,
+                hash_algorithm,
             ),
-            canon_tracker: CidTracker::from_cid_stores(prev_cid_info.canon_store, current_cid_info.canon_store),
         }
     }
 
@@ -220,15 +308,25 @@ pub(crate) struct RcRunParameters {
     pub(crate) current_peer_id: Rc<String>,
     pub(crate) timestamp: u64,
     pub(crate) ttl: u32,
+    pub(crate) particle_id: Rc<String>,
+    pub(crate) current_timestamp: u64,
+    pub(crate) data_format: DataFormat,
+    pub(crate) compression_threshold: u32,
+    pub(crate) require_signatures: bool,
 }
 
 impl RcRunParameters {
-    pub(crate) fn from_run_parameters(run_parameters: RunParameters) -> Self {
+    pub(crate) fn from_run_parameters(run_parameters: RunParameters, data_format: DataFormat) -> Self {
         Self {
             init_peer_id: Rc::new(run_parameters.init_peer_id),
             current_peer_id: Rc::new(run_parameters.current_peer_id),
             timestamp: run_parameters.timestamp,
             ttl: run_parameters.ttl,
+            particle_id: Rc::new(run_parameters.particle_id),
+            current_timestamp: run_parameters.current_timestamp,
+            data_format,
+            compression_threshold: run_parameters.compression_threshold,
+            require_signatures: run_parameters.require_signatures,
         }
     }
 }
@@ -244,6 +342,14 @@ impl<'i> Display for ExecutionCtx<'i> {
         writeln!(f, "current peer id: {}", self.run_parameters.current_peer_id)?;
         writeln!(f, "init peer id: {}", self.run_parameters.init_peer_id)?;
         writeln!(f, "timestamp: {}", self.run_parameters.timestamp)?;
+        writeln!(f, "particle id: {}", self.run_parameters.particle_id)?;
+        writeln!(f, "current timestamp: {}", self.run_parameters.current_timestamp)?;
+        writeln!(f, "data format: {}", self.run_parameters.data_format)?;
+        writeln!(
+            f,
+            "compression threshold: {}",
+            self.run_parameters.compression_threshold
+        )?;
         writeln!(f, "subgraph complete: {}", self.subgraph_completeness)?;
         writeln!(f, "next peer public keys: {:?}", self.next_peer_pks)?;
 
@@ -34,6 +34,9 @@ pub const PEER_ID_FIELD_NAME: &str = "peer_id";
 ///  - it's accessed by %last_error% literal
 ///  - if it's unset before the usage, JValue::Null will be used without join behaviour
 ///  - it's a global scalar, meaning that fold and new scopes doesn't apply for it
+///
+/// The same struct represents an error accessed by %error%, which is bound only
+/// to the catching branch of a xor instruction.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LastError {
     /// Error object that represents the last occurred error.
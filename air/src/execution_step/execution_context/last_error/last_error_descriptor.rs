@@ -16,6 +16,8 @@
 
 use super::last_error_definition::error_from_raw_fields;
 use super::LastError;
+use crate::execution_step::CatchableError;
+use crate::execution_step::ExecutionError;
 use crate::execution_step::LastErrorAffectable;
 use crate::execution_step::RcSecurityTetraplet;
 use crate::JValue;
@@ -30,6 +32,16 @@ pub(crate) struct LastErrorDescriptor {
     /// whether an error is being bubbled up from the bottom or just encountered.
     /// This allows to write a simple code to handle bubbling error up.
     error_can_be_set: bool,
+
+    /// Errors caught by enclosing xor instructions, the last one belongs to the innermost
+    /// catching branch. Unlike the last error, a scoped error is visible only inside its
+    /// catching branch, so it can't be reset or clobbered by errors from other branches.
+    /// The first element is used outside of any catching branch.
+    scoped_errors: Vec<LastError>,
+
+    /// The latest catchable error that doesn't affect the last error together with
+    /// an instruction where it occurred, a catching xor uses it to build its scoped error.
+    unaffecting_error: Option<(Rc<CatchableError>, String)>,
 }
 
 impl LastErrorDescriptor {
@@ -55,6 +67,21 @@ impl LastErrorDescriptor {
         )
     }
 
+    /// Remembers an instruction where a catchable error that doesn't affect the last error
+    /// occurred. Such an error is bubbled up as the same Rc, so only the innermost, i.e. failing,
+    /// instruction is remembered.
+    pub(crate) fn meet_error(&mut self, error: &ExecutionError, instruction: &str) {
+        let error = match error {
+            ExecutionError::Catchable(error) if !error.affects_last_error() => error,
+            _ => return,
+        };
+
+        match &self.unaffecting_error {
+            Some((met_error, _)) if Rc::ptr_eq(met_error, error) => {}
+            _ => self.unaffecting_error = Some((error.clone(), instruction.to_string())),
+        }
+    }
+
     pub(crate) fn set_from_ingredients(
         &mut self,
         error_code: i64,
@@ -77,10 +104,44 @@ impl LastErrorDescriptor {
         &self.last_error
     }
 
+    pub(crate) fn scoped_error(&self) -> &LastError {
+        // the first element is never popped
+        self.scoped_errors.last().unwrap()
+    }
+
     pub(crate) fn meet_xor_right_branch(&mut self) {
         self.error_can_be_set = true;
     }
 
+    /// Binds the error caught by a xor to its catching branch.
+    pub(crate) fn enter_catching_branch(&mut self, error: &ExecutionError, peer_id: &str) {
+        let scoped_error = match error {
+            ExecutionError::Catchable(error) if !error.affects_last_error() => {
+                let instruction = match self.unaffecting_error.take() {
+                    Some((met_error, instruction)) if Rc::ptr_eq(&met_error, error) => instruction,
+                    _ => String::new(),
+                };
+                let error_object =
+                    error_from_raw_fields(error.to_error_code(), &error.to_string(), &instruction, peer_id);
+                LastError {
+                    error: Rc::new(error_object),
+                    tetraplet: None,
+                }
+            }
+            // such errors set the last error at the place where they occurred,
+            // so it contains precise instruction, peer id and tetraplet
+            _ => self.last_error.clone(),
+        };
+
+        self.scoped_errors.push(scoped_error);
+    }
+
+    pub(crate) fn leave_catching_branch(&mut self) {
+        if self.scoped_errors.len() > 1 {
+            self.scoped_errors.pop();
+        }
+    }
+
     pub(crate) fn meet_par_successed_end(&mut self) {
         self.error_can_be_set = true;
     }
@@ -94,8 +155,10 @@ impl Default for LastErrorDescriptor {
         };
 
         Self {
-            last_error,
+            last_error: last_error.clone(),
             error_can_be_set: true,
+            scoped_errors: vec![last_error],
+            unaffecting_error: None,
         }
     }
 }
@@ -17,6 +17,7 @@
 mod values_sparse_matrix;
 
 use crate::execution_step::boxed_value::CanonStream;
+use crate::execution_step::boxed_value::CanonStreamMap;
 use crate::execution_step::boxed_value::ScalarRef;
 use crate::execution_step::errors_prelude::*;
 use crate::execution_step::ExecutionResult;
@@ -88,6 +89,8 @@ pub(crate) struct Scalars<'i> {
 
     pub(crate) canon_streams: ValuesSparseMatrix<CanonStream>,
 
+    pub(crate) canon_stream_maps: ValuesSparseMatrix<CanonStreamMap>,
+
     pub(crate) iterable_variables: HashMap<String, FoldState<'i>>,
 }
 
@@ -96,6 +99,7 @@ impl<'i> Scalars<'i> {
         Self {
             non_iterable_variables: ValuesSparseMatrix::new(),
             canon_streams: ValuesSparseMatrix::new(),
+            canon_stream_maps: ValuesSparseMatrix::new(),
             iterable_variables: HashMap::new(),
         }
     }
@@ -112,6 +116,16 @@ impl<'i> Scalars<'i> {
         self.canon_streams.set_value(name, value)
     }
 
+    /// Returns true if there was a previous value for the provided key on the same
+    /// fold block.
+    pub(crate) fn set_canon_map_value(
+        &mut self,
+        name: impl Into<String>,
+        value: CanonStreamMap,
+    ) -> ExecutionResult<bool> {
+        self.canon_stream_maps.set_value(name, value)
+    }
+
     pub(crate) fn set_iterable_value(
         &mut self,
         name: impl Into<String>,
@@ -154,6 +168,12 @@ impl<'i> Scalars<'i> {
             .ok_or_else(|| CatchableError::VariableWasNotInitializedAfterNew(name.to_string()).into())
     }
 
+    pub(crate) fn get_canon_stream_map(&'i self, name: &str) -> ExecutionResult<&'i CanonStreamMap> {
+        self.canon_stream_maps
+            .get_value(name)?
+            .ok_or_else(|| CatchableError::VariableWasNotInitializedAfterNew(name.to_string()).into())
+    }
+
     pub(crate) fn get_value(&'i self, name: &str) -> ExecutionResult<ScalarRef<'i>> {
         let value = self.get_non_iterable_scalar(name);
         let iterable_value = self.iterable_variables.get(name);
@@ -170,28 +190,33 @@ impl<'i> Scalars<'i> {
     pub(crate) fn variable_could_be_set(&self, variable_name: &str) -> bool {
         self.non_iterable_variables.variable_could_be_set(variable_name)
             || self.canon_streams.variable_could_be_set(variable_name)
+            || self.canon_stream_maps.variable_could_be_set(variable_name)
     }
 
     pub(crate) fn meet_fold_start(&mut self) {
         self.non_iterable_variables.meet_fold_start();
         self.canon_streams.meet_fold_start();
+        self.canon_stream_maps.meet_fold_start();
     }
 
     // meet next before recursion
     pub(crate) fn meet_next_before(&mut self) {
         self.non_iterable_variables.meet_next_before();
         self.canon_streams.meet_next_before();
+        self.canon_stream_maps.meet_next_before();
     }
 
     // meet next after recursion
     pub(crate) fn meet_next_after(&mut self) {
         self.non_iterable_variables.meet_next_after();
         self.canon_streams.meet_next_after();
+        self.canon_stream_maps.meet_next_after();
     }
 
     pub(crate) fn meet_fold_end(&mut self) {
         self.non_iterable_variables.meet_fold_end();
         self.canon_streams.meet_fold_end();
+        self.canon_stream_maps.meet_fold_end();
     }
 
     pub(crate) fn meet_new_start_scalar(&mut self, scalar_name: String) {
@@ -202,6 +227,10 @@ impl<'i> Scalars<'i> {
         self.canon_streams.meet_new_start(canon_stream_name);
     }
 
+    pub(crate) fn meet_new_start_canon_stream_map(&mut self, canon_stream_map_name: String) {
+        self.canon_stream_maps.meet_new_start(canon_stream_map_name);
+    }
+
     pub(crate) fn meet_new_end_scalar(&mut self, scalar_name: &str) -> ExecutionResult<()> {
         self.non_iterable_variables.meet_new_end(scalar_name)
     }
@@ -209,6 +238,10 @@ impl<'i> Scalars<'i> {
     pub(crate) fn meet_new_end_canon_stream(&mut self, canon_name: &str) -> ExecutionResult<()> {
         self.canon_streams.meet_new_end(canon_name)
     }
+
+    pub(crate) fn meet_new_end_canon_stream_map(&mut self, canon_map_name: &str) -> ExecutionResult<()> {
+        self.canon_stream_maps.meet_new_end(canon_map_name)
+    }
 }
 
 impl Default for Scalars<'_> {
@@ -223,6 +256,7 @@ impl<'i> fmt::Display for Scalars<'i> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "scalars:\n{}", self.non_iterable_variables)?;
         writeln!(f, "canon_streams:\n{}", self.canon_streams)?;
+        writeln!(f, "canon_stream_maps:\n{}", self.canon_stream_maps)?;
 
         for (name, _) in self.iterable_variables.iter() {
             // it's impossible to print an iterable value for now
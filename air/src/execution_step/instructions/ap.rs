@@ -27,9 +27,10 @@ use crate::log_instruction;
 use crate::trace_to_exec_err;
 use crate::JValue;
 use crate::SecurityTetraplet;
-use apply_to_arguments::*;
 use utils::*;
 
+pub(super) use apply_to_arguments::apply_to_arg;
+
 use air_parser::ast;
 use air_parser::ast::Ap;
 use air_trace_handler::merger::MergerApResult;
@@ -15,12 +15,13 @@
  */
 
 use super::*;
+use crate::execution_step::CatchableError;
 use crate::execution_step::PEEK_ALLOWED_ON_NON_EMPTY;
 
 use air_lambda_parser::LambdaAST;
 use air_parser::ast;
 
-pub(super) fn apply_to_arg(
+pub(crate) fn apply_to_arg(
     argument: &ast::ApArgument<'_>,
     exec_ctx: &ExecutionCtx<'_>,
     trace_ctx: &TraceHandler,
@@ -31,16 +32,22 @@ pub(super) fn apply_to_arg(
     let result = match argument {
         InitPeerId => apply_const(exec_ctx.run_parameters.init_peer_id.as_str(), exec_ctx, trace_ctx),
         LastError(error_accessor) => apply_last_error(error_accessor, exec_ctx, trace_ctx)?,
+        Error(error_accessor) => apply_scoped_error(error_accessor, exec_ctx, trace_ctx)?,
         Literal(value) => apply_const(*value, exec_ctx, trace_ctx),
         Timestamp => apply_const(exec_ctx.run_parameters.timestamp, exec_ctx, trace_ctx),
         TTL => apply_const(exec_ctx.run_parameters.ttl, exec_ctx, trace_ctx),
+        ParticleId => apply_particle_id(exec_ctx, trace_ctx)?,
         Number(value) => apply_const(value, exec_ctx, trace_ctx),
         Boolean(value) => apply_const(*value, exec_ctx, trace_ctx),
         EmptyArray => apply_const(serde_json::json!([]), exec_ctx, trace_ctx),
+        Array(values) => apply_array(values, exec_ctx, trace_ctx)?,
+        Object(entries) => apply_object(entries, exec_ctx, trace_ctx)?,
         Scalar(scalar) => apply_scalar(scalar, exec_ctx, trace_ctx, should_touch_trace)?,
         ScalarWithLambda(scalar) => apply_scalar_wl(scalar, exec_ctx, trace_ctx)?,
         CanonStream(canon_stream) => apply_canon_stream(canon_stream, exec_ctx, trace_ctx)?,
         CanonStreamWithLambda(canon_stream) => apply_canon_stream_wl(canon_stream, exec_ctx, trace_ctx)?,
+        CanonStreamMap(canon_stream_map) => apply_canon_stream_map(canon_stream_map, exec_ctx, trace_ctx)?,
+        CanonStreamMapWithLambda(canon_stream_map) => apply_canon_stream_map_wl(canon_stream_map, exec_ctx, trace_ctx)?,
     };
 
     Ok(result)
@@ -54,6 +61,55 @@ fn apply_const(value: impl Into<JValue>, exec_ctx: &ExecutionCtx<'_>, trace_ctx:
     ValueAggregate::new(value, tetraplet, trace_ctx.trace_pos())
 }
 
+fn apply_particle_id(exec_ctx: &ExecutionCtx<'_>, trace_ctx: &TraceHandler) -> ExecutionResult<ValueAggregate> {
+    use crate::execution_step::resolver::{prepare_host_value, PARTICLE_ID};
+
+    let particle_id = exec_ctx.run_parameters.particle_id.as_str();
+    let (value, mut tetraplets) = prepare_host_value(particle_id, PARTICLE_ID, exec_ctx)?;
+    // removing is safe because prepare_host_value always returns a vec with one element.
+    let tetraplet = tetraplets.remove(0);
+
+    Ok(ValueAggregate::new(Rc::new(value), tetraplet, trace_ctx.trace_pos()))
+}
+
+fn apply_array<'i>(
+    values: &[ast::ImmutableValue<'i>],
+    exec_ctx: &ExecutionCtx<'i>,
+    trace_ctx: &TraceHandler,
+) -> ExecutionResult<ValueAggregate> {
+    values.iter().try_for_each(check_literal)?;
+
+    let (value, _) = crate::execution_step::resolver::resolve_array(values, exec_ctx)?;
+    Ok(apply_const(value, exec_ctx, trace_ctx))
+}
+
+fn apply_object<'i>(
+    entries: &[ast::ObjectEntry<'i>],
+    exec_ctx: &ExecutionCtx<'i>,
+    trace_ctx: &TraceHandler,
+) -> ExecutionResult<ValueAggregate> {
+    entries.iter().try_for_each(|entry| check_literal(&entry.value))?;
+
+    let (value, _) = crate::execution_step::resolver::resolve_object(entries, exec_ctx)?;
+    Ok(apply_const(value, exec_ctx, trace_ctx))
+}
+
+/// A value aggregate keeps only one tetraplet, so an array or object literal applied by ap
+/// gets the literal one and must not contain values produced by services or hosts,
+/// otherwise their provenance would be lost.
+fn check_literal(value: &ast::ImmutableValue<'_>) -> ExecutionResult<()> {
+    use ast::ImmutableValue::*;
+
+    match value {
+        InitPeerId | Timestamp | TTL | Literal(_) | Number(_) | Boolean(_) | EmptyArray => Ok(()),
+        Array(values) => values.iter().try_for_each(check_literal),
+        Object(entries) => entries.iter().try_for_each(|entry| check_literal(&entry.value)),
+        LastError(_) | Error(_) | ParticleId | Variable(_) | VariableWithLambda(_) => {
+            Err(CatchableError::ApLiteralWithNonLiteralElement(value.to_string()).into())
+        }
+    }
+}
+
 fn apply_last_error<'i>(
     error_accessor: &Option<LambdaAST<'i>>,
     exec_ctx: &ExecutionCtx<'i>,
@@ -68,6 +124,20 @@ fn apply_last_error<'i>(
     Ok(result)
 }
 
+fn apply_scoped_error<'i>(
+    error_accessor: &Option<LambdaAST<'i>>,
+    exec_ctx: &ExecutionCtx<'i>,
+    trace_ctx: &TraceHandler,
+) -> ExecutionResult<ValueAggregate> {
+    let (value, mut tetraplets) = crate::execution_step::resolver::prepare_scoped_error(error_accessor, exec_ctx)?;
+    let value = Rc::new(value);
+    // removing is safe because prepare_scoped_error always returns a vec with one element.
+    let tetraplet = tetraplets.remove(0);
+
+    let result = ValueAggregate::new(value, tetraplet, trace_ctx.trace_pos());
+    Ok(result)
+}
+
 fn apply_scalar(
     ast_scalar: &ast::Scalar<'_>,
     exec_ctx: &ExecutionCtx<'_>,
@@ -136,3 +206,28 @@ fn apply_canon_stream_wl(
     let value = ValueAggregate::new(Rc::new(result.into_owned()), Rc::new(tetraplet), position);
     Ok(value)
 }
+
+fn apply_canon_stream_map(
+    ast_stream_map: &ast::CanonStreamMap<'_>,
+    exec_ctx: &ExecutionCtx<'_>,
+    trace_ctx: &TraceHandler,
+) -> ExecutionResult<ValueAggregate> {
+    let canon_stream_map = exec_ctx.scalars.get_canon_stream_map(ast_stream_map.name)?;
+    let value = canon_stream_map.as_jvalue();
+    let tetraplet = canon_stream_map.tetraplet().clone();
+    let position = trace_ctx.trace_pos();
+    let value = ValueAggregate::new(Rc::new(value), tetraplet, position);
+    Ok(value)
+}
+
+fn apply_canon_stream_map_wl(
+    ast_stream_map: &ast::CanonStreamMapWithLambda<'_>,
+    exec_ctx: &ExecutionCtx<'_>,
+    trace_ctx: &TraceHandler,
+) -> ExecutionResult<ValueAggregate> {
+    let variable = Variable::canon_stream_map(ast_stream_map.name);
+    let (jvalue, tetraplet) = apply_lambda(variable, &ast_stream_map.lambda, exec_ctx)?;
+    let position = trace_ctx.trace_pos();
+    let value = ValueAggregate::new(Rc::new(jvalue), Rc::new(tetraplet), position);
+    Ok(value)
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::ap::apply_to_arg;
+use super::ExecutionCtx;
+use super::ExecutionResult;
+use super::TraceHandler;
+use crate::execution_step::boxed_value::stream_map_entry;
+use crate::execution_step::execution_context::StreamValueDescriptor;
+use crate::execution_step::resolver;
+use crate::execution_step::CatchableError;
+use crate::execution_step::Generation;
+use crate::execution_step::ValueAggregate;
+use crate::log_instruction;
+use crate::trace_to_exec_err;
+use crate::JValue;
+
+use air_interpreter_data::ApResult;
+use air_parser::ast;
+use air_parser::ast::ApMap;
+use air_trace_handler::merger::MergerApResult;
+use air_trace_handler::merger::ValueSource;
+
+use std::rc::Rc;
+
+impl<'i> super::ExecutableInstruction<'i> for ApMap<'i> {
+    #[tracing::instrument(level = "debug", skip(exec_ctx, trace_ctx))]
+    fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
+        log_instruction!(ap, exec_ctx, trace_ctx);
+        // key and value should be resolved before touching a trace by the same reasons as for ap
+        let key = resolve_key(&self.key, exec_ctx)?;
+        let value = apply_to_arg(&self.value, exec_ctx, trace_ctx, true)?;
+
+        let merger_ap_result = trace_to_exec_err!(trace_ctx.meet_ap_map_start(), self)?;
+        let entry = stream_map_entry(&key, &value.result);
+        let entry = ValueAggregate::new(Rc::new(entry), value.tetraplet, value.trace_pos);
+
+        let value_descriptor = generate_value_descriptor(entry, &self.map, &merger_ap_result);
+        let generation = exec_ctx.streams.add_stream_value(value_descriptor)?;
+        trace_ctx.meet_ap_map_end(ApResult::new(generation));
+
+        Ok(())
+    }
+}
+
+fn resolve_key(key: &ast::StreamMapKeyClause<'_>, exec_ctx: &ExecutionCtx<'_>) -> ExecutionResult<String> {
+    use ast::StreamMapKeyClause::*;
+
+    let (key, _) = match key {
+        Literal(key) => return Ok(key.to_string()),
+        Int(key) => return Ok(key.to_string()),
+        Scalar(scalar) => resolver::resolve_ast_scalar(scalar, exec_ctx)?,
+        ScalarWithLambda(scalar) => resolver::resolve_ast_scalar_wl(scalar, exec_ctx)?,
+        CanonStreamWithLambda(canon_stream) => resolver::resolve_ast_canon_wl(canon_stream, exec_ctx)?,
+    };
+
+    match key {
+        JValue::String(key) => Ok(key),
+        JValue::Number(number) if number.is_i64() || number.is_u64() => Ok(number.to_string()),
+        key => Err(CatchableError::StreamMapKeyHasInvalidType(key).into()),
+    }
+}
+
+fn generate_value_descriptor<'map>(
+    value: ValueAggregate,
+    map: &'map ast::StreamMap<'_>,
+    ap_result: &MergerApResult,
+) -> StreamValueDescriptor<'map> {
+    match ap_result {
+        MergerApResult::NotMet => StreamValueDescriptor::new(
+            value,
+            map.name,
+            ValueSource::PreviousData,
+            Generation::Last,
+            map.position,
+        ),
+        MergerApResult::Met(met_result) => StreamValueDescriptor::new(
+            value,
+            map.name,
+            met_result.value_source,
+            Generation::Nth(met_result.generation),
+            map.position,
+        ),
+    }
+}
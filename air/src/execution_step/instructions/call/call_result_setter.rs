@@ -18,8 +18,10 @@ use super::*;
 use crate::execution_step::execution_context::*;
 use crate::execution_step::Generation;
 use crate::execution_step::ValueAggregate;
+use crate::JValue;
 use crate::UncatchableError;
 
+use air_interpreter_cid::CID;
 use air_interpreter_data::CallResult;
 use air_interpreter_data::TracePos;
 use air_interpreter_data::ValueRef;
@@ -32,18 +34,14 @@ pub(crate) fn populate_context_from_peer_service_result<'i>(
     output: &CallOutputValue<'i>,
     exec_ctx: &mut ExecutionCtx<'i>,
 ) -> ExecutionResult<CallResult> {
-    let cid = exec_ctx
-        .cid_state
-        .value_tracker
-        .record_value(executed_result.result.clone())
-        .map_err(UncatchableError::from)?;
-
-    match output {
+    let call_result = match output {
         CallOutputValue::Scalar(scalar) => {
+            let cid = record_value(&executed_result, exec_ctx)?;
             exec_ctx.scalars.set_scalar_value(scalar.name, executed_result)?;
-            Ok(CallResult::executed_scalar(cid))
+            CallResult::executed_scalar(cid)
         }
         CallOutputValue::Stream(stream) => {
+            let cid = record_value(&executed_result, exec_ctx)?;
             let value_descriptor = StreamValueDescriptor::new(
                 executed_result,
                 stream.name,
@@ -52,12 +50,31 @@ pub(crate) fn populate_context_from_peer_service_result<'i>(
                 stream.position,
             );
             let generation = exec_ctx.streams.add_stream_value(value_descriptor)?;
-            Ok(CallResult::executed_stream(cid, generation))
+            CallResult::executed_stream(cid, generation)
         }
-        // by the internal conventions if call has no output value,
-        // corresponding data should have scalar type
-        CallOutputValue::None => Ok(CallResult::executed_scalar(cid)),
-    }
+        // a result of a call without an output value can't be used anywhere,
+        // so only its CID is saved to check it while merging
+        CallOutputValue::None => {
+            let cid = exec_ctx
+                .cid_state
+                .value_tracker
+                .calculate_cid(&executed_result.result)
+                .map_err(UncatchableError::from)?;
+            CallResult::unused(cid)
+        }
+    };
+    exec_ctx.register_produced_call(&call_result)?;
+
+    Ok(call_result)
+}
+
+fn record_value(executed_result: &ValueAggregate, exec_ctx: &mut ExecutionCtx<'_>) -> ExecutionResult<Rc<CID<JValue>>> {
+    let cid = exec_ctx
+        .cid_state
+        .value_tracker
+        .record_value(executed_result.result.clone())
+        .map_err(UncatchableError::from)?;
+    Ok(cid)
 }
 
 pub(crate) fn populate_context_from_data<'i>(
@@ -93,20 +110,40 @@ pub(crate) fn populate_context_from_data<'i>(
             };
             Ok(result)
         }
-        // by the internal conventions if call has no output value,
-        // corresponding data should have scalar type
+        // data produced before unused call results were introduced
+        // contains scalars for calls without an output value
         (CallOutputValue::None, value @ ValueRef::Scalar(_)) => Ok(value),
         (_, value) => Err(ExecutionError::Uncatchable(
-            UncatchableError::CallResultNotCorrespondToInstr(value),
+            UncatchableError::CallResultNotCorrespondToInstr(CallResult::Executed(value)),
+        )),
+    }
+}
+
+/// Checks that an unused call result from data corresponds to a call without an output value.
+pub(crate) fn check_unused_call_result(
+    cid: Rc<CID<JValue>>,
+    output: &CallOutputValue<'_>,
+) -> ExecutionResult<CallResult> {
+    match output {
+        CallOutputValue::None => Ok(CallResult::unused(cid)),
+        _ => Err(ExecutionError::Uncatchable(
+            UncatchableError::CallResultNotCorrespondToInstr(CallResult::unused(cid)),
         )),
     }
 }
 
 /// Writes an executed state of a particle being sent to remote node.
-pub(crate) fn handle_remote_call(peer_pk: String, exec_ctx: &mut ExecutionCtx<'_>, trace_ctx: &mut TraceHandler) {
+pub(crate) fn handle_remote_call(
+    peer_pk: String,
+    exec_ctx: &mut ExecutionCtx<'_>,
+    trace_ctx: &mut TraceHandler,
+) -> ExecutionResult<()> {
     exec_ctx.next_peer_pks.push(peer_pk);
     exec_ctx.make_subgraph_incomplete();
 
     let new_call_result = CallResult::sent_peer_id(exec_ctx.run_parameters.current_peer_id.clone());
+    exec_ctx.register_produced_call(&new_call_result)?;
     trace_ctx.meet_call_end(new_call_result);
+
+    Ok(())
 }
@@ -15,6 +15,7 @@
  */
 
 use super::*;
+use crate::execution_step::instructions::call::call_result_setter::check_unused_call_result;
 use crate::execution_step::instructions::call::call_result_setter::populate_context_from_data;
 use crate::execution_step::CatchableError;
 use crate::execution_step::RcSecurityTetraplet;
@@ -24,6 +25,7 @@ use air_interpreter_data::Sender;
 use air_interpreter_interface::CallServiceResult;
 use air_parser::ast::CallOutputValue;
 use air_trace_handler::merger::MetCallResult;
+use air_trace_handler::merger::ValueSource;
 use air_trace_handler::TraceHandler;
 
 use fstrings::f;
@@ -46,6 +48,11 @@ pub(super) fn handle_prev_state<'i>(
 ) -> ExecutionResult<StateDescriptor> {
     use CallResult::*;
 
+    // states from the prev data are trusted, the same as for CIDs
+    if let ValueSource::CurrentData = met_result.source {
+        exec_ctx.verify_call_state(&met_result.result, &tetraplet.peer_pk)?;
+    }
+
     match met_result.result {
         // this call was failed on one of the previous executions,
         // here it's needed to bubble this special error up
@@ -94,6 +101,13 @@ pub(super) fn handle_prev_state<'i>(
             let call_result = CallResult::Executed(resulted_value);
             trace_ctx.meet_call_end(call_result);
 
+            Ok(StateDescriptor::executed())
+        }
+        // this instruction without an output value's been already executed
+        Unused(cid) => {
+            let call_result = check_unused_call_result(cid, output)?;
+            trace_ctx.meet_call_end(call_result);
+
             Ok(StateDescriptor::executed())
         }
     }
@@ -111,9 +125,9 @@ fn update_state_with_service_result<'i>(
     trace_ctx: &mut TraceHandler,
 ) -> ExecutionResult<()> {
     // check that service call succeeded
-    let service_result = handle_service_error(service_result, trace_ctx)?;
+    let service_result = handle_service_error(service_result, exec_ctx, trace_ctx)?;
     // try to get service result from call service result
-    let result = try_to_service_result(service_result, trace_ctx)?;
+    let result = try_to_service_result(service_result, exec_ctx, trace_ctx)?;
 
     let trace_pos = trace_ctx.trace_pos();
 
@@ -126,6 +140,7 @@ fn update_state_with_service_result<'i>(
 
 fn handle_service_error(
     service_result: CallServiceResult,
+    exec_ctx: &mut ExecutionCtx<'_>,
     trace_ctx: &mut TraceHandler,
 ) -> ExecutionResult<CallServiceResult> {
     use air_interpreter_interface::CALL_SERVICE_SUCCESS;
@@ -138,13 +153,16 @@ fn handle_service_error(
     let error_message = Rc::new(service_result.result);
     let error = CatchableError::LocalServiceError(service_result.ret_code, error_message.clone());
 
-    trace_ctx.meet_call_end(CallServiceFailed(service_result.ret_code, error_message));
+    let new_call_result = CallServiceFailed(service_result.ret_code, error_message);
+    exec_ctx.register_produced_call(&new_call_result)?;
+    trace_ctx.meet_call_end(new_call_result);
 
     Err(error.into())
 }
 
 fn try_to_service_result(
     service_result: CallServiceResult,
+    exec_ctx: &mut ExecutionCtx<'_>,
     trace_ctx: &mut TraceHandler,
 ) -> ExecutionResult<Rc<JValue>> {
     use CallResult::CallServiceFailed;
@@ -156,8 +174,9 @@ fn try_to_service_result(
                 f!("call_service result '{service_result}' can't be serialized or deserialized with an error: {e}");
             let error_msg = Rc::new(error_msg);
 
-            let error = CallServiceFailed(i32::MAX, error_msg.clone());
-            trace_ctx.meet_call_end(error);
+            let new_call_result = CallServiceFailed(i32::MAX, error_msg.clone());
+            exec_ctx.register_produced_call(&new_call_result)?;
+            trace_ctx.meet_call_end(new_call_result);
 
             Err(CatchableError::LocalServiceError(i32::MAX, error_msg).into())
         }
@@ -91,8 +91,7 @@ impl<'i> ResolvedCall<'i> {
         // call can be executed only on peers with such peer_id
         let tetraplet = &self.tetraplet;
         if tetraplet.peer_pk.as_str() != exec_ctx.run_parameters.current_peer_id.as_str() {
-            handle_remote_call(tetraplet.peer_pk.clone(), exec_ctx, trace_ctx);
-            return Ok(());
+            return handle_remote_call(tetraplet.peer_pk.clone(), exec_ctx, trace_ctx);
         }
 
         let request_params = match self.prepare_request_params(exec_ctx, tetraplet) {
@@ -110,10 +109,10 @@ impl<'i> ResolvedCall<'i> {
         exec_ctx.call_requests.insert(call_id, request_params);
 
         exec_ctx.make_subgraph_incomplete();
-        trace_ctx.meet_call_end(CallResult::sent_peer_id_with_call_id(
-            exec_ctx.run_parameters.current_peer_id.clone(),
-            call_id,
-        ));
+        let new_call_result =
+            CallResult::sent_peer_id_with_call_id(exec_ctx.run_parameters.current_peer_id.clone(), call_id);
+        exec_ctx.register_produced_call(&new_call_result)?;
+        trace_ctx.meet_call_end(new_call_result);
 
         Ok(())
     }
@@ -156,7 +155,7 @@ impl<'i> ResolvedCall<'i> {
         exec_ctx: &mut ExecutionCtx<'i>,
         trace_ctx: &mut TraceHandler,
     ) -> ExecutionResult<StateDescriptor> {
-        let prev_result = trace_ctx.meet_call_start();
+        let prev_result = trace_ctx.meet_call_start(&exec_ctx.cid_state.value_tracker);
         match trace_to_exec_err!(prev_result, raw_call)? {
             MergerCallResult::Met(call_result) => {
                 handle_prev_state(call_result, &self.tetraplet, &self.output, exec_ctx, trace_ctx)
@@ -27,6 +27,7 @@ use air_interpreter_cid::CID;
 use air_interpreter_data::CanonCidAggregate;
 use air_interpreter_data::CanonResult;
 use air_parser::ast;
+use air_parser::AirPos;
 use air_trace_handler::merger::MergerCanonResult;
 use polyplets::SecurityTetraplet;
 
@@ -55,20 +56,7 @@ fn handle_seen_canon(
     exec_ctx: &mut ExecutionCtx<'_>,
     trace_ctx: &mut TraceHandler,
 ) -> ExecutionResult<()> {
-    let tetraplet = exec_ctx.cid_state.get_tetraplet_by_cid(&tetraplet_cid)?;
-    let values = value_cids
-        .iter()
-        .map(|canon_value_cid| exec_ctx.cid_state.get_canon_value_by_cid(canon_value_cid))
-        .collect::<Result<Vec<_>, _>>()?;
-
-    let canon_stream = CanonStream::new(values, tetraplet);
-
-    let canon_stream_with_se = StreamWithSerializedView {
-        canon_stream,
-        tetraplet_cid,
-        value_cids,
-    };
-
+    let canon_stream_with_se = canon_stream_from_cids(tetraplet_cid, value_cids, exec_ctx)?;
     epilog(ast_canon.canon_stream.name, canon_stream_with_se, exec_ctx, trace_ctx)
 }
 
@@ -115,10 +103,32 @@ fn epilog(
     Ok(())
 }
 
-struct StreamWithSerializedView {
-    canon_stream: CanonStream,
+pub(super) struct StreamWithSerializedView {
+    pub(super) canon_stream: CanonStream,
+    pub(super) tetraplet_cid: Rc<CID<SecurityTetraplet>>,
+    pub(super) value_cids: Vec<Rc<CID<CanonCidAggregate>>>,
+}
+
+pub(super) fn canon_stream_from_cids(
     tetraplet_cid: Rc<CID<SecurityTetraplet>>,
     value_cids: Vec<Rc<CID<CanonCidAggregate>>>,
+    exec_ctx: &ExecutionCtx<'_>,
+) -> ExecutionResult<StreamWithSerializedView> {
+    let tetraplet = exec_ctx.cid_state.get_tetraplet_by_cid(&tetraplet_cid)?;
+    let values = value_cids
+        .iter()
+        .map(|canon_value_cid| exec_ctx.cid_state.get_canon_value_by_cid(canon_value_cid))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let canon_stream = CanonStream::new(values, tetraplet);
+
+    let result = StreamWithSerializedView {
+        canon_stream,
+        tetraplet_cid,
+        value_cids,
+    };
+
+    Ok(result)
 }
 
 fn create_canon_stream_from_name(
@@ -126,10 +136,15 @@ fn create_canon_stream_from_name(
     peer_id: String,
     exec_ctx: &mut ExecutionCtx<'_>,
 ) -> ExecutionResult<StreamWithSerializedView> {
-    let stream = get_stream_or_default(ast_canon, exec_ctx);
-
+    let stream = get_stream_or_default(ast_canon.stream.name, ast_canon.stream.position, exec_ctx);
     let canon_stream = CanonStream::from_stream(stream.as_ref(), peer_id);
+    record_canon_stream(canon_stream, exec_ctx)
+}
 
+pub(super) fn record_canon_stream(
+    canon_stream: CanonStream,
+    exec_ctx: &mut ExecutionCtx<'_>,
+) -> ExecutionResult<StreamWithSerializedView> {
     let value_cids = canon_stream
         .iter()
         .map(|val| -> Result<_, UncatchableError> {
@@ -142,12 +157,13 @@ fn create_canon_stream_from_name(
             };
             Ok(exec_ctx.cid_state.canon_tracker.record_value(canon_value_aggregate)?)
         })
-        .collect::<Result<_, _>>()?;
+        .collect::<Result<Vec<_>, _>>()?;
     let tetraplet_cid = exec_ctx
         .cid_state
         .tetraplet_tracker
         .record_value(canon_stream.tetraplet().clone())
         .map_err(UncatchableError::from)?;
+    exec_ctx.register_produced_state(&CanonResult::new(tetraplet_cid.clone(), value_cids.clone()))?;
 
     let result = StreamWithSerializedView {
         canon_stream,
@@ -161,7 +177,11 @@ fn create_canon_stream_from_name(
 /// This function gets a stream from context or return a default empty stream,
 /// it's crucial for deterministic behaviour, for more info see
 /// github.com/fluencelabs/aquavm/issues/346
-fn get_stream_or_default<'ctx>(ast_canon: &ast::Canon<'_>, exec_ctx: &'ctx ExecutionCtx<'_>) -> Cow<'ctx, Stream> {
-    let maybe_stream = exec_ctx.streams.get(ast_canon.stream.name, ast_canon.stream.position);
+pub(super) fn get_stream_or_default<'ctx>(
+    name: &str,
+    position: AirPos,
+    exec_ctx: &'ctx ExecutionCtx<'_>,
+) -> Cow<'ctx, Stream> {
+    let maybe_stream = exec_ctx.streams.get(name, position);
     maybe_stream.map(Cow::Borrowed).unwrap_or_default()
 }
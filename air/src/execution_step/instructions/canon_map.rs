@@ -0,0 +1,90 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::canon::canon_stream_from_cids;
+use super::canon::get_stream_or_default;
+use super::canon::record_canon_stream;
+use super::canon::StreamWithSerializedView;
+use super::ExecutionCtx;
+use super::ExecutionResult;
+use super::TraceHandler;
+use crate::execution_step::boxed_value::CanonStream;
+use crate::execution_step::boxed_value::CanonStreamMap;
+use crate::log_instruction;
+use crate::trace_to_exec_err;
+
+use air_interpreter_data::CanonResult;
+use air_parser::ast;
+use air_trace_handler::merger::MergerCanonResult;
+
+impl<'i> super::ExecutableInstruction<'i> for ast::CanonMap<'i> {
+    #[tracing::instrument(level = "debug", skip(exec_ctx, trace_ctx))]
+    fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
+        log_instruction!(canon, exec_ctx, trace_ctx);
+        let canon_result = trace_to_exec_err!(trace_ctx.meet_canon_map_start(), self)?;
+
+        match canon_result {
+            MergerCanonResult::CanonResult { tetraplet, values } => {
+                let canon_map_with_se = canon_stream_from_cids(tetraplet, values, exec_ctx)?;
+                epilog(self, canon_map_with_se, exec_ctx, trace_ctx)
+            }
+            MergerCanonResult::Empty => handle_unseen_canon_map(self, exec_ctx, trace_ctx),
+        }
+    }
+}
+
+fn handle_unseen_canon_map(
+    ast_canon_map: &ast::CanonMap<'_>,
+    exec_ctx: &mut ExecutionCtx<'_>,
+    trace_ctx: &mut TraceHandler,
+) -> ExecutionResult<()> {
+    let peer_id = crate::execution_step::instructions::resolve_peer_id_to_string(&ast_canon_map.peer_id, exec_ctx)?;
+
+    if exec_ctx.run_parameters.current_peer_id.as_str() != peer_id {
+        // see the corresponding comment in canon.rs
+        exec_ctx.make_subgraph_incomplete();
+        exec_ctx.next_peer_pks.push(peer_id);
+        return Ok(());
+    }
+
+    let stream_map = &ast_canon_map.stream_map;
+    let stream = get_stream_or_default(stream_map.name, stream_map.position, exec_ctx);
+    let canon_stream = CanonStream::from_stream(stream.as_ref(), peer_id);
+    let canon_map_with_se = record_canon_stream(canon_stream, exec_ctx)?;
+
+    epilog(ast_canon_map, canon_map_with_se, exec_ctx, trace_ctx)
+}
+
+fn epilog(
+    ast_canon_map: &ast::CanonMap<'_>,
+    canon_map_with_se: StreamWithSerializedView,
+    exec_ctx: &mut ExecutionCtx<'_>,
+    trace_ctx: &mut TraceHandler,
+) -> ExecutionResult<()> {
+    let StreamWithSerializedView {
+        canon_stream,
+        tetraplet_cid,
+        value_cids,
+    } = canon_map_with_se;
+
+    let canon_stream_map = CanonStreamMap::from_canon_stream(canon_stream);
+    exec_ctx
+        .scalars
+        .set_canon_map_value(ast_canon_map.canon_stream_map.name, canon_stream_map)
+        .map(|_| ())?;
+    trace_ctx.meet_canon_map_end(CanonResult::new(tetraplet_cid, value_cids));
+    Ok(())
+}
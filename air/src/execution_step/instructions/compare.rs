@@ -0,0 +1,59 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::compare_matchable::compare_matchables;
+use super::ExecutionCtx;
+use super::ExecutionResult;
+use super::TraceHandler;
+use crate::execution_step::CatchableError;
+use crate::execution_step::Joinable;
+use crate::joinable;
+use crate::log_instruction;
+
+use air_parser::ast::Compare;
+use air_parser::ast::ComparisonOperator;
+
+use std::cmp::Ordering;
+
+impl<'i> super::ExecutableInstruction<'i> for Compare<'i> {
+    fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
+        log_instruction!(compare, exec_ctx, trace_ctx);
+        exec_ctx.tracker.meet_compare();
+
+        let ordering = joinable!(
+            compare_matchables(&self.left_value, &self.right_value, exec_ctx),
+            exec_ctx,
+            ()
+        )?;
+
+        if !is_satisfied(self.operator, ordering) {
+            return Err(CatchableError::ComparisonNotSatisfied(self.operator).into());
+        }
+
+        self.instruction.execute(exec_ctx, trace_ctx)
+    }
+}
+
+fn is_satisfied(operator: ComparisonOperator, ordering: Ordering) -> bool {
+    use ComparisonOperator::*;
+
+    match operator {
+        Less => ordering.is_lt(),
+        LessOrEqual => ordering.is_le(),
+        Greater => ordering.is_gt(),
+        GreaterOrEqual => ordering.is_ge(),
+    }
+}
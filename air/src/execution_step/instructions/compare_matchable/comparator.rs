@@ -17,13 +17,17 @@
 use crate::execution_step::execution_context::ExecutionCtx;
 use crate::execution_step::instructions::ExecutionResult;
 use crate::execution_step::resolver::prepare_last_error;
+use crate::execution_step::resolver::prepare_scoped_error;
 use crate::execution_step::resolver::resolve_ast_variable;
 use crate::execution_step::resolver::resolve_ast_variable_wl;
+use crate::execution_step::resolver::resolve_to_args;
+use crate::execution_step::CatchableError;
 use crate::JValue;
 
 use air_parser::ast;
 
 use std::borrow::Cow;
+use std::cmp::Ordering;
 
 #[tracing::instrument(skip_all)]
 pub(crate) fn are_matchable_eq<'ctx>(
@@ -47,6 +51,11 @@ pub(crate) fn are_matchable_eq<'ctx>(
             compare_matchable(matchable, exec_ctx, make_object_comparator(value))
         }
 
+        (Error(error_accessor), matchable) | (matchable, Error(error_accessor)) => {
+            let (value, _) = prepare_scoped_error(error_accessor, exec_ctx)?;
+            compare_matchable(matchable, exec_ctx, make_object_comparator(value))
+        }
+
         (Literal(left_name), Literal(right_name)) => Ok(left_name == right_name),
         (Literal(value), matchable) | (matchable, Literal(value)) => {
             compare_matchable(matchable, exec_ctx, make_string_comparator(value))
@@ -59,6 +68,13 @@ pub(crate) fn are_matchable_eq<'ctx>(
             make_object_comparator(JValue::Number(exec_ctx.run_parameters.timestamp.into())),
         ),
 
+        (ParticleId, ParticleId) => Ok(true),
+        (ParticleId, matchable) | (matchable, ParticleId) => compare_matchable(
+            matchable,
+            exec_ctx,
+            make_string_comparator(exec_ctx.run_parameters.particle_id.as_str()),
+        ),
+
         (TTL, TTL) => Ok(true),
         (TTL, matchable) | (matchable, TTL) => compare_matchable(
             matchable,
@@ -71,6 +87,11 @@ pub(crate) fn are_matchable_eq<'ctx>(
             compare_matchable(matchable, exec_ctx, make_object_comparator(JValue::Array(vec![])))
         }
 
+        (literal @ (Array(_) | Object(_)), matchable) | (matchable, literal @ (Array(_) | Object(_))) => {
+            let (value, _) = resolve_to_args(literal, exec_ctx)?;
+            compare_matchable(matchable, exec_ctx, make_object_comparator(value))
+        }
+
         (Boolean(left_boolean), Boolean(right_boolean)) => Ok(left_boolean == right_boolean),
         (Boolean(value), matchable) | (matchable, Boolean(value)) => {
             compare_matchable(matchable, exec_ctx, make_object_comparator((*value).into()))
@@ -111,6 +132,58 @@ pub(crate) fn are_matchable_eq<'ctx>(
     }
 }
 
+/// Orders two matchable values, numbers are compared numerically and strings lexicographically.
+#[tracing::instrument(skip_all)]
+pub(crate) fn compare_matchables(
+    left: &ast::ImmutableValue<'_>,
+    right: &ast::ImmutableValue<'_>,
+    exec_ctx: &ExecutionCtx<'_>,
+) -> ExecutionResult<Ordering> {
+    let left = resolve_matchable(left, exec_ctx)?;
+    let right = resolve_matchable(right, exec_ctx)?;
+
+    match compare_jvalues(&left, &right) {
+        Some(ordering) => Ok(ordering),
+        None => Err(CatchableError::IncomparableValues { left, right }.into()),
+    }
+}
+
+/// Checks whether a collection, which should be an array or a canon stream, contains a value.
+#[tracing::instrument(skip_all)]
+pub(crate) fn is_matchable_member(
+    value: &ast::ImmutableValue<'_>,
+    collection: &ast::ImmutableValue<'_>,
+    exec_ctx: &ExecutionCtx<'_>,
+) -> ExecutionResult<bool> {
+    let value = resolve_matchable(value, exec_ctx)?;
+    let collection = resolve_matchable(collection, exec_ctx)?;
+
+    match collection {
+        JValue::Array(elements) => Ok(elements.contains(&value)),
+        collection => Err(CatchableError::MemberCollectionIsNotArray(collection).into()),
+    }
+}
+
+fn compare_jvalues(left: &JValue, right: &JValue) -> Option<Ordering> {
+    match (left, right) {
+        (JValue::Number(left), JValue::Number(right)) => compare_numbers(left, right),
+        (JValue::String(left), JValue::String(right)) => Some(left.cmp(right)),
+        _ => None,
+    }
+}
+
+fn compare_numbers(left: &serde_json::Number, right: &serde_json::Number) -> Option<Ordering> {
+    // integers are compared precisely, comparison through f64 is used only for floats
+    if let (Some(left), Some(right)) = (left.as_i64(), right.as_i64()) {
+        return Some(left.cmp(&right));
+    }
+    if let (Some(left), Some(right)) = (left.as_u64(), right.as_u64()) {
+        return Some(left.cmp(&right));
+    }
+
+    left.as_f64()?.partial_cmp(&right.as_f64()?)
+}
+
 type Comparator<'a> = Box<dyn Fn(Cow<'_, JValue>) -> bool + 'a>;
 
 fn compare_matchable<'ctx>(
@@ -118,51 +191,30 @@ fn compare_matchable<'ctx>(
     exec_ctx: &'ctx ExecutionCtx<'_>,
     comparator: Comparator<'ctx>,
 ) -> ExecutionResult<bool> {
+    let jvalue = resolve_matchable(matchable, exec_ctx)?;
+    Ok(comparator(Cow::Owned(jvalue)))
+}
+
+fn resolve_matchable(matchable: &ast::ImmutableValue<'_>, exec_ctx: &ExecutionCtx<'_>) -> ExecutionResult<JValue> {
     use ast::ImmutableValue::*;
 
-    match matchable {
-        InitPeerId => {
-            let init_peer_id = exec_ctx.run_parameters.init_peer_id.clone();
-            let jvalue = init_peer_id.as_str().into();
-            Ok(comparator(Cow::Owned(jvalue)))
-        }
-        LastError(error_accessor) => {
-            let (jvalue, _) = prepare_last_error(error_accessor, exec_ctx)?;
-            Ok(comparator(Cow::Owned(jvalue)))
-        }
-        Literal(str) => {
-            let jvalue = str.to_string().into();
-            Ok(comparator(Cow::Owned(jvalue)))
-        }
-        Timestamp => {
-            let jvalue = exec_ctx.run_parameters.timestamp.into();
-            Ok(comparator(Cow::Owned(jvalue)))
-        }
-        TTL => {
-            let jvalue = exec_ctx.run_parameters.ttl.into();
-            Ok(comparator(Cow::Owned(jvalue)))
-        }
-        Number(number) => {
-            let jvalue = number.into();
-            Ok(comparator(Cow::Owned(jvalue)))
-        }
-        Boolean(bool) => {
-            let jvalue = (*bool).into();
-            Ok(comparator(Cow::Owned(jvalue)))
-        }
-        EmptyArray => {
-            let jvalue = JValue::Array(vec![]);
-            Ok(comparator(Cow::Owned(jvalue)))
-        }
-        Variable(variable) => {
-            let (jvalue, _) = resolve_ast_variable(variable, exec_ctx)?;
-            Ok(comparator(Cow::Owned(jvalue)))
-        }
-        VariableWithLambda(variable) => {
-            let (jvalue, _) = resolve_ast_variable_wl(variable, exec_ctx)?;
-            Ok(comparator(Cow::Owned(jvalue)))
-        }
-    }
+    let jvalue = match matchable {
+        InitPeerId => exec_ctx.run_parameters.init_peer_id.as_str().into(),
+        LastError(error_accessor) => prepare_last_error(error_accessor, exec_ctx)?.0,
+        Error(error_accessor) => prepare_scoped_error(error_accessor, exec_ctx)?.0,
+        Literal(str) => str.to_string().into(),
+        Timestamp => exec_ctx.run_parameters.timestamp.into(),
+        TTL => exec_ctx.run_parameters.ttl.into(),
+        ParticleId => exec_ctx.run_parameters.particle_id.as_str().into(),
+        Number(number) => number.into(),
+        Boolean(bool) => (*bool).into(),
+        EmptyArray => JValue::Array(vec![]),
+        Array(_) | Object(_) => resolve_to_args(matchable, exec_ctx)?.0,
+        Variable(variable) => resolve_ast_variable(variable, exec_ctx)?.0,
+        VariableWithLambda(variable) => resolve_ast_variable_wl(variable, exec_ctx)?.0,
+    };
+
+    Ok(jvalue)
 }
 
 fn make_string_comparator(comparable_string: &str) -> Comparator<'_> {
@@ -17,3 +17,5 @@
 mod comparator;
 
 pub(super) use comparator::are_matchable_eq;
+pub(super) use comparator::compare_matchables;
+pub(super) use comparator::is_matchable_member;
@@ -47,6 +47,8 @@ impl<'i> super::ExecutableInstruction<'i> for Fail<'i> {
             Fail::CanonStreamWithLambda(canon_stream) => fail_with_canon_stream(canon_stream, exec_ctx),
             // bubble last error up
             Fail::LastError => fail_with_last_error(exec_ctx),
+            // bubble an error caught by the innermost xor up
+            Fail::Error => fail_with_scoped_error(exec_ctx),
         }
     }
 }
@@ -111,6 +113,16 @@ fn fail_with_last_error(exec_ctx: &mut ExecutionCtx<'_>) -> ExecutionResult<()>
     fail_with_error_object(exec_ctx, error, tetraplet)
 }
 
+fn fail_with_scoped_error(exec_ctx: &mut ExecutionCtx<'_>) -> ExecutionResult<()> {
+    let LastError { error, tetraplet } = exec_ctx.scoped_error();
+
+    // to avoid warnings from https://github.com/rust-lang/rust/issues/59159
+    let error = error.clone();
+    let tetraplet = tetraplet.clone();
+
+    fail_with_error_object(exec_ctx, error, tetraplet)
+}
+
 fn fail_with_error_object(
     exec_ctx: &mut ExecutionCtx<'_>,
     error: Rc<JValue>,
@@ -26,6 +26,11 @@ pub(crate) struct FoldState<'i> {
     pub(crate) back_iteration_started: bool,
     pub(crate) instr_head: Rc<Instruction<'i>>,
     pub(crate) last_instr_head: Option<Rc<Instruction<'i>>>,
+    // index of the current value among all values this fold iterates over
+    pub(crate) index: usize,
+    pub(crate) index_name: Option<&'i str>,
+    // iterations stop once this number of values has been iterated over
+    pub(crate) max_iterations: Option<usize>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -47,6 +52,45 @@ impl<'i> FoldState<'i> {
             back_iteration_started: false,
             instr_head,
             last_instr_head,
+            index: 0,
+            index_name: None,
+            max_iterations: None,
         }
     }
+
+    /// Binds the current iteration index to the provided name, starting counting from start_index.
+    pub(crate) fn with_index(mut self, index_name: Option<&'i str>, start_index: usize) -> Self {
+        self.index = start_index;
+        self.index_name = index_name;
+        self
+    }
+
+    pub(crate) fn with_max_iterations(mut self, max_iterations: Option<usize>) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Moves to the next value, returns false if the iterable is exhausted
+    /// or the maximum number of iterations is reached.
+    pub(crate) fn next(&mut self) -> bool {
+        if self.is_iterations_limit_reached() || !self.iterable.next() {
+            return false;
+        }
+
+        self.index += 1;
+        true
+    }
+
+    /// Moves back to the previous value, must be called only after a successful next.
+    pub(crate) fn prev(&mut self) {
+        self.iterable.prev();
+        self.index = self
+            .index
+            .checked_sub(1)
+            .expect("prev is called only after a successful next, so index is positive. QED.");
+    }
+
+    pub(crate) fn is_iterations_limit_reached(&self) -> bool {
+        matches!(self.max_iterations, Some(max_iterations) if self.index + 1 >= max_iterations)
+    }
 }
@@ -25,4 +25,5 @@ use super::ExecutionCtx;
 use super::ExecutionResult;
 use super::Instruction;
 use super::ScalarRef;
+use super::TraceHandler;
 use crate::execution_step::boxed_value::*;
@@ -37,6 +37,26 @@ pub(crate) enum FoldIterableScalar {
     ScalarBased(IterableValue),
 }
 
+/// Binds an index variable of a fold, if any, to the index of the current iteration.
+pub(crate) fn set_fold_index<'i>(
+    index_name: Option<&'i str>,
+    index: usize,
+    exec_ctx: &mut ExecutionCtx<'i>,
+    trace_ctx: &TraceHandler,
+) -> ExecutionResult<()> {
+    let index_name = match index_name {
+        Some(index_name) => index_name,
+        None => return Ok(()),
+    };
+
+    let value = Rc::new(JValue::from(index));
+    let tetraplet = SecurityTetraplet::literal_tetraplet(exec_ctx.run_parameters.init_peer_id.as_ref());
+    let value = ValueAggregate::new(value, Rc::new(tetraplet), trace_ctx.trace_pos());
+    exec_ctx.scalars.set_scalar_value(index_name, value)?;
+
+    Ok(())
+}
+
 /// Creates iterable value for given scalar iterable.
 pub(crate) fn create_scalar_iterable(
     exec_ctx: &ExecutionCtx<'_>,
@@ -25,9 +25,6 @@ use crate::log_instruction;
 
 use air_parser::ast::FoldScalar;
 use air_parser::ast::FoldScalarIterable;
-use air_parser::ast::Instruction;
-
-use std::rc::Rc;
 
 impl<'i> ExecutableInstruction<'i> for FoldScalar<'i> {
     fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
@@ -50,30 +47,30 @@ impl<'i> ExecutableInstruction<'i> for FoldScalar<'i> {
         match iterable {
             // just exit on empty iterable
             FoldIterableScalar::Empty => Ok(()),
-            FoldIterableScalar::ScalarBased(iterable) => fold(
-                iterable,
-                IterableType::Scalar,
-                self.iterator.name,
-                self.instruction.clone(),
-                self.last_instruction.clone(),
-                exec_ctx,
-                trace_ctx,
-            ),
+            FoldIterableScalar::ScalarBased(iterable) => {
+                let fold_state = FoldState::from_iterable(
+                    iterable,
+                    IterableType::Scalar,
+                    self.instruction.clone(),
+                    self.last_instruction.clone(),
+                )
+                .with_index(self.index.as_ref().map(|index| index.name), 0);
+
+                fold(fold_state, self.iterator.name, exec_ctx, trace_ctx)
+            }
         }
     }
 }
 
 pub(super) fn fold<'i>(
-    iterable: IterableValue,
-    iterable_type: IterableType,
+    fold_state: FoldState<'i>,
     iterator: &'i str,
-    instruction: Rc<Instruction<'i>>,
-    last_instruction: Option<Rc<Instruction<'i>>>,
     exec_ctx: &mut ExecutionCtx<'i>,
     trace_ctx: &mut TraceHandler,
 ) -> ExecutionResult<()> {
-    let fold_state = FoldState::from_iterable(iterable, iterable_type, instruction.clone(), last_instruction);
+    let instruction = fold_state.instr_head.clone();
     exec_ctx.scalars.meet_fold_start();
+    set_fold_index(fold_state.index_name, fold_state.index, exec_ctx, trace_ctx)?;
     exec_ctx.scalars.set_iterable_value(iterator, fold_state)?;
 
     let result = instruction.execute(exec_ctx, trace_ctx);
@@ -53,13 +53,22 @@ impl<'i> ExecutableInstruction<'i> for FoldStream<'i> {
         let mut stream_cursor = StreamCursor::new();
         let mut stream_iterable = stream_cursor.construct_iterables(stream);
         let mut observer = FoldGenerationObserver::new();
+        let mut iterated_values_count = 0;
 
         // this cycle manages recursive streams
-        while !stream_iterable.is_empty() {
+        while !stream_iterable.is_empty() && !is_iterations_limit_reached(self, iterated_values_count) {
             // add a new generation to made all consequence "new" (meaning that they are just executed on this peer)
             // write operation to this stream to write to this new generation
             add_new_generation_if_non_empty(&self.iterable, exec_ctx);
-            execute_iterations(stream_iterable, self, fold_id, &mut observer, exec_ctx, trace_ctx)?;
+            iterated_values_count = execute_iterations(
+                stream_iterable,
+                self,
+                fold_id,
+                iterated_values_count,
+                &mut observer,
+                exec_ctx,
+                trace_ctx,
+            )?;
 
             // it's needed to get stream again, because RefCell allows only one mutable borrowing at time,
             // and likely that stream could be mutably borrowed in execute_iterations
@@ -78,16 +87,22 @@ impl<'i> ExecutableInstruction<'i> for FoldStream<'i> {
 /// It must return only uncatchable errors (such as ones from TraceHandler), though
 /// catchable errors are suppressed and not propagated from this function, because of determinism.
 /// The issue with determinism here lies in invariant that all previous executed states
-/// must be met.
+/// must be met. Returns the number of values iterated over by the fold so far, it's used
+/// to continue an index and to respect the maximum number of iterations in the next generations.
 fn execute_iterations<'i>(
     iterables: Vec<IterableValue>,
     fold_stream: &FoldStream<'i>,
     fold_id: u32,
+    mut iterated_values_count: usize,
     generation_observer: &mut FoldGenerationObserver,
     exec_ctx: &mut ExecutionCtx<'i>,
     trace_ctx: &mut TraceHandler,
-) -> ExecutionResult<()> {
+) -> ExecutionResult<usize> {
     for iterable in iterables.into_iter() {
+        if is_iterations_limit_reached(fold_stream, iterated_values_count) {
+            break;
+        }
+
         let value = match iterable.peek() {
             Some(value) => value,
             // it's ok, because some generation level of a stream on some point inside execution
@@ -96,23 +111,38 @@ fn execute_iterations<'i>(
         };
 
         let value_pos = value.pos();
+        let generation_len = iterable.len();
         trace_to_exec_err!(trace_ctx.meet_iteration_start(fold_id, value_pos), fold_stream)?;
-        let result = fold(
+
+        let fold_state = FoldState::from_iterable(
             iterable,
             IterableType::Stream(fold_id),
-            fold_stream.iterator.name,
             fold_stream.instruction.clone(),
             fold_stream.last_instruction.clone(),
-            exec_ctx,
-            trace_ctx,
-        );
+        )
+        .with_index(
+            fold_stream.index.as_ref().map(|index| index.name),
+            iterated_values_count,
+        )
+        .with_max_iterations(max_iterations(fold_stream));
+
+        let result = fold(fold_state, fold_stream.iterator.name, exec_ctx, trace_ctx);
         throw_error_if_not_catchable(result)?;
         trace_to_exec_err!(trace_ctx.meet_generation_end(fold_id), fold_stream)?;
 
         generation_observer.observe_completeness(exec_ctx.is_subgraph_complete());
+        iterated_values_count += generation_len;
     }
 
-    Ok(())
+    Ok(iterated_values_count)
+}
+
+fn max_iterations(fold_stream: &FoldStream<'_>) -> Option<usize> {
+    fold_stream.max_iterations.map(|max_iterations| max_iterations as usize)
+}
+
+fn is_iterations_limit_reached(fold_stream: &FoldStream<'_>, iterated_values_count: usize) -> bool {
+    matches!(max_iterations(fold_stream), Some(max_iterations) if iterated_values_count >= max_iterations)
 }
 
 /// Safety: this function should be called iff stream is present in context
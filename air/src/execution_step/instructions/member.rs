@@ -0,0 +1,45 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::compare_matchable::is_matchable_member;
+use super::ExecutionCtx;
+use super::ExecutionResult;
+use super::TraceHandler;
+use crate::execution_step::CatchableError;
+use crate::execution_step::Joinable;
+use crate::joinable;
+use crate::log_instruction;
+
+use air_parser::ast::Member;
+
+impl<'i> super::ExecutableInstruction<'i> for Member<'i> {
+    fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
+        log_instruction!(member, exec_ctx, trace_ctx);
+        exec_ctx.tracker.meet_member();
+
+        let is_member = joinable!(
+            is_matchable_member(&self.value, &self.collection, exec_ctx),
+            exec_ctx,
+            ()
+        )?;
+
+        if !is_member {
+            return Err(CatchableError::MemberValueNotFound.into());
+        }
+
+        self.instruction.execute(exec_ctx, trace_ctx)
+    }
+}
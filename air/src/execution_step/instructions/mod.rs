@@ -15,14 +15,18 @@
  */
 
 mod ap;
+mod ap_map;
 mod call;
 mod canon;
+mod canon_map;
+mod compare;
 mod compare_matchable;
 mod fail;
 mod fold;
 mod fold_scalar;
 mod fold_stream;
 mod match_;
+mod member;
 mod mismatch;
 mod never;
 mod new;
@@ -30,6 +34,7 @@ mod next;
 mod null;
 mod par;
 mod seq;
+mod ttl_guard;
 mod xor;
 
 pub(crate) use call::triplet::resolve_peer_id_to_string;
@@ -51,13 +56,15 @@ macro_rules! execute {
     ($self:expr, $instr:expr, $exec_ctx:ident, $trace_ctx:ident) => {{
         match $instr.execute($exec_ctx, $trace_ctx) {
             Err(e) => {
+                // TODO: avoid excess copying here
+                let instruction = $instr.to_string();
                 $exec_ctx.last_error_descriptor.try_to_set_from_error(
                     &e,
-                    // TODO: avoid excess copying here
-                    &$instr.to_string(),
+                    &instruction,
                     $exec_ctx.run_parameters.current_peer_id.as_ref(),
                     None,
                 );
+                $exec_ctx.last_error_descriptor.meet_error(&e, &instruction);
                 Err(e)
             }
             v => v,
@@ -77,7 +84,9 @@ impl<'i> ExecutableInstruction<'i> for Instruction<'i> {
             Instruction::Call(call) => call.execute(exec_ctx, trace_ctx),
 
             Instruction::Ap(ap) => execute!(self, ap, exec_ctx, trace_ctx),
+            Instruction::ApMap(ap_map) => execute!(self, ap_map, exec_ctx, trace_ctx),
             Instruction::Canon(canon) => execute!(self, canon, exec_ctx, trace_ctx),
+            Instruction::CanonMap(canon_map) => execute!(self, canon_map, exec_ctx, trace_ctx),
             Instruction::Fail(fail) => execute!(self, fail, exec_ctx, trace_ctx),
             Instruction::FoldScalar(fold) => execute!(self, fold, exec_ctx, trace_ctx),
             Instruction::FoldStream(fold) => execute!(self, fold, exec_ctx, trace_ctx),
@@ -90,6 +99,9 @@ impl<'i> ExecutableInstruction<'i> for Instruction<'i> {
             Instruction::Xor(xor) => execute!(self, xor, exec_ctx, trace_ctx),
             Instruction::Match(match_) => execute!(self, match_, exec_ctx, trace_ctx),
             Instruction::MisMatch(mismatch) => execute!(self, mismatch, exec_ctx, trace_ctx),
+            Instruction::Compare(compare) => execute!(self, compare, exec_ctx, trace_ctx),
+            Instruction::Member(member) => execute!(self, member, exec_ctx, trace_ctx),
+            Instruction::TtlGuard(ttl_guard) => execute!(self, ttl_guard, exec_ctx, trace_ctx),
 
             Instruction::Error => unreachable!("should not execute if parsing succeeded. QED."),
         }
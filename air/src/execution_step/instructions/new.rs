@@ -57,6 +57,13 @@ fn prolog<'i>(new: &New<'i>, exec_ctx: &mut ExecutionCtx<'i>) {
         NewArgument::CanonStream(canon_stream) => exec_ctx
             .scalars
             .meet_new_start_canon_stream(canon_stream.name.to_string()),
+        NewArgument::StreamMap(stream_map) => {
+            let iteration = exec_ctx.tracker.new_tracker.get_iteration(position);
+            exec_ctx.streams.meet_scope_start(stream_map.name, new.span, iteration);
+        }
+        NewArgument::CanonStreamMap(canon_stream_map) => exec_ctx
+            .scalars
+            .meet_new_start_canon_stream_map(canon_stream_map.name.to_string()),
     }
 
     exec_ctx.tracker.meet_new(position);
@@ -73,5 +80,14 @@ fn epilog<'i>(new: &New<'i>, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut Tr
         }
         NewArgument::Scalar(scalar) => exec_ctx.scalars.meet_new_end_scalar(scalar.name),
         NewArgument::CanonStream(canon_stream) => exec_ctx.scalars.meet_new_end_canon_stream(canon_stream.name),
+        NewArgument::StreamMap(stream_map) => {
+            exec_ctx
+                .streams
+                .meet_scope_end(stream_map.name.to_string(), position, trace_ctx)?;
+            Ok(())
+        }
+        NewArgument::CanonStreamMap(canon_stream_map) => {
+            exec_ctx.scalars.meet_new_end_canon_stream_map(canon_stream_map.name)
+        }
     }
 }
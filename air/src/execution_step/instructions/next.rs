@@ -14,6 +14,7 @@
  * limitations under the License.
  */
 
+use super::fold::set_fold_index;
 use super::fold::IterableType;
 use super::ExecutionCtx;
 use super::ExecutionResult;
@@ -34,7 +35,7 @@ impl<'i> super::ExecutableInstruction<'i> for Next<'i> {
         maybe_meet_iteration_end(self, fold_state, trace_ctx)?;
 
         // TODO: refactor a body of this if to reduce LOCs count and improve readability
-        if !fold_state.iterable.next() {
+        if !fold_state.next() {
             maybe_meet_back_iterator(self, fold_state, trace_ctx)?;
 
             let fold_state = exec_ctx.scalars.get_iterable(iterator_name)?;
@@ -46,7 +47,11 @@ impl<'i> super::ExecutableInstruction<'i> for Next<'i> {
             } else {
                 // if no last instruction, execute never as a fallback for fold over stream (it'll be removed in future)
                 let fold_state = exec_ctx.scalars.get_iterable_mut(iterator_name)?;
-                if !fold_state.back_iteration_started && matches!(fold_state.iterable_type, IterableType::Stream(_)) {
+                // a fold that reached its maximum number of iterations doesn't wait for new values
+                if !fold_state.back_iteration_started
+                    && !fold_state.is_iterations_limit_reached()
+                    && matches!(fold_state.iterable_type, IterableType::Stream(_))
+                {
                     fold_state.back_iteration_started = true;
                     // this set the last iteration of a next to not executed for fold over streams
                     // for more info see https://github.com/fluencelabs/aquavm/issues/333
@@ -59,8 +64,11 @@ impl<'i> super::ExecutableInstruction<'i> for Next<'i> {
         }
 
         let next_instr = fold_state.instr_head.clone();
+        let index_name = fold_state.index_name;
+        let index = fold_state.index;
         maybe_meet_iteration_start(self, fold_state, trace_ctx)?;
         exec_ctx.scalars.meet_next_before();
+        set_fold_index(index_name, index, exec_ctx, trace_ctx)?;
 
         let result = next_instr.execute(exec_ctx, trace_ctx);
         exec_ctx.scalars.meet_next_after();
@@ -68,7 +76,7 @@ impl<'i> super::ExecutableInstruction<'i> for Next<'i> {
 
         // get the same fold state again because of borrow checker
         let fold_state = exec_ctx.scalars.get_iterable_mut(iterator_name)?;
-        fold_state.iterable.prev();
+        fold_state.prev();
         maybe_meet_back_iterator(self, fold_state, trace_ctx)?;
 
         Ok(())
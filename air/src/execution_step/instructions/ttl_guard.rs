@@ -0,0 +1,65 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::ExecutionCtx;
+use super::ExecutionResult;
+use super::TraceHandler;
+use crate::execution_step::resolver::resolve_to_args;
+use crate::execution_step::CatchableError;
+use crate::execution_step::Joinable;
+use crate::joinable;
+use crate::log_instruction;
+use crate::trace_to_exec_err;
+
+use air_interpreter_data::TtlGuardResult;
+use air_parser::ast::TtlGuard;
+use air_trace_handler::merger::MergerTtlGuardResult;
+
+impl<'i> super::ExecutableInstruction<'i> for TtlGuard<'i> {
+    fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
+        log_instruction!(ttl_guard, exec_ctx, trace_ctx);
+        exec_ctx.tracker.meet_ttl_guard();
+
+        let (min_remaining_ttl, _) = joinable!(resolve_to_args(&self.min_remaining_ttl, exec_ctx), exec_ctx, ())?;
+        let required = min_remaining_ttl
+            .as_u64()
+            .ok_or(CatchableError::TtlGuardArgumentIsNotNumber(min_remaining_ttl))?;
+
+        let remaining = remaining_ttl(exec_ctx);
+        // the host time differs between runs, so the decision made the first time is kept
+        // in the trace and replayed, otherwise a branch could be switched on the same peer
+        let passed = match trace_to_exec_err!(trace_ctx.meet_ttl_guard_start(), self)? {
+            MergerTtlGuardResult::Met(ttl_guard_result) => ttl_guard_result.passed,
+            MergerTtlGuardResult::NotMet => remaining >= required,
+        };
+        trace_ctx.meet_ttl_guard_end(TtlGuardResult::new(passed));
+
+        if !passed {
+            return Err(CatchableError::NotEnoughRemainingTtl { remaining, required }.into());
+        }
+
+        self.instruction.execute(exec_ctx, trace_ctx)
+    }
+}
+
+/// Returns how many milliseconds remain before the particle TTL expires
+/// according to the host time, it's zero if the particle is already expired.
+fn remaining_ttl(exec_ctx: &ExecutionCtx<'_>) -> u64 {
+    let run_parameters = &exec_ctx.run_parameters;
+    let deadline = run_parameters.timestamp.saturating_add(run_parameters.ttl as u64);
+
+    deadline.saturating_sub(run_parameters.current_timestamp)
+}
@@ -33,7 +33,13 @@ impl<'i> super::ExecutableInstruction<'i> for Xor<'i> {
 
                 exec_ctx.flush_subgraph_completeness();
                 exec_ctx.last_error_descriptor.meet_xor_right_branch();
-                self.1.execute(exec_ctx, trace_ctx)
+                exec_ctx
+                    .last_error_descriptor
+                    .enter_catching_branch(&e, exec_ctx.run_parameters.current_peer_id.as_ref());
+
+                let result = self.1.execute(exec_ctx, trace_ctx);
+                exec_ctx.last_error_descriptor.leave_catching_branch();
+                result
             }
             res => res,
         }
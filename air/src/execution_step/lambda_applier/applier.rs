@@ -43,7 +43,7 @@ pub(crate) fn select_by_lambda_from_stream<'value>(
 ) -> ExecutionResult<StreamSelectResult<'value>> {
     match lambda {
         LambdaAST::ValuePath(value_path) => select_by_path_from_stream(stream, value_path, exec_ctx),
-        LambdaAST::Functor(functor) => Ok(select_by_functor_from_stream(stream, functor)),
+        LambdaAST::Functor(functor) => select_by_functor_from_stream(stream, functor),
     }
 }
 
@@ -53,8 +53,8 @@ pub(crate) fn select_by_lambda_from_scalar<'value>(
     exec_ctx: &ExecutionCtx<'_>,
 ) -> ExecutionResult<Cow<'value, JValue>> {
     match lambda {
-        LambdaAST::ValuePath(value_path) => select_by_path_from_scalar(value, value_path.iter(), exec_ctx),
-        LambdaAST::Functor(functor) => select_by_functor_from_scalar(value, functor).map(Cow::Owned),
+        LambdaAST::ValuePath(value_path) => select_by_path_from_scalar(value, value_path, exec_ctx),
+        LambdaAST::Functor(functor) => select_by_functor_from_scalar(value, functor),
     }
 }
 
@@ -64,81 +64,258 @@ fn select_by_path_from_stream<'value>(
     exec_ctx: &ExecutionCtx<'_>,
 ) -> ExecutionResult<StreamSelectResult<'value>> {
     let (prefix, body) = lambda.split_first();
+    let (prefix, is_optional) = unwrap_optional_accessor(prefix);
+    let stream_size = stream.len();
     let idx = match prefix {
-        ValueAccessor::ArrayAccess { idx } => *idx,
+        ValueAccessor::ArrayAccess { idx } => Ok(idx),
+        ValueAccessor::ArrayAccessFromEnd { idx_from_end } => {
+            let idx = idx_from_end_to_idx(stream_size, idx_from_end);
+            lambda_to_execution_error!(idx.map(|idx| idx as u32).ok_or(
+                LambdaError::CanonStreamNotHaveEnoughValuesFromEnd {
+                    stream_size,
+                    idx_from_end,
+                }
+            ))
+        }
+        ValueAccessor::ArraySlice(_) | ValueAccessor::Wildcard | ValueAccessor::Filter(_) => {
+            // these accessors select several stream values, so the result isn't bound to
+            // a particular stream value anymore and a stream is treated as an ordinary array,
+            // callers are responsible for a tetraplet of such a result
+            let stream = JValue::Array(stream.cloned().collect());
+            let result = select_by_path_from_scalar(&stream, lambda, exec_ctx)?;
+            return Ok(StreamSelectResult::from_value(result.into_owned()));
+        }
         ValueAccessor::FieldAccessByName { field_name } => {
-            return lambda_to_execution_error!(Err(LambdaError::FieldAccessorAppliedToStream {
+            lambda_to_execution_error!(Err(LambdaError::FieldAccessorAppliedToStream {
                 field_name: field_name.to_string(),
-            }));
+            }))
         }
         ValueAccessor::FieldAccessByScalar { scalar_name } => {
             let scalar = exec_ctx.scalars.get_value(scalar_name)?;
-            lambda_to_execution_error!(try_scalar_ref_as_idx(scalar))?
+            Ok(lambda_to_execution_error!(try_scalar_ref_as_idx(scalar))?)
         }
+        ValueAccessor::Optional(_) => unreachable!("optional accessors can't be nested. QED."),
         ValueAccessor::Error => unreachable!("should not execute if parsing succeeded. QED."),
     };
 
-    let stream_size = stream.len();
-    let value = lambda_to_execution_error!(stream
-        .peekable()
-        .nth(idx as usize)
-        .ok_or(LambdaError::CanonStreamNotHaveEnoughValues { stream_size, idx }))?;
+    let value = idx.and_then(|idx| {
+        lambda_to_execution_error!(stream
+            .peekable()
+            .nth(idx as usize)
+            .map(|value| (idx, value))
+            .ok_or(LambdaError::CanonStreamNotHaveEnoughValues { stream_size, idx }))
+    });
+    let (idx, value) = match value {
+        Ok(value) => value,
+        Err(error) if is_optional && is_missing_value_error(&error) => {
+            return Ok(StreamSelectResult::from_value(JValue::Null))
+        }
+        Err(error) => return Err(error),
+    };
 
-    let result = select_by_path_from_scalar(value, body.iter(), exec_ctx)?;
+    let result = select_by_path_from_scalar(value, body, exec_ctx)?;
     let select_result = StreamSelectResult::from_cow(result, idx);
     Ok(select_result)
 }
 
 fn select_by_functor_from_stream<'value>(
-    stream: impl ExactSizeIterator<Item = &'value JValue> + 'value,
+    mut stream: impl ExactSizeIterator<Item = &'value JValue> + 'value,
     functor: &Functor,
-) -> StreamSelectResult<'value> {
+) -> ExecutionResult<StreamSelectResult<'value>> {
+    let stream_size = stream.len();
+
     match functor {
         Functor::Length => {
-            let result = serde_json::json!(stream.len());
-            StreamSelectResult::from_value(result)
+            let result = serde_json::json!(stream_size);
+            Ok(StreamSelectResult::from_value(result))
+        }
+        Functor::IsEmpty => {
+            let result = serde_json::json!(stream_size == 0);
+            Ok(StreamSelectResult::from_value(result))
+        }
+        Functor::First | Functor::Last => {
+            let idx = match functor {
+                Functor::First => 0,
+                _ => stream_size.saturating_sub(1),
+            };
+            let value = stream.nth(idx).ok_or_else(|| empty_array_error(functor))?;
+            Ok(StreamSelectResult::from_cow(Cow::Borrowed(value), idx as u32))
+        }
+        // a stream is an array-like value, so it's always an error for object functors
+        Functor::Keys | Functor::Values => {
+            let stream = JValue::Array(stream.cloned().collect());
+            select_by_functor_from_scalar(&stream, functor)
+                .map(|result| StreamSelectResult::from_value(result.into_owned()))
         }
     }
 }
 
-fn select_by_path_from_scalar<'value, 'accessor>(
-    mut value: &'value JValue,
-    lambda: impl Iterator<Item = &'accessor ValueAccessor<'accessor>>,
+fn select_by_path_from_scalar<'value>(
+    value: &'value JValue,
+    lambda: &[ValueAccessor<'_>],
     exec_ctx: &ExecutionCtx<'_>,
 ) -> ExecutionResult<Cow<'value, JValue>> {
-    for accessor in lambda {
-        match accessor {
-            ValueAccessor::ArrayAccess { idx } => {
-                value = lambda_to_execution_error!(try_jvalue_with_idx(value, *idx))?;
-            }
-            ValueAccessor::FieldAccessByName { field_name } => {
-                value = lambda_to_execution_error!(try_jvalue_with_field_name(value, field_name))?;
-            }
-            ValueAccessor::FieldAccessByScalar { scalar_name } => {
-                let scalar = exec_ctx.scalars.get_value(scalar_name)?;
-                value = lambda_to_execution_error!(select_by_scalar(value, scalar))?;
-            }
-            ValueAccessor::Error => unreachable!("should not execute if parsing succeeded. QED."),
+    let mut value = Cow::Borrowed(value);
+
+    for (accessor_id, accessor) in lambda.iter().enumerate() {
+        if let ValueAccessor::Wildcard = accessor {
+            let projection = select_by_wildcard(&value, &lambda[accessor_id + 1..], exec_ctx)?;
+            return Ok(Cow::Owned(projection));
         }
+
+        let (accessor, is_optional) = unwrap_optional_accessor(accessor);
+        let result = match &value {
+            Cow::Borrowed(value) => select_by_accessor(value, &accessor, exec_ctx),
+            // slices and filters produce new values, so the rest of a path is applied to an owned value
+            Cow::Owned(value) => {
+                select_by_accessor(value, &accessor, exec_ctx).map(|value| Cow::Owned(value.into_owned()))
+            }
+        };
+
+        value = match result {
+            Ok(value) => value,
+            // an optional accessor turns the whole lambda into null if the requested element is missing
+            Err(error) if is_optional && is_missing_value_error(&error) => return Ok(Cow::Owned(JValue::Null)),
+            Err(error) => return Err(error),
+        };
     }
 
+    Ok(value)
+}
+
+/// Applies the rest of a path to each element of an array or each value of an object
+/// and collects results into a new array.
+fn select_by_wildcard(
+    value: &JValue,
+    lambda: &[ValueAccessor<'_>],
+    exec_ctx: &ExecutionCtx<'_>,
+) -> ExecutionResult<JValue> {
+    let elements = lambda_to_execution_error!(try_jvalue_elements(value))?;
+
+    let projection = elements
+        .into_iter()
+        .map(|element| select_by_path_from_scalar(element, lambda, exec_ctx).map(Cow::into_owned))
+        .collect::<ExecutionResult<Vec<_>>>()?;
+
+    Ok(JValue::Array(projection))
+}
+
+fn select_by_accessor<'value>(
+    value: &'value JValue,
+    accessor: &ValueAccessor<'_>,
+    exec_ctx: &ExecutionCtx<'_>,
+) -> ExecutionResult<Cow<'value, JValue>> {
+    let value = match accessor {
+        ValueAccessor::ArrayAccess { idx } => lambda_to_execution_error!(try_jvalue_with_idx(value, *idx))?,
+        ValueAccessor::ArrayAccessFromEnd { idx_from_end } => {
+            lambda_to_execution_error!(try_jvalue_with_idx_from_end(value, *idx_from_end))?
+        }
+        ValueAccessor::ArraySlice(slice) => {
+            let sliced = lambda_to_execution_error!(try_jvalue_with_slice(value, slice))?;
+            return Ok(Cow::Owned(sliced));
+        }
+        ValueAccessor::Filter(predicate) => {
+            let filtered = lambda_to_execution_error!(try_jvalue_with_filter(value, predicate))?;
+            return Ok(Cow::Owned(filtered));
+        }
+        ValueAccessor::Wildcard => unreachable!("wildcard is handled by select_by_path_from_scalar. QED."),
+        ValueAccessor::FieldAccessByName { field_name } => {
+            lambda_to_execution_error!(try_jvalue_with_field_name(value, field_name))?
+        }
+        ValueAccessor::FieldAccessByScalar { scalar_name } => {
+            let scalar = exec_ctx.scalars.get_value(scalar_name)?;
+            lambda_to_execution_error!(select_by_scalar(value, scalar))?
+        }
+        ValueAccessor::Optional(_) => unreachable!("optional accessors are unwrapped by callers. QED."),
+        ValueAccessor::Error => unreachable!("should not execute if parsing succeeded. QED."),
+    };
+
     Ok(Cow::Borrowed(value))
 }
 
-fn select_by_functor_from_scalar(value: &JValue, functor: &Functor) -> ExecutionResult<JValue> {
+fn select_by_functor_from_scalar<'value>(
+    value: &'value JValue,
+    functor: &Functor,
+) -> ExecutionResult<Cow<'value, JValue>> {
     match functor {
         Functor::Length => {
             let length = value
                 .as_array()
-                .ok_or_else(|| {
-                    ExecutionError::Catchable(Rc::new(CatchableError::LengthFunctorAppliedToNotArray(value.clone())))
-                })?
+                .ok_or_else(|| catchable_error(CatchableError::LengthFunctorAppliedToNotArray(value.clone())))?
                 .len();
-            Ok(serde_json::json!(length))
+            Ok(Cow::Owned(serde_json::json!(length)))
+        }
+        Functor::Keys => {
+            let keys = value
+                .as_object()
+                .ok_or_else(|| catchable_error(CatchableError::KeysFunctorAppliedToNotObject(value.clone())))?
+                .keys()
+                .map(|key| JValue::String(key.clone()))
+                .collect();
+            Ok(Cow::Owned(JValue::Array(keys)))
+        }
+        Functor::Values => {
+            let values = value
+                .as_object()
+                .ok_or_else(|| catchable_error(CatchableError::ValuesFunctorAppliedToNotObject(value.clone())))?
+                .values()
+                .cloned()
+                .collect();
+            Ok(Cow::Owned(JValue::Array(values)))
+        }
+        Functor::IsEmpty => {
+            let is_empty = match value {
+                JValue::Array(array) => array.is_empty(),
+                JValue::Object(object) => object.is_empty(),
+                _ => {
+                    let error = CatchableError::IsEmptyFunctorAppliedToNotCollection(value.clone());
+                    return Err(catchable_error(error));
+                }
+            };
+            Ok(Cow::Owned(serde_json::json!(is_empty)))
+        }
+        Functor::First | Functor::Last => {
+            let array = value.as_array().ok_or_else(|| {
+                catchable_error(CatchableError::ElementFunctorAppliedToNotArray {
+                    functor: *functor,
+                    value: value.clone(),
+                })
+            })?;
+            let element = match functor {
+                Functor::First => array.first(),
+                _ => array.last(),
+            };
+            let element = element.ok_or_else(|| empty_array_error(functor))?;
+            Ok(Cow::Borrowed(element))
         }
     }
 }
 
+/// Returns an accessor wrapped into an optional one and whether it was optional.
+fn unwrap_optional_accessor<'input>(accessor: &ValueAccessor<'input>) -> (ValueAccessor<'input>, bool) {
+    match accessor {
+        ValueAccessor::Optional(accessor) => ((*accessor).into(), true),
+        accessor => (*accessor, false),
+    }
+}
+
+fn is_missing_value_error(error: &ExecutionError) -> bool {
+    match error {
+        ExecutionError::Catchable(error) => {
+            matches!(error.as_ref(), CatchableError::LambdaApplierError(error) if error.is_missing_value())
+        }
+        ExecutionError::Uncatchable(_) => false,
+    }
+}
+
+fn catchable_error(error: CatchableError) -> ExecutionError {
+    ExecutionError::Catchable(Rc::new(error))
+}
+
+fn empty_array_error(functor: &Functor) -> ExecutionError {
+    catchable_error(CatchableError::ElementFunctorAppliedToEmptyArray(*functor))
+}
+
 impl<'value> StreamSelectResult<'value> {
     pub(self) fn from_cow(result: Cow<'value, JValue>, tetraplet_idx: u32) -> Self {
         Self {
@@ -28,6 +28,9 @@ pub enum LambdaError {
     #[error("lambda is applied to an empty stream")]
     EmptyStream,
 
+    #[error("lambda is applied to a stream that have only '{stream_size}' elements, but '-{idx_from_end}' requested")]
+    CanonStreamNotHaveEnoughValuesFromEnd { stream_size: usize, idx_from_end: u32 },
+
     #[error("field accessor (with field name = '{field_name}') can't be applied to a stream")]
     FieldAccessorAppliedToStream { field_name: String },
 
@@ -37,6 +40,15 @@ pub enum LambdaError {
     #[error("value '{value}' does not contain element for idx = '{idx}'")]
     ValueNotContainSuchArrayIdx { value: JValue, idx: u32 },
 
+    #[error("value '{value}' does not contain element for idx = '-{idx_from_end}'")]
+    ValueNotContainSuchArrayIdxFromEnd { value: JValue, idx_from_end: u32 },
+
+    #[error("value '{value}' is not an array-type to match array selector '{selector}'")]
+    ArraySelectorNotMatchValue { value: JValue, selector: String },
+
+    #[error("value '{value}' is neither an array nor an object to match wildcard accessor")]
+    WildcardNotMatchValue { value: JValue },
+
     #[error("value '{value}' does not contain element with field name = '{field_name}'")]
     ValueNotContainSuchField { value: JValue, field_name: String },
 
@@ -52,3 +64,22 @@ pub enum LambdaError {
     #[error("stream accessor `{scalar_accessor}` should has number (u32) type")]
     StreamAccessorHasInvalidType { scalar_accessor: JValue },
 }
+
+impl LambdaError {
+    /// Returns true if an error means that a value doesn't contain an element requested
+    /// by an accessor, such errors are suppressed by optional accessors. Type mismatches
+    /// are not considered as missing values.
+    pub(crate) fn is_missing_value(&self) -> bool {
+        use LambdaError::*;
+
+        matches!(
+            self,
+            CanonStreamNotHaveEnoughValues { .. }
+                | EmptyStream
+                | CanonStreamNotHaveEnoughValuesFromEnd { .. }
+                | ValueNotContainSuchArrayIdx { .. }
+                | ValueNotContainSuchArrayIdxFromEnd { .. }
+                | ValueNotContainSuchField { .. }
+        )
+    }
+}
@@ -20,6 +20,10 @@ use crate::execution_step::ScalarRef;
 use crate::execution_step::PEEK_ALLOWED_ON_NON_EMPTY;
 use crate::JValue;
 
+use air_lambda_ast::ArraySlice;
+use air_lambda_ast::FilterPredicate;
+use air_lambda_ast::FilterValue;
+
 pub(super) fn try_jvalue_with_idx(jvalue: &JValue, idx: u32) -> LambdaResult<&JValue> {
     match jvalue {
         JValue::Array(values) => values
@@ -35,6 +39,101 @@ pub(super) fn try_jvalue_with_idx(jvalue: &JValue, idx: u32) -> LambdaResult<&JV
     }
 }
 
+pub(super) fn try_jvalue_with_idx_from_end(jvalue: &JValue, idx_from_end: u32) -> LambdaResult<&JValue> {
+    match jvalue {
+        JValue::Array(values) => idx_from_end_to_idx(values.len(), idx_from_end)
+            .and_then(|idx| values.get(idx))
+            .ok_or_else(|| LambdaError::ValueNotContainSuchArrayIdxFromEnd {
+                value: jvalue.clone(),
+                idx_from_end,
+            }),
+        _ => Err(LambdaError::ArraySelectorNotMatchValue {
+            value: jvalue.clone(),
+            selector: format!("[-{idx_from_end}]"),
+        }),
+    }
+}
+
+pub(super) fn try_jvalue_with_slice(jvalue: &JValue, slice: &ArraySlice) -> LambdaResult<JValue> {
+    match jvalue {
+        JValue::Array(values) => {
+            let sliced = slice_indices(values.len(), slice)
+                .map(|idx| values[idx].clone())
+                .collect();
+            Ok(JValue::Array(sliced))
+        }
+        _ => Err(LambdaError::ArraySelectorNotMatchValue {
+            value: jvalue.clone(),
+            selector: format!("[{slice}]"),
+        }),
+    }
+}
+
+pub(super) fn try_jvalue_with_filter(jvalue: &JValue, predicate: &FilterPredicate<'_>) -> LambdaResult<JValue> {
+    let values = match jvalue {
+        JValue::Array(values) => values,
+        _ => {
+            return Err(LambdaError::ArraySelectorNotMatchValue {
+                value: jvalue.clone(),
+                selector: format!("[{predicate}]"),
+            })
+        }
+    };
+
+    let expected_value = filter_value_to_jvalue(&predicate.value);
+    let filtered = values
+        .iter()
+        .filter(|value| value.get(predicate.field_name) == Some(&expected_value))
+        .cloned()
+        .collect();
+
+    Ok(JValue::Array(filtered))
+}
+
+/// Returns elements of an array or values of an object.
+pub(super) fn try_jvalue_elements(jvalue: &JValue) -> LambdaResult<Vec<&JValue>> {
+    match jvalue {
+        JValue::Array(values) => Ok(values.iter().collect()),
+        JValue::Object(values) => Ok(values.values().collect()),
+        _ => Err(LambdaError::WildcardNotMatchValue { value: jvalue.clone() }),
+    }
+}
+
+fn filter_value_to_jvalue(value: &FilterValue<'_>) -> JValue {
+    match value {
+        FilterValue::String(str) => JValue::String(str.to_string()),
+        FilterValue::Number(number) => (*number).into(),
+        FilterValue::Boolean(bool) => JValue::Bool(*bool),
+        FilterValue::Null => JValue::Null,
+    }
+}
+
+/// Converts an index counted from the end of an array to an ordinary one,
+/// returns None if it points outside of the array.
+pub(super) fn idx_from_end_to_idx(len: usize, idx_from_end: u32) -> Option<usize> {
+    match idx_from_end {
+        0 => None,
+        idx_from_end => len.checked_sub(idx_from_end as usize),
+    }
+}
+
+/// Returns indices of elements selected by the slice from an array with the provided length,
+/// out of range bounds are clamped in the same way as python does.
+pub(super) fn slice_indices(len: usize, slice: &ArraySlice) -> impl Iterator<Item = usize> {
+    let normalize_bound = |bound: i64| -> usize {
+        let len = len as i64;
+        let bound = if bound < 0 { bound + len } else { bound };
+        bound.clamp(0, len) as usize
+    };
+
+    let start = slice.start.map(normalize_bound).unwrap_or(0);
+    let end = slice.end.map(normalize_bound).unwrap_or(len);
+    // zero step is rejected by the lambda parser
+    let step = slice.step.unwrap_or(1).max(1) as usize;
+
+    (start..end.max(start)).step_by(step)
+}
+
 pub(super) fn try_jvalue_with_field_name<'value>(
     jvalue: &'value JValue,
     field_name: &str,
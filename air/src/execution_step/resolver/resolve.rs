@@ -39,17 +39,74 @@ pub(crate) fn resolve_to_args<'i>(
     match value {
         InitPeerId => prepare_const(ctx.run_parameters.init_peer_id.as_str(), ctx),
         LastError(error_accessor) => prepare_last_error(error_accessor, ctx),
+        Error(error_accessor) => prepare_scoped_error(error_accessor, ctx),
         Literal(value) => prepare_const(value.to_string(), ctx),
         Timestamp => prepare_const(ctx.run_parameters.timestamp, ctx),
         TTL => prepare_const(ctx.run_parameters.ttl, ctx),
+        ParticleId => prepare_host_value(ctx.run_parameters.particle_id.as_str(), PARTICLE_ID, ctx),
         Boolean(value) => prepare_const(*value, ctx),
         Number(value) => prepare_const(value, ctx),
         EmptyArray => prepare_const(json!([]), ctx),
+        Array(values) => resolve_array(values, ctx),
+        Object(entries) => resolve_object(entries, ctx),
         Variable(variable) => resolve_ast_variable(variable, ctx),
         VariableWithLambda(variable) => resolve_ast_variable_wl(variable, ctx),
     }
 }
 
+/// Resolves every element of an array literal, tetraplets are collected in the same order
+/// as elements, so each element keeps its own provenance.
+pub(crate) fn resolve_array<'i>(
+    values: &[ast::ImmutableValue<'i>],
+    ctx: &ExecutionCtx<'i>,
+) -> ExecutionResult<(JValue, RcSecurityTetraplets)> {
+    let mut jvalues = Vec::with_capacity(values.len());
+    let mut tetraplets = Vec::with_capacity(values.len());
+
+    for value in values {
+        let (jvalue, value_tetraplets) = resolve_to_args(value, ctx)?;
+        jvalues.push(jvalue);
+        tetraplets.extend(value_tetraplets);
+    }
+
+    Ok((JValue::Array(jvalues), tetraplets))
+}
+
+/// Resolves every value of an object literal, tetraplets are collected in the order
+/// of entries in the literal.
+pub(crate) fn resolve_object<'i>(
+    entries: &[ast::ObjectEntry<'i>],
+    ctx: &ExecutionCtx<'i>,
+) -> ExecutionResult<(JValue, RcSecurityTetraplets)> {
+    let mut object = serde_json::Map::with_capacity(entries.len());
+    let mut tetraplets = Vec::with_capacity(entries.len());
+
+    for entry in entries {
+        let (jvalue, value_tetraplets) = resolve_to_args(&entry.value, ctx)?;
+        object.insert(entry.key.to_string(), jvalue);
+        tetraplets.extend(value_tetraplets);
+    }
+
+    Ok((JValue::Object(object), tetraplets))
+}
+
+/// Name of particle metadata that is used in tetraplets of host-provided values.
+pub(crate) const PARTICLE_ID: &str = "%particle_id%";
+
+/// Prepares read-only metadata provided by a host, its tetraplet refers to the current peer.
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn prepare_host_value(
+    arg: impl Into<JValue>,
+    value_name: &str,
+    ctx: &ExecutionCtx<'_>,
+) -> ExecutionResult<(JValue, RcSecurityTetraplets)> {
+    let jvalue = arg.into();
+    let tetraplet = SecurityTetraplet::host_tetraplet(ctx.run_parameters.current_peer_id.as_ref(), value_name);
+    let tetraplet = Rc::new(tetraplet);
+
+    Ok((jvalue, vec![tetraplet]))
+}
+
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn prepare_const(
     arg: impl Into<JValue>,
@@ -62,14 +119,27 @@ pub(crate) fn prepare_const(
     Ok((jvalue, vec![tetraplet]))
 }
 
-#[allow(clippy::unnecessary_wraps)]
 pub(crate) fn prepare_last_error<'i>(
     error_accessor: &Option<LambdaAST<'i>>,
     ctx: &ExecutionCtx<'i>,
 ) -> ExecutionResult<(JValue, RcSecurityTetraplets)> {
-    use crate::LastError;
+    prepare_error(ctx.last_error(), error_accessor, ctx)
+}
+
+/// Prepares an error caught by the innermost enclosing xor.
+pub(crate) fn prepare_scoped_error<'i>(
+    error_accessor: &Option<LambdaAST<'i>>,
+    ctx: &ExecutionCtx<'i>,
+) -> ExecutionResult<(JValue, RcSecurityTetraplets)> {
+    prepare_error(ctx.scoped_error(), error_accessor, ctx)
+}
 
-    let LastError { error, tetraplet } = ctx.last_error();
+fn prepare_error<'i>(
+    error: &crate::LastError,
+    error_accessor: &Option<LambdaAST<'i>>,
+    ctx: &ExecutionCtx<'i>,
+) -> ExecutionResult<(JValue, RcSecurityTetraplets)> {
+    let crate::LastError { error, tetraplet } = error;
 
     let jvalue = match error_accessor {
         Some(error_accessor) => select_by_lambda_from_scalar(error.as_ref(), error_accessor, ctx)?.into_owned(),
@@ -116,6 +186,10 @@ pub(crate) fn resolve_variable<'ctx, 'i>(
             let canon_stream = ctx.scalars.get_canon_stream(name)?;
             Ok(Box::new(canon_stream))
         }
+        Variable::CanonStreamMap { name, .. } => {
+            let canon_stream_map = ctx.scalars.get_canon_stream_map(name)?;
+            Ok(Box::new(canon_stream_map))
+        }
     }
 }
 
@@ -0,0 +1,91 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::JValue;
+
+use air_interpreter_cid::CID;
+use air_interpreter_data::CallResult;
+use air_interpreter_data::CanonCidAggregate;
+use air_interpreter_data::CanonResult;
+use air_interpreter_data::CidInfo;
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::ExecutionTrace;
+use air_interpreter_data::ValueRef;
+use polyplets::SecurityTetraplet;
+
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// CIDs referenced from a trace directly or through canon aggregates.
+#[derive(Default)]
+struct ReachableCids {
+    values: HashSet<Rc<CID<JValue>>>,
+    tetraplets: HashSet<Rc<CID<SecurityTetraplet>>>,
+    canon_aggregates: HashSet<Rc<CID<CanonCidAggregate>>>,
+}
+
+/// Removes values that aren't referenced from the resulting trace from all CID stores,
+/// otherwise values of overwritten or abandoned branches would be shipped forever.
+#[tracing::instrument(skip_all)]
+pub(super) fn prune_unreachable_cids(trace: &ExecutionTrace, cid_info: &mut CidInfo) {
+    let reachable_cids = collect_reachable_cids(trace, cid_info);
+
+    cid_info.value_store.retain(|cid| reachable_cids.values.contains(cid));
+    cid_info
+        .tetraplet_store
+        .retain(|cid| reachable_cids.tetraplets.contains(cid));
+    cid_info
+        .canon_store
+        .retain(|cid| reachable_cids.canon_aggregates.contains(cid));
+}
+
+fn collect_reachable_cids(trace: &ExecutionTrace, cid_info: &CidInfo) -> ReachableCids {
+    let mut reachable_cids = ReachableCids::default();
+
+    for state in trace.iter() {
+        match state {
+            ExecutedState::Call(CallResult::Executed(ValueRef::Scalar(cid)))
+            | ExecutedState::Call(CallResult::Executed(ValueRef::Stream { cid, .. })) => {
+                reachable_cids.values.insert(cid.clone());
+            }
+            ExecutedState::Canon(canon_result) | ExecutedState::CanonMap(canon_result) => {
+                collect_canon_cids(canon_result, cid_info, &mut reachable_cids);
+            }
+            ExecutedState::Call(_)
+            | ExecutedState::Par(_)
+            | ExecutedState::Fold(_)
+            | ExecutedState::Ap(_)
+            | ExecutedState::ApMap(_)
+            | ExecutedState::TtlGuard(_) => {}
+        }
+    }
+
+    reachable_cids
+}
+
+fn collect_canon_cids(canon_result: &CanonResult, cid_info: &CidInfo, reachable_cids: &mut ReachableCids) {
+    reachable_cids.tetraplets.insert(canon_result.tetraplet.clone());
+
+    for canon_cid in &canon_result.values {
+        reachable_cids.canon_aggregates.insert(canon_cid.clone());
+
+        // a missing aggregate would be already reported by the execution step
+        if let Some(canon_aggregate) = cid_info.canon_store.get(canon_cid) {
+            reachable_cids.values.insert(canon_aggregate.value.clone());
+            reachable_cids.tetraplets.insert(canon_aggregate.tetraplet.clone());
+        }
+    }
+}
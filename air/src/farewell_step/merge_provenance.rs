@@ -0,0 +1,33 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::cell::RefCell;
+
+thread_local! {
+    // the report is kept apart from InterpreterOutcome, so this debugging feature
+    // doesn't change the outcome record exported by the interpreter
+    static MERGE_PROVENANCE: RefCell<Vec<u8>> = RefCell::new(vec![]);
+}
+
+pub(crate) fn store_merge_provenance(report: Vec<u8>) {
+    MERGE_PROVENANCE.with(|stored_report| *stored_report.borrow_mut() = report);
+}
+
+/// Returns a JSON report about provenance of states of the result trace produced by the last
+/// execution on the current thread, it's empty if tracking wasn't enabled by run parameters.
+pub fn take_merge_provenance() -> Vec<u8> {
+    MERGE_PROVENANCE.with(|stored_report| stored_report.take())
+}
@@ -14,10 +14,14 @@
  * limitations under the License.
  */
 
+mod cid_pruning;
 mod errors;
+mod merge_provenance;
 mod outcome;
 
 pub use errors::FarewellError;
+pub(crate) use merge_provenance::store_merge_provenance;
+pub use merge_provenance::take_merge_provenance;
 
 pub(crate) use outcome::from_execution_error;
 pub(crate) use outcome::from_success_result;
@@ -14,6 +14,8 @@
  * limitations under the License.
  */
 
+use super::cid_pruning::prune_unreachable_cids;
+use super::store_merge_provenance;
 use super::FarewellError;
 use crate::execution_step::ExecutionCtx;
 use crate::execution_step::TraceHandler;
@@ -22,6 +24,7 @@ use crate::InterpreterOutcome;
 use crate::ToErrorCode;
 use crate::INTERPRETER_SUCCESS;
 
+use air_interpreter_data::compress_data;
 use air_interpreter_data::InterpreterData;
 use air_interpreter_interface::CallRequests;
 use air_utils::measure;
@@ -89,19 +92,37 @@ fn populate_outcome_from_contexts(
         Err(outcome) => return outcome,
     };
 
+    if let Some(report) = trace_handler.provenance_report() {
+        store_merge_provenance(serde_json::to_vec(report).expect("default serializer shouldn't fail"));
+    }
+    let trace = trace_handler.into_result_trace();
+    let mut cid_info = exec_ctx.cid_state.into();
+    prune_unreachable_cids(&trace, &mut cid_info);
+
+    let mut signatures = exec_ctx.signature_store;
+    if let Some(key_pair) = &exec_ctx.key_pair {
+        let particle_id = &exec_ctx.run_parameters.particle_id;
+        if let Some(signed_cids) = exec_ctx.signature_tracker.into_signed_cids(key_pair, particle_id) {
+            signatures.put(key_pair.peer_id(), signed_cids);
+        }
+    }
+
     let data = InterpreterData::from_execution_result(
-        trace_handler.into_result_trace(),
+        trace,
         global_streams,
         restricted_streams,
-        exec_ctx.cid_state.into(),
+        cid_info,
+        signatures,
         exec_ctx.last_call_request_id,
         semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("cargo version is valid"),
     );
     let data = measure!(
-        serde_json::to_vec(&data).expect("default serializer shouldn't fail"),
+        data.serialize(exec_ctx.run_parameters.data_format)
+            .expect("default serializer shouldn't fail"),
         tracing::Level::TRACE,
-        "serde_json::to_vec(data)"
+        "InterpreterData::serialize"
     );
+    let data = compress_data(data, exec_ctx.run_parameters.compression_threshold);
     let next_peer_pks = dedup(exec_ctx.next_peer_pks);
     let call_requests = measure!(
         serde_json::to_vec(&exec_ctx.call_requests).expect("default serializer shouldn't fail"),
@@ -41,6 +41,7 @@ pub use execution_step::ExecutionError;
 pub use execution_step::LambdaError;
 pub use execution_step::LastErrorObjectError;
 pub use execution_step::UncatchableError;
+pub use farewell_step::take_merge_provenance;
 pub use farewell_step::FarewellError;
 pub use polyplets::ResolvedTriplet;
 pub use polyplets::SecurityTetraplet;
@@ -48,6 +49,7 @@ pub use preparation_step::PreparationError;
 pub use utils::ToErrorCode;
 
 pub use crate::runner::execute_air;
+pub use crate::runner::execute_precompiled_air;
 
 pub mod interpreter_data {
     pub use air_interpreter_data::*;
@@ -55,11 +57,17 @@ pub mod interpreter_data {
 
 pub mod parser {
     pub use air_parser::ast::Instruction;
+    pub use air_parser::BINARY_AIR_VERSION;
 
     /// Parse an AIR script to AST.
     pub fn parse(script: &str) -> Result<Box<Instruction<'_>>, String> {
         air_parser::parse(script)
     }
+
+    /// Parse an AIR script and encode it to the binary form accepted by `execute_precompiled_air`.
+    pub fn precompile(script: &str) -> Result<Vec<u8>, air_parser::BinaryAirError> {
+        air_parser::precompile_air(script)
+    }
 }
 
 pub(crate) type JValue = serde_json::Value;
@@ -16,7 +16,13 @@
 
 use crate::ToErrorCode;
 use air_interpreter_data::data_version;
+use air_interpreter_data::CidVerificationError;
+use air_interpreter_data::DataDeError;
+use air_interpreter_data::DataDiagnostic;
 use air_interpreter_data::Versions;
+use air_interpreter_signatures::KeyError;
+use air_interpreter_signatures::SignatureVerificationError;
+use air_parser::BinaryAirError;
 
 use serde_json::Error as SerdeJsonError;
 use strum::IntoEnumIterator;
@@ -41,7 +47,7 @@ pub enum PreparationError {
         super::interpreter_version(),
         data_version()
     )]
-    DataDeFailed { data: Vec<u8>, error: SerdeJsonError },
+    DataDeFailed { data: Vec<u8>, error: DataDeError },
 
     /// Errors occurred on executed trace deserialization
     /// when it was possible to recover versions.
@@ -57,7 +63,7 @@ pub enum PreparationError {
     )]
     DataDeFailedWithVersions {
         data: Vec<u8>,
-        error: SerdeJsonError,
+        error: DataDeError,
         versions: Versions,
     },
 
@@ -77,6 +83,44 @@ pub enum PreparationError {
         actual_version: semver::Version,
         required_version: semver::Version,
     },
+
+    /// Error occurred while decoding precompiled AIR.
+    #[error(transparent)]
+    BinaryAIRDecodeFailed(BinaryAirError),
+
+    /// Error occurred when a host requested output data in an unknown format.
+    #[error("data format `{0}` requested by the host is unsupported")]
+    UnsupportedDataFormat(u8),
+
+    /// Error occurred when a value in the current data doesn't match its CID.
+    #[error("current data contains an invalid CID: {0}")]
+    CidVerificationFailed(CidVerificationError),
+
+    /// Error occurred when a host requested CIDs calculated by an unknown hash algorithm.
+    #[error("CID hash algorithm `{0}` requested by the host is unsupported")]
+    UnsupportedCidHashAlgorithm(u8),
+
+    /// Error occurred when a signing key passed by the host isn't a valid ed25519 secret key.
+    #[error("signing key passed by the host is invalid: {0}")]
+    InvalidSigningKey(KeyError),
+
+    /// Error occurred when a signing key passed by the host doesn't correspond to the current peer id.
+    #[error(
+        "signing key passed by the host belongs to `{key_peer_id}`, but the current peer id is `{current_peer_id}`"
+    )]
+    SigningKeyMismatch {
+        key_peer_id: String,
+        current_peer_id: String,
+    },
+
+    /// Error occurred when the current data contains an invalid signature or a state that isn't
+    /// signed by a peer produced it.
+    #[error("current data contains invalid signatures: {0}")]
+    SignatureVerificationFailed(SignatureVerificationError),
+
+    /// Error occurred when the current data is structurally inconsistent.
+    #[error("current data is malformed: {}", join_diagnostics(.0))]
+    MalformedData(Vec<DataDiagnostic>),
 }
 
 impl ToErrorCode for PreparationError {
@@ -87,11 +131,11 @@ impl ToErrorCode for PreparationError {
 }
 
 impl PreparationError {
-    pub fn data_de_failed(data: Vec<u8>, error: SerdeJsonError) -> Self {
+    pub fn data_de_failed(data: Vec<u8>, error: DataDeError) -> Self {
         Self::DataDeFailed { data, error }
     }
 
-    pub fn data_de_failed_with_versions(data: Vec<u8>, error: SerdeJsonError, versions: Versions) -> Self {
+    pub fn data_de_failed_with_versions(data: Vec<u8>, error: DataDeError, versions: Versions) -> Self {
         Self::DataDeFailedWithVersions { data, error, versions }
     }
 
@@ -106,3 +150,11 @@ impl PreparationError {
         }
     }
 }
+
+fn join_diagnostics(diagnostics: &[DataDiagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -21,6 +21,7 @@ mod preparation;
 pub use errors::PreparationError;
 
 pub(crate) use preparation::prepare;
+pub(crate) use preparation::AirSource;
 pub(crate) use preparation::PreparationDescriptor;
 
 use interpreter_versions::interpreter_version;
@@ -19,8 +19,13 @@ use crate::execution_step::execution_context::ExecCtxIngredients;
 use crate::execution_step::ExecutionCtx;
 use crate::execution_step::TraceHandler;
 
+use air_interpreter_cid::CidHashAlgorithm;
+use air_interpreter_data::DataDeError;
+use air_interpreter_data::ExecutedState;
 use air_interpreter_data::InterpreterData;
+use air_interpreter_interface::DataFormat;
 use air_interpreter_interface::RunParameters;
+use air_interpreter_signatures::KeyPair;
 use air_parser::ast::Instruction;
 
 type PreparationResult<T> = Result<T, PreparationError>;
@@ -32,12 +37,21 @@ pub(crate) struct PreparationDescriptor<'ctx, 'i> {
     pub(crate) air: Instruction<'i>,
 }
 
+/// AIR script in one of the forms supported by the interpreter.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum AirSource<'i> {
+    /// Textual AIR that should be parsed.
+    Text(&'i str),
+    /// AIR precompiled to the binary form, it's only decoded.
+    Binary(&'i [u8]),
+}
+
 /// Parse and prepare supplied data and AIR script.
 #[tracing::instrument(skip_all)]
 pub(crate) fn prepare<'i>(
     prev_data: &[u8],
     current_data: &[u8],
-    raw_air: &'i str,
+    air_source: AirSource<'i>,
     call_results: &[u8],
     run_parameters: RunParameters,
 ) -> PreparationResult<PreparationDescriptor<'static, 'i>> {
@@ -45,14 +59,20 @@ pub(crate) fn prepare<'i>(
     let current_data = try_to_data(current_data)?;
 
     check_version_compatibility(&current_data)?;
+    if !run_parameters.skip_cid_verification {
+        verify_cids(&current_data)?;
+    }
+    check_data_sanity(&current_data)?;
+    verify_signatures(&prev_data, &current_data, &run_parameters)?;
 
-    let air: Instruction<'i> = *air_parser::parse(raw_air).map_err(PreparationError::AIRParseError)?;
+    let air = to_instruction(air_source)?;
 
     let prev_ingredients = ExecCtxIngredients {
         global_streams: prev_data.global_streams,
         last_call_request_id: prev_data.last_call_request_id,
         restricted_streams: prev_data.restricted_streams,
         cid_info: prev_data.cid_info,
+        signatures: prev_data.signatures,
     };
 
     let current_ingredients = ExecCtxIngredients {
@@ -60,10 +80,15 @@ pub(crate) fn prepare<'i>(
         last_call_request_id: current_data.last_call_request_id,
         restricted_streams: current_data.restricted_streams,
         cid_info: current_data.cid_info,
+        signatures: current_data.signatures,
     };
 
+    let track_merge_provenance = run_parameters.track_merge_provenance;
     let exec_ctx = make_exec_ctx(prev_ingredients, current_ingredients, call_results, run_parameters)?;
-    let trace_handler = TraceHandler::from_trace(prev_data.trace, current_data.trace);
+    let mut trace_handler = TraceHandler::from_trace(prev_data.trace, current_data.trace);
+    if track_merge_provenance {
+        trace_handler.track_provenance();
+    }
 
     let result = PreparationDescriptor {
         exec_ctx,
@@ -74,6 +99,18 @@ pub(crate) fn prepare<'i>(
     Ok(result)
 }
 
+#[tracing::instrument(skip_all)]
+fn to_instruction(air_source: AirSource<'_>) -> PreparationResult<Instruction<'_>> {
+    match air_source {
+        AirSource::Text(raw_air) => air_parser::parse(raw_air)
+            .map(|air| *air)
+            .map_err(PreparationError::AIRParseError),
+        AirSource::Binary(binary_air) => {
+            air_parser::decode_air(binary_air).map_err(PreparationError::BinaryAIRDecodeFailed)
+        }
+    }
+}
+
 fn try_to_data(raw_data: &[u8]) -> PreparationResult<InterpreterData> {
     // treat empty slice as an empty data,
     // it allows abstracting from an internal format for an empty data
@@ -84,7 +121,7 @@ fn try_to_data(raw_data: &[u8]) -> PreparationResult<InterpreterData> {
     InterpreterData::try_from_slice(raw_data).map_err(|de_error| to_date_de_error(raw_data.to_vec(), de_error))
 }
 
-fn to_date_de_error(raw_data: Vec<u8>, de_error: serde_json::Error) -> PreparationError {
+fn to_date_de_error(raw_data: Vec<u8>, de_error: DataDeError) -> PreparationError {
     match InterpreterData::try_get_versions(&raw_data) {
         Ok(versions) => PreparationError::data_de_failed_with_versions(raw_data, de_error, versions),
         Err(_) => PreparationError::data_de_failed(raw_data, de_error),
@@ -100,11 +137,106 @@ fn make_exec_ctx(
 ) -> PreparationResult<ExecutionCtx<'static>> {
     let call_results = serde_json::from_slice(call_results)
         .map_err(|e| PreparationError::call_results_de_failed(call_results.to_vec(), e))?;
-
-    let ctx = ExecutionCtx::new(prev_ingredients, current_ingredients, call_results, run_parameters);
+    let data_format = DataFormat::from_u8(run_parameters.data_format)
+        .ok_or(PreparationError::UnsupportedDataFormat(run_parameters.data_format))?;
+    let cid_hash_algorithm = CidHashAlgorithm::from_u8(run_parameters.cid_hash_algorithm).ok_or(
+        PreparationError::UnsupportedCidHashAlgorithm(run_parameters.cid_hash_algorithm),
+    )?;
+    let key_pair = make_key_pair(&run_parameters)?;
+
+    let ctx = ExecutionCtx::new(
+        prev_ingredients,
+        current_ingredients,
+        call_results,
+        run_parameters,
+        data_format,
+        cid_hash_algorithm,
+        key_pair,
+    );
     Ok(ctx)
 }
 
+fn make_key_pair(run_parameters: &RunParameters) -> PreparationResult<Option<KeyPair>> {
+    if run_parameters.signing_key.is_empty() {
+        return Ok(None);
+    }
+
+    let key_pair =
+        KeyPair::from_secret_key(&run_parameters.signing_key).map_err(PreparationError::InvalidSigningKey)?;
+    let key_peer_id = key_pair.peer_id();
+    if key_peer_id != run_parameters.current_peer_id {
+        return Err(PreparationError::SigningKeyMismatch {
+            key_peer_id,
+            current_peer_id: run_parameters.current_peer_id.clone(),
+        });
+    }
+
+    Ok(Some(key_pair))
+}
+
+// prev data was produced by this peer, so only the current data is verified
+#[tracing::instrument(skip_all)]
+fn verify_cids(data: &InterpreterData) -> PreparationResult<()> {
+    data.cid_info.verify().map_err(PreparationError::CidVerificationFailed)
+}
+
+// unlike CIDs, structure of the data is checked regardless of the trust to a sender,
+// so malformed data is rejected before execution instead of failing in the middle of it
+#[tracing::instrument(skip_all)]
+fn check_data_sanity(data: &InterpreterData) -> PreparationResult<()> {
+    let diagnostics = data.check_sanity();
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(PreparationError::MalformedData(diagnostics))
+    }
+}
+
+// records of prev data are trusted the same way as its CIDs, so only new records are verified,
+// then canon states absent in prev data are checked against signatures of peers from their tetraplets;
+// call states are checked during execution, when their merge source and peer become known;
+// ap, par and fold states aren't signed, since they don't contain data produced by a peer
+#[tracing::instrument(skip_all)]
+fn verify_signatures(
+    prev_data: &InterpreterData,
+    current_data: &InterpreterData,
+    run_parameters: &RunParameters,
+) -> PreparationResult<()> {
+    let signatures = &current_data.signatures;
+    signatures
+        .verify(&prev_data.signatures, &run_parameters.particle_id)
+        .map_err(PreparationError::SignatureVerificationFailed)?;
+
+    // states of peers that don't sign them are rejected only by an explicit policy,
+    // so signing peers interoperate with ones that don't sign yet
+    let signatures_required = run_parameters.require_signatures;
+    let prev_canon_results = prev_data
+        .trace
+        .iter()
+        .filter_map(|state| match state {
+            ExecutedState::Canon(canon_result) | ExecutedState::CanonMap(canon_result) => Some(canon_result),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    for state in current_data.trace.iter() {
+        if let ExecutedState::Canon(canon_result) | ExecutedState::CanonMap(canon_result) = state {
+            if prev_canon_results.contains(&canon_result) {
+                continue;
+            }
+
+            // an absent tetraplet has already been reported by the sanity check
+            if let Some(tetraplet) = current_data.cid_info.tetraplet_store.get(&canon_result.tetraplet) {
+                signatures
+                    .verify_state(&tetraplet.peer_pk, canon_result, signatures_required)
+                    .map_err(PreparationError::SignatureVerificationFailed)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn check_version_compatibility(data: &InterpreterData) -> PreparationResult<()> {
     if &data.versions.interpreter_version < super::min_supported_version() {
         return Err(PreparationError::UnsupportedInterpreterVersion {
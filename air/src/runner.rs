@@ -17,6 +17,7 @@
 use crate::execution_step::ExecutableInstruction;
 use crate::farewell_step as farewell;
 use crate::preparation_step::prepare;
+use crate::preparation_step::AirSource;
 use crate::preparation_step::PreparationDescriptor;
 
 use air_interpreter_interface::InterpreterOutcome;
@@ -44,22 +45,50 @@ pub fn execute_air(
         params.current_peer_id,
     );
 
-    execute_air_impl(air, prev_data, data, params, call_results).unwrap_or_else(identity)
+    execute_air_impl(AirSource::Text(&air), prev_data, data, params, call_results).unwrap_or_else(identity)
+}
+
+/// Executes AIR precompiled by `air_parser::precompile_air`, it behaves like `execute_air`,
+/// but skips lexing and parsing of the script.
+#[tracing::instrument(skip_all)]
+pub fn execute_precompiled_air(
+    binary_air: Vec<u8>,
+    prev_data: Vec<u8>,
+    data: Vec<u8>,
+    params: RunParameters,
+    call_results: Vec<u8>,
+) -> InterpreterOutcome {
+    use std::convert::identity;
+
+    log::trace!(
+        target: RUN_PARAMS,
+        "air interpreter version is {}, run parameters for precompiled air:\
+            init peer id {}\
+            current peer id {}",
+        env!("CARGO_PKG_VERSION"),
+        params.init_peer_id,
+        params.current_peer_id,
+    );
+
+    execute_air_impl(AirSource::Binary(&binary_air), prev_data, data, params, call_results).unwrap_or_else(identity)
 }
 
 #[allow(clippy::result_large_err)]
 fn execute_air_impl(
-    air: String,
+    air_source: AirSource<'_>,
     prev_data: Vec<u8>,
     data: Vec<u8>,
     params: RunParameters,
     call_results: Vec<u8>,
 ) -> Result<InterpreterOutcome, InterpreterOutcome> {
+    // a report of a previous execution shouldn't be taken for a report of this one
+    farewell::store_merge_provenance(vec![]);
+
     let PreparationDescriptor {
         mut exec_ctx,
         mut trace_handler,
         air,
-    } = match prepare(&prev_data, &data, air.as_str(), &call_results, params) {
+    } = match prepare(&prev_data, &data, air_source, &call_results, params) {
         Ok(descriptor) => descriptor,
         // return the prev data in case of errors
         Err(error) => return Err(farewell::from_uncatchable_error(prev_data, error)),
@@ -14,6 +14,7 @@
  * limitations under the License.
  */
 
+use air::PreparationError;
 use air_interpreter_cid::CID;
 use air_interpreter_data::{CidStore, CidTracker};
 use air_test_framework::AirScriptExecutor;
@@ -182,11 +183,11 @@ fn test_canon_value_not_found() {
     let cur_data = raw_data_from_trace_with_canon(trace, CidTracker::<_>::new(), tetraplet_tracker, canon_tracker);
     let result = call_vm!(vm, <_>::default(), air_script, vec![], cur_data);
 
-    assert_eq!(result.ret_code, 20012);
-    assert_eq!(
-        result.error_message,
-        format!("value for CID \"{missing_cid}\" not found")
-    );
+    let expected_error = PreparationError::MalformedData(vec![DataDiagnostic::MissingValue {
+        position: 1.into(),
+        cid: missing_cid.to_string(),
+    }]);
+    assert!(check_error(&result, expected_error));
 }
 
 #[test]
@@ -226,7 +227,7 @@ fn test_canon_root_tetraplet_not_found() {
         ),
     ];
 
-    let missing_cid = "bagaaiera2bwoxisr5k7qlbzhxi2jmdqlgqybqgxcfwt3v652nqdo5fyc665q";
+    let missing_cid = "bagaaierariqtety22n42p6t5qpjunzcs342og7ufi7ogy56wvyf3rt5okfda";
     let tetraplet_store: CidStore<_> = tetraplet_tracker.into();
     assert!(tetraplet_store.get(&CID::<_>::new(missing_cid)).is_some());
 
@@ -238,11 +239,11 @@ fn test_canon_root_tetraplet_not_found() {
     let cur_data = raw_data_from_trace_with_canon(trace, value_tracker, fake_tetraplet_tracker, canon_tracker);
     let result = call_vm!(vm, <_>::default(), air_script, vec![], cur_data);
 
-    assert_eq!(result.ret_code, 20012);
-    assert_eq!(
-        result.error_message,
-        format!("tetraplet for CID \"{missing_cid}\" not found")
-    );
+    let expected_error = PreparationError::MalformedData(vec![DataDiagnostic::MissingTetraplet {
+        position: 1.into(),
+        cid: missing_cid.to_string(),
+    }]);
+    assert!(check_error(&result, expected_error));
 }
 
 #[test]
@@ -281,7 +282,7 @@ fn test_canon_tetraplet_not_found() {
         ),
     ];
 
-    let missing_cid = "bagaaieracu6twiik6az3cosyzlplrscon3ek6rnu3lkjnflibphqkw6kcdiq";
+    let missing_cid = "bagaaiera7wkb7wkd57c53bytqrci32uinnv2p4l5bkm5saip3xmngvvykfaa";
     let tetraplet_store: CidStore<_> = tetraplet_tracker.into();
     assert!(tetraplet_store.get(&CID::<_>::new(missing_cid)).is_some());
 
@@ -293,11 +294,11 @@ fn test_canon_tetraplet_not_found() {
     let cur_data = raw_data_from_trace_with_canon(trace, value_tracker, fake_tetraplet_tracker, canon_tracker);
     let result = call_vm!(vm, <_>::default(), air_script, vec![], cur_data);
 
-    assert_eq!(result.ret_code, 20012);
-    assert_eq!(
-        result.error_message,
-        format!("tetraplet for CID \"{missing_cid}\" not found"),
-    );
+    let expected_error = PreparationError::MalformedData(vec![DataDiagnostic::MissingTetraplet {
+        position: 1.into(),
+        cid: missing_cid.to_string(),
+    }]);
+    assert!(check_error(&result, expected_error));
 }
 
 #[test]
@@ -336,16 +337,16 @@ fn test_canon_agg_not_found() {
         ),
     ];
 
-    let missing_cid = "bagaaierapp2oi35ib4iveexfswax6jcf2zhj3e2ergzjyavm6m7stlzh23ta";
+    let missing_cid = "bagaaierao56slplkw5q64yhb4uc4rrv5ysq7ikgsydwhhhhrfcfl3hqbe45q";
     let canon_store: CidStore<_> = canon_tracker.into();
     assert!(canon_store.get(&CID::<_>::new(missing_cid)).is_some());
 
     let cur_data = raw_data_from_trace_with_canon(trace, value_tracker, tetraplet_tracker, <_>::default());
     let result = call_vm!(vm, <_>::default(), air_script, vec![], cur_data);
 
-    assert_eq!(result.ret_code, 20012);
-    assert_eq!(
-        result.error_message,
-        format!("canon aggregate for CID \"{missing_cid}\" not found")
-    );
+    let expected_error = PreparationError::MalformedData(vec![DataDiagnostic::MissingCanonValue {
+        position: 1.into(),
+        cid: missing_cid.to_string(),
+    }]);
+    assert!(check_error(&result, expected_error));
 }
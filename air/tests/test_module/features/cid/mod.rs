@@ -16,6 +16,8 @@
 
 mod canon;
 
+use air::PreparationError;
+use air_interpreter_data::CidStore;
 use air_interpreter_data::CidTracker;
 use air_test_framework::AirScriptExecutor;
 use air_test_utils::prelude::*;
@@ -35,11 +37,11 @@ fn test_missing_cid() {
 
     let cur_data = raw_data_from_trace(trace, tracker);
     let result = call_vm!(vm, <_>::default(), air_script, vec![], cur_data);
-    assert_eq!(result.ret_code, 20012);
-    assert_eq!(
-        result.error_message,
-        "value for CID \"bagaaieraondvznakk2hi3kfaixhnceatpykz7cikytniqo3lc7ogkgz2qbeq\" not found"
-    );
+    let expected_error = PreparationError::MalformedData(vec![DataDiagnostic::MissingValue {
+        position: 0.into(),
+        cid: "bagaaieraondvznakk2hi3kfaixhnceatpykz7cikytniqo3lc7ogkgz2qbeq".to_string(),
+    }]);
+    assert!(check_error(&result, expected_error));
 }
 
 #[test]
@@ -121,3 +123,77 @@ fn test_stream_cid() {
     assert_eq!(data.trace, expected_trace);
     assert_eq!(data.cid_info.value_store, tracker.into());
 }
+
+#[test]
+fn test_unreachable_cids_are_pruned() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let air_script = r#"
+       (seq
+          (call "peer_id" ("service" "call1") [] x)
+          (call "peer_id" ("service" "call2") [] y))"#;
+    let mut value_tracker = CidTracker::<JValue>::new();
+    let mut tetraplet_tracker = CidTracker::<SecurityTetraplet>::new();
+    let mut canon_tracker = CidTracker::<CanonCidAggregate>::new();
+    let trace = vec![
+        scalar_tracked(42, &mut value_tracker),
+        scalar_tracked(43, &mut value_tracker),
+    ];
+    let expected_value_store: CidStore<_> = value_tracker.clone().into();
+
+    // values of an abandoned branch aren't referenced from the trace
+    let unreachable_value = value_tracker.record_value(json!(44)).unwrap();
+    let unreachable_tetraplet = tetraplet_tracker
+        .record_value(SecurityTetraplet::literal_tetraplet("peer_id"))
+        .unwrap();
+    canon_tracker
+        .record_value(CanonCidAggregate {
+            value: unreachable_value,
+            tetraplet: unreachable_tetraplet,
+        })
+        .unwrap();
+
+    let cur_data = raw_data_from_trace_with_canon(trace, value_tracker, tetraplet_tracker, canon_tracker);
+    let result = checked_call_vm!(vm, <_>::default(), air_script, vec![], cur_data);
+    let data = data_from_result(&result);
+
+    assert_eq!(data.trace.len(), 2);
+    assert_eq!(data.cid_info.value_store, expected_value_store);
+    assert!(data.cid_info.tetraplet_store.is_empty());
+    assert!(data.cid_info.canon_store.is_empty());
+}
+
+#[test]
+fn test_cids_of_different_hash_algorithms() {
+    let vm_1_peer_id = "vm_1_peer_id";
+    let vm_2_peer_id = "vm_2_peer_id";
+    let mut vm_1 = create_avm(set_variable_call_service(json!("value")), vm_1_peer_id);
+    let mut vm_2 = create_avm(echo_call_service(), vm_2_peer_id);
+
+    let air_script = f!(r#"
+       (seq
+          (call "{vm_1_peer_id}" ("service" "call1") [] x)
+          (call "{vm_2_peer_id}" ("service" "call2") [x] y))"#);
+
+    let blake3_run_params = TestRunParameters {
+        cid_hash_algorithm: CidHashAlgorithm::Blake3_256,
+        ..<_>::default()
+    };
+    let result_1 = checked_call_vm!(vm_1, blake3_run_params, &air_script, "", "");
+
+    // a peer producing SHA2-256 CIDs accepts BLAKE3-256 ones
+    let result_2 = checked_call_vm!(vm_2, <_>::default(), &air_script, "", result_1.data);
+    let data = data_from_result(&result_2);
+
+    let mut blake3_tracker =
+        CidTracker::<JValue>::from_cid_stores(<_>::default(), <_>::default(), CidHashAlgorithm::Blake3_256);
+    let mut sha2_tracker = CidTracker::<JValue>::new();
+    let expected_trace = vec![
+        scalar_tracked("value", &mut blake3_tracker),
+        scalar_tracked("value", &mut sha2_tracker),
+    ];
+    assert_eq!(data.trace, expected_trace);
+    assert!(data.cid_info.value_store.verify().is_ok());
+    assert_eq!(data.cid_info.value_store.len(), 2);
+}
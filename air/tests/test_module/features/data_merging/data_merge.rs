@@ -336,3 +336,128 @@ fn fold_merge() {
         }
     }
 }
+
+#[test]
+fn merge_data_in_different_formats() {
+    use executed_state::*;
+
+    let vm_1_peer_id = "vm_1_peer_id";
+    let vm_2_peer_id = "vm_2_peer_id";
+    let mut vm_1 = create_avm(set_variable_call_service(json!("value")), vm_1_peer_id);
+    let mut vm_2 = create_avm(set_variable_call_service(json!("value")), vm_2_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{vm_1_peer_id}" ("" "") [] result_1)
+            (call "{vm_2_peer_id}" ("" "") [] result_2)
+        )
+        "#);
+
+    let cbor_run_params = TestRunParameters {
+        data_format: DataFormat::Cbor,
+        ..<_>::default()
+    };
+    let result_1 = checked_call_vm!(vm_1, cbor_run_params.clone(), &script, "", "");
+    assert_eq!(detect_data_format(&result_1.data), DataFormat::Cbor);
+
+    let result_2 = checked_call_vm!(vm_2, <_>::default(), &script, "", result_1.data.clone());
+    assert_eq!(detect_data_format(&result_2.data), DataFormat::Json);
+
+    let expected_trace = vec![scalar_string("value"), scalar_string("value")];
+    assert_eq!(trace_from_result(&result_2), expected_trace);
+
+    // CBOR prev data is merged with JSON current data
+    let result_3 = checked_call_vm!(vm_1, cbor_run_params, &script, result_1.data, result_2.data);
+    assert_eq!(detect_data_format(&result_3.data), DataFormat::Cbor);
+    assert_eq!(trace_from_result(&result_3), expected_trace);
+}
+
+#[test]
+fn merge_compressed_data() {
+    use executed_state::*;
+
+    let vm_1_peer_id = "vm_1_peer_id";
+    let vm_2_peer_id = "vm_2_peer_id";
+    let big_value = json!("value".repeat(1024));
+    let mut vm_1 = create_avm(set_variable_call_service(big_value.clone()), vm_1_peer_id);
+    let mut vm_2 = create_avm(set_variable_call_service(json!("value")), vm_2_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{vm_1_peer_id}" ("" "") [] result_1)
+            (call "{vm_2_peer_id}" ("" "") [] result_2)
+        )
+        "#);
+
+    let compressing_run_params = TestRunParameters {
+        compression_threshold: 1024,
+        ..<_>::default()
+    };
+    let result_1 = checked_call_vm!(vm_1, compressing_run_params.clone(), &script, "", "");
+    assert!(is_compressed(&result_1.data));
+
+    // compressed current data is accepted by a peer with compression disabled
+    let result_2 = checked_call_vm!(vm_2, <_>::default(), &script, "", result_1.data.clone());
+    assert!(!is_compressed(&result_2.data));
+
+    let expected_trace = vec![scalar(big_value), scalar_string("value")];
+    assert_eq!(trace_from_result(&result_2), expected_trace);
+
+    // compressed prev data is merged as well
+    let result_3 = checked_call_vm!(vm_1, compressing_run_params, &script, result_1.data, result_2.data);
+    assert!(is_compressed(&result_3.data));
+    assert_eq!(trace_from_result(&result_3), expected_trace);
+
+    // small data isn't compressed even if compression is enabled
+    let small_run_params = TestRunParameters {
+        compression_threshold: u32::MAX,
+        ..<_>::default()
+    };
+    let result_4 = checked_call_vm!(vm_2, small_run_params, &script, "", "");
+    assert!(!is_compressed(&result_4.data));
+}
+
+#[test]
+fn merge_provenance_is_reported() {
+    let vm_1_peer_id = "vm_1_peer_id";
+    let vm_2_peer_id = "vm_2_peer_id";
+    let mut vm_1 = create_avm(set_variable_call_service(json!("value")), vm_1_peer_id);
+    let mut vm_2 = create_avm(set_variable_call_service(json!("value")), vm_2_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (par
+                (call "{vm_1_peer_id}" ("" "") [] result_1)
+                (call "{vm_2_peer_id}" ("" "") [] result_2))
+            (seq
+                (ap ("key" "value") %map)
+                (canon "{vm_2_peer_id}" %map #%map)))
+        "#);
+
+    let result_1 = checked_call_vm!(vm_1, <_>::default(), &script, "", "");
+    assert!(result_1.merge_provenance.is_empty());
+
+    let tracking_run_params = TestRunParameters {
+        track_merge_provenance: true,
+        ..<_>::default()
+    };
+    let result_2 = checked_call_vm!(vm_2, tracking_run_params.clone(), &script, "", result_1.data.clone());
+    assert!(!result_2.merge_provenance.is_empty());
+
+    let result_3 = checked_call_vm!(vm_1, tracking_run_params, &script, result_1.data, result_2.data);
+    let actual_report: JValue = serde_json::from_slice(&result_3.merge_provenance).unwrap();
+    let expected_report = json!({
+        "states": [
+            {"trace_pos": 0, "source": "both", "prev_pos": 0, "current_pos": 0, "instruction": "par"},
+            {"trace_pos": 1, "source": "both", "prev_pos": 1, "current_pos": 1, "instruction": "call"},
+            {"trace_pos": 2, "source": "current_data", "prev_pos": 2, "current_pos": 2, "instruction": "call"},
+            {"trace_pos": 3, "source": "both", "prev_pos": 3, "current_pos": 3, "instruction": "ap_map"},
+            {"trace_pos": 4, "source": "current_data", "prev_pos": null, "current_pos": 4, "instruction": "canon_map"},
+        ]
+    });
+    assert_eq!(actual_report, expected_report);
+
+    // the report is kept apart from the outcome, so it shouldn't leak to an untracked run
+    let result_4 = checked_call_vm!(vm_2, <_>::default(), &script, "", result_3.data);
+    assert!(result_4.merge_provenance.is_empty());
+}
@@ -0,0 +1,173 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use air::UncatchableError;
+use air_interpreter_cid::value_to_cid;
+use air_interpreter_cid::value_to_json_cid;
+use air_interpreter_cid::CidHashAlgorithm;
+use air_interpreter_cid::JSON_CODEC;
+use air_test_utils::prelude::*;
+use air_trace_handler::merger::CallResultError;
+use air_trace_handler::merger::MergeError;
+use air_trace_handler::TraceHandlerError;
+
+use std::rc::Rc;
+
+#[test]
+fn data_of_old_version_is_migrated() {
+    let client_id = "client_id";
+    let mut client = create_avm(echo_call_service(), client_id);
+
+    // data of 0.6.0 version produced by the 0.35.0 interpreter
+    let script = include_str!("../../../../../benches/performance_metering/network_explore/script.air");
+    let prev_data = include_bytes!("../../../../../benches/performance_metering/network_explore/prev_data.json");
+    let current_data = include_bytes!("../../../../../benches/performance_metering/network_explore/cur_data.json");
+
+    let result = checked_call_vm!(
+        client,
+        <_>::default(),
+        script,
+        prev_data.to_vec(),
+        current_data.to_vec()
+    );
+    let data = data_from_result(&result);
+    let current_data = InterpreterData::try_from_slice(current_data).unwrap();
+    assert_eq!(data.trace, current_data.trace);
+    assert_eq!(data.cid_info.value_store, current_data.cid_info.value_store);
+    assert!(data.cid_info.verify().is_ok());
+}
+
+#[test]
+fn scalar_result_of_call_without_output_is_merged_with_unused_one() {
+    let peer_1_id = "peer_1_id";
+    let mut peer_1 = create_avm(echo_call_service(), peer_1_id);
+    let peer_2_id = "peer_2_id";
+    let mut peer_2 = create_avm(echo_call_service(), peer_2_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{peer_1_id}" ("" "") ["result"])
+            (call "{peer_2_id}" ("" "") ["result"])
+        )"#);
+
+    // data of 0.6.0 version stores results of calls without output as scalars
+    let mut value_tracker = CidTracker::new();
+    let prev_trace = vec![scalar_tracked("result", &mut value_tracker)];
+    let mut prev_data: JValue = serde_json::from_slice(&raw_data_from_trace(prev_trace, value_tracker)).unwrap();
+    prev_data["version"] = json!("0.6.0");
+    let prev_data = serde_json::to_vec(&prev_data).unwrap();
+
+    let peer_1_result = checked_call_vm!(peer_1, <_>::default(), &script, "", "");
+    let result = checked_call_vm!(peer_2, <_>::default(), script, prev_data, peer_1_result.data);
+    let data = data_from_result(&result);
+
+    let expected_trace = vec![unused_string("result"), unused_string("result")];
+    assert_eq!(data.trace, expected_trace);
+    assert!(data.cid_info.value_store.is_empty());
+}
+
+#[test]
+fn scalar_result_is_merged_with_unused_one_of_other_hash_algorithm() {
+    let peer_1_id = "peer_1_id";
+    let mut peer_1 = create_avm(echo_call_service(), peer_1_id);
+    let peer_2_id = "peer_2_id";
+    let mut peer_2 = create_avm(echo_call_service(), peer_2_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{peer_1_id}" ("" "") ["result"])
+            (call "{peer_2_id}" ("" "") ["result"])
+        )"#);
+    let peer_1_result = checked_call_vm!(peer_1, <_>::default(), &script, "", "");
+
+    // data of 0.6.0 version produced by a peer using BLAKE3-256 CIDs
+    let prev_data = |value: &str| {
+        let mut value_tracker =
+            CidTracker::from_cid_stores(<_>::default(), <_>::default(), CidHashAlgorithm::Blake3_256);
+        let prev_trace = vec![scalar_tracked(value, &mut value_tracker)];
+        let mut prev_data: JValue = serde_json::from_slice(&raw_data_from_trace(prev_trace, value_tracker)).unwrap();
+        prev_data["version"] = json!("0.6.0");
+        serde_json::to_vec(&prev_data).unwrap()
+    };
+
+    let result = checked_call_vm!(
+        peer_2,
+        <_>::default(),
+        &script,
+        prev_data("result"),
+        peer_1_result.data.clone()
+    );
+    let data = data_from_result(&result);
+
+    let blake3_cid = value_to_cid(&json!("result"), JSON_CODEC, CidHashAlgorithm::Blake3_256).unwrap();
+    let expected_trace = vec![
+        ExecutedState::Call(CallResult::unused(Rc::new(blake3_cid))),
+        unused_string("result"),
+    ];
+    assert_eq!(data.trace, expected_trace);
+
+    // results of different values are still incompatible
+    let result = call_vm!(
+        peer_2,
+        <_>::default(),
+        &script,
+        prev_data("other result"),
+        peer_1_result.data
+    );
+    let other_cid = value_to_cid(&json!("other result"), JSON_CODEC, CidHashAlgorithm::Blake3_256).unwrap();
+    let expected_error = UncatchableError::TraceError {
+        trace_error: TraceHandlerError::MergeError(MergeError::IncorrectCallResult(
+            CallResultError::IncompatibleCallResults {
+                prev_call: CallResult::executed_scalar(Rc::new(other_cid)),
+                current_call: CallResult::unused(value_to_json_cid(&json!("result")).unwrap().into()),
+            },
+        )),
+        instruction: f!(r#"call "{peer_1_id}" ("" "") ["result"] "#),
+    };
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn data_with_legacy_cids_is_accepted() {
+    let peer_2_id = "peer_2_id";
+    let arguments_call_service: CallServiceClosure =
+        Box::new(|params| CallServiceResult::ok(JValue::Array(params.arguments)));
+    let mut peer_2 = create_avm(arguments_call_service, peer_2_id);
+
+    let script = r#"
+        (seq
+            (seq
+                (seq
+                    (call "peer_1_id" ("service" "function") [] scalar)
+                    (call "peer_1_id" ("service" "function") []))
+                (seq
+                    (seq
+                        (call "peer_1_id" ("service" "function") [] $stream)
+                        (call "peer_1_id" ("service" "function") [] $stream))
+                    (canon "peer_1_id" $stream #canon)))
+            (call "peer_2_id" ("service" "function") [scalar #canon] result))
+    "#;
+
+    // produced by the 0.37.0 interpreter, which calculated CIDs from the default JSON form
+    let legacy_data = include_bytes!("../../../../../crates/air-lib/interpreter-data/fixtures/data_0_6_3.json");
+
+    let result = checked_call_vm!(peer_2, <_>::default(), script, "", legacy_data.to_vec());
+    let data = data_from_result(&result);
+    assert!(data.cid_info.verify().is_ok());
+
+    let value = json!({"float": 1.5e30, "int": 42, "list": [1.0, "\u{1f600}"]});
+    let expected_result = json!([value, [value, value]]);
+    assert_eq!(data.trace.last(), Some(&scalar(expected_result)));
+}
@@ -16,4 +16,5 @@
 
 mod air_basic;
 mod data_merge;
+mod data_migration;
 mod executed_trace_basic;
@@ -16,3 +16,4 @@
 
 mod invalid_air;
 mod last_error;
+mod scoped_error;
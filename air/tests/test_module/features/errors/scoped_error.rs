@@ -0,0 +1,160 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_test_utils::prelude::*;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn create_check_service_closure(args_to_check: Rc<RefCell<Option<JValue>>>) -> CallServiceClosure {
+    Box::new(move |params| -> CallServiceResult {
+        *args_to_check.borrow_mut() = Some(JValue::Array(params.arguments));
+        CallServiceResult::ok(json!(""))
+    })
+}
+
+#[test]
+fn scoped_error_is_bound_in_catching_branch() {
+    let local_peer_id = "local_peer_id";
+    let args = Rc::new(RefCell::new(None));
+    let mut local_vm = create_avm(create_check_service_closure(args.clone()), local_peer_id);
+
+    let script = f!(r#"
+        (xor
+            (fail 1 "error message")
+            (call "{local_peer_id}" ("" "") [%error%.$.error_code %error%.$.message])
+        )
+    "#);
+
+    let _ = checked_call_vm!(local_vm, <_>::default(), &script, "", "");
+
+    let actual_args = args.borrow().clone().unwrap();
+    assert_eq!(actual_args, json!([1, "error message"]));
+}
+
+#[test]
+fn scoped_error_is_null_outside_catching_branch() {
+    let local_peer_id = "local_peer_id";
+    let args = Rc::new(RefCell::new(None));
+    let mut local_vm = create_avm(create_check_service_closure(args.clone()), local_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (xor
+                (fail 1 "error message")
+                (null)
+            )
+            (call "{local_peer_id}" ("" "") [%error% %last_error%.$.error_code])
+        )
+    "#);
+
+    let _ = checked_call_vm!(local_vm, <_>::default(), &script, "", "");
+
+    let actual_args = args.borrow().clone().unwrap();
+    assert_eq!(actual_args, json!([null, 1]));
+}
+
+#[test]
+fn scoped_error_is_not_clobbered_by_nested_xor() {
+    let local_peer_id = "local_peer_id";
+    let args = Rc::new(RefCell::new(None));
+    let mut local_vm = create_avm(create_check_service_closure(args.clone()), local_peer_id);
+
+    let script = f!(r#"
+        (xor
+            (fail 1 "outer error")
+            (seq
+                (xor
+                    (fail 2 "inner error")
+                    (null)
+                )
+                (call "{local_peer_id}" ("" "") [%error%.$.message %last_error%.$.message])
+            )
+        )
+    "#);
+
+    let _ = checked_call_vm!(local_vm, <_>::default(), &script, "", "");
+
+    let actual_args = args.borrow().clone().unwrap();
+    assert_eq!(actual_args, json!(["outer error", "inner error"]));
+}
+
+#[test]
+fn scoped_error_is_set_by_not_satisfied_match() {
+    let local_peer_id = "local_peer_id";
+    let args = Rc::new(RefCell::new(None));
+    let mut local_vm = create_avm(create_check_service_closure(args.clone()), local_peer_id);
+
+    let script = f!(r#"
+        (xor
+            (match 1 2
+                (null)
+            )
+            (call "{local_peer_id}" ("" "") [%error%.$.instruction %last_error%])
+        )
+    "#);
+
+    let _ = checked_call_vm!(local_vm, <_>::default(), &script, "", "");
+
+    let actual_args = args.borrow().clone().unwrap();
+    assert_eq!(actual_args, json!(["match 1 2", null]));
+}
+
+#[test]
+fn scoped_error_contains_failed_nested_instruction() {
+    let local_peer_id = "local_peer_id";
+    let args = Rc::new(RefCell::new(None));
+    let mut local_vm = create_avm(create_check_service_closure(args.clone()), local_peer_id);
+
+    let script = f!(r#"
+        (xor
+            (seq
+                (null)
+                (mismatch 1 1
+                    (null)
+                )
+            )
+            (call "{local_peer_id}" ("" "") [%error%.$.instruction])
+        )
+    "#);
+
+    let _ = checked_call_vm!(local_vm, <_>::default(), &script, "", "");
+
+    let actual_args = args.borrow().clone().unwrap();
+    assert_eq!(actual_args, json!(["mismatch 1 1"]));
+}
+
+#[test]
+fn fail_with_scoped_error_rethrows_it() {
+    let local_peer_id = "local_peer_id";
+    let args = Rc::new(RefCell::new(None));
+    let mut local_vm = create_avm(create_check_service_closure(args.clone()), local_peer_id);
+
+    let script = f!(r#"
+        (xor
+            (xor
+                (fail 1 "error message")
+                (fail %error%)
+            )
+            (call "{local_peer_id}" ("" "") [%error%.$.error_code %error%.$.message])
+        )
+    "#);
+
+    let _ = checked_call_vm!(local_vm, <_>::default(), &script, "", "");
+
+    let actual_args = args.borrow().clone().unwrap();
+    assert_eq!(actual_args, json!([1, "error message"]));
+}
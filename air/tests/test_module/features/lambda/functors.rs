@@ -38,7 +38,7 @@ fn length_functor_for_array_scalar() {
 
     let expected_trace = vec![
         executed_state::scalar(json!([1, 1, 1])),
-        executed_state::scalar_number(3),
+        executed_state::unused_number(3),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -103,7 +103,7 @@ fn length_functor_for_stream() {
                 },
             ]
         })),
-        executed_state::scalar_number(2),
+        executed_state::unused_number(2),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -131,7 +131,7 @@ fn length_functor_for_empty_stream() {
             json!({"tetraplet": {"function_name": "", "json_path": "", "peer_pk": "init_peer_id", "service_id": ""},
                 "values": []} ),
         ),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(0),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -166,7 +166,7 @@ fn length_functor_for_canon_stream() {
                            {"result": 1, "tetraplet": {"function_name": "", "json_path": "", "peer_pk": "init_peer_id", "service_id": ""}, "trace_pos": 1}
                 ]} ),
         ),
-        executed_state::scalar_number(2),
+        executed_state::unused_number(2),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -193,7 +193,205 @@ fn length_functor_for_empty_canon_stream() {
         executed_state::canon(
             json!({"tetraplet": {"function_name": "", "json_path": "", "peer_pk": "init_peer_id", "service_id": ""}, "values": []} ),
         ),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(0),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn keys_and_values_functors_for_object_scalar() {
+    let script = r#"
+        (seq
+            (call %init_peer_id% ("" "") [] variable) ; ok = {"b": 2, "a": 1}
+            (seq
+                (call %init_peer_id% ("" "") [variable.keys]) ; behaviour = echo
+                (call %init_peer_id% ("" "") [variable.values]) ; behaviour = echo
+            )
+        )
+        "#;
+
+    let init_peer_id = "init_peer_id";
+    let executor = AirScriptExecutor::simple(TestRunParameters::from_init_peer_id(init_peer_id), script)
+        .expect("invalid test AIR script");
+
+    let result = executor.execute_one(init_peer_id).unwrap();
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![
+        executed_state::scalar(json!({"a": 1, "b": 2})),
+        executed_state::unused(json!(["a", "b"])),
+        executed_state::unused(json!([1, 2])),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn keys_functor_for_non_object_scalar() {
+    let script = r#"
+        (seq
+            (call %init_peer_id% ("" "") [] variable) ; ok = [1, 2]
+            (call %init_peer_id% ("" "") [variable.keys]) ; behaviour = echo
+        )
+        "#;
+
+    let init_peer_id = "init_peer_id";
+    let executor = AirScriptExecutor::simple(TestRunParameters::from_init_peer_id(init_peer_id), script)
+        .expect("invalid test AIR script");
+
+    let result = executor.execute_one(init_peer_id).unwrap();
+    check_error(&result, CatchableError::KeysFunctorAppliedToNotObject(json!([1, 2])));
+}
+
+#[test]
+fn is_empty_functor_for_scalars() {
+    let script = r#"
+        (seq
+            (seq
+                (call %init_peer_id% ("" "") [] array) ; ok = []
+                (call %init_peer_id% ("" "") [] object) ; ok = {"a": 1}
+            )
+            (seq
+                (call %init_peer_id% ("" "") [array.is_empty]) ; behaviour = echo
+                (call %init_peer_id% ("" "") [object.is_empty]) ; behaviour = echo
+            )
+        )
+        "#;
+
+    let init_peer_id = "init_peer_id";
+    let executor = AirScriptExecutor::simple(TestRunParameters::from_init_peer_id(init_peer_id), script)
+        .expect("invalid test AIR script");
+
+    let result = executor.execute_one(init_peer_id).unwrap();
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![
+        executed_state::scalar(json!([])),
+        executed_state::scalar(json!({"a": 1})),
+        executed_state::unused(json!(true)),
+        executed_state::unused(json!(false)),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn first_and_last_functors_for_canon_stream() {
+    let script = r#"
+        (seq
+            (seq
+                (ap 1 $stream)
+                (ap 2 $stream))
+            (seq
+                (canon %init_peer_id% $stream #canon_stream)
+                (seq
+                    (call %init_peer_id% ("" "") [#canon_stream.first]) ; behaviour = echo
+                    (call %init_peer_id% ("" "") [#canon_stream.last]) ; behaviour = echo
+                )
+            )
+        )
+        "#;
+
+    let init_peer_id = "init_peer_id";
+    let executor = AirScriptExecutor::simple(TestRunParameters::from_init_peer_id(init_peer_id), script)
+        .expect("invalid test AIR script");
+
+    let result = executor.execute_one(init_peer_id).unwrap();
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![
+        executed_state::ap(0),
+        executed_state::ap(0),
+        executed_state::canon(
+            json!({"tetraplet": {"function_name": "", "json_path": "", "peer_pk": "init_peer_id", "service_id": ""},
+                "values": [{"result": 1, "tetraplet": {"function_name": "", "json_path": "", "peer_pk": "init_peer_id", "service_id": ""}, "trace_pos": 0},
+                           {"result": 2, "tetraplet": {"function_name": "", "json_path": "", "peer_pk": "init_peer_id", "service_id": ""}, "trace_pos": 1}
+                ]} ),
+        ),
+        executed_state::unused_number(1),
+        executed_state::unused_number(2),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn first_functor_for_empty_array_scalar() {
+    let script = r#"
+        (seq
+            (call %init_peer_id% ("" "") [] variable) ; ok = []
+            (call %init_peer_id% ("" "") [variable.first]) ; behaviour = echo
+        )
+        "#;
+
+    let init_peer_id = "init_peer_id";
+    let executor = AirScriptExecutor::simple(TestRunParameters::from_init_peer_id(init_peer_id), script)
+        .expect("invalid test AIR script");
+
+    let result = executor.execute_one(init_peer_id).unwrap();
+    check_error(
+        &result,
+        CatchableError::ElementFunctorAppliedToEmptyArray(air_lambda_ast::Functor::First),
+    );
+}
+
+#[test]
+fn keys_functor_for_last_error() {
+    let script = r#"
+        (xor
+            (fail 1 "error message")
+            (call %init_peer_id% ("" "") [%last_error%.keys]) ; behaviour = echo
+        )
+        "#;
+
+    let init_peer_id = "init_peer_id";
+    let executor = AirScriptExecutor::simple(TestRunParameters::from_init_peer_id(init_peer_id), script)
+        .expect("invalid test AIR script");
+
+    let result = executor.execute_one(init_peer_id).unwrap();
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![executed_state::unused(json!([
+        "error_code",
+        "instruction",
+        "message",
+        "peer_id"
+    ]))];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn first_functor_keeps_tetraplet() {
+    let set_variable_peer_id = "set_variable_peer_id";
+    let set_variable_peer_result = json!([1, 2, 3]);
+    let mut set_variable_vm = create_avm(
+        set_variable_call_service(set_variable_peer_result.clone()),
+        set_variable_peer_id,
+    );
+
+    let tetraplet_catcher_peer_id = "tetraplet_catcher_peer_id";
+    let (call_service, actual_tetraplet) = tetraplet_host_function(echo_call_service());
+    let mut tetraplet_catcher_vm = create_avm(call_service, tetraplet_catcher_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] scalar)
+            (call "{tetraplet_catcher_peer_id}" ("" "") [scalar.first])
+        )
+        "#);
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+    let result = checked_call_vm!(tetraplet_catcher_vm, <_>::default(), &script, "", result.data);
+    let actual_trace = trace_from_result(&result);
+
+    let expected_tetraplet = RefCell::new(vec![vec![SecurityTetraplet::new(
+        set_variable_peer_id,
+        "",
+        "",
+        ".first",
+    )]]);
+    assert_eq!(actual_tetraplet.as_ref(), &expected_tetraplet);
+
+    let expected_trace = vec![
+        executed_state::scalar(set_variable_peer_result),
+        executed_state::unused_number(1),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -233,7 +431,7 @@ fn functor_dont_influence_tetraplet() {
 
     let expected_trace = vec![
         executed_state::scalar(set_variable_peer_result),
-        executed_state::scalar_number(3),
+        executed_state::unused_number(3),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -18,6 +18,9 @@ use air::CatchableError;
 use air::LambdaError;
 use air_test_utils::prelude::*;
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
 #[test]
 fn lambda_not_allowed_for_non_objects_and_arrays() {
     let set_variable_peer_id = "set_variable";
@@ -71,7 +74,7 @@ fn lambda_with_string_scalar() {
     let result = checked_call_vm!(local_vm, <_>::default(), script, "", result.data);
     let trace = trace_from_result(&result);
 
-    assert_eq!(&trace[2.into()], &executed_state::scalar_number(1u32));
+    assert_eq!(&trace[2.into()], &executed_state::unused_number(1u32));
 }
 
 #[test]
@@ -103,7 +106,7 @@ fn lambda_with_number_scalar() {
     let result = checked_call_vm!(local_vm, <_>::default(), script, "", result.data);
     let trace = trace_from_result(&result);
 
-    assert_eq!(&trace[2.into()], &executed_state::scalar_number(1u32));
+    assert_eq!(&trace[2.into()], &executed_state::unused_number(1u32));
 }
 
 #[test]
@@ -146,7 +149,7 @@ fn lambda_with_number_stream() {
     let result = checked_call_vm!(local_vm, <_>::default(), script, "", result.data);
     let actual_trace = trace_from_result(&result);
 
-    assert_eq!(&actual_trace[6.into()], &executed_state::scalar_number(2));
+    assert_eq!(&actual_trace[6.into()], &executed_state::unused_number(2));
 }
 
 #[test]
@@ -194,7 +197,7 @@ fn lambda_with_number_stream_and_followed_scalar() {
     let result = checked_call_vm!(local_vm, <_>::default(), script, "", result.data);
     let actual_trace = trace_from_result(&result);
 
-    assert_eq!(&actual_trace[7.into()], &executed_state::scalar_number(checkable_value));
+    assert_eq!(&actual_trace[7.into()], &executed_state::unused_number(checkable_value));
 }
 
 #[test]
@@ -277,3 +280,349 @@ fn lambda_with_canon_stream_join() {
         &executed_state::request_sent_by("set_variable"),
     );
 }
+
+#[test]
+fn lambda_with_negative_index_and_slices() {
+    let set_variable_peer_id = "set_variable";
+    let mut set_variable_vm = create_avm(set_variable_call_service(json!([0, 1, 2, 3, 4])), set_variable_peer_id);
+
+    let local_peer_id = "local_peer_id";
+    let mut local_vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] array)
+            (seq
+                (seq
+                    (call "{local_peer_id}" ("" "") [array.$.[-1]])
+                    (call "{local_peer_id}" ("" "") [array.$.[1:3]])
+                )
+                (seq
+                    (call "{local_peer_id}" ("" "") [array.$.[-3:]])
+                    (call "{local_peer_id}" ("" "") [array.$.[::2].[-1]])
+                )
+            )
+        )
+        "#);
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+    let result = checked_call_vm!(local_vm, <_>::default(), script, "", result.data);
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![
+        executed_state::scalar(json!([0, 1, 2, 3, 4])),
+        executed_state::unused_number(4),
+        executed_state::unused(json!([1, 2])),
+        executed_state::unused(json!([2, 3, 4])),
+        executed_state::unused_number(4),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn lambda_with_negative_index_and_slices_for_canon_stream() {
+    let local_peer_id = "local_peer_id";
+    let mut local_vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (seq
+                    (ap 1 $stream)
+                    (ap 2 $stream))
+                (seq
+                    (ap 3 $stream)
+                    (canon "{local_peer_id}" $stream #canon_stream)))
+            (seq
+                (call "{local_peer_id}" ("" "") [#canon_stream.$.[-1]])
+                (call "{local_peer_id}" ("" "") [#canon_stream.$.[:-1]])
+            )
+        )
+        "#);
+
+    let result = checked_call_vm!(local_vm, <_>::default(), script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    assert_eq!(&actual_trace[4.into()], &executed_state::unused_number(3));
+    assert_eq!(&actual_trace[5.into()], &executed_state::unused(json!([1, 2])));
+}
+
+#[test]
+fn negative_index_out_of_range() {
+    let set_variable_peer_id = "set_variable";
+    let mut set_variable_vm = create_avm(set_variable_call_service(json!([0, 1])), set_variable_peer_id);
+
+    let local_peer_id = "local_peer_id";
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] array)
+            (call "{local_peer_id}" ("" "") [array.$.[-3]])
+        )
+        "#);
+
+    let result = call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+
+    let expected_error = CatchableError::LambdaApplierError(LambdaError::ValueNotContainSuchArrayIdxFromEnd {
+        value: json!([0, 1]),
+        idx_from_end: 3,
+    });
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn lambda_with_wildcard_and_filter() {
+    let set_variable_peer_id = "set_variable";
+    let peers = json!([
+        {"peer_id": "peer_1", "weight": 1, "online": true},
+        {"peer_id": "peer_2", "weight": 2, "online": false},
+        {"peer_id": "peer_3", "weight": 3, "online": true},
+    ]);
+    let mut set_variable_vm = create_avm(set_variable_call_service(peers.clone()), set_variable_peer_id);
+
+    let local_peer_id = "local_peer_id";
+    let mut local_vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] peers)
+            (seq
+                (seq
+                    (call "{local_peer_id}" ("" "") [peers.$.[*].peer_id])
+                    (call "{local_peer_id}" ("" "") [peers.$.[?online == true].[*].weight])
+                )
+                (seq
+                    (call "{local_peer_id}" ("" "") [peers.$.[?peer_id == "peer_2"].[0].weight])
+                    (call "{local_peer_id}" ("" "") [peers.$.[?weight == 42]])
+                )
+            )
+        )
+        "#);
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+    let result = checked_call_vm!(local_vm, <_>::default(), script, "", result.data);
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![
+        executed_state::scalar(peers),
+        executed_state::unused(json!(["peer_1", "peer_2", "peer_3"])),
+        executed_state::unused(json!([1, 3])),
+        executed_state::unused_number(2),
+        executed_state::unused(json!([])),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn lambda_with_filter_for_canon_stream() {
+    let local_peer_id = "local_peer_id";
+    let mut local_vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (seq
+                    (ap {{"name": "a" "value": 1}} $stream)
+                    (ap {{"name": "b" "value": 2}} $stream))
+                (seq
+                    (ap {{"name": "a" "value": 3}} $stream)
+                    (canon "{local_peer_id}" $stream #canon_stream)))
+            (seq
+                (call "{local_peer_id}" ("" "") [#canon_stream.$.[?name == "a"].[*].value])
+                (call "{local_peer_id}" ("" "") [#canon_stream.$.[*].name])
+            )
+        )
+        "#);
+
+    let result = checked_call_vm!(local_vm, <_>::default(), script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    assert_eq!(&actual_trace[4.into()], &executed_state::unused(json!([1, 3])));
+    assert_eq!(&actual_trace[5.into()], &executed_state::unused(json!(["a", "b", "a"])));
+}
+
+#[test]
+fn wildcard_applied_to_not_collection() {
+    let set_variable_peer_id = "set_variable";
+    let mut set_variable_vm = create_avm(set_variable_call_service(json!({"field": 1})), set_variable_peer_id);
+
+    let local_peer_id = "local_peer_id";
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] value)
+            (call "{local_peer_id}" ("" "") [value.$.field.[*]])
+        )
+        "#);
+
+    let result = call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+
+    let expected_error = CatchableError::LambdaApplierError(LambdaError::WildcardNotMatchValue { value: json!(1) });
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn lambda_with_optional_accessors() {
+    let set_variable_peer_id = "set_variable";
+    let value = json!({"peers": ["peer_1", "peer_2"], "info": null});
+    let mut set_variable_vm = create_avm(set_variable_call_service(value.clone()), set_variable_peer_id);
+
+    let local_peer_id = "local_peer_id";
+    let mut local_vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] value)
+            (seq
+                (seq
+                    (call "{local_peer_id}" ("" "") [value.$.peers?.[1]?])
+                    (call "{local_peer_id}" ("" "") [value.$.peers.[5]?])
+                )
+                (seq
+                    (call "{local_peer_id}" ("" "") [value.$.missing?.field])
+                    (call "{local_peer_id}" ("" "") [value.$.unknown?])
+                )
+            )
+        )
+        "#);
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+    let result = checked_call_vm!(local_vm, <_>::default(), script, "", result.data);
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![
+        executed_state::scalar(value),
+        executed_state::unused_string("peer_2"),
+        executed_state::unused(json!(null)),
+        executed_state::unused(json!(null)),
+        executed_state::unused(json!(null)),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn lambda_with_optional_accessor_for_canon_stream() {
+    let local_peer_id = "local_peer_id";
+    let mut local_vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (ap 1 $stream)
+                (canon "{local_peer_id}" $stream #canon_stream))
+            (seq
+                (call "{local_peer_id}" ("" "") [#canon_stream.$.[0]?])
+                (call "{local_peer_id}" ("" "") [#canon_stream.$.[3]?])
+            )
+        )
+        "#);
+
+    let result = checked_call_vm!(local_vm, <_>::default(), script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    assert_eq!(&actual_trace[2.into()], &executed_state::unused_number(1));
+    assert_eq!(&actual_trace[3.into()], &executed_state::unused(json!(null)));
+}
+
+#[test]
+fn non_optional_accessor_after_optional_one_fails() {
+    let set_variable_peer_id = "set_variable";
+    let mut set_variable_vm = create_avm(set_variable_call_service(json!({"field": {}})), set_variable_peer_id);
+
+    let local_peer_id = "local_peer_id";
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] value)
+            (call "{local_peer_id}" ("" "") [value.$.field?.missing])
+        )
+        "#);
+
+    let result = call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+
+    let expected_error = CatchableError::LambdaApplierError(LambdaError::ValueNotContainSuchField {
+        value: json!({}),
+        field_name: "missing".to_string(),
+    });
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn optional_accessor_applied_to_value_of_other_type_fails() {
+    let set_variable_peer_id = "set_variable";
+    let mut set_variable_vm = create_avm(
+        set_variable_call_service(json!({"number": 1, "array": [1]})),
+        set_variable_peer_id,
+    );
+
+    let local_peer_id = "local_peer_id";
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] value)
+            (call "{local_peer_id}" ("" "") [value.$.number.field?])
+        )
+        "#);
+
+    let result = call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+
+    let expected_error = CatchableError::LambdaApplierError(LambdaError::FieldAccessorNotMatchValue {
+        value: json!(1),
+        field_name: "field".to_string(),
+    });
+    assert!(check_error(&result, expected_error));
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] value)
+            (call "{local_peer_id}" ("" "") [value.$.array.field?])
+        )
+        "#);
+
+    let result = call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+
+    let expected_error = CatchableError::LambdaApplierError(LambdaError::FieldAccessorNotMatchValue {
+        value: json!([1]),
+        field_name: "field".to_string(),
+    });
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn canon_stream_slice_wildcard_and_filter_keep_tetraplet() {
+    let canon_peer_id = "canon_peer_id";
+    let mut canon_vm = create_avm(echo_call_service(), canon_peer_id);
+
+    let local_peer_id = "local_peer_id";
+    let actual_tetraplets = Rc::new(RefCell::new(Vec::new()));
+    let actual_tetraplets_inner = actual_tetraplets.clone();
+    let tetraplets_collector: CallServiceClosure = Box::new(move |params| -> CallServiceResult {
+        actual_tetraplets_inner.borrow_mut().push(params.tetraplets.clone());
+        CallServiceResult::ok(params.arguments[0].clone())
+    });
+    let mut local_vm = create_avm(tetraplets_collector, local_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (seq
+                    (ap {{"name": "a"}} $stream)
+                    (ap {{"name": "b"}} $stream))
+                (canon "{canon_peer_id}" $stream #canon_stream))
+            (seq
+                (call "{local_peer_id}" ("" "") [#canon_stream.$.[:-1]])
+                (seq
+                    (call "{local_peer_id}" ("" "") [#canon_stream.$.[*].name])
+                    (call "{local_peer_id}" ("" "") [#canon_stream.$.[?name == "b"]]))))
+        "#);
+
+    let result = checked_call_vm!(canon_vm, <_>::default(), &script, "", "");
+    checked_call_vm!(local_vm, <_>::default(), &script, "", result.data);
+
+    // results of these accessors consist of several stream values, so they keep the canon stream tetraplet
+    let expected_tetraplets = [".$.[:-1]", ".$.[*].name", r#".$.[?name == "b"]"#]
+        .iter()
+        .map(|json_path| vec![vec![SecurityTetraplet::new(canon_peer_id, "", "", *json_path)]])
+        .collect::<Vec<_>>();
+    assert_eq!(*actual_tetraplets.borrow(), expected_tetraplets);
+}
@@ -66,8 +66,8 @@ fn scalars_scope() {
         executed_state::scalar(peers),
         executed_state::par(1, 0),
         executed_state::scalar(array_1_content),
-        executed_state::scalar_string("result from unit_call_service"),
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
         executed_state::par(1, 0),
         executed_state::request_sent_by(some_peer_id),
     ];
@@ -116,9 +116,9 @@ fn before_after_of_next() {
         executed_state::scalar_number(0),
         executed_state::scalar_number(1),
         executed_state::scalar_number(2),
-        executed_state::scalar_number(2),
-        executed_state::scalar_number(1),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(2),
+        executed_state::unused_number(1),
+        executed_state::unused_number(0),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -195,17 +195,17 @@ fn local_and_global_scalars() {
         executed_state::scalar_number(0),
         executed_state::scalar_number(1),
         executed_state::scalar_number(2),
-        executed_state::scalar_number(2),
-        executed_state::scalar_number(3),
+        executed_state::unused_number(2),
         executed_state::scalar_number(3),
-        executed_state::scalar_number(1),
+        executed_state::unused_number(3),
+        executed_state::unused_number(1),
         executed_state::scalar_number(4),
         executed_state::scalar_number(5),
-        executed_state::scalar_number(5),
-        executed_state::scalar_number(6),
+        executed_state::unused_number(5),
         executed_state::scalar_number(6),
-        executed_state::scalar_number(4),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(6),
+        executed_state::unused_number(4),
+        executed_state::unused_number(0),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -109,7 +109,7 @@ fn scopes_check_that_scalar_not_overwritten_by_fold_end() {
         executed_state::scalar(json!([1, 2, 3])),
         executed_state::scalar(json!([1, 2, 3])),
         executed_state::service_failed(1, "failed result from fallible_call_service"),
-        executed_state::scalar(json!([1, 2, 3])),
+        executed_state::unused(json!([1, 2, 3])),
     ];
 
     assert_eq!(actual_trace, expected_trace);
@@ -0,0 +1,228 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::PreparationError;
+use air::UncatchableError;
+use air_interpreter_cid::value_to_json_cid;
+use air_interpreter_signatures::KeyPair;
+use air_interpreter_signatures::SignatureVerificationError;
+use air_test_utils::prelude::*;
+
+const SIGNING_KEY: [u8; 32] = [42; 32];
+const OTHER_SIGNING_KEY: [u8; 32] = [43; 32];
+
+fn signing_run_params() -> TestRunParameters {
+    TestRunParameters {
+        signing_key: SIGNING_KEY.to_vec(),
+        ..<_>::default()
+    }
+}
+
+fn other_signing_run_params() -> TestRunParameters {
+    TestRunParameters {
+        signing_key: OTHER_SIGNING_KEY.to_vec(),
+        ..<_>::default()
+    }
+}
+
+fn requiring_run_params() -> TestRunParameters {
+    TestRunParameters {
+        signing_key: OTHER_SIGNING_KEY.to_vec(),
+        require_signatures: true,
+        ..<_>::default()
+    }
+}
+
+fn call_script(vm_1_peer_id: &str, vm_2_peer_id: &str) -> String {
+    f!(r#"
+       (seq
+          (call "{vm_1_peer_id}" ("service" "call") [] scalar)
+          (call "{vm_2_peer_id}" ("service" "call") [scalar] result))"#)
+}
+
+fn canon_script(vm_1_peer_id: &str, vm_2_peer_id: &str) -> String {
+    f!(r#"
+       (seq
+          (seq
+             (call "{vm_1_peer_id}" ("service" "call") [] $stream)
+             (canon "{vm_1_peer_id}" $stream #canon))
+          (call "{vm_2_peer_id}" ("service" "call") [#canon] result))"#)
+}
+
+#[test]
+fn test_produced_states_are_signed() {
+    let vm_1_peer_id = KeyPair::from_secret_key(&SIGNING_KEY).unwrap().peer_id();
+    let vm_2_peer_id = "vm_2_peer_id";
+    let mut vm_1 = create_avm(set_variable_call_service(json!("value")), &vm_1_peer_id);
+    let mut vm_2 = create_avm(echo_call_service(), vm_2_peer_id);
+
+    let air_script = canon_script(&vm_1_peer_id, vm_2_peer_id);
+    let result_1 = checked_call_vm!(vm_1, signing_run_params(), &air_script, "", "");
+    let data_1 = data_from_result(&result_1);
+
+    // each run signs states it produced: the first one sends a request to the local service,
+    // the second one signs the call, the canon and the request sent to vm_2 at once
+    let signed_cids = data_1.signatures.get(&vm_1_peer_id).unwrap();
+    assert_eq!(signed_cids.len(), 2);
+    assert_eq!(signed_cids[0].cids.len(), 1);
+    assert_eq!(signed_cids[1].cids.len(), 3);
+
+    // a peer without a signing key accepts signed data and keeps its signatures
+    let result_2 = checked_call_vm!(vm_2, <_>::default(), &air_script, "", result_1.data);
+    let data_2 = data_from_result(&result_2);
+    assert_eq!(data_2.signatures, data_1.signatures);
+}
+
+#[test]
+fn test_forged_canon_is_rejected() {
+    let vm_1_peer_id = KeyPair::from_secret_key(&SIGNING_KEY).unwrap().peer_id();
+    let vm_2_peer_id = "vm_2_peer_id";
+    let mut vm_1 = create_avm(set_variable_call_service(json!("value")), &vm_1_peer_id);
+    let mut vm_2 = create_avm(echo_call_service(), vm_2_peer_id);
+
+    let air_script = canon_script(&vm_1_peer_id, vm_2_peer_id);
+    let result_1 = checked_call_vm!(vm_1, signing_run_params(), &air_script, "", "");
+
+    let mut forged_data: JValue = serde_json::from_slice(&result_1.data).unwrap();
+    let forged_canon = &mut forged_data["trace"][1]["canon"];
+    forged_canon["values"].as_array_mut().unwrap().clear();
+    let forged_canon: CanonResult = serde_json::from_value(forged_canon.clone()).unwrap();
+    let forged_data = serde_json::to_vec(&forged_data).unwrap();
+
+    let result_2 = call_vm!(vm_2, <_>::default(), &air_script, "", forged_data);
+    let expected_error = PreparationError::SignatureVerificationFailed(SignatureVerificationError::UnsignedState {
+        peer_id: vm_1_peer_id,
+        cid: value_to_json_cid(&forged_canon).unwrap().into(),
+    });
+    assert!(check_error(&result_2, expected_error));
+}
+
+#[test]
+fn test_forged_signature_is_rejected() {
+    let vm_1_peer_id = KeyPair::from_secret_key(&SIGNING_KEY).unwrap().peer_id();
+    let vm_2_peer_id = "vm_2_peer_id";
+    let mut vm_1 = create_avm(set_variable_call_service(json!("value")), &vm_1_peer_id);
+    let mut vm_2 = create_avm(echo_call_service(), vm_2_peer_id);
+
+    let air_script = canon_script(&vm_1_peer_id, vm_2_peer_id);
+    let result_1 = checked_call_vm!(vm_1, signing_run_params(), &air_script, "", "");
+
+    let mut forged_data: JValue = serde_json::from_slice(&result_1.data).unwrap();
+    forged_data["signatures"][&vm_1_peer_id][0]["cids"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!("forged cid"));
+    let forged_data = serde_json::to_vec(&forged_data).unwrap();
+
+    let expected_error = || {
+        PreparationError::SignatureVerificationFailed(SignatureVerificationError::InvalidSignature {
+            peer_id: vm_1_peer_id.clone(),
+        })
+    };
+    let result_2 = call_vm!(vm_2, <_>::default(), &air_script, "", forged_data);
+    assert!(check_error(&result_2, expected_error()));
+
+    // signatures are bound to a particle
+    let other_particle_params = TestRunParameters {
+        particle_id: "other_particle_id".to_string(),
+        ..<_>::default()
+    };
+    let result_2 = call_vm!(vm_2, other_particle_params, &air_script, "", result_1.data);
+    assert!(check_error(&result_2, expected_error()));
+}
+
+#[test]
+fn test_forged_call_is_rejected() {
+    let vm_1_peer_id = KeyPair::from_secret_key(&SIGNING_KEY).unwrap().peer_id();
+    let vm_2_peer_id = "vm_2_peer_id";
+    let mut vm_1 = create_avm(set_variable_call_service(json!("value")), &vm_1_peer_id);
+    let mut vm_2 = create_avm(echo_call_service(), vm_2_peer_id);
+
+    let air_script = call_script(&vm_1_peer_id, vm_2_peer_id);
+    let result_1 = checked_call_vm!(vm_1, signing_run_params(), &air_script, "", "");
+
+    let forged_call = CallResult::failed(1, "forged error");
+    let mut forged_data: JValue = serde_json::from_slice(&result_1.data).unwrap();
+    forged_data["trace"][0] = serde_json::to_value(ExecutedState::Call(forged_call.clone())).unwrap();
+    let forged_data = serde_json::to_vec(&forged_data).unwrap();
+
+    let result_2 = call_vm!(vm_2, <_>::default(), &air_script, "", forged_data);
+    let expected_error = UncatchableError::SignatureVerificationFailed(SignatureVerificationError::UnsignedState {
+        peer_id: vm_1_peer_id,
+        cid: value_to_json_cid(&forged_call).unwrap().into(),
+    });
+    assert!(check_error(&result_2, expected_error));
+}
+
+#[test]
+fn test_stripped_signatures_are_rejected_if_required() {
+    let vm_1_peer_id = KeyPair::from_secret_key(&SIGNING_KEY).unwrap().peer_id();
+    let vm_2_peer_id = KeyPair::from_secret_key(&OTHER_SIGNING_KEY).unwrap().peer_id();
+    let mut vm_1 = create_avm(set_variable_call_service(json!("value")), &vm_1_peer_id);
+    let mut vm_2 = create_avm(echo_call_service(), &vm_2_peer_id);
+
+    let air_script = canon_script(&vm_1_peer_id, &vm_2_peer_id);
+    let result_1 = checked_call_vm!(vm_1, signing_run_params(), &air_script, "", "");
+    let data_1 = data_from_result(&result_1);
+    let canon_result = match &data_1.trace[1.into()] {
+        ExecutedState::Canon(canon_result) => canon_result.clone(),
+        state => panic!("expected a canon state, got {state:?}"),
+    };
+
+    let mut stripped_data: JValue = serde_json::from_slice(&result_1.data).unwrap();
+    stripped_data["signatures"] = json!({});
+    let stripped_data = serde_json::to_vec(&stripped_data).unwrap();
+
+    // a signing peer accepts unsigned data, unless signatures are required
+    let mut vm_3 = create_avm(echo_call_service(), &vm_2_peer_id);
+    checked_call_vm!(vm_3, other_signing_run_params(), &air_script, "", stripped_data.clone());
+
+    let result_2 = call_vm!(vm_2, requiring_run_params(), &air_script, "", stripped_data);
+    let expected_error = PreparationError::SignatureVerificationFailed(SignatureVerificationError::UnsignedState {
+        peer_id: vm_1_peer_id,
+        cid: value_to_json_cid(&canon_result).unwrap().into(),
+    });
+    assert!(check_error(&result_2, expected_error));
+}
+
+#[test]
+fn test_unsigned_call_is_rejected_if_required() {
+    let vm_1_peer_id = "vm_1_peer_id";
+    let vm_2_peer_id = KeyPair::from_secret_key(&OTHER_SIGNING_KEY).unwrap().peer_id();
+    let mut vm_1 = create_avm(set_variable_call_service(json!("value")), vm_1_peer_id);
+    let mut vm_2 = create_avm(echo_call_service(), &vm_2_peer_id);
+
+    let air_script = call_script(vm_1_peer_id, &vm_2_peer_id);
+    let result_1 = checked_call_vm!(vm_1, <_>::default(), &air_script, "", "");
+    let data_1 = data_from_result(&result_1);
+    let unsigned_call = match &data_1.trace[0.into()] {
+        ExecutedState::Call(call_result) => call_result.clone(),
+        state => panic!("expected a call state, got {state:?}"),
+    };
+
+    // peers accept unsigned data, unless signatures are required
+    let mut vm_3 = create_avm(echo_call_service(), &vm_2_peer_id);
+    checked_call_vm!(vm_3, <_>::default(), &air_script, "", result_1.data.clone());
+    let mut vm_4 = create_avm(echo_call_service(), &vm_2_peer_id);
+    checked_call_vm!(vm_4, other_signing_run_params(), &air_script, "", result_1.data.clone());
+
+    let result_2 = call_vm!(vm_2, requiring_run_params(), &air_script, "", result_1.data);
+    let expected_error = UncatchableError::SignatureVerificationFailed(SignatureVerificationError::UnsignedState {
+        peer_id: vm_1_peer_id.to_string(),
+        cid: value_to_json_cid(&unsigned_call).unwrap().into(),
+    });
+    assert!(check_error(&result_2, expected_error));
+}
@@ -156,7 +156,7 @@ fn recursive_stream_many_iterations() {
     let result = checked_call_vm!(vm_2, <_>::default(), script, "", result.data);
     let actual_trace = trace_from_result(&result);
     let actual_last_state = actual_trace.last().unwrap();
-    let expected_last_state = executed_state::scalar_string(result_value);
+    let expected_last_state = executed_state::unused_string(result_value);
     assert_eq!(actual_last_state, &expected_last_state);
 }
 
@@ -236,8 +236,8 @@ fn recursive_stream_join() {
         executed_state::scalar_string("non_join"),
         executed_state::ap(4),
         executed_state::scalar_string("join"),
-        executed_state::scalar_string(""),
-        executed_state::scalar_string(result_value),
+        executed_state::unused_string(""),
+        executed_state::unused_string(result_value),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -371,7 +371,7 @@ fn recursive_stream_inner_fold() {
     let actual_trace = trace_from_result(&result);
 
     let actual_last_state = actual_trace.last().unwrap();
-    let expected_last_state = executed_state::scalar_string(result_value);
+    let expected_last_state = executed_state::unused_string(result_value);
     assert_eq!(actual_last_state, &expected_last_state);
 }
 
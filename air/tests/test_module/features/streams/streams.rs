@@ -81,7 +81,7 @@ fn stream_merging_v0() {
 
     let unit_call_service_result = "result from unit_call_service";
     let expected_trace_1 = vec![
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(11, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -100,12 +100,12 @@ fn stream_merging_v0() {
             executed_state::subtrace_lore(9, subtrace_desc(17, 2), subtrace_desc(21, 0)),
             executed_state::subtrace_lore(12, subtrace_desc(19, 2), subtrace_desc(21, 0)),
         ]),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
     ];
     assert_eq!(actual_trace_1, expected_trace_1);
 
@@ -119,7 +119,7 @@ fn stream_merging_v0() {
     let actual_trace_2 = trace_from_result(&executor_result_2);
 
     let expected_trace_2 = vec![
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(11, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -140,16 +140,16 @@ fn stream_merging_v0() {
             executed_state::subtrace_lore(8, subtrace_desc(21, 2), subtrace_desc(25, 0)),
             executed_state::subtrace_lore(13, subtrace_desc(23, 2), subtrace_desc(25, 0)),
         ]),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
     ];
     assert_eq!(actual_trace_2.deref(), expected_trace_2);
 
@@ -163,7 +163,7 @@ fn stream_merging_v0() {
     let actual_trace_3 = trace_from_result(&executor_result_3);
 
     let expected_trace_3 = vec![
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(11, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -186,20 +186,20 @@ fn stream_merging_v0() {
             executed_state::subtrace_lore(10, subtrace_desc(25, 2), subtrace_desc(29, 0)),
             executed_state::subtrace_lore(11, subtrace_desc(27, 2), subtrace_desc(29, 0)),
         ]),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
     ];
     assert_eq!(actual_trace_3, expected_trace_3);
 }
@@ -233,7 +233,7 @@ fn stream_merging_v1() {
 
     let unit_call_service_result = "result from unit_call_service";
     let expected_trace_1 = vec![
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(11, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -253,14 +253,14 @@ fn stream_merging_v1() {
             executed_state::subtrace_lore(12, subtrace_desc(19, 2), subtrace_desc(21, 1)),
         ]),
         executed_state::par(7, 1),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(4, 1),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(1, 1),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
     ];
     assert_eq!(actual_trace_1, expected_trace_1);
 
@@ -274,7 +274,7 @@ fn stream_merging_v1() {
     let actual_trace_2 = trace_from_result(&executor_result_2);
 
     let expected_trace_2 = vec![
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(11, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -296,20 +296,20 @@ fn stream_merging_v1() {
             executed_state::subtrace_lore(13, subtrace_desc(26, 2), subtrace_desc(28, 1)),
         ]),
         executed_state::par(7, 1),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(4, 1),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(1, 1),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(4, 1),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(1, 1),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
     ];
     assert_eq!(actual_trace_2, expected_trace_2);
 
@@ -323,7 +323,7 @@ fn stream_merging_v1() {
     let actual_trace_3 = trace_from_result(&executor_result_3);
 
     let expected_trace_3 = vec![
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(11, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -347,26 +347,26 @@ fn stream_merging_v1() {
             executed_state::subtrace_lore(11, subtrace_desc(32, 2), subtrace_desc(34, 1)),
         ]),
         executed_state::par(7, 1),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(4, 1),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(1, 1),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(4, 1),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(1, 1),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(4, 1),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(1, 1),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
     ];
     assert_eq!(actual_trace_3, expected_trace_3);
 }
@@ -401,7 +401,7 @@ fn stream_merging_v2() {
 
     let unit_call_service_result = "result from unit_call_service";
     let expected_trace_1 = vec![
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(11, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -420,12 +420,12 @@ fn stream_merging_v2() {
             executed_state::subtrace_lore(9, subtrace_desc(16, 1), subtrace_desc(19, 2)),
             executed_state::subtrace_lore(12, subtrace_desc(17, 1), subtrace_desc(18, 2)),
         ]),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
     ];
     assert_eq!(actual_trace_1, expected_trace_1);
 
@@ -439,7 +439,7 @@ fn stream_merging_v2() {
     let actual_trace_2 = trace_from_result(&executor_result_2);
 
     let expected_trace_2 = vec![
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(11, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -460,16 +460,16 @@ fn stream_merging_v2() {
             executed_state::subtrace_lore(8, subtrace_desc(21, 0), subtrace_desc(23, 2)),
             executed_state::subtrace_lore(13, subtrace_desc(21, 0), subtrace_desc(21, 2)),
         ]),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
     ];
     assert_eq!(actual_trace_2, expected_trace_2);
 
@@ -483,7 +483,7 @@ fn stream_merging_v2() {
     let actual_trace_3 = trace_from_result(&executor_result_3);
 
     let expected_trace_3 = vec![
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(11, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -506,20 +506,20 @@ fn stream_merging_v2() {
             executed_state::subtrace_lore(10, subtrace_desc(25, 0), subtrace_desc(27, 2)),
             executed_state::subtrace_lore(11, subtrace_desc(25, 0), subtrace_desc(25, 2)),
         ]),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
     ];
     assert_eq!(actual_trace_3, expected_trace_3);
 }
@@ -47,7 +47,7 @@ fn par_early_exit() {
     let actual_trace_1 = trace_from_result(&setter_3_res_1);
 
     let expected_trace = vec![
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
         executed_state::par(12, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -91,7 +91,7 @@ fn par_early_exit() {
     let actual_trace_3 = trace_from_result(&init_result_2);
 
     let expected_trace = vec![
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
         executed_state::par(12, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -111,7 +111,7 @@ fn par_early_exit() {
     assert_eq!(actual_trace_2, expected_trace);
 
     let expected_trace = vec![
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
         executed_state::par(12, 1),
         executed_state::par(9, 1),
         executed_state::par(7, 1),
@@ -126,7 +126,7 @@ fn par_early_exit() {
         executed_state::stream_string("success result from fallible_call_service", 0),
         executed_state::service_failed(1, "failed result from fallible_call_service"),
         executed_state::service_failed(1, "failed result from fallible_call_service"),
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
     ];
     assert_eq!(actual_trace_3, expected_trace);
 
@@ -209,7 +209,7 @@ fn fold_early_exit() {
     );
     let actual_trace = trace_from_result(&last_peer_checker_result);
 
-    let expected_state = executed_state::scalar(json!({
+    let expected_state = executed_state::unused(json!({
                 "error_code": 10000i64,
                 "instruction" : r#"call "error_trigger_id" ("error" "") [] "#,
                 "message": r#"Local service error, ret_code is 1, error message is '"failed result from fallible_call_service"'"#,
@@ -315,18 +315,18 @@ fn fold_par_early_exit() {
             executed_state::subtrace_lore(11, subtrace_desc(23, 2), subtrace_desc(25, 0)),
         ]),
         executed_state::par(1, 0),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(1, 0),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(5, 0),
         executed_state::fold(vec![
             executed_state::subtrace_lore(10, subtrace_desc(27, 2), subtrace_desc(29, 0)),
             executed_state::subtrace_lore(11, subtrace_desc(29, 2), subtrace_desc(31, 0)),
         ]),
         executed_state::par(1, 0),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::par(1, 0),
-        executed_state::scalar_string(unit_call_service_result),
+        executed_state::unused_string(unit_call_service_result),
         executed_state::service_failed(1, "failed result from fallible_call_service"),
         executed_state::par(15, 0),
         executed_state::par(13, 1),
@@ -196,6 +196,126 @@ fn check_tetraplet_works_correctly() {
     assert_eq!(arg_tetraplets, expected_tetraplets);
 }
 
+#[test]
+fn array_literal_keeps_tetraplets_of_elements() {
+    let set_variable_vm_peer_id = String::from("some_peer_id_1");
+    let mut set_variable_vm = create_avm(
+        set_variable_call_service(json!({"args": ["1", "2"]})),
+        set_variable_vm_peer_id.clone(),
+    );
+
+    let (arg_host_func, arg_tetraplets) = arg_host_function();
+    let client_peer_id = String::from("client_id");
+    let mut client_vm = create_avm(arg_host_func, client_peer_id.clone());
+
+    let service_id = String::from("some_service_id");
+    let function_name = String::from("some_function_name");
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_vm_peer_id}" ("{service_id}" "{function_name}") [] value)
+            (call "{client_peer_id}" ("local_service_id" "local_fn_name") [["literal" value.$.args.[0]]])
+        )"#);
+
+    let test_params = TestRunParameters::from_init_peer_id("init_peer_id");
+    let result = checked_call_vm!(set_variable_vm, test_params.clone(), script.clone(), "", "");
+    checked_call_vm!(client_vm, test_params.clone(), script, "", result.data);
+
+    let literal_tetraplet = SecurityTetraplet::literal_tetraplet(test_params.init_peer_id);
+    let value_tetraplet = SecurityTetraplet {
+        peer_pk: set_variable_vm_peer_id,
+        service_id,
+        function_name,
+        json_path: String::from(".$.args.[0]"),
+    };
+
+    let expected_tetraplets = vec![vec![literal_tetraplet, value_tetraplet]];
+    let expected_tetraplets = Rc::new(RefCell::new(expected_tetraplets));
+    assert_eq!(arg_tetraplets, expected_tetraplets);
+}
+
+#[test]
+fn slice_is_recorded_in_tetraplet() {
+    let set_variable_vm_peer_id = String::from("some_peer_id_1");
+    let mut set_variable_vm = create_avm(
+        set_variable_call_service(json!({"args": ["1", "2", "3"]})),
+        set_variable_vm_peer_id.clone(),
+    );
+
+    let (arg_host_func, arg_tetraplets) = arg_host_function();
+    let client_peer_id = String::from("client_id");
+    let mut client_vm = create_avm(arg_host_func, client_peer_id.clone());
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_vm_peer_id}" ("" "") [] value)
+            (call "{client_peer_id}" ("local_service_id" "local_fn_name") [value.$.args.[1:] value.$.args.[-1]])
+        )"#);
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), script.clone(), "", "");
+    checked_call_vm!(client_vm, <_>::default(), script, "", result.data);
+
+    let first_arg_tetraplet = SecurityTetraplet::new(set_variable_vm_peer_id.clone(), "", "", ".$.args.[1:]");
+    let second_arg_tetraplet = SecurityTetraplet::new(set_variable_vm_peer_id, "", "", ".$.args.[-1]");
+
+    let expected_tetraplets = vec![vec![first_arg_tetraplet], vec![second_arg_tetraplet]];
+    let expected_tetraplets = Rc::new(RefCell::new(expected_tetraplets));
+    assert_eq!(arg_tetraplets, expected_tetraplets);
+}
+
+#[test]
+fn optional_accessor_is_recorded_in_tetraplet() {
+    let set_variable_vm_peer_id = String::from("some_peer_id_1");
+    let mut set_variable_vm = create_avm(
+        set_variable_call_service(json!({"args": ["1", "2", "3"]})),
+        set_variable_vm_peer_id.clone(),
+    );
+
+    let (arg_host_func, arg_tetraplets) = arg_host_function();
+    let client_peer_id = String::from("client_id");
+    let mut client_vm = create_avm(arg_host_func, client_peer_id.clone());
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_vm_peer_id}" ("" "") [] value)
+            (call "{client_peer_id}" ("local_service_id" "local_fn_name") [value.$.args.[0]? value.$.other?.[0]])
+        )"#);
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), script.clone(), "", "");
+    checked_call_vm!(client_vm, <_>::default(), script, "", result.data);
+
+    let first_arg_tetraplet = SecurityTetraplet::new(set_variable_vm_peer_id.clone(), "", "", ".$.args.[0]?");
+    let second_arg_tetraplet = SecurityTetraplet::new(set_variable_vm_peer_id, "", "", ".$.other?.[0]");
+
+    let expected_tetraplets = vec![vec![first_arg_tetraplet], vec![second_arg_tetraplet]];
+    let expected_tetraplets = Rc::new(RefCell::new(expected_tetraplets));
+    assert_eq!(arg_tetraplets, expected_tetraplets);
+}
+
+#[test]
+fn particle_id_has_host_tetraplet() {
+    let (arg_host_func, arg_tetraplets) = arg_host_function();
+    let client_peer_id = String::from("client_id");
+    let mut client_vm = create_avm(arg_host_func, client_peer_id.clone());
+
+    let script = f!(r#"
+        (seq
+            (ap %particle_id% particle_id)
+            (call "{client_peer_id}" ("local_service_id" "local_fn_name") [%particle_id% particle_id])
+        )"#);
+
+    let test_params = TestRunParameters {
+        init_peer_id: String::from("init_peer_id"),
+        particle_id: String::from("some_particle_id"),
+        ..<_>::default()
+    };
+    checked_call_vm!(client_vm, test_params, script, "", "");
+
+    let host_tetraplet = SecurityTetraplet::host_tetraplet(client_peer_id, "%particle_id%");
+    let expected_tetraplets = vec![vec![host_tetraplet.clone()], vec![host_tetraplet]];
+    let expected_tetraplets = Rc::new(RefCell::new(expected_tetraplets));
+    assert_eq!(arg_tetraplets, expected_tetraplets);
+}
+
 use fluence_app_service::AppService;
 use fluence_app_service::AppServiceConfig;
 use fluence_app_service::MarineConfig;
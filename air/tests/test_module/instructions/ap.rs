@@ -14,6 +14,7 @@
  * limitations under the License.
  */
 
+use air::CatchableError;
 use air_test_utils::prelude::*;
 
 use std::cell::RefCell;
@@ -43,7 +44,7 @@ fn ap_with_scalars() {
     let actual_trace = trace_from_result(&result);
     let expected_state = vec![
         executed_state::scalar(json!({ "field": test_value })),
-        executed_state::scalar_string(test_value),
+        executed_state::unused_string(test_value),
     ];
 
     assert_eq!(actual_trace, expected_state);
@@ -81,7 +82,7 @@ fn ap_with_string_literal() {
             ]
         }
         )),
-        executed_state::scalar(json!([some_string])),
+        executed_state::unused(json!([some_string])),
     ];
 
     assert_eq!(actual_trace, expected_state);
@@ -116,7 +117,7 @@ fn ap_with_bool_literal() {
                 }
             ]
         })),
-        executed_state::scalar(json!([true])),
+        executed_state::unused(json!([true])),
     ];
 
     assert_eq!(actual_trace, expected_state);
@@ -151,13 +152,54 @@ fn ap_with_number_literal() {
                 }
             ]
         })),
-        executed_state::scalar(json!([100])),
+        executed_state::unused(json!([100])),
     ];
 
     assert_eq!(actual_trace, expected_state);
     assert!(result.next_peer_pks.is_empty());
 }
 
+#[test]
+fn ap_with_array_and_object_literals() {
+    let vm_1_peer_id = "vm_1_peer_id";
+    let mut vm_1 = create_avm(echo_call_service(), vm_1_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (ap {{"peer": %init_peer_id% "values": [1 "two" []]}} scalar)
+            (call "{vm_1_peer_id}" ("" "") [scalar]))
+        "#);
+
+    let test_params = TestRunParameters::from_init_peer_id("init_peer_id");
+    let result = checked_call_vm!(vm_1, test_params, script, "", "");
+
+    let actual_trace = trace_from_result(&result);
+    let expected_state = vec![executed_state::unused(
+        json!({"peer": "init_peer_id", "values": [1, "two", []]}),
+    )];
+
+    assert_eq!(actual_trace, expected_state);
+    assert!(result.next_peer_pks.is_empty());
+}
+
+#[test]
+fn ap_with_array_literal_with_variable() {
+    let vm_1_peer_id = "vm_1_peer_id";
+    let mut vm_1 = create_avm(echo_call_service(), vm_1_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{vm_1_peer_id}" ("" "") ["value"] value)
+            (ap ["literal" [value]] scalar))
+        "#);
+
+    let result = call_vm!(vm_1, <_>::default(), script, "", "");
+
+    // a tetraplet of the service produced value would be lost in the literal
+    let expected_error = CatchableError::ApLiteralWithNonLiteralElement(String::from("value"));
+    assert!(check_error(&result, expected_error));
+}
+
 #[test]
 fn ap_with_last_error() {
     let vm_1_peer_id = "vm_1_peer_id";
@@ -186,7 +228,7 @@ fn ap_with_last_error() {
                 }
             ]
         })),
-        executed_state::scalar(json!([null])),
+        executed_state::unused(json!([null])),
     ];
 
     assert_eq!(actual_trace, expected_state);
@@ -209,7 +251,7 @@ fn ap_with_timestamp() {
     let result = checked_call_vm!(vm_1, test_params.clone(), script, "", "");
 
     let actual_trace = trace_from_result(&result);
-    let expected_state = vec![executed_state::scalar_number(test_params.timestamp)];
+    let expected_state = vec![executed_state::unused_number(test_params.timestamp)];
 
     assert_eq!(actual_trace, expected_state);
 }
@@ -230,7 +272,7 @@ fn ap_with_ttl() {
     let result = checked_call_vm!(vm_1, test_params.clone(), script, "", "");
 
     let actual_trace = trace_from_result(&result);
-    let expected_state = vec![executed_state::scalar_number(test_params.ttl)];
+    let expected_state = vec![executed_state::unused_number(test_params.ttl)];
 
     assert_eq!(actual_trace, expected_state);
 }
@@ -271,7 +313,7 @@ fn ap_with_dst_stream() {
                 }
             ]
         })),
-        executed_state::scalar(json!([{ "field": test_value }])),
+        executed_state::unused(json!([{ "field": test_value }])),
     ];
 
     assert_eq!(actual_trace, expected_state);
@@ -324,7 +366,7 @@ fn ap_canon_stream_with_lambda() {
             ]
             }
         )),
-        executed_state::scalar(json!([1])),
+        executed_state::unused(json!([1])),
     ];
     assert_eq!(actual_trace, expected_state);
 
@@ -384,7 +426,7 @@ fn ap_canon_stream() {
             ]
             }
         )),
-        executed_state::scalar(json!([[0, 1]])),
+        executed_state::unused(json!([[0, 1]])),
     ];
     assert_eq!(actual_trace, expected_state);
 
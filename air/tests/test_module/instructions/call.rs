@@ -15,8 +15,11 @@
  */
 
 use air::UncatchableError;
+use air_interpreter_cid::value_to_json_cid;
 use air_test_utils::prelude::*;
 
+use std::rc::Rc;
+
 // Check that %init_peer_id% alias works correctly (by comparing result with it and explicit peer id).
 // Additionally, check that empty string for data does the same as empty call path.
 #[test]
@@ -82,6 +85,25 @@ fn call_with_ttl() {
     assert_eq!(actual_trace, expected_trace);
 }
 
+#[test]
+fn call_with_particle_id() {
+    let vm_peer_id = "test_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let script = f!(r#"(call "{vm_peer_id}" ("" "") [%particle_id%] result_name)"#);
+
+    let test_params = TestRunParameters {
+        particle_id: "some_particle_id".to_string(),
+        ..<_>::default()
+    };
+    let result = checked_call_vm!(vm, test_params.clone(), script, "", "");
+
+    let actual_trace = trace_from_result(&result);
+    let expected_trace = vec![executed_state::scalar_string(test_params.particle_id)];
+
+    assert_eq!(actual_trace, expected_trace);
+}
+
 // Check that specifying remote peer id in call will result its appearing in next_peer_pks.
 #[test]
 fn remote_peer_id_call() {
@@ -171,3 +193,38 @@ fn string_parameters() {
     assert_eq!(actual_trace.len(), 2);
     assert_eq!(actual_trace[1.into()], expected_state);
 }
+
+// Check that a result of a call without output isn't kept in data, only its CID is.
+#[test]
+fn call_without_output() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let script = f!(r#"
+            (call "{vm_peer_id}" ("" "") ["result"])
+        "#);
+
+    let result = checked_call_vm!(vm, <_>::default(), script, "", "");
+    let data = data_from_result(&result);
+
+    assert_eq!(data.trace, vec![executed_state::unused_string("result")]);
+    assert!(data.cid_info.value_store.is_empty());
+}
+
+// Check that an unused call result can't be used by a call with output.
+#[test]
+fn unused_call_result_for_call_with_output() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let script = f!(r#"
+            (call "{vm_peer_id}" ("" "") ["result"] output)
+        "#);
+
+    let current_data = raw_data_from_trace(vec![executed_state::unused_string("result")], <_>::default());
+    let result = call_vm!(vm, <_>::default(), script, "", current_data);
+
+    let cid = value_to_json_cid(&json!("result")).unwrap();
+    let expected_error = UncatchableError::CallResultNotCorrespondToInstr(CallResult::unused(Rc::new(cid)));
+    assert!(check_error(&result, expected_error));
+}
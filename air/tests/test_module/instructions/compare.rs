@@ -0,0 +1,134 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::CatchableError;
+use air_parser::ast::ComparisonOperator;
+use air_test_utils::prelude::*;
+
+#[test]
+fn compare_numbers() {
+    let set_variable_peer_id = "set_variable_peer_id";
+    let mut set_variable_vm = create_avm(set_variable_call_service(json!(5)), set_variable_peer_id);
+
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (seq
+                (call "{set_variable_peer_id}" ("" "") [] value)
+                (seq
+                    (seq
+                        (xor
+                            (lt value 10
+                                (call "{local_peer_id}" ("" "") ["lt"]))
+                            (call "{local_peer_id}" ("" "") ["not lt"]))
+                        (xor
+                            (lte value 5
+                                (call "{local_peer_id}" ("" "") ["lte"]))
+                            (call "{local_peer_id}" ("" "") ["not lte"])))
+                    (seq
+                        (xor
+                            (gt value 5.5
+                                (call "{local_peer_id}" ("" "") ["gt"]))
+                            (call "{local_peer_id}" ("" "") ["not gt"]))
+                        (xor
+                            (gte value -1
+                                (call "{local_peer_id}" ("" "") ["gte"]))
+                            (call "{local_peer_id}" ("" "") ["not gte"])))))"#);
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+    let result = checked_call_vm!(vm, <_>::default(), script, "", result.data);
+
+    let actual_trace = trace_from_result(&result);
+    let expected_trace = vec![
+        executed_state::scalar_number(5),
+        executed_state::unused_string("lt"),
+        executed_state::unused_string("lte"),
+        executed_state::unused_string("not gt"),
+        executed_state::unused_string("gte"),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn compare_strings() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (seq
+                (call "{local_peer_id}" ("" "") ["abc"] value)
+                (xor
+                    (lt value "abd"
+                        (call "{local_peer_id}" ("" "") ["lt"]))
+                    (call "{local_peer_id}" ("" "") ["not lt"])))"#);
+
+    let result = checked_call_vm!(vm, <_>::default(), script, "", "");
+
+    let actual_trace = trace_from_result(&result);
+    assert_eq!(actual_trace[1.into()], executed_state::unused_string("lt"));
+}
+
+#[test]
+fn compare_with_ttl() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (xor
+                (gte %ttl% 1000
+                    (call "{local_peer_id}" ("" "") ["enough ttl"]))
+                (call "{local_peer_id}" ("" "") ["not enough ttl"]))"#);
+
+    let test_params = TestRunParameters::new("init_peer_id", 0, 500);
+    let result = checked_call_vm!(vm, test_params, script, "", "");
+
+    let actual_trace = trace_from_result(&result);
+    assert_eq!(actual_trace[0.into()], executed_state::unused_string("not enough ttl"));
+}
+
+#[test]
+fn compare_without_xor() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (gt 1 2
+                (call "{local_peer_id}" ("" "") ["gt"]))"#);
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+
+    let expected_error = CatchableError::ComparisonNotSatisfied(ComparisonOperator::Greater);
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn compare_incomparable_values() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (lt "1" 2
+                (call "{local_peer_id}" ("" "") ["lt"]))"#);
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+
+    let expected_error = CatchableError::IncomparableValues {
+        left: json!("1"),
+        right: json!(2),
+    };
+    assert!(check_error(&result, expected_error));
+}
@@ -343,14 +343,14 @@ fn shadowing() {
         scalar_string_array(vec!["1", "2"]),
         scalar_string_array(vec!["1", "2"]),
         scalar_string("1"),
+        unused_string("1"),
         scalar_string("1"),
-        scalar_string("1"),
-        scalar_string("1"),
+        unused_string("1"),
         par(1, 1),
         scalar_string("1"),
-        scalar_string("1"),
-        scalar_string("2"),
+        unused_string("1"),
         scalar_string("2"),
+        unused_string("2"),
         request_sent_by("B"),
     ];
 
@@ -411,10 +411,10 @@ fn shadowing_scope() {
         scalar_string_array(vec!["1", "2"]),
         scalar_string("value"),
         scalar_string("1"),
+        unused_string("1"),
         scalar_string("1"),
-        scalar_string("1"),
-        scalar_string("1"),
-        scalar_string("value"),
+        unused_string("1"),
+        unused_string("value"),
         scalar_string("value"),
         scalar_string("2"),
         request_sent_by("A"),
@@ -543,7 +543,7 @@ fn fold_stream_seq_next_completes_with_null() {
             SubTraceDesc::new(3.into(), 0),
         )]),
         executed_state::stream_number(1, 0),
-        executed_state::scalar_number(1),
+        executed_state::unused_number(1),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -585,7 +585,7 @@ fn fold_scalar_seq_next_completes_with_null() {
         executed_state::canon(
             json!({"tetraplet": {"function_name": "", "json_path": "", "peer_pk": "vm_peer_id", "service_id": ""}, "values": []}),
         ),
-        executed_state::scalar(service_result),
+        executed_state::unused(service_result),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -665,7 +665,7 @@ fn fold_stream_seq_next_saves_call_result() {
         executed_state::stream_number(1, 0),
         executed_state::stream_number(2, 1),
         executed_state::stream_number(2, 2),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(0),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -766,3 +766,175 @@ fn fold_par_next_completes() {
     ];
     assert_eq!(actual_trace, expected_trace);
 }
+
+#[test]
+fn fold_scalar_with_index() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let script = f!(r#"
+            (seq
+                (call "{vm_peer_id}" ("" "") [["a" "b" "c"]] iterable)
+                (fold iterable v i
+                    (seq
+                        (call "{vm_peer_id}" ("" "") [i] $indices)
+                        (next v)
+                    )
+                )
+            )
+            "#);
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![
+        executed_state::scalar_string_array(vec!["a", "b", "c"]),
+        executed_state::stream_number(0, 0),
+        executed_state::stream_number(1, 1),
+        executed_state::stream_number(2, 2),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn fold_stream_with_iterations_limit() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let script = f!(r#"
+            (seq
+                (seq
+                    (seq
+                        (ap 1 $stream)
+                        (ap 2 $stream)
+                    )
+                    (ap 3 $stream)
+                )
+                (seq
+                    (fold $stream v i 2
+                        (seq
+                            (call "{vm_peer_id}" ("" "") [i] $indices)
+                            (next v)
+                        )
+                        (null)
+                    )
+                    (call "{vm_peer_id}" ("" "") [0])
+                )
+            )
+            "#);
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![
+        executed_state::ap(0),
+        executed_state::ap(0),
+        executed_state::ap(0),
+        executed_state::fold(vec![
+            subtrace_lore(0, SubTraceDesc::new(4.into(), 1), SubTraceDesc::new(6.into(), 0)),
+            subtrace_lore(1, SubTraceDesc::new(5.into(), 1), SubTraceDesc::new(6.into(), 0)),
+        ]),
+        executed_state::stream_number(0, 0),
+        executed_state::stream_number(1, 1),
+        executed_state::unused_number(0),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn fold_stream_with_iterations_limit_completes_without_last_instruction() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let script = f!(r#"
+            (seq
+                (seq
+                    (ap 1 $stream)
+                    (ap 2 $stream)
+                )
+                (seq
+                    (fold $stream v 1
+                        (seq
+                            (call "{vm_peer_id}" ("" "") [v] $results)
+                            (next v)
+                        )
+                    )
+                    (call "{vm_peer_id}" ("" "") [0])
+                )
+            )
+            "#);
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![
+        executed_state::ap(0),
+        executed_state::ap(0),
+        executed_state::fold(vec![subtrace_lore(
+            0,
+            SubTraceDesc::new(3.into(), 1),
+            SubTraceDesc::new(4.into(), 0),
+        )]),
+        executed_state::stream_number(1, 0),
+        executed_state::unused_number(0),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn fold_stream_with_iterations_limit_merges_different_generations() {
+    let peer_id_1 = "peer_id_1";
+    let peer_id_2 = "peer_id_2";
+    let mut vm_1 = create_avm(echo_call_service(), peer_id_1);
+    let mut vm_2 = create_avm(echo_call_service(), peer_id_2);
+
+    let script = f!(r#"
+            (seq
+                (seq
+                    (par
+                        (call "{peer_id_1}" ("" "") ["value_1"] $stream)
+                        (call "{peer_id_2}" ("" "") ["value_2"] $stream)
+                    )
+                    (fold $stream v 1
+                        (seq
+                            (call "{peer_id_1}" ("" "") [v] $results)
+                            (next v)
+                        )
+                    )
+                )
+                (seq
+                    (canon "{peer_id_2}" $results #results)
+                    (call "{peer_id_2}" ("" "") [#results] results)
+                )
+            )
+            "#);
+
+    let result_1 = checked_call_vm!(vm_1, <_>::default(), &script, "", "");
+    let result_2 = checked_call_vm!(vm_2, <_>::default(), &script, "", result_1.data.clone());
+    let result_3 = checked_call_vm!(vm_1, <_>::default(), &script, result_1.data, result_2.data.clone());
+
+    // the second peer has its own value in a newer generation than the value from current data,
+    // so the limit stops the fold on the same value the first peer iterated over
+    let expected_trace = vec![
+        executed_state::par(1, 1),
+        executed_state::stream_string("value_1", 0),
+        executed_state::stream_string("value_2", 1),
+        executed_state::fold(vec![subtrace_lore(
+            1,
+            SubTraceDesc::new(4.into(), 1),
+            SubTraceDesc::new(5.into(), 0),
+        )]),
+        executed_state::stream_string("value_1", 0),
+        executed_state::canon(json!({
+            "tetraplet": {"function_name": "", "json_path": "", "peer_pk": peer_id_2, "service_id": ""},
+            "values": [{
+                "result": "value_1",
+                "tetraplet": {"function_name": "", "json_path": "", "peer_pk": peer_id_1, "service_id": ""},
+                "trace_pos": 4
+            }]
+        })),
+        executed_state::scalar(json!(["value_1"])),
+    ];
+    assert_eq!(trace_from_result(&result_2), expected_trace);
+    assert_eq!(trace_from_result(&result_3), expected_trace);
+}
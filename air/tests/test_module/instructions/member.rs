@@ -0,0 +1,120 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::CatchableError;
+use air_test_utils::prelude::*;
+
+#[test]
+fn member_of_array() {
+    let set_variable_peer_id = "set_variable_peer_id";
+    let allowed = json!(["peer_1", "init_peer_id", "peer_2"]);
+    let mut set_variable_vm = create_avm(set_variable_call_service(allowed), set_variable_peer_id);
+
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (seq
+                (call "{set_variable_peer_id}" ("" "") [] allowed)
+                (seq
+                    (xor
+                        (member %init_peer_id% allowed
+                            (call "{local_peer_id}" ("" "") ["allowed"]))
+                        (call "{local_peer_id}" ("" "") ["not allowed"]))
+                    (xor
+                        (member "peer_3" allowed
+                            (call "{local_peer_id}" ("" "") ["allowed"]))
+                        (call "{local_peer_id}" ("" "") ["not allowed"]))))"#);
+
+    let test_params = TestRunParameters::from_init_peer_id("init_peer_id");
+    let result = checked_call_vm!(set_variable_vm, test_params.clone(), &script, "", "");
+    let result = checked_call_vm!(vm, test_params, script, "", result.data);
+
+    let actual_trace = trace_from_result(&result);
+    assert_eq!(actual_trace[1.into()], executed_state::unused_string("allowed"));
+    assert_eq!(actual_trace[2.into()], executed_state::unused_string("not allowed"));
+}
+
+#[test]
+fn member_of_canon_stream() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (seq
+                (seq
+                    (seq
+                        (ap 1 $stream)
+                        (ap 2 $stream))
+                    (canon "{local_peer_id}" $stream #canon_stream))
+                (xor
+                    (member 2 #canon_stream
+                        (call "{local_peer_id}" ("" "") ["member"]))
+                    (call "{local_peer_id}" ("" "") ["not member"])))"#);
+
+    let result = checked_call_vm!(vm, <_>::default(), script, "", "");
+
+    let actual_trace = trace_from_result(&result);
+    assert_eq!(actual_trace[3.into()], executed_state::unused_string("member"));
+}
+
+#[test]
+fn member_of_array_literal() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (xor
+                (member %init_peer_id% ["peer_1" "peer_2"]
+                    (call "{local_peer_id}" ("" "") ["member"]))
+                (call "{local_peer_id}" ("" "") ["not member"]))"#);
+
+    let test_params = TestRunParameters::from_init_peer_id("peer_2");
+    let result = checked_call_vm!(vm, test_params, script, "", "");
+
+    let actual_trace = trace_from_result(&result);
+    assert_eq!(actual_trace[0.into()], executed_state::unused_string("member"));
+}
+
+#[test]
+fn member_without_xor() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (member 1 []
+                (call "{local_peer_id}" ("" "") ["member"]))"#);
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+
+    let expected_error = CatchableError::MemberValueNotFound;
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn member_of_not_array() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (member 1 "1"
+                (call "{local_peer_id}" ("" "") ["member"]))"#);
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+
+    let expected_error = CatchableError::MemberCollectionIsNotArray(json!("1"));
+    assert!(check_error(&result, expected_error));
+}
@@ -17,12 +17,16 @@
 mod ap;
 mod call;
 mod canon;
+mod compare;
 mod fail;
 mod fold;
 mod match_;
+mod member;
 mod mismatch;
 mod never;
 mod new;
 mod par;
 mod seq;
+mod stream_map;
+mod ttl_guard;
 mod xor;
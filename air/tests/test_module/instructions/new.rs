@@ -127,7 +127,7 @@ fn several_restrictions() {
 
             ]
         })),
-        executed_state::scalar(json!([])),
+        executed_state::unused(json!([])),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -208,7 +208,7 @@ fn check_influence_to_not_restricted() {
             ]
         }
         )),
-        executed_state::scalar(json!(["push more"])),
+        executed_state::unused(json!(["push more"])),
         executed_state::canon(json!({
             "tetraplet": {"function_name": "", "json_path": "", "peer_pk": "vm_peer_id", "service_id": ""},
             "values": [
@@ -219,14 +219,14 @@ fn check_influence_to_not_restricted() {
                 }
             ]
         })),
-        executed_state::scalar(json!(["push more"])),
+        executed_state::unused(json!(["push more"])),
         executed_state::canon(json!({
             "tetraplet": {"function_name": "", "json_path": "", "peer_pk": "vm_peer_id", "service_id": ""},
             "values": [
             ]
         })),
-        executed_state::scalar(json!([])),
-        executed_state::scalar(json!(["more"])),
+        executed_state::unused(json!([])),
+        executed_state::unused(json!(["more"])),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -441,10 +441,10 @@ fn new_with_scalars_with_errors() {
     let expected_trace = vec![
         executed_state::scalar_number(1),
         executed_state::scalar_number(2),
-        executed_state::scalar_number(2),
+        executed_state::unused_number(2),
         executed_state::service_failed(1, r#"failed result from fallible_call_service"#),
-        executed_state::scalar_number(1),
-        executed_state::scalar_number(1),
+        executed_state::unused_number(1),
+        executed_state::unused_number(1),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -485,8 +485,8 @@ fn new_with_global_scalars() {
     let expected_trace = vec![
         executed_state::scalar_number(1),
         executed_state::scalar_number(2),
-        executed_state::scalar_number(2),
-        executed_state::scalar_number(1),
+        executed_state::unused_number(2),
+        executed_state::unused_number(1),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -566,20 +566,20 @@ fn new_with_scalars_in_lfold_with_outside_next() {
     let expected_trace = vec![
         executed_state::scalar(json!([1, 2, 3])),
         executed_state::scalar_number(0),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(0),
         executed_state::scalar_number(10),
-        executed_state::scalar_number(10),
-        executed_state::scalar_number(1),
+        executed_state::unused_number(10),
         executed_state::scalar_number(1),
+        executed_state::unused_number(1),
         executed_state::scalar_number(11),
-        executed_state::scalar_number(11),
-        executed_state::scalar_number(2),
+        executed_state::unused_number(11),
         executed_state::scalar_number(2),
+        executed_state::unused_number(2),
         executed_state::scalar_number(12),
-        executed_state::scalar_number(12),
-        executed_state::scalar_number(2),
-        executed_state::scalar_number(1),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(12),
+        executed_state::unused_number(2),
+        executed_state::unused_number(1),
+        executed_state::unused_number(0),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -623,20 +623,20 @@ fn new_with_scalars_in_rfold_with_outside_next() {
     let expected_trace = vec![
         executed_state::scalar(json!([1, 2, 3])),
         executed_state::scalar_number(0),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(0),
         executed_state::scalar_number(10),
-        executed_state::scalar_number(10),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(10),
+        executed_state::unused_number(0),
         executed_state::scalar_number(1),
-        executed_state::scalar_number(1),
-        executed_state::scalar_number(11),
+        executed_state::unused_number(1),
         executed_state::scalar_number(11),
-        executed_state::scalar_number(1),
+        executed_state::unused_number(11),
+        executed_state::unused_number(1),
         executed_state::scalar_number(2),
-        executed_state::scalar_number(2),
-        executed_state::scalar_number(12),
+        executed_state::unused_number(2),
         executed_state::scalar_number(12),
-        executed_state::scalar_number(2),
+        executed_state::unused_number(12),
+        executed_state::unused_number(2),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -683,23 +683,23 @@ fn new_with_scalars_in_fold_with_inside_next() {
     let expected_trace = vec![
         executed_state::scalar(json!([1, 2, 3])),
         executed_state::scalar_number(0),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(0),
         executed_state::scalar_number(10),
-        executed_state::scalar_number(10),
-        executed_state::scalar_number(1),
+        executed_state::unused_number(10),
         executed_state::scalar_number(1),
+        executed_state::unused_number(1),
         executed_state::scalar_number(11),
-        executed_state::scalar_number(11),
-        executed_state::scalar_number(2),
+        executed_state::unused_number(11),
         executed_state::scalar_number(2),
+        executed_state::unused_number(2),
         executed_state::scalar_number(12),
-        executed_state::scalar_number(12),
-        executed_state::scalar_number(12),
-        executed_state::scalar_number(2),
-        executed_state::scalar_number(11),
-        executed_state::scalar_number(1),
-        executed_state::scalar_number(10),
-        executed_state::scalar_number(0),
+        executed_state::unused_number(12),
+        executed_state::unused_number(12),
+        executed_state::unused_number(2),
+        executed_state::unused_number(11),
+        executed_state::unused_number(1),
+        executed_state::unused_number(10),
+        executed_state::unused_number(0),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -0,0 +1,210 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::CatchableError;
+use air_test_utils::prelude::*;
+
+use fstrings::f;
+use fstrings::format_args_f;
+
+#[test]
+fn ap_map_with_canon_map() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (ap ("key_1" "value_1") %map)
+                (seq
+                    (ap (42 [1 2]) %map)
+                    (ap ("key_1" "value_2") %map)))
+            (seq
+                (canon "{peer_id}" %map #%map)
+                (seq
+                    (call "{peer_id}" ("" "") [#%map] map)
+                    (call "{peer_id}" ("" "") [#%map.$.key_1] value))))
+        "#);
+
+    let result = checked_call_vm!(vm, <_>::default(), script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    // the first value appended with a key is the one this key is associated with
+    let expected_map = json!({"key_1": "value_1", "42": [1, 2]});
+    assert_eq!(actual_trace.len(), 6);
+    assert_eq!(&actual_trace[0.into()], &executed_state::ap_map(0));
+    assert_eq!(&actual_trace[1.into()], &executed_state::ap_map(0));
+    assert_eq!(&actual_trace[2.into()], &executed_state::ap_map(0));
+    assert!(matches!(&actual_trace[3.into()], ExecutedState::CanonMap(_)));
+    assert_eq!(&actual_trace[4.into()], &executed_state::scalar(expected_map));
+    assert_eq!(&actual_trace[5.into()], &executed_state::scalar_string("value_1"));
+}
+
+#[test]
+fn ap_map_with_non_string_key() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(set_variable_call_service(json!({"a": 1})), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{peer_id}" ("" "") [] key)
+            (ap (key "value") %map))
+        "#);
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+    let expected_error = CatchableError::StreamMapKeyHasInvalidType(json!({"a": 1}));
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn canon_map_lambda_with_missing_key() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (ap ("key" "value") %map)
+                (canon "{peer_id}" %map #%map))
+            (call "{peer_id}" ("" "") [#%map.$.other_key] value))
+        "#);
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+    assert_ne!(result.ret_code, 0);
+}
+
+#[test]
+fn ap_map_merges_on_different_peers_in_any_order() {
+    let peer_id_1 = "peer_id_1";
+    let peer_id_2 = "peer_id_2";
+    let canon_peer_id = "canon_peer_id";
+    let mut vm_1 = create_avm(echo_call_service(), peer_id_1);
+    let mut vm_2 = create_avm(echo_call_service(), peer_id_2);
+    let mut canon_vm = create_avm(echo_call_service(), canon_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (par
+                (seq
+                    (call "{peer_id_1}" ("" "") ["value_1"] value_1)
+                    (seq
+                        (ap ("key_1" value_1) %map)
+                        (ap ("shared_key" value_1) %map)))
+                (seq
+                    (call "{peer_id_2}" ("" "") ["value_2"] value_2)
+                    (seq
+                        (ap ("key_2" value_2) %map)
+                        (ap ("shared_key" value_2) %map))))
+            (seq
+                (canon "{canon_peer_id}" %map #%map)
+                (call "{canon_peer_id}" ("" "") [#%map] map)))
+        "#);
+
+    let result_1 = checked_call_vm!(vm_1, <_>::default(), &script, "", "");
+    let result_2 = checked_call_vm!(vm_2, <_>::default(), &script, "", "");
+
+    // each peer merges both traces, but in a different order
+    let merged_by_1 = checked_call_vm!(
+        vm_1,
+        <_>::default(),
+        &script,
+        result_1.data.clone(),
+        result_2.data.clone()
+    );
+    let merged_by_2 = checked_call_vm!(vm_2, <_>::default(), &script, result_2.data, result_1.data);
+
+    let canon_result_1 = checked_call_vm!(canon_vm, <_>::default(), &script, "", merged_by_1.data);
+    let canon_result_2 = checked_call_vm!(
+        canon_vm,
+        <_>::default(),
+        &script,
+        canon_result_1.data.clone(),
+        merged_by_2.data
+    );
+
+    // the first value appended to the map by the merging peer wins
+    let expected_map = executed_state::scalar(json!({
+        "key_1": "value_1",
+        "key_2": "value_2",
+        "shared_key": "value_1",
+    }));
+    let actual_trace_1 = trace_from_result(&canon_result_1);
+    assert_eq!(actual_trace_1.last(), Some(&expected_map));
+
+    // once a map is canonicalized, merging the other order of the same values doesn't change it
+    let actual_trace_2 = trace_from_result(&canon_result_2);
+    assert_eq!(actual_trace_2.last(), Some(&expected_map));
+    let canon_map_state_pos = 7.into();
+    assert!(matches!(
+        &actual_trace_1[canon_map_state_pos],
+        ExecutedState::CanonMap(_)
+    ));
+    assert_eq!(actual_trace_1[canon_map_state_pos], actual_trace_2[canon_map_state_pos]);
+}
+
+#[test]
+fn canon_map_merges_with_the_same_state() {
+    let peer_id_1 = "peer_id_1";
+    let peer_id_2 = "peer_id_2";
+    let mut vm_1 = create_avm(echo_call_service(), peer_id_1);
+    let mut vm_2 = create_avm(echo_call_service(), peer_id_2);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (ap ("key" "value") %map)
+                (canon "{peer_id_1}" %map #%map))
+            (par
+                (call "{peer_id_1}" ("" "") [#%map] map_1)
+                (call "{peer_id_2}" ("" "") [#%map.$.key] value_2)))
+        "#);
+
+    let result_1 = checked_call_vm!(vm_1, <_>::default(), &script, "", "");
+    let result_2 = checked_call_vm!(vm_2, <_>::default(), &script, "", result_1.data.clone());
+    let result_3 = checked_call_vm!(vm_1, <_>::default(), &script, result_1.data, result_2.data);
+
+    let actual_trace = trace_from_result(&result_3);
+    assert!(matches!(&actual_trace[1.into()], ExecutedState::CanonMap(_)));
+    assert_eq!(
+        &actual_trace[3.into()],
+        &executed_state::scalar(json!({"key": "value"}))
+    );
+    assert_eq!(&actual_trace[4.into()], &executed_state::scalar_string("value"));
+}
+
+#[test]
+fn canon_map_with_different_states_is_not_merged() {
+    let peer_id_1 = "peer_id_1";
+    let peer_id_2 = "peer_id_2";
+    let mut vm_1 = create_avm(echo_call_service(), peer_id_1);
+    let mut vm_2 = create_avm(echo_call_service(), peer_id_2);
+
+    let script = |value: &str| {
+        f!(r#"
+        (seq
+            (ap ("key" "{value}") %map)
+            (seq
+                (canon "{peer_id_1}" %map #%map)
+                (call "{peer_id_2}" ("" "") [#%map])))
+        "#)
+    };
+
+    let result_1 = checked_call_vm!(vm_1, <_>::default(), script("value_1"), "", "");
+    let result_2 = checked_call_vm!(vm_1, <_>::default(), script("value_2"), "", "");
+    let result = call_vm!(vm_2, <_>::default(), script("value_1"), result_1.data, result_2.data);
+
+    assert_ne!(result.ret_code, 0);
+}
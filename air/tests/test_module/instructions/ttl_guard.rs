@@ -0,0 +1,159 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::CatchableError;
+use air_test_utils::prelude::*;
+
+fn test_params(timestamp: u64, ttl: u32, current_timestamp: u64) -> TestRunParameters {
+    TestRunParameters {
+        timestamp,
+        ttl,
+        current_timestamp,
+        ..<_>::default()
+    }
+}
+
+#[test]
+fn ttl_guard_with_enough_ttl() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (xor
+                (ttl_guard 1000
+                    (call "{local_peer_id}" ("" "") ["enough ttl"]))
+                (call "{local_peer_id}" ("" "") ["not enough ttl"]))"#);
+
+    let result = checked_call_vm!(vm, test_params(10_000, 5_000, 14_000), script, "", "");
+
+    let actual_trace = trace_from_result(&result);
+    let expected_trace = vec![
+        executed_state::ttl_guard(true),
+        executed_state::unused_string("enough ttl"),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn ttl_guard_with_not_enough_ttl() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (xor
+                (ttl_guard 1000
+                    (call "{local_peer_id}" ("" "") ["enough ttl"]))
+                (call "{local_peer_id}" ("" "") [%error%.$.message]))"#);
+
+    let result = checked_call_vm!(vm, test_params(10_000, 5_000, 14_001), script, "", "");
+
+    let actual_trace = trace_from_result(&result);
+    let expected_error = CatchableError::NotEnoughRemainingTtl {
+        remaining: 999,
+        required: 1000,
+    };
+    let expected_trace = vec![
+        executed_state::ttl_guard(false),
+        executed_state::unused_string(expected_error.to_string()),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn ttl_guard_with_expired_particle() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (ttl_guard 0
+                (call "{local_peer_id}" ("" "") ["enough ttl"]))"#);
+
+    let result = checked_call_vm!(vm, test_params(10_000, 5_000, 20_000), script, "", "");
+    let actual_trace = trace_from_result(&result);
+    assert_eq!(actual_trace[1.into()], executed_state::unused_string("enough ttl"));
+
+    let script = f!(r#"
+            (ttl_guard 1
+                (call "{local_peer_id}" ("" "") ["enough ttl"]))"#);
+
+    let result = call_vm!(vm, test_params(10_000, 5_000, 20_000), script, "", "");
+
+    let expected_error = CatchableError::NotEnoughRemainingTtl {
+        remaining: 0,
+        required: 1,
+    };
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn ttl_guard_with_not_number() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = f!(r#"
+            (ttl_guard "1000"
+                (call "{local_peer_id}" ("" "") ["enough ttl"]))"#);
+
+    let result = call_vm!(vm, test_params(10_000, 5_000, 10_000), script, "", "");
+
+    let expected_error = CatchableError::TtlGuardArgumentIsNotNumber(json!("1000"));
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn ttl_guard_decision_is_replayed() {
+    let local_peer_id = "local_peer_id";
+    let mut local_vm = create_avm(echo_call_service(), local_peer_id);
+
+    let remote_peer_id = "remote_peer_id";
+    let mut remote_vm = create_avm(echo_call_service(), remote_peer_id);
+
+    let script = f!(r#"
+            (xor
+                (ttl_guard 1000
+                    (call "{remote_peer_id}" ("" "") ["enough ttl"]))
+                (call "{local_peer_id}" ("" "") ["not enough ttl"]))"#);
+
+    let result_1 = checked_call_vm!(local_vm, test_params(10_000, 5_000, 14_000), &script, "", "");
+    let expected_trace = vec![
+        executed_state::ttl_guard(true),
+        executed_state::request_sent_by(local_peer_id),
+    ];
+    assert_eq!(trace_from_result(&result_1), expected_trace);
+
+    // the clock is moved forward, so the guard would fail if it was evaluated once again
+    let result_2 = checked_call_vm!(
+        local_vm,
+        test_params(10_000, 5_000, 14_500),
+        &script,
+        result_1.data.clone(),
+        ""
+    );
+    assert_eq!(trace_from_result(&result_2), expected_trace);
+
+    let result_3 = checked_call_vm!(
+        remote_vm,
+        test_params(10_000, 5_000, 14_900),
+        &script,
+        "",
+        result_1.data
+    );
+    let expected_trace = vec![
+        executed_state::ttl_guard(true),
+        executed_state::unused_string("enough ttl"),
+    ];
+    assert_eq!(trace_from_result(&result_3), expected_trace);
+}
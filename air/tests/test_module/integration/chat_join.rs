@@ -306,14 +306,14 @@ fn init_peer_id() {
     let client_1_actual_trace = trace_from_result(&client_1_result);
 
     let client_1_expected_trace = vec![
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
         executed_state::scalar(json!([[client_1_peer_id], ["B"]])),
         executed_state::par(2, 3),
-        executed_state::scalar_string("result from unit_call_service"),
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
         executed_state::par(2, 0),
-        executed_state::scalar_string("result from unit_call_service"),
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
         executed_state::request_sent_by(client_1_peer_id),
     ];
 
@@ -325,15 +325,15 @@ fn init_peer_id() {
     let initiator_1_actual_trace = trace_from_result(&initiator_1_result);
 
     let initiator_1_expected_trace = vec![
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
         executed_state::scalar(json!([[client_1_peer_id], ["B"]])),
         executed_state::par(2, 3),
-        executed_state::scalar_string("result from unit_call_service"),
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
         executed_state::par(2, 0),
-        executed_state::scalar_string("result from unit_call_service"),
-        executed_state::scalar_string("result from unit_call_service"),
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
     ];
 
     assert_eq!(initiator_1_actual_trace, initiator_1_expected_trace);
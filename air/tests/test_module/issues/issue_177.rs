@@ -68,7 +68,13 @@ fn issue_177() {
     // client 1: demand result for (call %init_peer_id% ("getDataSrv" "-relay-") [] -relay-)
     let client_result_1 = client
         .runner
-        .call(script, "", "", client_peer_id, 0, 0, None, HashMap::new())
+        .call(
+            script,
+            "",
+            "",
+            &TestRunParameters::from_init_peer_id(client_peer_id),
+            HashMap::new(),
+        )
         .expect("call should be success");
     let expected_call_requests = maplit::hashmap! {
         1 => CallRequestParams::new("getDataSrv", "-relay-", vec![], vec![]),
@@ -86,10 +92,7 @@ fn issue_177() {
             script,
             client_result_1.data,
             "",
-            client_peer_id,
-            0,
-            0,
-            None,
+            &TestRunParameters::from_init_peer_id(client_peer_id),
             call_results,
         )
         .expect("call should be success");
@@ -103,10 +106,7 @@ fn issue_177() {
             script,
             "",
             client_result_2.data.clone(),
-            client_peer_id,
-            0,
-            0,
-            None,
+            &TestRunParameters::from_init_peer_id(client_peer_id),
             HashMap::new(),
         )
         .expect("call should be success");
@@ -126,10 +126,7 @@ fn issue_177() {
             script,
             relay_result_1.data,
             "",
-            client_peer_id,
-            0,
-            0,
-            None,
+            &TestRunParameters::from_init_peer_id(client_peer_id),
             call_results,
         )
         .expect("call should be success");
@@ -145,10 +142,7 @@ fn issue_177() {
             script,
             relay_result_2.data,
             "",
-            client_peer_id,
-            0,
-            0,
-            None,
+            &TestRunParameters::from_init_peer_id(client_peer_id),
             call_results,
         )
         .expect("call should be success");
@@ -164,10 +158,7 @@ fn issue_177() {
             script,
             relay_result_3.data,
             "",
-            client_peer_id,
-            0,
-            0,
-            None,
+            &TestRunParameters::from_init_peer_id(client_peer_id),
             call_results,
         )
         .expect("call should be success");
@@ -180,10 +171,7 @@ fn issue_177() {
             script,
             client_result_2.data,
             relay_result_4.data,
-            client_peer_id,
-            0,
-            0,
-            None,
+            &TestRunParameters::from_init_peer_id(client_peer_id),
             HashMap::new(),
         )
         .expect("call should be success");
@@ -204,10 +192,7 @@ fn issue_177() {
             script,
             client_result_3.data,
             "",
-            client_peer_id,
-            0,
-            0,
-            None,
+            &TestRunParameters::from_init_peer_id(client_peer_id),
             call_results,
         )
         .expect("call should be success");
@@ -228,10 +213,7 @@ fn issue_177() {
         script,
         client_result_4.data,
         "",
-        client_peer_id,
-        0,
-        0,
-        None,
+        &TestRunParameters::from_init_peer_id(client_peer_id),
         call_results,
     );
     // before patch the interpreter crashed here
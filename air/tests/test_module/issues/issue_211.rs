@@ -100,7 +100,7 @@ fn issue_211() {
                 },
             ]
         })),
-        executed_state::scalar_string("expected result"),
+        executed_state::unused_string("expected result"),
         executed_state::canon(json!({
             "tetraplet": {"function_name": "", "json_path": "", "peer_pk": "peer_1_id", "service_id": ""},
             "values": [
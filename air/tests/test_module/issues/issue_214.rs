@@ -61,7 +61,7 @@ fn issue_214() {
     let expected_trace = vec![
         executed_state::scalar_string(relay_id),
         executed_state::scalar(scalar),
-        executed_state::scalar_string(error_handler),
+        executed_state::unused_string(error_handler),
     ];
     let actual_trace = trace_from_result(&result);
 
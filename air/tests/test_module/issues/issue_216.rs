@@ -50,7 +50,7 @@ fn issue_216() {
 
     let expected_trace = vec![
         executed_state::scalar(json!([])),
-        executed_state::scalar_string(error_message),
+        executed_state::unused_string(error_message),
     ];
     assert_eq!(actual_trace, expected_trace);
 }
@@ -95,11 +95,11 @@ fn issue_221() {
         ]),
         executed_state::par(3, 0),
         executed_state::par(1, 1),
-        executed_state::scalar_string(peer_1_value),
+        executed_state::unused_string(peer_1_value),
         executed_state::request_sent_by(peer_1_id),
         executed_state::par(3, 0),
         executed_state::par(1, 1),
-        executed_state::scalar_string(peer_2_value),
+        executed_state::unused_string(peer_2_value),
         executed_state::request_sent_by(peer_2_id),
         executed_state::request_sent_by(join_1_id),
     ];
@@ -74,8 +74,8 @@ fn issue_222() {
             executed_state::subtrace_lore(3, SubTraceDesc::new(5.into(), 1), SubTraceDesc::new(6.into(), 0)),
             executed_state::subtrace_lore(2, SubTraceDesc::new(6.into(), 1), SubTraceDesc::new(7.into(), 0)),
         ]),
-        executed_state::scalar(json!([2])),
-        executed_state::scalar(json!([1])),
+        executed_state::unused(json!([2])),
+        executed_state::unused(json!([1])),
     ];
 
     assert_eq!(actual_trace.deref(), expected_trace);
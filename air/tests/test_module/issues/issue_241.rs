@@ -60,8 +60,8 @@ fn issue_241() {
         executed_state::scalar(peers),
         executed_state::par(1, 4),
         executed_state::scalar(array_1_content),
-        executed_state::scalar_string("result from unit_call_service"),
-        executed_state::scalar_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
+        executed_state::unused_string("result from unit_call_service"),
         executed_state::par(1, 0),
         // before 0.22.0 scalars wasn't clear after end of a fold block and here was more states
         // from the second iteration of fold over array-1
@@ -79,7 +79,7 @@ fn issue_302() {
                 },
             ]
         })),
-        executed_state::scalar(json!([1, 2, 0])),
+        executed_state::unused(json!([1, 2, 0])),
     ];
     assert_eq!(actual_trace.deref(), expected_trace);
 }
@@ -31,7 +31,13 @@ fn unprocessed_call_result() {
 
     let result = client_vm
         .runner
-        .call(air, prev_data, "", client_peer_id, 0, 0, None, call_results_4_call)
+        .call(
+            air,
+            prev_data,
+            "",
+            &TestRunParameters::from_init_peer_id(client_peer_id),
+            call_results_4_call,
+        )
         .unwrap();
 
     let expected_call_service_result = air_interpreter_interface::CallServiceResult::ok(&json!("null"));
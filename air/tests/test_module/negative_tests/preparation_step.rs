@@ -15,7 +15,9 @@
  */
 
 use air::PreparationError;
+use air_interpreter_cid::value_to_json_cid;
 use air_interpreter_interface::{CallResults, RunParameters};
+use air_interpreter_signatures::KeyPair;
 use air_test_utils::prelude::*;
 
 use serde::Deserialize;
@@ -37,7 +39,7 @@ fn invalid_data_without_versions() {
 
     let result = call_vm!(vm, <_>::default(), script, "", invalid_data.clone());
 
-    let expected_serde_error = serde_json::from_slice::<InterpreterData>(&invalid_data).err().unwrap();
+    let expected_serde_error = InterpreterData::try_from_slice(&invalid_data).err().unwrap();
     let expected_error = PreparationError::DataDeFailed {
         data: invalid_data,
         error: expected_serde_error,
@@ -67,7 +69,7 @@ fn invalid_data_with_versions() {
 
     let result = call_vm!(vm, <_>::default(), script, "", invalid_data.clone());
 
-    let expected_serde_error = serde_json::from_slice::<InterpreterData>(&invalid_data).err().unwrap();
+    let expected_serde_error = InterpreterData::try_from_slice(&invalid_data).err().unwrap();
     let expected_error = PreparationError::DataDeFailedWithVersions {
         data: invalid_data,
         error: expected_serde_error,
@@ -85,7 +87,11 @@ fn invalid_callresults() {
     let data = Vec::<u8>::new();
     let wrong_call_results = Vec::<u32>::new();
     let wrong_call_results = serde_json::to_vec(&wrong_call_results).unwrap();
-    let run_parameters = RunParameters::new(client_peer_id.clone(), client_peer_id.clone(), 0, 0);
+    let run_parameters = RunParameters::from(RunOptions {
+        init_peer_id: client_peer_id.clone(),
+        current_peer_id: client_peer_id,
+        ..<_>::default()
+    });
     let result = air::execute_air(air, prev_data, data, run_parameters, wrong_call_results.clone());
     let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
 
@@ -99,3 +105,135 @@ fn invalid_callresults() {
 
     assert!(check_error(&result, expected_error));
 }
+
+#[test]
+fn unsupported_data_format() {
+    let air = r#"(null)"#.to_string();
+    let client_peer_id = "some_peer_id".to_string();
+    let unsupported_data_format = 42;
+    let run_parameters = RunParameters {
+        data_format: unsupported_data_format,
+        ..RunParameters::from(RunOptions {
+            init_peer_id: client_peer_id.clone(),
+            current_peer_id: client_peer_id,
+            ..<_>::default()
+        })
+    };
+    let call_results = serde_json::to_vec(&CallResults::new()).unwrap();
+    let result = air::execute_air(air, vec![], vec![], run_parameters, call_results);
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::UnsupportedDataFormat(unsupported_data_format);
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn unsupported_cid_hash_algorithm() {
+    let air = r#"(null)"#.to_string();
+    let client_peer_id = "some_peer_id".to_string();
+    let unsupported_cid_hash_algorithm = 42;
+    let run_parameters = RunParameters {
+        cid_hash_algorithm: unsupported_cid_hash_algorithm,
+        ..RunParameters::from(RunOptions {
+            init_peer_id: client_peer_id.clone(),
+            current_peer_id: client_peer_id,
+            ..<_>::default()
+        })
+    };
+    let call_results = serde_json::to_vec(&CallResults::new()).unwrap();
+    let result = air::execute_air(air, vec![], vec![], run_parameters, call_results);
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::UnsupportedCidHashAlgorithm(unsupported_cid_hash_algorithm);
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn forged_value_cid() {
+    let vm_1_peer_id = "vm_1_peer_id";
+    let vm_2_peer_id = "vm_2_peer_id";
+    let mut vm_1 = create_avm(set_variable_call_service(json!("value")), vm_1_peer_id);
+    let mut vm_2 = create_avm(echo_call_service(), vm_2_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{vm_1_peer_id}" ("" "") [] result)
+            (call "{vm_2_peer_id}" ("" "") [result] result_2)
+        )
+        "#);
+
+    let result = checked_call_vm!(vm_1, <_>::default(), &script, "", "");
+    let mut forged_data: JValue = serde_json::from_slice(&result.data).unwrap();
+    let value_store = forged_data["cid_info"]["value_store"].as_object_mut().unwrap();
+    let (value_cid, value) = value_store.iter_mut().next().unwrap();
+    let value_cid = value_cid.clone();
+    *value = json!("forged value");
+    let forged_data = serde_json::to_vec(&forged_data).unwrap();
+
+    let result = call_vm!(vm_2, <_>::default(), &script, "", forged_data.clone());
+    let expected_error = PreparationError::CidVerificationFailed(CidVerificationError::Mismatch {
+        expected_cid: value_cid,
+        actual_cid: value_to_json_cid(&json!("forged value")).unwrap().into(),
+    });
+    assert!(check_error(&result, expected_error));
+
+    // a trusted peer's data isn't verified
+    let trusted_run_params = TestRunParameters {
+        skip_cid_verification: true,
+        ..<_>::default()
+    };
+    let result = checked_call_vm!(vm_2, trusted_run_params, &script, "", forged_data);
+    let trace = trace_from_result(&result);
+    assert_eq!(trace[1.into()], scalar_string("forged value"));
+}
+
+#[test]
+fn signing_key_mismatch() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(unit_call_service(), vm_peer_id);
+
+    let signing_key = [42; 32];
+    let run_params = TestRunParameters {
+        signing_key: signing_key.to_vec(),
+        ..<_>::default()
+    };
+    let result = call_vm!(vm, run_params, "(null)", "", "");
+
+    let expected_error = PreparationError::SigningKeyMismatch {
+        key_peer_id: KeyPair::from_secret_key(&signing_key).unwrap().peer_id(),
+        current_peer_id: vm_peer_id.to_string(),
+    };
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn malformed_data() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(unit_call_service(), vm_peer_id);
+
+    let script = f!(r#"
+        (par
+            (call "{vm_peer_id}" ("" "") [] $stream)
+            (fold $stream v
+                (seq
+                    (call "{vm_peer_id}" ("" "") [v])
+                    (next v))))
+        "#);
+
+    let mut tracker = CidTracker::new();
+    let trace = vec![
+        par(1, 2),
+        stream_tracked("", 0, &mut tracker),
+        fold(vec![subtrace_lore(1, subtrace_desc(3, 2), subtrace_desc(5, 0))]),
+        scalar_tracked("", &mut tracker),
+    ];
+    let data = raw_data_from_trace(trace, tracker);
+
+    let result = call_vm!(vm, <_>::default(), &script, "", data);
+    let expected_error = PreparationError::MalformedData(vec![DataDiagnostic::FoldSizeOverflow {
+        position: 2.into(),
+        states_count: 2,
+        available: 1,
+    }]);
+    assert!(check_error(&result, expected_error));
+}
@@ -31,6 +31,7 @@ mod call_service_result;
 mod outcome;
 mod particle_parameters;
 pub mod raw_outcome;
+mod run_options;
 
 use serde_json::Error as SerdeError;
 use thiserror::Error as ThisError;
@@ -76,3 +77,4 @@ pub use call_request_parameters::*;
 pub use call_service_result::*;
 pub use outcome::*;
 pub use particle_parameters::*;
+pub use run_options::*;
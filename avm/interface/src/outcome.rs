@@ -40,6 +40,32 @@ pub struct AVMOutcome {
     /// Time of a particle execution
     /// (it counts only execution time without operations with DataStore and so on)
     pub execution_time: Duration,
+
+    /// Statistics of the parsed-script cache at the moment of this execution.
+    pub script_cache_stats: ScriptCacheStats,
+
+    /// JSON report about provenance of states of the result trace,
+    /// it's empty if tracking wasn't enabled in the AVM config.
+    pub merge_provenance: Vec<u8>,
+}
+
+/// Describes usage of the parsed-script cache of an AVM.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptCacheStats {
+    /// True, if a precompiled script was taken from the cache for this execution.
+    pub hit: bool,
+
+    /// Overall count of cache hits.
+    pub hits: u64,
+
+    /// Overall count of cache misses.
+    pub misses: u64,
+
+    /// Count of scripts currently held in the cache.
+    pub size: usize,
+
+    /// Overall size in bytes of precompiled scripts currently held in the cache.
+    pub size_bytes: usize,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -56,6 +82,8 @@ impl AVMOutcome {
         next_peer_pks: Vec<String>,
         memory_delta: usize,
         execution_time: Duration,
+        script_cache_stats: ScriptCacheStats,
+        merge_provenance: Vec<u8>,
     ) -> Self {
         Self {
             data,
@@ -63,6 +91,8 @@ impl AVMOutcome {
             next_peer_pks,
             memory_delta,
             execution_time,
+            script_cache_stats,
+            merge_provenance,
         }
     }
 
@@ -71,6 +101,7 @@ impl AVMOutcome {
         raw_outcome: RawAVMOutcome,
         memory_delta: usize,
         execution_time: Duration,
+        script_cache_stats: ScriptCacheStats,
     ) -> Result<Self, ErrorAVMOutcome> {
         use air_interpreter_interface::INTERPRETER_SUCCESS;
 
@@ -80,6 +111,7 @@ impl AVMOutcome {
             data,
             call_requests,
             next_peer_pks,
+            merge_provenance,
         } = raw_outcome;
 
         let avm_outcome = AVMOutcome::new(
@@ -88,6 +120,8 @@ impl AVMOutcome {
             next_peer_pks,
             memory_delta,
             execution_time,
+            script_cache_stats,
+            merge_provenance,
         );
 
         if ret_code == INTERPRETER_SUCCESS {
@@ -31,6 +31,9 @@ pub struct RawAVMOutcome {
     pub data: Vec<u8>,
     pub call_requests: CallRequests,
     pub next_peer_pks: Vec<String>,
+    /// JSON report about provenance of states of the result trace, it isn't a part of
+    /// the interpreter outcome and is filled by runners only if tracking was enabled.
+    pub merge_provenance: Vec<u8>,
 }
 
 impl RawAVMOutcome {
@@ -51,6 +54,7 @@ impl RawAVMOutcome {
             data,
             call_requests,
             next_peer_pks,
+            merge_provenance: vec![],
         };
 
         Ok(raw_avm_outcome)
@@ -0,0 +1,117 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_interpreter_cid::CidHashAlgorithm;
+use air_interpreter_interface::DataFormat;
+use air_interpreter_interface::RunParameters;
+
+/// Options of a single interpreter run: parameters of a particle and settings of a host.
+/// Options that aren't set explicitly have default values, e.g. produced data isn't compressed.
+#[derive(Clone, Default)]
+pub struct RunOptions {
+    /// Peer id of a peer that start this particle.
+    pub init_peer_id: String,
+
+    /// Peer id of a current peer.
+    pub current_peer_id: String,
+
+    /// Id of a particle being executed.
+    pub particle_id: String,
+
+    /// Unix timestamp from a particle in milliseconds.
+    pub timestamp: u64,
+
+    /// TTL set by init peer id in milliseconds.
+    pub ttl: u32,
+
+    /// Unix timestamp of a host in milliseconds at the moment of execution.
+    pub current_timestamp: u64,
+
+    /// Format of data produced by the interpreter.
+    pub data_format: DataFormat,
+
+    /// Produced data not smaller than this size in bytes is compressed, 0 disables compression.
+    pub compression_threshold: u32,
+
+    /// Disables verification of CIDs in the current data.
+    pub skip_cid_verification: bool,
+
+    /// Hash algorithm used to calculate CIDs of produced values.
+    pub cid_hash_algorithm: CidHashAlgorithm,
+
+    /// Ed25519 secret key of the current peer, an empty key disables signing.
+    pub signing_key: Vec<u8>,
+
+    /// Rejects states of peers that don't sign them, signatures present in data are verified regardless.
+    pub require_signatures: bool,
+
+    /// Enables tracking of where states of the result trace came from.
+    pub track_merge_provenance: bool,
+}
+
+/// Parameters of the interpreter tracing, they're used only by runs with tracing.
+#[derive(Debug, Clone, Default)]
+pub struct TracingParameters {
+    /// Filter of tracing spans and events in the `tracing_subscriber::EnvFilter` syntax, e.g. `info`.
+    pub params: String,
+
+    /// 0 for the JSON output, any other value for the human-readable one.
+    pub output_mode: u8,
+}
+
+impl From<RunOptions> for RunParameters {
+    fn from(options: RunOptions) -> Self {
+        Self {
+            init_peer_id: options.init_peer_id,
+            current_peer_id: options.current_peer_id,
+            timestamp: options.timestamp,
+            ttl: options.ttl,
+            particle_id: options.particle_id,
+            current_timestamp: options.current_timestamp,
+            data_format: options.data_format.into(),
+            compression_threshold: options.compression_threshold,
+            skip_cid_verification: options.skip_cid_verification,
+            cid_hash_algorithm: options.cid_hash_algorithm.into(),
+            signing_key: options.signing_key,
+            require_signatures: options.require_signatures,
+            track_merge_provenance: options.track_merge_provenance,
+        }
+    }
+}
+
+// the signing key is secret, so it's never written to logs
+impl std::fmt::Debug for RunOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RunOptions")
+            .field("init_peer_id", &self.init_peer_id)
+            .field("current_peer_id", &self.current_peer_id)
+            .field("particle_id", &self.particle_id)
+            .field("timestamp", &self.timestamp)
+            .field("ttl", &self.ttl)
+            .field("current_timestamp", &self.current_timestamp)
+            .field("data_format", &self.data_format)
+            .field("compression_threshold", &self.compression_threshold)
+            .field("skip_cid_verification", &self.skip_cid_verification)
+            .field("cid_hash_algorithm", &self.cid_hash_algorithm)
+            .field(
+                "signing_key",
+                &format_args!("<{} bytes>", self.signing_key.len()),
+            )
+            .field("require_signatures", &self.require_signatures)
+            .field("track_merge_provenance", &self.track_merge_provenance)
+            .finish()
+    }
+}
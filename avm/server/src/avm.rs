@@ -19,6 +19,7 @@ use super::AVMDataStore;
 use super::AVMError;
 use super::AVMMemoryStats;
 use crate::config::AVMConfig;
+use crate::script_cache::ScriptCache;
 use crate::AVMResult;
 
 use avm_data_store::AnomalyData;
@@ -26,11 +27,13 @@ use avm_interface::raw_outcome::RawAVMOutcome;
 use avm_interface::AVMOutcome;
 use avm_interface::CallResults;
 use avm_interface::ParticleParameters;
+use avm_interface::RunOptions;
 
 use std::ops::Deref;
 use std::ops::DerefMut;
 use std::time::Duration;
 use std::time::Instant;
+use std::time::SystemTime;
 
 /// A newtype needed to mark it as `unsafe impl Send`
 struct SendSafeRunner(AVMRunner);
@@ -54,6 +57,9 @@ impl DerefMut for SendSafeRunner {
 pub struct AVM<E> {
     runner: SendSafeRunner,
     data_store: AVMDataStore<E>,
+    script_cache: ScriptCache,
+    /// options set by the config, particle parameters are filled on each call
+    run_options: RunOptions,
 }
 
 impl<E> AVM<E> {
@@ -65,14 +71,45 @@ impl<E> AVM<E> {
             max_heap_size,
             logging_mask,
             mut data_store,
+            script_cache_capacity,
+            script_cache_byte_limit,
+            data_format,
+            compression_threshold,
+            skip_cid_verification,
+            cid_hash_algorithm,
+            signing_key,
+            require_signatures,
+            track_merge_provenance,
         } = config;
 
         data_store.initialize()?;
 
-        let runner = AVMRunner::new(air_wasm_path, max_heap_size, logging_mask)
+        let mut runner = AVMRunner::new(air_wasm_path, max_heap_size, logging_mask)
             .map_err(AVMError::RunnerError)?;
+        let script_cache_capacity =
+            if script_cache_capacity > 0 && !binary_air_supported(&mut runner) {
+                0
+            } else {
+                script_cache_capacity
+            };
         let runner = SendSafeRunner(runner);
-        let avm = Self { runner, data_store };
+        let script_cache = ScriptCache::new(script_cache_capacity, script_cache_byte_limit);
+        let run_options = RunOptions {
+            data_format,
+            compression_threshold,
+            skip_cid_verification,
+            cid_hash_algorithm,
+            signing_key,
+            require_signatures,
+            track_merge_provenance,
+            ..<_>::default()
+        };
+        let avm = Self {
+            runner,
+            data_store,
+            script_cache,
+            run_options,
+        };
 
         Ok(avm)
     }
@@ -92,21 +129,35 @@ impl<E> AVM<E> {
         )?;
         let current_data = data.into();
 
+        let run_options = RunOptions {
+            init_peer_id: particle_parameters.init_peer_id.to_string(),
+            current_peer_id: particle_parameters.current_peer_id.to_string(),
+            particle_id: particle_parameters.particle_id.to_string(),
+            timestamp: particle_parameters.timestamp,
+            ttl: particle_parameters.ttl,
+            current_timestamp: unix_timestamp_now(),
+            ..self.run_options.clone()
+        };
         let execution_start_time = Instant::now();
         let memory_size_before = self.memory_stats().memory_size;
-        let outcome = self
-            .runner
-            .call(
+        let (precompiled_air, cache_hit) = self.script_cache.get_or_precompile(&air);
+        let outcome = match precompiled_air {
+            Some(binary_air) => self.runner.call_precompiled(
+                binary_air,
+                prev_data,
+                current_data.clone(),
+                run_options,
+                call_results.clone(),
+            ),
+            None => self.runner.call(
                 air.clone(),
                 prev_data,
                 current_data.clone(),
-                particle_parameters.init_peer_id.clone().into_owned(),
-                particle_parameters.timestamp,
-                particle_parameters.ttl,
-                particle_parameters.current_peer_id.clone(),
+                run_options,
                 call_results.clone(),
-            )
-            .map_err(AVMError::RunnerError)?;
+            ),
+        }
+        .map_err(AVMError::RunnerError)?;
 
         let execution_time = execution_start_time.elapsed();
         let memory_delta = self.memory_stats().memory_size - memory_size_before;
@@ -131,8 +182,10 @@ impl<E> AVM<E> {
             &particle_parameters.particle_id,
             &particle_parameters.current_peer_id,
         )?;
-        let outcome = AVMOutcome::from_raw_outcome(outcome, memory_delta, execution_time)
-            .map_err(AVMError::InterpreterFailed)?;
+        let script_cache_stats = self.script_cache.stats(cache_hit);
+        let outcome =
+            AVMOutcome::from_raw_outcome(outcome, memory_delta, execution_time, script_cache_stats)
+                .map_err(AVMError::InterpreterFailed)?;
 
         Ok(outcome)
     }
@@ -190,3 +243,34 @@ impl<E> AVM<E> {
             .map_err(Into::into)
     }
 }
+
+/// Checks that the interpreter accepts scripts precompiled by the host,
+/// otherwise every cached script would fail to decode.
+fn binary_air_supported(runner: &mut AVMRunner) -> bool {
+    match runner.binary_air_version() {
+        Ok(version) if version == air_parser::BINARY_AIR_VERSION => true,
+        Ok(version) => {
+            log::warn!(
+                "script cache is disabled: interpreter accepts binary AIR of version {}, but the host produces {}",
+                version,
+                air_parser::BINARY_AIR_VERSION
+            );
+            false
+        }
+        Err(error) => {
+            log::warn!(
+                "script cache is disabled: failed to get binary AIR version of the interpreter: {}",
+                error
+            );
+            false
+        }
+    }
+}
+
+/// Returns the current host time in milliseconds, the same units are used for particle timestamps.
+fn unix_timestamp_now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
@@ -15,6 +15,9 @@
  */
 
 use super::AVMDataStore;
+use air_interpreter_cid::CidHashAlgorithm;
+use air_interpreter_interface::DataFormat;
+
 use std::path::PathBuf;
 
 /// Describes behaviour of the AVM.
@@ -29,4 +32,39 @@ pub struct AVMConfig<E> {
     pub logging_mask: i32,
 
     pub data_store: AVMDataStore<E>,
+
+    /// Maximum count of precompiled scripts kept by the parsed-script cache, 0 disables the cache.
+    /// The cache is disabled as well if the interpreter Wasm accepts another binary AIR version than the host.
+    pub script_cache_capacity: usize,
+
+    /// Maximum overall size in bytes of precompiled scripts kept by the parsed-script cache,
+    /// 0 disables the cache.
+    pub script_cache_byte_limit: usize,
+
+    /// Format of data produced by the interpreter, data in any format is accepted as input.
+    pub data_format: DataFormat,
+
+    /// Produced data not smaller than this size in bytes is compressed, 0 disables compression.
+    /// Compressed data is accepted as input regardless of this setting.
+    pub compression_threshold: u32,
+
+    /// Disables verification of CIDs in data received from other peers,
+    /// it should be set only if all peers are trusted.
+    pub skip_cid_verification: bool,
+
+    /// Hash algorithm used to calculate CIDs of produced values,
+    /// all peers of a particle should use the same one.
+    pub cid_hash_algorithm: CidHashAlgorithm,
+
+    /// Ed25519 secret key of the current peer, CIDs of states produced by the interpreter are signed
+    /// with it, an empty key disables signing. Signatures in received data are verified regardless.
+    pub signing_key: Vec<u8>,
+
+    /// Rejects states of peers that don't sign them, it should be set only if all peers of
+    /// a particle sign their states. Signatures present in data are verified regardless.
+    pub require_signatures: bool,
+
+    /// Enables tracking of where states of the result trace came from, the report is returned
+    /// in outcomes. It's intended for debugging merge issues and slows down execution.
+    pub track_merge_provenance: bool,
 }
@@ -28,6 +28,7 @@ mod avm;
 mod config;
 mod errors;
 mod runner;
+mod script_cache;
 
 pub use avm::AVM;
 pub use config::AVMConfig;
@@ -50,6 +51,8 @@ pub use marine::HostImportError;
 pub use marine::IType;
 pub use marine::IValue;
 
+pub use air_interpreter_cid::CidHashAlgorithm;
+pub use air_interpreter_interface::DataFormat;
 pub use polyplets::SecurityTetraplet;
 
 pub use avm_data_store::AnomalyData;
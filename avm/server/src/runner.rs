@@ -18,9 +18,12 @@ use crate::RunnerError;
 use crate::RunnerResult;
 
 use air_interpreter_interface::InterpreterOutcome;
+use air_interpreter_interface::RunParameters;
 use air_utils::measure;
 use avm_interface::raw_outcome::RawAVMOutcome;
 use avm_interface::CallResults;
+use avm_interface::RunOptions;
+use avm_interface::TracingParameters;
 use marine::IValue;
 use marine::Marine;
 use marine::MarineConfig;
@@ -64,92 +67,108 @@ impl AVMRunner {
         Ok(avm)
     }
 
-    #[allow(clippy::too_many_arguments)]
     #[tracing::instrument(skip_all)]
     pub fn call(
         &mut self,
         air: impl Into<String>,
         prev_data: impl Into<Vec<u8>>,
         data: impl Into<Vec<u8>>,
-        init_peer_id: impl Into<String>,
-        timestamp: u64,
-        ttl: u32,
-        current_peer_id: impl Into<String>,
+        run_options: RunOptions,
         call_results: CallResults,
     ) -> RunnerResult<RawAVMOutcome> {
+        let track_merge_provenance = run_options.track_merge_provenance;
         let args = prepare_args(
-            air,
+            IValue::String(air.into()),
             prev_data,
             data,
-            current_peer_id.into(),
-            init_peer_id.into(),
-            timestamp,
-            ttl,
+            run_options,
             call_results,
         );
 
-        let result = measure!(
-            self.marine
-                .call_with_ivalues(&self.wasm_filename, "invoke", &args, <_>::default())?,
-            tracing::Level::INFO,
-            "marine.call_with_ivalues",
-            method = "invoke",
-        );
+        self.invoke_entry_point("invoke", args, track_merge_provenance)
+    }
 
-        let result = try_as_one_value_vec(result)?;
-        let outcome = InterpreterOutcome::from_ivalue(result)
-            .map_err(RunnerError::InterpreterResultDeError)?;
-        let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+    /// Executes AIR that was already precompiled to the binary form by `air_parser::precompile_air`.
+    #[tracing::instrument(skip_all)]
+    pub fn call_precompiled(
+        &mut self,
+        binary_air: impl Into<Vec<u8>>,
+        prev_data: impl Into<Vec<u8>>,
+        data: impl Into<Vec<u8>>,
+        run_options: RunOptions,
+        call_results: CallResults,
+    ) -> RunnerResult<RawAVMOutcome> {
+        let track_merge_provenance = run_options.track_merge_provenance;
+        let args = prepare_args(
+            IValue::ByteArray(binary_air.into()),
+            prev_data,
+            data,
+            run_options,
+            call_results,
+        );
 
-        Ok(outcome)
+        self.invoke_entry_point("invoke_precompiled", args, track_merge_provenance)
     }
 
-    #[allow(clippy::too_many_arguments)]
     #[tracing::instrument(skip_all)]
     pub fn call_tracing(
         &mut self,
         air: impl Into<String>,
         prev_data: impl Into<Vec<u8>>,
         data: impl Into<Vec<u8>>,
-        init_peer_id: impl Into<String>,
-        timestamp: u64,
-        ttl: u32,
-        current_peer_id: impl Into<String>,
+        run_options: RunOptions,
         call_results: CallResults,
-        tracing_params: String,
-        tracing_output_mode: u8,
+        tracing_parameters: TracingParameters,
     ) -> RunnerResult<RawAVMOutcome> {
+        let track_merge_provenance = run_options.track_merge_provenance;
         let mut args = prepare_args(
-            air,
+            IValue::String(air.into()),
             prev_data,
             data,
-            current_peer_id.into(),
-            init_peer_id.into(),
-            timestamp,
-            ttl,
+            run_options,
             call_results,
         );
-        args.push(IValue::String(tracing_params));
-        args.push(IValue::U8(tracing_output_mode));
+        args.push(IValue::String(tracing_parameters.params));
+        args.push(IValue::U8(tracing_parameters.output_mode));
 
-        let result = measure!(
-            self.marine.call_with_ivalues(
-                &self.wasm_filename,
-                "invoke_tracing",
-                &args,
-                <_>::default(),
-            )?,
-            tracing::Level::INFO,
-            "marine.call_with_ivalues",
-            method = "invoke_tracing",
-        );
+        self.invoke_entry_point("invoke_tracing", args, track_merge_provenance)
+    }
 
-        let result = try_as_one_value_vec(result)?;
-        let outcome = InterpreterOutcome::from_ivalue(result)
-            .map_err(RunnerError::InterpreterResultDeError)?;
-        let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+    /// Returns the version of binary AIR accepted by the loaded interpreter.
+    /// Interpreters older than the binary AIR support don't export it, so an error is returned for them.
+    pub fn binary_air_version(&mut self) -> RunnerResult<u32> {
+        use RunnerError::IncorrectInterpreterResult;
+
+        let result = self.marine.call_with_ivalues(
+            &self.wasm_filename,
+            "binary_air_version",
+            &[],
+            <_>::default(),
+        )?;
+
+        match try_as_one_value_vec(result)? {
+            IValue::U32(version) => Ok(version),
+            value => Err(IncorrectInterpreterResult(vec![value])),
+        }
+    }
 
-        Ok(outcome)
+    /// Returns the merge provenance report of the last call. It isn't a part of the outcome to keep
+    /// the outcome record compatible, so interpreters without tracking support don't export it
+    /// and an error is returned for them.
+    pub fn merge_provenance(&mut self) -> RunnerResult<Vec<u8>> {
+        use RunnerError::IncorrectInterpreterResult;
+
+        let result = self.marine.call_with_ivalues(
+            &self.wasm_filename,
+            "merge_provenance",
+            &[],
+            <_>::default(),
+        )?;
+
+        match try_as_one_value_vec(result)? {
+            IValue::ByteArray(report) => Ok(report),
+            value => Err(IncorrectInterpreterResult(vec![value])),
+        }
     }
 
     pub fn memory_stats(&self) -> AVMMemoryStats {
@@ -163,27 +182,46 @@ impl AVMRunner {
             max_memory_size: stats[0].max_memory_size,
         }
     }
+
+    fn invoke_entry_point(
+        &mut self,
+        entry_point: &'static str,
+        args: Vec<IValue>,
+        track_merge_provenance: bool,
+    ) -> RunnerResult<RawAVMOutcome> {
+        let result = measure!(
+            self.marine.call_with_ivalues(
+                &self.wasm_filename,
+                entry_point,
+                &args,
+                <_>::default()
+            )?,
+            tracing::Level::INFO,
+            "marine.call_with_ivalues",
+            method = entry_point,
+        );
+
+        let result = try_as_one_value_vec(result)?;
+        let outcome = InterpreterOutcome::from_ivalue(result)
+            .map_err(RunnerError::InterpreterResultDeError)?;
+        let mut outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+        if track_merge_provenance {
+            outcome.merge_provenance = self.merge_provenance()?;
+        }
+
+        Ok(outcome)
+    }
 }
 
-#[allow(clippy::too_many_arguments)]
 #[tracing::instrument(skip(air, prev_data, data, call_results))]
 fn prepare_args(
-    air: impl Into<String>,
+    air: IValue,
     prev_data: impl Into<Vec<u8>>,
     data: impl Into<Vec<u8>>,
-    current_peer_id: String,
-    init_peer_id: String,
-    timestamp: u64,
-    ttl: u32,
+    run_options: RunOptions,
     call_results: CallResults,
 ) -> Vec<IValue> {
-    let run_parameters = air_interpreter_interface::RunParameters::new(
-        init_peer_id,
-        current_peer_id,
-        timestamp,
-        ttl,
-    )
-    .into_ivalue();
+    let run_parameters = RunParameters::from(run_options).into_ivalue();
 
     let call_results = avm_interface::into_raw_result(call_results);
     let call_results = measure!(
@@ -193,7 +231,7 @@ fn prepare_args(
     );
 
     vec![
-        IValue::String(air.into()),
+        air,
         IValue::ByteArray(prev_data.into()),
         IValue::ByteArray(data.into()),
         run_parameters,
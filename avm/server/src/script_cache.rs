@@ -0,0 +1,230 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use avm_interface::ScriptCacheStats;
+use sha2::Digest;
+use sha2::Sha256;
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+type ScriptHash = [u8; 32];
+
+/// Keeps AIR scripts precompiled to the binary form keyed by a hash of their text,
+/// so particles sharing the same script are parsed only once.
+/// When the cache is full either by count or by size, the oldest inserted scripts are evicted.
+pub(crate) struct ScriptCache {
+    capacity: usize,
+    byte_limit: usize,
+    size_bytes: usize,
+    scripts: HashMap<ScriptHash, Vec<u8>>,
+    insertion_order: VecDeque<ScriptHash>,
+    hits: u64,
+    misses: u64,
+}
+
+impl ScriptCache {
+    /// Creates a cache able to hold up to `capacity` scripts taking not more than `byte_limit` bytes
+    /// overall, zero capacity or byte limit disables it.
+    pub(crate) fn new(capacity: usize, byte_limit: usize) -> Self {
+        let capacity = if byte_limit == 0 { 0 } else { capacity };
+
+        Self {
+            capacity,
+            byte_limit,
+            size_bytes: 0,
+            scripts: HashMap::with_capacity(capacity),
+            insertion_order: VecDeque::with_capacity(capacity),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns a precompiled script and whether it was found in the cache.
+    /// None is returned if the cache is disabled or the script can't be parsed,
+    /// in this case the script should be passed to the interpreter as is to get
+    /// the usual error from it.
+    pub(crate) fn get_or_precompile(&mut self, air: &str) -> (Option<Vec<u8>>, bool) {
+        if self.capacity == 0 {
+            return (None, false);
+        }
+
+        let script_hash = hash_script(air);
+        if let Some(binary_air) = self.scripts.get(&script_hash) {
+            self.hits += 1;
+            return (Some(binary_air.clone()), true);
+        }
+
+        self.misses += 1;
+        let binary_air = match air_parser::precompile_air(air) {
+            Ok(binary_air) => binary_air,
+            Err(error) => {
+                log::debug!(
+                    "script isn't cached, because it can't be precompiled: {}",
+                    error
+                );
+                return (None, false);
+            }
+        };
+        self.insert(script_hash, binary_air.clone());
+
+        (Some(binary_air), false)
+    }
+
+    pub(crate) fn stats(&self, hit: bool) -> ScriptCacheStats {
+        ScriptCacheStats {
+            hit,
+            hits: self.hits,
+            misses: self.misses,
+            size: self.scripts.len(),
+            size_bytes: self.size_bytes,
+        }
+    }
+
+    fn insert(&mut self, script_hash: ScriptHash, binary_air: Vec<u8>) {
+        if binary_air.len() > self.byte_limit {
+            log::debug!(
+                "script isn't cached, because its size {} exceeds the cache byte limit {}",
+                binary_air.len(),
+                self.byte_limit
+            );
+            return;
+        }
+
+        while self.scripts.len() >= self.capacity
+            || self.size_bytes + binary_air.len() > self.byte_limit
+        {
+            let oldest_hash = match self.insertion_order.pop_front() {
+                Some(oldest_hash) => oldest_hash,
+                None => break,
+            };
+            if let Some(oldest_script) = self.scripts.remove(&oldest_hash) {
+                self.size_bytes -= oldest_script.len();
+            }
+        }
+
+        self.size_bytes += binary_air.len();
+        self.scripts.insert(script_hash, binary_air);
+        self.insertion_order.push_back(script_hash);
+    }
+}
+
+fn hash_script(air: &str) -> ScriptHash {
+    Sha256::digest(air.as_bytes()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_script_hits_cache() {
+        let mut cache = ScriptCache::new(2, usize::MAX);
+        let air = r#"(call "peer_id" ("service" "function") [] result)"#;
+
+        let (first, first_hit) = cache.get_or_precompile(air);
+        let (second, second_hit) = cache.get_or_precompile(air);
+
+        assert!(first.is_some());
+        assert!(!first_hit);
+        assert_eq!(first, second);
+        assert!(second_hit);
+
+        let expected_stats = ScriptCacheStats {
+            hit: true,
+            hits: 1,
+            misses: 1,
+            size: 1,
+            size_bytes: first.unwrap().len(),
+        };
+        assert_eq!(cache.stats(second_hit), expected_stats);
+    }
+
+    #[test]
+    fn oldest_script_is_evicted() {
+        let mut cache = ScriptCache::new(2, usize::MAX);
+
+        cache.get_or_precompile("(null)");
+        cache.get_or_precompile("(never)");
+        cache.get_or_precompile("(seq (null) (null))");
+        assert_eq!(cache.stats(false).size, 2);
+
+        let (_, hit) = cache.get_or_precompile("(never)");
+        assert!(hit);
+
+        let (_, hit) = cache.get_or_precompile("(null)");
+        assert!(!hit);
+    }
+
+    #[test]
+    fn scripts_are_evicted_by_byte_limit() {
+        let small_air = "(null)";
+        let big_air = "(seq (seq (null) (null)) (seq (null) (null)))";
+        let small_size = air_parser::precompile_air(small_air).unwrap().len();
+        let big_size = air_parser::precompile_air(big_air).unwrap().len();
+        let mut cache = ScriptCache::new(10, small_size + big_size);
+
+        cache.get_or_precompile(small_air);
+        cache.get_or_precompile("(never)");
+        assert_eq!(cache.stats(false).size, 2);
+
+        cache.get_or_precompile(big_air);
+        let stats = cache.stats(false);
+        assert!(stats.size_bytes <= small_size + big_size);
+
+        let (_, hit) = cache.get_or_precompile(big_air);
+        assert!(hit);
+
+        let (_, hit) = cache.get_or_precompile(small_air);
+        assert!(!hit);
+    }
+
+    #[test]
+    fn script_bigger_than_byte_limit_isnt_cached() {
+        let air = "(seq (null) (null))";
+        let binary_size = air_parser::precompile_air(air).unwrap().len();
+        let mut cache = ScriptCache::new(2, binary_size - 1);
+
+        let (binary_air, hit) = cache.get_or_precompile(air);
+        assert!(binary_air.is_some());
+        assert!(!hit);
+
+        let (_, hit) = cache.get_or_precompile(air);
+        assert!(!hit);
+        assert_eq!(cache.stats(hit).size, 0);
+        assert_eq!(cache.stats(hit).size_bytes, 0);
+    }
+
+    #[test]
+    fn invalid_script_isnt_cached() {
+        let mut cache = ScriptCache::new(2, usize::MAX);
+
+        let (binary_air, hit) = cache.get_or_precompile("(seq (null))");
+        assert!(binary_air.is_none());
+        assert!(!hit);
+        assert_eq!(cache.stats(hit).size, 0);
+    }
+
+    #[test]
+    fn zero_capacity_disables_cache() {
+        let mut cache = ScriptCache::new(0, usize::MAX);
+
+        let (binary_air, hit) = cache.get_or_precompile("(null)");
+        assert!(binary_air.is_none());
+        assert!(!hit);
+        assert_eq!(cache.stats(hit), ScriptCacheStats::default());
+    }
+}
@@ -18,12 +18,15 @@ mod impls;
 mod traits;
 
 use super::CanonStream;
+use super::CanonStreamMap;
+use super::CanonStreamMapWithLambda;
 use super::CanonStreamWithLambda;
 use super::ImmutableVariable;
 use super::ImmutableVariableWithLambda;
 use super::Scalar;
 use super::ScalarWithLambda;
 use super::Stream;
+use super::StreamMap;
 
 use air_lambda_ast::LambdaAST;
 
@@ -68,17 +71,31 @@ pub struct Triplet<'i> {
 pub enum ImmutableValue<'i> {
     InitPeerId,
     LastError(Option<LambdaAST<'i>>),
+    Error(Option<LambdaAST<'i>>),
     Timestamp,
     TTL,
+    ParticleId,
     Literal(&'i str),
     Number(Number),
     Boolean(bool),
-    EmptyArray, // only empty arrays are allowed now
+    EmptyArray,
+    #[serde(deserialize_with = "crate::binary::deserialize_nested")]
+    Array(Vec<ImmutableValue<'i>>),
+    #[serde(deserialize_with = "crate::binary::deserialize_nested")]
+    Object(Vec<ObjectEntry<'i>>),
     Variable(ImmutableVariable<'i>),
     VariableWithLambda(ImmutableVariableWithLambda<'i>),
 }
 
-#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+/// Represents a `"key": value` pair of an object literal.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ObjectEntry<'i> {
+    pub key: &'i str,
+    #[serde(borrow)]
+    pub value: ImmutableValue<'i>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub enum CallOutputValue<'i> {
     #[serde(borrow)]
     Scalar(Scalar<'i>),
@@ -92,15 +109,33 @@ pub enum ApArgument<'i> {
     InitPeerId,
     Timestamp,
     TTL,
+    ParticleId,
     LastError(Option<LambdaAST<'i>>),
+    Error(Option<LambdaAST<'i>>),
     Literal(&'i str),
     Number(Number),
     Boolean(bool),
     EmptyArray,
+    #[serde(deserialize_with = "crate::binary::deserialize_nested")]
+    Array(Vec<ImmutableValue<'i>>),
+    #[serde(deserialize_with = "crate::binary::deserialize_nested")]
+    Object(Vec<ObjectEntry<'i>>),
     Scalar(Scalar<'i>),
     ScalarWithLambda(ScalarWithLambda<'i>),
     CanonStream(CanonStream<'i>),
     CanonStreamWithLambda(CanonStreamWithLambda<'i>),
+    CanonStreamMap(CanonStreamMap<'i>),
+    CanonStreamMapWithLambda(CanonStreamMapWithLambda<'i>),
+}
+
+/// Contains all variable variants that could be used as a key in a stream map.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum StreamMapKeyClause<'i> {
+    Literal(&'i str),
+    Int(i64),
+    Scalar(Scalar<'i>),
+    ScalarWithLambda(ScalarWithLambda<'i>),
+    CanonStreamWithLambda(CanonStreamWithLambda<'i>),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -137,4 +172,8 @@ pub enum NewArgument<'i> {
     Stream(Stream<'i>),
     #[serde(borrow)]
     CanonStream(CanonStream<'i>),
+    #[serde(borrow)]
+    StreamMap(StreamMap<'i>),
+    #[serde(borrow)]
+    CanonStreamMap(CanonStreamMap<'i>),
 }
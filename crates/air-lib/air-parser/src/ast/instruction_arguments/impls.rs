@@ -16,7 +16,9 @@
 
 use super::ApResult;
 use super::CallOutputValue;
+use super::ImmutableValue;
 use super::NewArgument;
+use super::ObjectEntry;
 use super::Scalar;
 use super::Stream;
 use crate::parser::lexer::AirPos;
@@ -27,6 +29,8 @@ impl<'i> NewArgument<'i> {
             Self::Scalar(scalar) => scalar.name,
             Self::Stream(stream) => stream.name,
             Self::CanonStream(canon_stream) => canon_stream.name,
+            Self::StreamMap(stream_map) => stream_map.name,
+            Self::CanonStreamMap(canon_stream_map) => canon_stream_map.name,
         }
     }
 }
@@ -57,3 +61,9 @@ impl<'i> CallOutputValue<'i> {
         Self::Stream(Stream { name, position })
     }
 }
+
+impl<'i> ObjectEntry<'i> {
+    pub fn new(key: &'i str, value: ImmutableValue<'i>) -> Self {
+        Self { key, value }
+    }
+}
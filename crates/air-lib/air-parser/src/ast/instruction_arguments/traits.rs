@@ -36,18 +36,28 @@ impl fmt::Display for ImmutableValue<'_> {
         match self {
             InitPeerId => write!(f, "%init_peer_id%"),
             LastError(error_accessor) => display_last_error(f, error_accessor),
+            Error(error_accessor) => display_error(f, error_accessor),
             Literal(literal) => write!(f, r#""{literal}""#),
             Timestamp => write!(f, "%timestamp%"),
             TTL => write!(f, "%ttl%"),
+            ParticleId => write!(f, "%particle_id%"),
             Number(number) => write!(f, "{number}"),
             Boolean(bool) => write!(f, "{bool}"),
             EmptyArray => write!(f, "[]"),
+            Array(values) => display_array(f, values),
+            Object(entries) => display_object(f, entries),
             Variable(variable) => write!(f, "{variable}"),
             VariableWithLambda(variable) => write!(f, "{variable}"),
         }
     }
 }
 
+impl fmt::Display for ObjectEntry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, r#""{}": {}"#, self.key, self.value)
+    }
+}
+
 impl fmt::Display for ResolvableToPeerIdVariable<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use ResolvableToPeerIdVariable::*;
@@ -94,16 +104,36 @@ impl fmt::Display for ApArgument<'_> {
         match self {
             InitPeerId => write!(f, "%init_peer_id%"),
             LastError(error_accessor) => display_last_error(f, error_accessor),
+            Error(error_accessor) => display_error(f, error_accessor),
             Literal(str) => write!(f, r#""{str}""#),
             Timestamp => write!(f, "%timestamp%"),
             TTL => write!(f, "%ttl%"),
+            ParticleId => write!(f, "%particle_id%"),
             Number(number) => write!(f, "{number}"),
             Boolean(bool) => write!(f, "{bool}"),
             EmptyArray => write!(f, "[]"),
+            Array(values) => display_array(f, values),
+            Object(entries) => display_object(f, entries),
             Scalar(scalar) => write!(f, "{scalar}"),
             ScalarWithLambda(scalar) => write!(f, "{scalar}"),
             CanonStream(canon_stream) => write!(f, "{canon_stream}"),
             CanonStreamWithLambda(canon_stream) => write!(f, "{canon_stream}"),
+            CanonStreamMap(canon_stream_map) => write!(f, "{canon_stream_map}"),
+            CanonStreamMapWithLambda(canon_stream_map) => write!(f, "{canon_stream_map}"),
+        }
+    }
+}
+
+impl fmt::Display for StreamMapKeyClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use StreamMapKeyClause::*;
+
+        match self {
+            Literal(literal) => write!(f, r#""{literal}""#),
+            Int(int) => write!(f, "{int}"),
+            Scalar(scalar) => write!(f, "{scalar}"),
+            ScalarWithLambda(scalar) => write!(f, "{scalar}"),
+            CanonStreamWithLambda(canon_stream) => write!(f, "{canon_stream}"),
         }
     }
 }
@@ -124,6 +154,8 @@ impl fmt::Display for NewArgument<'_> {
             Self::Scalar(scalar) => write!(f, "{scalar}"),
             Self::Stream(stream) => write!(f, "{stream}"),
             Self::CanonStream(canon_stream) => write!(f, "{canon_stream}"),
+            Self::StreamMap(stream_map) => write!(f, "{stream_map}"),
+            Self::CanonStreamMap(canon_stream_map) => write!(f, "{canon_stream_map}"),
         }
     }
 }
@@ -173,3 +205,22 @@ fn display_last_error(f: &mut fmt::Formatter, lambda_ast: &Option<LambdaAST>) ->
         None => write!(f, "%last_error%"),
     }
 }
+
+fn display_error(f: &mut fmt::Formatter, lambda_ast: &Option<LambdaAST>) -> fmt::Result {
+    match lambda_ast {
+        Some(lambda_ast) => write!(f, "%error%{lambda_ast}"),
+        None => write!(f, "%error%"),
+    }
+}
+
+fn display_array(f: &mut fmt::Formatter, values: &[ImmutableValue<'_>]) -> fmt::Result {
+    use itertools::Itertools;
+
+    write!(f, "[{}]", values.iter().join(" "))
+}
+
+fn display_object(f: &mut fmt::Formatter, entries: &[ObjectEntry<'_>]) -> fmt::Result {
+    use itertools::Itertools;
+
+    write!(f, "{{{}}}", entries.iter().join(" "))
+}
@@ -19,141 +19,280 @@ mod traits;
 
 use super::*;
 
+use serde::Deserialize;
 use serde::Serialize;
 
 use std::rc::Rc;
 
 // TODO: sort instruction in alphanumeric order
 #[allow(clippy::large_enum_variant)] // for Null and Error variants
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum Instruction<'i> {
+    #[serde(borrow)]
     Call(Call<'i>),
+    #[serde(borrow)]
     Ap(Ap<'i>),
+    #[serde(borrow)]
+    ApMap(ApMap<'i>),
+    #[serde(borrow)]
     Canon(Canon<'i>),
+    #[serde(borrow)]
+    CanonMap(CanonMap<'i>),
+    #[serde(borrow)]
     Seq(Seq<'i>),
+    #[serde(borrow)]
     Par(Par<'i>),
+    #[serde(borrow)]
     Xor(Xor<'i>),
+    #[serde(borrow)]
     Match(Match<'i>),
+    #[serde(borrow)]
     MisMatch(MisMatch<'i>),
+    #[serde(borrow)]
+    Compare(Compare<'i>),
+    #[serde(borrow)]
+    Member(Member<'i>),
+    #[serde(borrow)]
+    TtlGuard(TtlGuard<'i>),
+    #[serde(borrow)]
     Fail(Fail<'i>),
+    #[serde(borrow)]
     FoldScalar(FoldScalar<'i>),
+    #[serde(borrow)]
     FoldStream(FoldStream<'i>),
     Never(Never),
+    #[serde(borrow)]
     New(New<'i>),
+    #[serde(borrow)]
     Next(Next<'i>),
     Null(Null),
     Error,
 }
 
 /// (call (peer part of a triplet: PeerPart) (function part of a triplet: FunctionPart) [arguments] output)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct Call<'i> {
+    #[serde(borrow)]
     pub triplet: Triplet<'i>,
+    #[serde(borrow)]
     pub args: Rc<Vec<ImmutableValue<'i>>>,
+    #[serde(borrow)]
     pub output: CallOutputValue<'i>,
 }
 
 /// (ap argument result)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct Ap<'i> {
+    #[serde(borrow)]
     pub argument: ApArgument<'i>,
+    #[serde(borrow)]
     pub result: ApResult<'i>,
 }
 
+/// (ap (key value) %map)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ApMap<'i> {
+    #[serde(borrow)]
+    pub key: StreamMapKeyClause<'i>,
+    #[serde(borrow)]
+    pub value: ApArgument<'i>,
+    #[serde(borrow)]
+    pub map: StreamMap<'i>,
+}
+
 /// (canon peer_id $stream #canon_stream)
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct Canon<'i> {
+    #[serde(borrow)]
     pub peer_id: ResolvableToPeerIdVariable<'i>,
+    #[serde(borrow)]
     pub stream: Stream<'i>,
+    #[serde(borrow)]
     pub canon_stream: CanonStream<'i>,
 }
 
+/// (canon peer_id %stream_map #%canon_stream_map)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct CanonMap<'i> {
+    #[serde(borrow)]
+    pub peer_id: ResolvableToPeerIdVariable<'i>,
+    #[serde(borrow)]
+    pub stream_map: StreamMap<'i>,
+    #[serde(borrow)]
+    pub canon_stream_map: CanonStreamMap<'i>,
+}
+
 /// (seq instruction instruction)
-#[derive(Serialize, Debug, PartialEq)]
-pub struct Seq<'i>(pub Box<Instruction<'i>>, pub Box<Instruction<'i>>);
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Seq<'i>(
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
+    pub  Box<Instruction<'i>>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
+    pub  Box<Instruction<'i>>,
+);
 
 /// (par instruction instruction)
-#[derive(Serialize, Debug, PartialEq)]
-pub struct Par<'i>(pub Box<Instruction<'i>>, pub Box<Instruction<'i>>);
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Par<'i>(
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
+    pub  Box<Instruction<'i>>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
+    pub  Box<Instruction<'i>>,
+);
 
 /// (xor instruction instruction)
-#[derive(Serialize, Debug, PartialEq)]
-pub struct Xor<'i>(pub Box<Instruction<'i>>, pub Box<Instruction<'i>>);
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Xor<'i>(
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
+    pub  Box<Instruction<'i>>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
+    pub  Box<Instruction<'i>>,
+);
 
 /// (match left_value right_value instruction)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct Match<'i> {
+    #[serde(borrow)]
     pub left_value: ImmutableValue<'i>,
+    #[serde(borrow)]
     pub right_value: ImmutableValue<'i>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
     pub instruction: Box<Instruction<'i>>,
 }
 
 /// (mismatch left_value right_value instruction)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct MisMatch<'i> {
+    #[serde(borrow)]
     pub left_value: ImmutableValue<'i>,
+    #[serde(borrow)]
     pub right_value: ImmutableValue<'i>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
+    pub instruction: Box<Instruction<'i>>,
+}
+
+/// (lt left_value right_value instruction)
+/// (lte left_value right_value instruction)
+/// (gt left_value right_value instruction)
+/// (gte left_value right_value instruction)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Compare<'i> {
+    pub operator: ComparisonOperator,
+    #[serde(borrow)]
+    pub left_value: ImmutableValue<'i>,
+    #[serde(borrow)]
+    pub right_value: ImmutableValue<'i>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
+    pub instruction: Box<Instruction<'i>>,
+}
+
+/// Ordering operators, numbers are compared numerically and strings lexicographically.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ComparisonOperator {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+/// (member value collection instruction)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Member<'i> {
+    #[serde(borrow)]
+    pub value: ImmutableValue<'i>,
+    #[serde(borrow)]
+    pub collection: ImmutableValue<'i>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
+    pub instruction: Box<Instruction<'i>>,
+}
+
+/// (ttl_guard min_remaining_ttl instruction)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct TtlGuard<'i> {
+    #[serde(borrow)]
+    pub min_remaining_ttl: ImmutableValue<'i>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
     pub instruction: Box<Instruction<'i>>,
 }
 
 /// (fail 1337 "error message")
 /// (fail %last_error%)
 /// (fail value)
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub enum Fail<'i> {
+    #[serde(borrow)]
     Scalar(Scalar<'i>),
+    #[serde(borrow)]
     ScalarWithLambda(ScalarWithLambda<'i>),
     Literal {
         ret_code: i64,
         error_message: &'i str,
     },
+    #[serde(borrow)]
     CanonStreamWithLambda(CanonStreamWithLambda<'i>),
     LastError,
+    Error,
 }
 
-/// (fold scalar_iterable iterator instruction)
-#[derive(Serialize, Debug, PartialEq)]
+/// (fold scalar_iterable iterator index? instruction)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct FoldScalar<'i> {
     #[serde(borrow)]
     pub iterable: FoldScalarIterable<'i>,
     #[serde(borrow)]
     pub iterator: Scalar<'i>,
+    /// Optional scalar bound to the index of the current iteration.
+    #[serde(borrow)]
+    pub index: Option<Scalar<'i>>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
     pub instruction: Rc<Instruction<'i>>,
     // option is needed to provide a graceful period of adoption
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
     pub last_instruction: Option<Rc<Instruction<'i>>>,
     pub span: Span,
 }
 
-/// (fold stream_iterable iterator instruction)
-#[derive(Serialize, Debug, PartialEq)]
+/// (fold stream_iterable iterator index? max_iterations? instruction)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct FoldStream<'i> {
+    #[serde(borrow)]
     pub iterable: Stream<'i>,
     #[serde(borrow)]
     pub iterator: Scalar<'i>,
+    /// Optional scalar bound to the index of the current iteration.
+    #[serde(borrow)]
+    pub index: Option<Scalar<'i>>,
+    /// Maximum number of stream values the fold iterates over.
+    pub max_iterations: Option<u32>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
     pub instruction: Rc<Instruction<'i>>,
     // option is needed to provide a graceful period of adoption
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
     pub last_instruction: Option<Rc<Instruction<'i>>>,
     pub span: Span,
 }
 
 /// (fold stream_iterable iterator instruction)
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct Next<'i> {
+    #[serde(borrow)]
     pub iterator: Scalar<'i>,
 }
 
 /// (never)
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct Never;
 
 /// (new variable instruction)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct New<'i> {
+    #[serde(borrow)]
     pub argument: NewArgument<'i>,
+    #[serde(borrow, deserialize_with = "crate::binary::deserialize_nested")]
     pub instruction: Box<Instruction<'i>>,
     pub span: Span,
 }
 
 /// (null)
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct Null;
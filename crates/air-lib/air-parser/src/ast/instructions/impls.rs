@@ -22,6 +22,12 @@ impl<'i> Ap<'i> {
     }
 }
 
+impl<'i> ApMap<'i> {
+    pub fn new(key: StreamMapKeyClause<'i>, value: ApArgument<'i>, map: StreamMap<'i>) -> Self {
+        Self { key, value, map }
+    }
+}
+
 impl<'i> Call<'i> {
     pub fn new(
         triplet: Triplet<'i>,
@@ -50,6 +56,20 @@ impl<'i> Canon<'i> {
     }
 }
 
+impl<'i> CanonMap<'i> {
+    pub fn new(
+        peer_id: ResolvableToPeerIdVariable<'i>,
+        stream_map: StreamMap<'i>,
+        canon_stream_map: CanonStreamMap<'i>,
+    ) -> Self {
+        Self {
+            peer_id,
+            stream_map,
+            canon_stream_map,
+        }
+    }
+}
+
 impl<'i> Seq<'i> {
     pub fn new(
         left_instruction: Box<Instruction<'i>>,
@@ -105,10 +125,50 @@ impl<'i> MisMatch<'i> {
     }
 }
 
+impl<'i> Compare<'i> {
+    pub fn new(
+        operator: ComparisonOperator,
+        left_value: ImmutableValue<'i>,
+        right_value: ImmutableValue<'i>,
+        instruction: Box<Instruction<'i>>,
+    ) -> Self {
+        Self {
+            operator,
+            left_value,
+            right_value,
+            instruction,
+        }
+    }
+}
+
+impl<'i> Member<'i> {
+    pub fn new(
+        value: ImmutableValue<'i>,
+        collection: ImmutableValue<'i>,
+        instruction: Box<Instruction<'i>>,
+    ) -> Self {
+        Self {
+            value,
+            collection,
+            instruction,
+        }
+    }
+}
+
+impl<'i> TtlGuard<'i> {
+    pub fn new(min_remaining_ttl: ImmutableValue<'i>, instruction: Box<Instruction<'i>>) -> Self {
+        Self {
+            min_remaining_ttl,
+            instruction,
+        }
+    }
+}
+
 impl<'i> FoldScalar<'i> {
     pub fn new(
         iterable: FoldScalarIterable<'i>,
         iterator: Scalar<'i>,
+        index: Option<Scalar<'i>>,
         instruction: Instruction<'i>,
         last_instruction: Option<Instruction<'i>>,
         span: Span,
@@ -116,6 +176,7 @@ impl<'i> FoldScalar<'i> {
         Self {
             iterable,
             iterator,
+            index,
             instruction: Rc::new(instruction),
             last_instruction: last_instruction.map(Rc::new),
             span,
@@ -127,6 +188,8 @@ impl<'i> FoldStream<'i> {
     pub fn new(
         iterable: Stream<'i>,
         iterator: Scalar<'i>,
+        index: Option<Scalar<'i>>,
+        max_iterations: Option<u32>,
         instruction: Instruction<'i>,
         last_instruction: Option<Instruction<'i>>,
         span: Span,
@@ -134,6 +197,8 @@ impl<'i> FoldStream<'i> {
         Self {
             iterable,
             iterator,
+            index,
+            max_iterations,
             instruction: Rc::new(instruction),
             last_instruction: last_instruction.map(Rc::new),
             span,
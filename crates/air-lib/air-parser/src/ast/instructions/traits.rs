@@ -25,12 +25,17 @@ impl fmt::Display for Instruction<'_> {
         match self {
             Call(call) => write!(f, "{call}"),
             Canon(canon) => write!(f, "{canon}"),
+            CanonMap(canon_map) => write!(f, "{canon_map}"),
             Ap(ap) => write!(f, "{ap}"),
+            ApMap(ap_map) => write!(f, "{ap_map}"),
             Seq(seq) => write!(f, "{seq}"),
             Par(par) => write!(f, "{par}"),
             Xor(xor) => write!(f, "{xor}"),
             Match(match_) => write!(f, "{match_}"),
             MisMatch(mismatch) => write!(f, "{mismatch}"),
+            Compare(compare) => write!(f, "{compare}"),
+            Member(member) => write!(f, "{member}"),
+            TtlGuard(ttl_guard) => write!(f, "{ttl_guard}"),
             Fail(fail) => write!(f, "{fail}"),
             FoldScalar(fold) => write!(f, "{fold}"),
             FoldStream(fold) => write!(f, "{fold}"),
@@ -68,6 +73,22 @@ impl fmt::Display for Ap<'_> {
     }
 }
 
+impl fmt::Display for CanonMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "canon {} {} {}",
+            self.peer_id, self.stream_map, self.canon_stream_map
+        )
+    }
+}
+
+impl fmt::Display for ApMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ap ({} {}) {}", self.key, self.value, self.map)
+    }
+}
+
 impl fmt::Display for Fail<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -81,19 +102,33 @@ impl fmt::Display for Fail<'_> {
                 write!(f, "fail {stream}")
             }
             Fail::LastError => write!(f, "fail %last_error%"),
+            Fail::Error => write!(f, "fail %error%"),
         }
     }
 }
 
 impl fmt::Display for FoldScalar<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "fold {} {}", self.iterable, self.iterator)
+        write!(f, "fold {} {}", self.iterable, self.iterator)?;
+        if let Some(index) = &self.index {
+            write!(f, " {index}")?;
+        }
+
+        Ok(())
     }
 }
 
 impl fmt::Display for FoldStream<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "fold {} {}", self.iterable, self.iterator)
+        write!(f, "fold {} {}", self.iterable, self.iterator)?;
+        if let Some(index) = &self.index {
+            write!(f, " {index}")?;
+        }
+        if let Some(max_iterations) = self.max_iterations {
+            write!(f, " {max_iterations}")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -133,6 +168,41 @@ impl fmt::Display for MisMatch<'_> {
     }
 }
 
+impl fmt::Display for Compare<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.operator, self.left_value, self.right_value
+        )
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ComparisonOperator::*;
+
+        match self {
+            Less => write!(f, "lt"),
+            LessOrEqual => write!(f, "lte"),
+            Greater => write!(f, "gt"),
+            GreaterOrEqual => write!(f, "gte"),
+        }
+    }
+}
+
+impl fmt::Display for Member<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "member {} {}", self.value, self.collection)
+    }
+}
+
+impl fmt::Display for TtlGuard<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ttl_guard {}", self.min_remaining_ttl)
+    }
+}
+
 impl fmt::Display for Never {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "never")
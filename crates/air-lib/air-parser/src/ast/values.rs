@@ -62,6 +62,29 @@ pub struct CanonStreamWithLambda<'i> {
     pub position: AirPos,
 }
 
+/// A stream map without a lambda.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct StreamMap<'i> {
+    pub name: &'i str,
+    pub position: AirPos,
+}
+
+/// A canonicalized stream map without a lambda.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CanonStreamMap<'i> {
+    pub name: &'i str,
+    pub position: AirPos,
+}
+
+/// A canonicalized stream map with a lambda.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CanonStreamMapWithLambda<'i> {
+    pub name: &'i str,
+    #[serde(borrow)]
+    pub lambda: LambdaAST<'i>,
+    pub position: AirPos,
+}
+
 /// A variable that could be either scalar or stream without lambda.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum ImmutableVariable<'i> {
@@ -69,6 +92,8 @@ pub enum ImmutableVariable<'i> {
     Scalar(Scalar<'i>),
     #[serde(borrow)]
     CanonStream(CanonStream<'i>),
+    #[serde(borrow)]
+    CanonStreamMap(CanonStreamMap<'i>),
 }
 
 /// A variable that could be either scalar or stream with possible lambda expression.
@@ -78,4 +103,6 @@ pub enum ImmutableVariableWithLambda<'i> {
     Scalar(ScalarWithLambda<'i>),
     #[serde(borrow)]
     CanonStream(CanonStreamWithLambda<'i>),
+    #[serde(borrow)]
+    CanonStreamMap(CanonStreamMapWithLambda<'i>),
 }
@@ -0,0 +1,206 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests;
+
+use crate::ast::Instruction;
+
+use bincode::Options;
+use serde::de::Error as _;
+use serde::Deserialize;
+use serde::Deserializer;
+use thiserror::Error as ThisError;
+
+use std::cell::Cell;
+
+/// Binary AIR consists of this magic, a little-endian u32 version of the encoding and
+/// an AST encoded with bincode. Positions of variables are preserved, so errors produced by
+/// the interpreter point to the same places of the original script.
+pub const BINARY_AIR_MAGIC: &[u8; 4] = b"AIRB";
+
+/// Version of the binary AIR encoding, it should be increased on every change of AST
+/// that affects its serialized form.
+pub const BINARY_AIR_VERSION: u32 = 1;
+
+/// Maximum size of an encoded AST, it bounds memory a decoder could be forced to allocate.
+pub const MAX_BINARY_AIR_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Maximum nesting depth of instructions and values, decoding is recursive,
+/// so binary air with deeper nesting is rejected to not overflow the stack.
+pub const MAX_BINARY_AIR_NESTING_DEPTH: usize = 512;
+
+const HEADER_SIZE: usize = BINARY_AIR_MAGIC.len() + std::mem::size_of::<u32>();
+
+thread_local! {
+    static NESTING_DEPTH: Cell<usize> = Cell::new(0);
+}
+
+#[derive(ThisError, Debug)]
+pub enum BinaryAirError {
+    #[error("air can't be parsed:\n{0}")]
+    ParseError(String),
+
+    #[error("binary air doesn't start with the expected magic")]
+    InvalidMagic,
+
+    #[error("binary air has version {actual}, but only version {expected} is supported")]
+    UnsupportedVersion { actual: u32, expected: u32 },
+
+    #[error("binary air can't be encoded: {0}")]
+    EncodeError(#[source] bincode::Error),
+
+    #[error("binary air can't be decoded: {0}")]
+    DecodeError(#[source] bincode::Error),
+
+    #[error("binary air contains an instruction that wasn't parsed successfully")]
+    ContainsErrorInstruction,
+
+    #[error("binary air doesn't pass validation:\n{0}")]
+    ValidationError(String),
+}
+
+/// Parses and validates an AIR script and then encodes it to the binary form,
+/// such a script could be executed many times without lexing and parsing.
+pub fn precompile_air(script: &str) -> Result<Vec<u8>, BinaryAirError> {
+    let air = crate::parse(script).map_err(BinaryAirError::ParseError)?;
+    encode_air(&air)
+}
+
+/// Encodes an already parsed AIR script to the binary form.
+pub fn encode_air(air: &Instruction<'_>) -> Result<Vec<u8>, BinaryAirError> {
+    validate_air(air)?;
+
+    let encoded_air = bincode_options()
+        .serialize(air)
+        .map_err(BinaryAirError::EncodeError)?;
+
+    let mut binary_air = Vec::with_capacity(HEADER_SIZE + encoded_air.len());
+    binary_air.extend_from_slice(BINARY_AIR_MAGIC);
+    binary_air.extend_from_slice(&BINARY_AIR_VERSION.to_le_bytes());
+    binary_air.extend(encoded_air);
+
+    Ok(binary_air)
+}
+
+/// Decodes an AIR script from the binary form, the resulted AST borrows strings
+/// from the supplied slice.
+pub fn decode_air(binary_air: &[u8]) -> Result<Instruction<'_>, BinaryAirError> {
+    if binary_air.len() < HEADER_SIZE || !binary_air.starts_with(BINARY_AIR_MAGIC) {
+        return Err(BinaryAirError::InvalidMagic);
+    }
+
+    let (header, encoded_air) = binary_air.split_at(HEADER_SIZE);
+    let mut version = [0u8; 4];
+    version.copy_from_slice(&header[BINARY_AIR_MAGIC.len()..]);
+    let version = u32::from_le_bytes(version);
+    if version != BINARY_AIR_VERSION {
+        return Err(BinaryAirError::UnsupportedVersion {
+            actual: version,
+            expected: BINARY_AIR_VERSION,
+        });
+    }
+
+    // bincode doesn't apply the size limit when it decodes from a slice
+    if encoded_air.len() as u64 > MAX_BINARY_AIR_SIZE {
+        return Err(BinaryAirError::DecodeError(Box::new(
+            bincode::ErrorKind::SizeLimit,
+        )));
+    }
+
+    let air: Instruction<'_> = bincode_options()
+        .deserialize(encoded_air)
+        .map_err(BinaryAirError::DecodeError)?;
+
+    // binary air could be produced not only by this encoder, so it's checked
+    // the same way as a parsed script before it could be executed
+    validate_air(&air)?;
+
+    Ok(air)
+}
+
+fn validate_air(air: &Instruction<'_>) -> Result<(), BinaryAirError> {
+    if contains_error_instruction(air) {
+        return Err(BinaryAirError::ContainsErrorInstruction);
+    }
+
+    let errors = crate::parser::validate_ast(air);
+    if !errors.is_empty() {
+        return Err(BinaryAirError::ValidationError(errors.join("\n")));
+    }
+
+    Ok(())
+}
+
+fn bincode_options() -> impl Options {
+    bincode::DefaultOptions::new().with_limit(MAX_BINARY_AIR_SIZE)
+}
+
+/// Deserializes a nested instruction or value, failing if the nesting is too deep.
+pub(crate) fn deserialize_nested<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let depth = NESTING_DEPTH.with(|depth| {
+        depth.set(depth.get() + 1);
+        depth.get()
+    });
+
+    let result = if depth > MAX_BINARY_AIR_NESTING_DEPTH {
+        Err(D::Error::custom(format!(
+            "nesting depth exceeds {MAX_BINARY_AIR_NESTING_DEPTH}"
+        )))
+    } else {
+        T::deserialize(deserializer)
+    };
+
+    NESTING_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    result
+}
+
+fn contains_error_instruction(air: &Instruction<'_>) -> bool {
+    use Instruction::*;
+
+    match air {
+        Error => true,
+        Call(_) | Ap(_) | ApMap(_) | Canon(_) | CanonMap(_) | Fail(_) | Never(_) | Next(_)
+        | Null(_) => false,
+        Seq(seq) => contains_error_instruction(&seq.0) || contains_error_instruction(&seq.1),
+        Par(par) => contains_error_instruction(&par.0) || contains_error_instruction(&par.1),
+        Xor(xor) => contains_error_instruction(&xor.0) || contains_error_instruction(&xor.1),
+        Match(match_) => contains_error_instruction(&match_.instruction),
+        MisMatch(mismatch) => contains_error_instruction(&mismatch.instruction),
+        Compare(compare) => contains_error_instruction(&compare.instruction),
+        Member(member) => contains_error_instruction(&member.instruction),
+        TtlGuard(ttl_guard) => contains_error_instruction(&ttl_guard.instruction),
+        FoldScalar(fold) => {
+            contains_error_instruction(&fold.instruction)
+                || fold
+                    .last_instruction
+                    .as_ref()
+                    .map_or(false, |last| contains_error_instruction(last))
+        }
+        FoldStream(fold) => {
+            contains_error_instruction(&fold.instruction)
+                || fold
+                    .last_instruction
+                    .as_ref()
+                    .map_or(false, |last| contains_error_instruction(last))
+        }
+        New(new) => contains_error_instruction(&new.instruction),
+    }
+}
@@ -0,0 +1,314 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+use crate::ast::*;
+
+#[test]
+fn binary_air_roundtrip() {
+    let script = r#"
+        (seq
+            (seq
+                (call %init_peer_id% ("service_id" "fn_name") [%ttl% "literal" 42 -1.5 [1 "2"] {"k": %particle_id%}] $stream)
+                (canon %init_peer_id% $stream #canon_stream)
+            )
+            (xor
+                (fold $stream iterator idx 3
+                    (seq
+                        (ttl_guard 1000
+                            (ap iterator.$.field?.[0] scalar)
+                        )
+                        (next iterator)
+                    )
+                    (null)
+                )
+                (seq
+                    (match %error%.$.error_code 10000
+                        (fail %error%)
+                    )
+                    (new $stream
+                        (never)
+                    )
+                )
+            )
+        )
+        "#;
+
+    let air = crate::parse(script).unwrap();
+    let binary_air = encode_air(&air).unwrap();
+    let decoded_air = decode_air(&binary_air).unwrap();
+
+    assert_eq!(decoded_air, *air);
+}
+
+#[test]
+fn precompiled_air_starts_with_header() {
+    let binary_air = precompile_air("(null)").unwrap();
+
+    assert!(binary_air.starts_with(BINARY_AIR_MAGIC));
+    assert_eq!(
+        &binary_air[BINARY_AIR_MAGIC.len()..HEADER_SIZE],
+        &BINARY_AIR_VERSION.to_le_bytes()
+    );
+    assert_eq!(decode_air(&binary_air).unwrap(), Instruction::Null(Null));
+}
+
+#[test]
+fn precompile_invalid_air() {
+    let result = precompile_air("(seq (null))");
+
+    assert!(matches!(result, Err(BinaryAirError::ParseError(_))));
+}
+
+#[test]
+fn decode_air_with_invalid_magic() {
+    let mut binary_air = precompile_air("(null)").unwrap();
+    binary_air[0] = b'X';
+
+    let result = decode_air(&binary_air);
+    assert!(matches!(result, Err(BinaryAirError::InvalidMagic)));
+
+    let result = decode_air(&BINARY_AIR_MAGIC[..]);
+    assert!(matches!(result, Err(BinaryAirError::InvalidMagic)));
+}
+
+#[test]
+fn decode_air_with_unsupported_version() {
+    let mut binary_air = precompile_air("(null)").unwrap();
+    let unsupported_version = BINARY_AIR_VERSION + 1;
+    binary_air[BINARY_AIR_MAGIC.len()..HEADER_SIZE]
+        .copy_from_slice(&unsupported_version.to_le_bytes());
+
+    let result = decode_air(&binary_air);
+    assert!(matches!(
+        result,
+        Err(BinaryAirError::UnsupportedVersion { actual, expected })
+            if actual == unsupported_version && expected == BINARY_AIR_VERSION
+    ));
+}
+
+#[test]
+fn decode_malformed_air() {
+    let mut binary_air = precompile_air("(seq (null) (null))").unwrap();
+    binary_air.truncate(binary_air.len() - 1);
+
+    let result = decode_air(&binary_air);
+    assert!(matches!(result, Err(BinaryAirError::DecodeError(_))));
+}
+
+#[test]
+fn error_instruction_is_rejected() {
+    let air = Instruction::Seq(Seq(
+        Box::new(Instruction::Null(Null)),
+        Box::new(Instruction::Error),
+    ));
+    let result = encode_air(&air);
+    assert!(matches!(
+        result,
+        Err(BinaryAirError::ContainsErrorInstruction)
+    ));
+
+    let fold = FoldScalar::new(
+        FoldScalarIterable::EmptyArray,
+        Scalar::new("iterator", 0.into()),
+        None,
+        Instruction::Error,
+        None,
+        Span::new(0.into(), 0.into()),
+    );
+    let air = Instruction::FoldScalar(fold);
+    let result = encode_air(&air);
+    assert!(matches!(
+        result,
+        Err(BinaryAirError::ContainsErrorInstruction)
+    ));
+
+    // an encoder from another implementation could produce such a script
+    let mut binary_air = BINARY_AIR_MAGIC.to_vec();
+    binary_air.extend_from_slice(&BINARY_AIR_VERSION.to_le_bytes());
+    binary_air.extend(bincode_options().serialize(&Instruction::Error).unwrap());
+    let result = decode_air(&binary_air);
+    assert!(matches!(
+        result,
+        Err(BinaryAirError::ContainsErrorInstruction)
+    ));
+}
+
+// encodes air without any checks, as an encoder from another implementation could do
+fn encode_unchecked(air: &Instruction<'_>) -> Vec<u8> {
+    let mut binary_air = BINARY_AIR_MAGIC.to_vec();
+    binary_air.extend_from_slice(&BINARY_AIR_VERSION.to_le_bytes());
+    binary_air.extend(bincode::DefaultOptions::new().serialize(air).unwrap());
+    binary_air
+}
+
+// parses a valid script and then replaces a part of its AST, because such a broken AST
+// can't be obtained from the parser
+fn encode_rewritten(script: &str, from: &str, to: &str) -> Vec<u8> {
+    let air = crate::parse(script).unwrap();
+    let json_air = serde_json::to_string(&air).unwrap();
+    assert!(json_air.contains(from), "{}", json_air);
+
+    let json_air = json_air.replace(from, to);
+    let air: Instruction<'_> = serde_json::from_str(&json_air).unwrap();
+    encode_unchecked(&air)
+}
+
+fn assert_validation_error(binary_air: &[u8], expected_message: &str) {
+    match decode_air(binary_air) {
+        Err(BinaryAirError::ValidationError(message)) => {
+            assert!(message.contains(expected_message), "{}", message)
+        }
+        result => panic!("validation error expected, but got {:?}", result),
+    }
+}
+
+#[test]
+fn undefined_variable_is_rejected() {
+    let script = r#"
+        (seq
+            (call "peer" ("" "") [] x)
+            (call "peer" ("" "") [x])
+        )
+        "#;
+    let binary_air = encode_rewritten(
+        script,
+        r#""output":{"Scalar":{"name":"x""#,
+        r#""output":{"Scalar":{"name":"y""#,
+    );
+
+    assert_validation_error(&binary_air, "variable 'x' wasn't defined");
+}
+
+#[test]
+fn undefined_iterable_is_rejected() {
+    let script = r#"
+        (fold [] k (next k))
+        "#;
+    let binary_air = encode_rewritten(
+        script,
+        r#"{"Next":{"iterator":{"name":"k""#,
+        r#"{"Next":{"iterator":{"name":"i""#,
+    );
+
+    assert_validation_error(&binary_air, "iterable 'i' wasn't defined");
+}
+
+#[test]
+fn multiple_next_in_fold_is_rejected() {
+    let script = r#"
+        (fold [] i
+            (seq
+                (next i)
+                (fold [] k (next k))
+            )
+        )
+        "#;
+    let binary_air = encode_rewritten(
+        script,
+        r#"{"Next":{"iterator":{"name":"k""#,
+        r#"{"Next":{"iterator":{"name":"i""#,
+    );
+
+    assert_validation_error(&binary_air, "multiple next instructions for iterator 'i'");
+}
+
+#[test]
+fn new_on_iterator_is_rejected() {
+    let script = r#"
+        (fold [] i
+            (seq
+                (new x (null))
+                (next i)
+            )
+        )
+        "#;
+    let binary_air = encode_rewritten(script, r#""name":"x""#, r#""name":"i""#);
+
+    assert_validation_error(
+        &binary_air,
+        "new can't be applied to a 'i' because it's an iterator",
+    );
+}
+
+#[test]
+fn too_deep_instructions_are_rejected() {
+    let nested_seq = |depth: usize| {
+        (0..depth).fold(Instruction::Null(Null), |air, _| {
+            Instruction::Seq(Seq(Box::new(air), Box::new(Instruction::Null(Null))))
+        })
+    };
+
+    let binary_air = encode_unchecked(&nested_seq(MAX_BINARY_AIR_NESTING_DEPTH));
+    assert!(decode_air(&binary_air).is_ok());
+
+    let binary_air = encode_unchecked(&nested_seq(MAX_BINARY_AIR_NESTING_DEPTH + 1));
+    let result = decode_air(&binary_air);
+    assert!(
+        matches!(&result, Err(BinaryAirError::DecodeError(error)) if error.to_string().contains("nesting depth")),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn too_deep_values_are_rejected() {
+    let nested_array = (0..=MAX_BINARY_AIR_NESTING_DEPTH)
+        .fold(ImmutableValue::EmptyArray, |value, _| {
+            ImmutableValue::Array(vec![value])
+        });
+    let air = Instruction::Call(Call::new(
+        Triplet {
+            peer_id: ResolvableToPeerIdVariable::Literal("peer"),
+            service_id: ResolvableToStringVariable::Literal(""),
+            function_name: ResolvableToStringVariable::Literal(""),
+        },
+        std::rc::Rc::new(vec![nested_array]),
+        CallOutputValue::None,
+    ));
+
+    let binary_air = encode_unchecked(&air);
+    let result = decode_air(&binary_air);
+    assert!(
+        matches!(&result, Err(BinaryAirError::DecodeError(error)) if error.to_string().contains("nesting depth")),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn too_big_air_is_rejected() {
+    let literal = "a".repeat(MAX_BINARY_AIR_SIZE as usize + 1);
+    let air = Instruction::Fail(Fail::Literal {
+        ret_code: 1,
+        error_message: &literal,
+    });
+
+    let result = encode_air(&air);
+    assert!(
+        matches!(&result, Err(BinaryAirError::EncodeError(error)) if matches!(**error, bincode::ErrorKind::SizeLimit)),
+        "{:?}",
+        result
+    );
+
+    let binary_air = encode_unchecked(&air);
+    let result = decode_air(&binary_air);
+    assert!(
+        matches!(&result, Err(BinaryAirError::DecodeError(error)) if matches!(**error, bincode::ErrorKind::SizeLimit)),
+        "{:?}",
+        result
+    );
+}
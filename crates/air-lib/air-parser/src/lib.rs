@@ -26,8 +26,17 @@
 )]
 
 pub mod ast;
+mod binary;
 mod parser;
 
+pub use binary::decode_air;
+pub use binary::encode_air;
+pub use binary::precompile_air;
+pub use binary::BinaryAirError;
+pub use binary::BINARY_AIR_MAGIC;
+pub use binary::BINARY_AIR_VERSION;
+pub use binary::MAX_BINARY_AIR_NESTING_DEPTH;
+pub use binary::MAX_BINARY_AIR_SIZE;
 pub use parser::lexer::AirPos;
 pub use parser::parse;
 pub use parser::AIRLexer;
@@ -1,5 +1,5 @@
 // auto-generated: "lalrpop 0.19.8"
-// sha3: 3ad9b22b9e4c2674604f2810bb1b7b297eecd7ba9091a4c8694fb34d4c670b6e
+// sha3: 7fea13bd86c24162917cff7af53641df43bad60fe0bd07b109cb570bc9df9f16
 use crate::ast::*;
 use crate::parser::ParserError;
 use crate::parser::VariableValidator;
@@ -7,6 +7,7 @@ use crate::parser::Span;
 use crate::parser::lexer::{AirPos, Token};
 use air_lambda_parser::LambdaAST;
 use lalrpop_util::ErrorRecovery;
+use lalrpop_util::ParseError;
 use std::rc::Rc;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
@@ -26,6 +27,7 @@ mod __parse__AIR {
     use crate::parser::lexer::{AirPos, Token};
     use air_lambda_parser::LambdaAST;
     use lalrpop_util::ErrorRecovery;
+    use lalrpop_util::ParseError;
     use std::rc::Rc;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -41,321 +43,504 @@ mod __parse__AIR {
         Variant1(bool),
         Variant2((&'input str, AirPos)),
         Variant3((&'input str, LambdaAST<'input>, AirPos)),
-        Variant4(f64),
-        Variant5(i64),
-        Variant6(LambdaAST<'input>),
+        Variant4(LambdaAST<'input>),
+        Variant5(f64),
+        Variant6(i64),
         Variant7(&'input str),
         Variant8(__lalrpop_util::ErrorRecovery<AirPos, Token<'input>, ParserError>),
         Variant9(ImmutableValue<'input>),
         Variant10(alloc::vec::Vec<ImmutableValue<'input>>),
-        Variant11(AirPos),
-        Variant12(Box<Instruction<'input>>),
-        Variant13(ApArgument<'input>),
-        Variant14(ApResult<'input>),
-        Variant15(Vec<ImmutableValue<'input>>),
-        Variant16(CallOutputValue<'input>),
-        Variant17(core::option::Option<CallOutputValue<'input>>),
-        Variant18(CanonStream<'input>),
-        Variant19(Fail<'input>),
-        Variant20(FoldScalarIterable<'input>),
-        Variant21(ResolvableToStringVariable<'input>),
-        Variant22(core::option::Option<Box<Instruction<'input>>>),
-        Variant23(NewArgument<'input>),
-        Variant24(Number),
-        Variant25(ResolvableToPeerIdVariable<'input>),
-        Variant26(Stream<'input>),
-        Variant27(Triplet<'input>),
+        Variant11(ObjectEntry<'input>),
+        Variant12(alloc::vec::Vec<ObjectEntry<'input>>),
+        Variant13(AirPos),
+        Variant14(Box<Instruction<'input>>),
+        Variant15(ApArgument<'input>),
+        Variant16(ApResult<'input>),
+        Variant17(Vec<ImmutableValue<'input>>),
+        Variant18(CallOutputValue<'input>),
+        Variant19(core::option::Option<CallOutputValue<'input>>),
+        Variant20(CanonStream<'input>),
+        Variant21(CanonStreamMap<'input>),
+        Variant22(ComparisonOperator),
+        Variant23(Fail<'input>),
+        Variant24(u32),
+        Variant25(core::option::Option<u32>),
+        Variant26(FoldScalarIterable<'input>),
+        Variant27(ResolvableToStringVariable<'input>),
+        Variant28(core::option::Option<Box<Instruction<'input>>>),
+        Variant29(NewArgument<'input>),
+        Variant30(Number),
+        Variant31(ResolvableToPeerIdVariable<'input>),
+        Variant32(core::option::Option<(&'input str, AirPos)>),
+        Variant33(Stream<'input>),
+        Variant34(StreamMap<'input>),
+        Variant35(StreamMapKeyClause<'input>),
+        Variant36(Triplet<'input>),
     }
     const __ACTION: &[i16] = &[
         // State 0
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 1
-        0, 0, 42, 0, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 0, 54, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 5, 6, 7, 8, 67, 68, 69, 70, 9, 10, 11, 71, 12, 72, 73, 13, 14, 15, 16, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 58, 0, 0, 59, 0, 0, 60, 61, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 18, 0, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 58, 0, 0, 59, 0, 0, 60, 61, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        21, 0, 0, 22, 0, 23, 0, 94, 95, 96, 97, 98, 99, 100, 82, 83, 101, 102, 103, 104, 105, 106, 107, 0, 0, 108, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 64, 0, 65, 0, 66, 0, 0, 67, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 113, 0, 0, 114, 0, 115, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 70, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 72, 73, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 113, 0, 0, 114, 0, 115, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 76, 0, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 119, 0, 0, 120, 0, 121, 0, 0, 0, 122, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 76, 0, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 125, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 128, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 90, 0, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 18, 0, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 18, 0, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 18, 0, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 0, 0, 0, 0, 0, 131, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 134, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 95, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 13
-        0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 18, 0, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 0, 76, 0, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 16
-        0, 0, 76, 0, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 18, 0, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 18, 139, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 147, 0, 0, 0, 148, 0, 149, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 111, 112, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 18, 151, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        0, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 23
-        0, 0, 76, 120, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 24
-        0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 18, 0, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 18, 0, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 18, 0, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 28
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 29
-        0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 111, 112, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 30
-        0, 0, 76, 133, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 31
-        36, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 32
-        36, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 33
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 18, 166, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 35
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 38, 9, 39, 40, 10, 11, 12, 0,
+        0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 36
-        -60, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60,
+        0, 0, 0, 22, 0, 23, 0, 94, 95, 96, 97, 98, 99, 100, 82, 83, 101, 102, 103, 104, 105, 106, 107, 0, 0, 108, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 37
-        0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 18, 171, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 39
-        0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 175, 0, 176, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 180, 0, 181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 41
-        0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 18, 184, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 45
-        -67, 0, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 46
-        -66, 0, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 49
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 18, 0, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 175, 0, 176, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 18, 204, 19, 0, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 208, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 53
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 55
-        22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 56
-        -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 57
-        -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 59
-        -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 60
-        -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 61
-        -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 62
-        0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66,
         // State 63
-        0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 64
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 65
-        0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -98, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98,
         // State 66
-        0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -51, 0, -51, 0, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, 0, -51, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 67
-        0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -52, 0, -52, 0, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, 0, 0, -52, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 68
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -49, 0, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, 0, 0, -49, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 69
-        0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -50, 0, -50, 0, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, 0, 0, -50, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 70
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 71
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 72
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 73
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -139, 0, 0, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, 0, 0, -139, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139,
         // State 74
-        -87, 0, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, 0, -87, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87,
+        -140, 0, 0, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, 0, 0, -140, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140,
         // State 75
-        0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -147, 0, 0, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, 0, 0, -147, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147,
         // State 76
-        -88, 0, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, 0, -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88,
+        -149, 0, 0, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, 0, 0, -149, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149,
         // State 77
-        -92, 0, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, 0, -92, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92,
+        -150, 0, 0, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, 0, 0, -150, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150,
         // State 78
-        -93, 0, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, 0, -93, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93,
+        -148, 0, 0, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, 0, 0, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148,
         // State 79
-        -81, 0, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, 0, -81, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81,
+        -133, 0, 0, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, 0, 0, -133, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133,
         // State 80
-        -82, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, 0, -82, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82,
+        -134, 0, 0, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, 0, 0, -134, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134,
         // State 81
-        -83, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, 0, -83, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83,
+        -107, -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, 0, -107, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107,
         // State 82
-        -84, 0, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, 0, -84, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84,
+        -106, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, 0, -106, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106,
         // State 83
-        -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, 0, -90, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90,
+        -130, 0, 0, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, 0, 0, -130, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130,
         // State 84
-        -91, 0, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, 0, -91, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91,
+        -131, 0, 0, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, 0, 0, -131, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131,
         // State 85
-        -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, 0, -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86,
+        -132, 0, 0, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, 0, 0, -132, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132,
         // State 86
-        -85, 0, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, 0, -85, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85,
+        -135, 0, 0, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, 0, 0, -135, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135,
         // State 87
-        -48, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48,
+        -138, 0, 0, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, 0, 0, -138, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138,
         // State 88
-        -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65,
+        -145, 0, 0, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, 0, 0, -145, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145,
         // State 89
-        -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63,
+        -146, 0, 0, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, 0, 0, -146, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146,
         // State 90
-        -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64,
+        -137, 0, 0, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, 0, 0, -137, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137,
         // State 91
-        0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -136, 0, 0, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, 0, 0, -136, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136,
         // State 92
-        -49, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49,
+        0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 93
-        0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 94
-        0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 95
-        0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 96
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 97
-        0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 98
-        -51, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51,
+        0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 99
-        0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 100
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 101
-        -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, 0, -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89,
+        0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 102
-        0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 103
-        -56, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56,
+        0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 104
-        0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 105
-        0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 106
-        0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 107
-        -45, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45,
+        0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 108
-        0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, -78, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 109
-        0, -77, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, -77, -77, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 110
-        0, -74, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, -74, -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 111
-        0, -75, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, -75, -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 112
-        0, -76, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, -76, -76, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 113
-        0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 114
-        -43, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43,
+        -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 115
-        0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 116
-        0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 117
-        0, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 118
-        0, 0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 119
-        0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 120
-        0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 121
-        0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 122
-        0, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 123
-        0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 124
-        -50, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50,
+        0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 125
-        -47, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 126
-        -46, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 127
-        -57, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 128
-        0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 129
-        0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75,
         // State 130
-        -42, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42,
+        -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103,
         // State 131
-        0, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105,
         // State 132
-        0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101,
         // State 133
-        -44, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44,
+        -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102,
         // State 134
-        0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104,
         // State 135
-        -53, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53,
+        0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 136
-        0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -76, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76,
         // State 137
-        -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55,
+        0, 0, 0, -12, -12, -12, 0, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, 0, 0, -12, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 138
-        -58, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58,
+        -141, 0, 0, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, 0, 0, -141, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141,
         // State 139
-        -59, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59,
+        0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 140
-        0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -143, 0, 0, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, 0, 0, -143, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143,
         // State 141
-        -52, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52,
+        0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 142
-        -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54,
+        0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, -128, 0, -128, 0, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, 0, 0, -128, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, -125, 0, -125, 0, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, 0, 0, -125, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 0, -124, 0, -124, 0, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, 0, 0, -124, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, -126, 0, -126, 0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, 0, 0, -126, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, -127, 0, -127, 0, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, 0, 0, -127, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 153
+        0, -123, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 154
+        -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78,
+        // State 155
+        0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 157
+        0, 193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 158
+        -91, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91,
+        // State 159
+        0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        0, 195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 161
+        0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        0, 197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 164
+        0, 0, 0, -13, -13, -13, 0, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, 0, 0, -13, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 165
+        -142, 0, 0, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, 0, 0, -142, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142,
+        // State 166
+        0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        -144, 0, 0, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, 0, 0, -144, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144,
+        // State 168
+        -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71,
+        // State 169
+        0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 170
+        0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, -121, 0, -121, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, -118, 0, -118, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 174
+        0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, -115, 0, -115, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, -116, 0, -116, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, -117, 0, -117, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 177
+        0, 202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 178
+        -68, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68,
+        // State 179
+        0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 180
+        0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, 0, 0, -4, -4, -4, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, 0, 0, -40, -40, -40, 0, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, 0, 0, -40, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 184
+        0, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 185
+        0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        0, 206, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 187
+        0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 188
+        -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59,
+        // State 189
+        0, 211, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 190
+        0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        0, 213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 192
+        -77, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77,
+        // State 193
+        -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74,
+        // State 194
+        -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73,
+        // State 195
+        -97, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97,
+        // State 196
+        -92, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92,
+        // State 197
+        -95, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95,
+        // State 198
+        0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 199
+        0, 215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 200
+        0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 201
+        -67, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67,
+        // State 202
+        0, 0, 0, -5, -5, -5, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 203
+        0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        -69, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69,
+        // State 205
+        -70, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70,
+        // State 206
+        0, 216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 207
+        -82, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82,
+        // State 208
+        0, 221, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 209
+        -90, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90,
+        // State 210
+        -93, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93,
+        // State 211
+        -96, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96,
+        // State 212
+        -94, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94,
+        // State 213
+        0, 224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 214
+        0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 215
+        -80, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80,
+        // State 216
+        0, 225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 217
+        -81, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81,
+        // State 218
+        0, 226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 219
+        -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86,
+        // State 220
+        -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88,
+        // State 221
+        0, 229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 222
+        -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89,
+        // State 223
+        -72, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72,
+        // State 224
+        -79, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79,
+        // State 225
+        -84, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84,
+        // State 226
+        0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 227
+        -85, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85,
+        // State 228
+        -87, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87,
+        // State 229
+        -83, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 33 + integer]
+        __ACTION[(state as usize) * 48 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
@@ -425,13 +610,13 @@ mod __parse__AIR {
         // State 32
         0,
         // State 33
-        -94,
+        0,
         // State 34
-        -8,
+        0,
         // State 35
         0,
         // State 36
-        -60,
+        0,
         // State 37
         0,
         // State 38
@@ -485,11 +670,11 @@ mod __parse__AIR {
         // State 62
         0,
         // State 63
-        0,
+        -151,
         // State 64
-        0,
+        -16,
         // State 65
-        0,
+        -98,
         // State 66
         0,
         // State 67
@@ -533,7 +718,7 @@ mod __parse__AIR {
         // State 86
         0,
         // State 87
-        -48,
+        0,
         // State 88
         0,
         // State 89
@@ -543,7 +728,7 @@ mod __parse__AIR {
         // State 91
         0,
         // State 92
-        -49,
+        0,
         // State 93
         0,
         // State 94
@@ -555,7 +740,7 @@ mod __parse__AIR {
         // State 97
         0,
         // State 98
-        -51,
+        0,
         // State 99
         0,
         // State 100
@@ -565,7 +750,7 @@ mod __parse__AIR {
         // State 102
         0,
         // State 103
-        -56,
+        0,
         // State 104
         0,
         // State 105
@@ -573,7 +758,7 @@ mod __parse__AIR {
         // State 106
         0,
         // State 107
-        -45,
+        0,
         // State 108
         0,
         // State 109
@@ -587,7 +772,7 @@ mod __parse__AIR {
         // State 113
         0,
         // State 114
-        -43,
+        0,
         // State 115
         0,
         // State 116
@@ -607,99 +792,318 @@ mod __parse__AIR {
         // State 123
         0,
         // State 124
-        -50,
+        0,
         // State 125
-        -47,
+        0,
         // State 126
-        -46,
+        0,
         // State 127
-        -57,
+        0,
         // State 128
         0,
         // State 129
-        0,
+        -75,
         // State 130
-        -42,
+        0,
         // State 131
         0,
         // State 132
         0,
         // State 133
-        -44,
+        0,
         // State 134
         0,
         // State 135
-        -53,
-        // State 136
         0,
+        // State 136
+        -76,
         // State 137
-        -55,
+        0,
         // State 138
-        -58,
+        0,
         // State 139
-        -59,
+        0,
         // State 140
         0,
         // State 141
-        -52,
+        0,
         // State 142
-        -54,
+        0,
+        // State 143
+        0,
+        // State 144
+        0,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        0,
+        // State 151
+        0,
+        // State 152
+        0,
+        // State 153
+        0,
+        // State 154
+        -78,
+        // State 155
+        0,
+        // State 156
+        0,
+        // State 157
+        0,
+        // State 158
+        -91,
+        // State 159
+        0,
+        // State 160
+        0,
+        // State 161
+        0,
+        // State 162
+        0,
+        // State 163
+        0,
+        // State 164
+        0,
+        // State 165
+        0,
+        // State 166
+        0,
+        // State 167
+        0,
+        // State 168
+        -71,
+        // State 169
+        0,
+        // State 170
+        0,
+        // State 171
+        0,
+        // State 172
+        0,
+        // State 173
+        0,
+        // State 174
+        0,
+        // State 175
+        0,
+        // State 176
+        0,
+        // State 177
+        0,
+        // State 178
+        -68,
+        // State 179
+        0,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        0,
+        // State 184
+        0,
+        // State 185
+        0,
+        // State 186
+        0,
+        // State 187
+        0,
+        // State 188
+        0,
+        // State 189
+        0,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        -77,
+        // State 193
+        -74,
+        // State 194
+        -73,
+        // State 195
+        -97,
+        // State 196
+        -92,
+        // State 197
+        -95,
+        // State 198
+        0,
+        // State 199
+        0,
+        // State 200
+        0,
+        // State 201
+        -67,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        -69,
+        // State 205
+        -70,
+        // State 206
+        0,
+        // State 207
+        -82,
+        // State 208
+        0,
+        // State 209
+        -90,
+        // State 210
+        -93,
+        // State 211
+        -96,
+        // State 212
+        -94,
+        // State 213
+        0,
+        // State 214
+        0,
+        // State 215
+        -80,
+        // State 216
+        0,
+        // State 217
+        -81,
+        // State 218
+        0,
+        // State 219
+        -86,
+        // State 220
+        -88,
+        // State 221
+        0,
+        // State 222
+        -89,
+        // State 223
+        -72,
+        // State 224
+        -79,
+        // State 225
+        -84,
+        // State 226
+        0,
+        // State 227
+        -85,
+        // State 228
+        -87,
+        // State 229
+        -83,
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 30,
-            5 => 33,
-            6 => 12,
-            7 => 93,
-            8 => match state {
-                30 => 131,
-                _ => 117,
+            2 => 51,
+            5 => match state {
+                22 => 38,
+                _ => 35,
             },
-            9 => 22,
-            10 => 113,
-            12 => 120,
-            13 => 62,
-            14 => 68,
-            15 => 128,
-            16 => match state {
-                10 => 19,
-                11 => 20,
-                25 => 31,
-                26 => 32,
-                0 => 34,
-                17 => 102,
-                18 => 104,
-                19 => 105,
-                20 => 106,
-                27 => 122,
-                28 => 123,
-                31 => 134,
-                32 => 136,
-                _ => 18,
+            7 => match state {
+                21 => 37,
+                _ => 34,
             },
-            18 => 17,
-            19 => match state {
-                1 => 40,
-                _ => 74,
+            10 => 63,
+            11 => match state {
+                36 => 169,
+                _ => 19,
             },
-            20 => 55,
+            12 => 142,
+            13 => match state {
+                51 => 202,
+                _ => 181,
+            },
+            14 => 40,
+            15 => 177,
+            17 => 184,
+            18 => 186,
+            19 => 2,
+            20 => 116,
             21 => match state {
-                2 => 56,
-                _ => 14,
+                56 => 60,
+                _ => 54,
+            },
+            23 => 123,
+            24 => 199,
+            25 => match state {
+                13 => 30,
+                15 => 32,
+                44 => 52,
+                45 => 55,
+                53 => 58,
+                54 => 59,
+                56 => 61,
+                60 => 62,
+                0 => 64,
+                28 => 157,
+                29 => 159,
+                30 => 160,
+                31 => 161,
+                32 => 162,
+                33 => 163,
+                46 => 189,
+                47 => 190,
+                48 => 191,
+                52 => 206,
+                55 => 208,
+                58 => 216,
+                59 => 218,
+                61 => 221,
+                62 => 226,
+                _ => 29,
             },
-            22 => match state {
-                29 => 129,
-                _ => 108,
+            27 => 28,
+            28 => match state {
+                3 | 36 => 92,
+                _ => 73,
             },
-            23 => 29,
-            24 => 24,
-            25 => 13,
-            26 => match state {
-                6 => 15,
-                7 => 16,
-                15 => 27,
-                16 => 28,
-                _ => 118,
+            29 => match state {
+                35 | 38 => 166,
+                _ => 139,
+            },
+            30 => 109,
+            31 => match state {
+                4 => 110,
+                _ => 24,
+            },
+            32 => match state {
+                50 => 200,
+                _ => 172,
+            },
+            34 => 50,
+            35 => 42,
+            36 => match state {
+                57 => 213,
+                _ => 43,
+            },
+            37 => 36,
+            38 => 23,
+            39 => match state {
+                2 => 16,
+                8 => 25,
+                9 => 26,
+                10 => 27,
+                14 => 31,
+                16 => 33,
+                25 => 46,
+                26 => 47,
+                27 => 48,
+                34 | 37 => 164,
+                41 | 51 => 182,
+                49 => 198,
+                _ => 137,
             },
             _ => 0,
         }
@@ -708,20 +1112,29 @@ mod __parse__AIR {
         const __TERMINAL: &[&str] = &[
             r###""(""###,
             r###"")""###,
+            r###"":""###,
             r###""[""###,
             r###""]""###,
+            r###""{""###,
+            r###""}""###,
             r###"Boolean"###,
             r###"CanonStream"###,
+            r###"CanonStreamMap"###,
+            r###"CanonStreamMapWithLambda"###,
             r###"CanonStreamWithLambda"###,
+            r###"Error"###,
+            r###"ErrorWithLambda"###,
             r###"F64"###,
             r###"I64"###,
             r###"InitPeerId"###,
             r###"LastError"###,
             r###"LastErrorWithLambda"###,
             r###"Literal"###,
+            r###"ParticleId"###,
             r###"Scalar"###,
             r###"ScalarWithLambda"###,
             r###"Stream"###,
+            r###"StreamMap"###,
             r###"TTL"###,
             r###"Timestamp"###,
             r###"ap"###,
@@ -729,7 +1142,12 @@ mod __parse__AIR {
             r###"canon"###,
             r###"fail"###,
             r###"fold"###,
+            r###"gt"###,
+            r###"gte"###,
+            r###"lt"###,
+            r###"lte"###,
             r###"match_"###,
+            r###"member"###,
             r###"mismatch"###,
             r###"never"###,
             r###"new"###,
@@ -737,6 +1155,7 @@ mod __parse__AIR {
             r###"null"###,
             r###"par"###,
             r###"seq"###,
+            r###"ttl_guard"###,
             r###"xor"###,
         ];
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
@@ -792,7 +1211,7 @@ mod __parse__AIR {
 
         #[inline]
         fn error_action(&self, state: i16) -> i16 {
-            __action(state, 33 - 1)
+            __action(state, 48 - 1)
         }
 
         #[inline]
@@ -861,36 +1280,51 @@ mod __parse__AIR {
         match *__token {
             Token::OpenRoundBracket if true => Some(0),
             Token::CloseRoundBracket if true => Some(1),
-            Token::OpenSquareBracket if true => Some(2),
-            Token::CloseSquareBracket if true => Some(3),
-            Token::Boolean(_) if true => Some(4),
-            Token::CanonStream { name: _, position: _ } if true => Some(5),
-            Token::CanonStreamWithLambda { name: _, lambda: _, position: _ } if true => Some(6),
-            Token::F64(_) if true => Some(7),
-            Token::I64(_) if true => Some(8),
-            Token::InitPeerId if true => Some(9),
-            Token::LastError if true => Some(10),
-            Token::LastErrorWithLambda(_) if true => Some(11),
-            Token::StringLiteral(_) if true => Some(12),
-            Token::Scalar { name: _, position: _ } if true => Some(13),
-            Token::ScalarWithLambda { name: _, lambda: _, position: _ } if true => Some(14),
-            Token::Stream { name: _, position: _ } if true => Some(15),
-            Token::TTL if true => Some(16),
-            Token::Timestamp if true => Some(17),
-            Token::Ap if true => Some(18),
-            Token::Call if true => Some(19),
-            Token::Canon if true => Some(20),
-            Token::Fail if true => Some(21),
-            Token::Fold if true => Some(22),
-            Token::Match if true => Some(23),
-            Token::MisMatch if true => Some(24),
-            Token::Never if true => Some(25),
-            Token::New if true => Some(26),
-            Token::Next if true => Some(27),
-            Token::Null if true => Some(28),
-            Token::Par if true => Some(29),
-            Token::Seq if true => Some(30),
-            Token::Xor if true => Some(31),
+            Token::Colon if true => Some(2),
+            Token::OpenSquareBracket if true => Some(3),
+            Token::CloseSquareBracket if true => Some(4),
+            Token::OpenCurlyBracket if true => Some(5),
+            Token::CloseCurlyBracket if true => Some(6),
+            Token::Boolean(_) if true => Some(7),
+            Token::CanonStream { name: _, position: _ } if true => Some(8),
+            Token::CanonStreamMap { name: _, position: _ } if true => Some(9),
+            Token::CanonStreamMapWithLambda { name: _, lambda: _, position: _ } if true => Some(10),
+            Token::CanonStreamWithLambda { name: _, lambda: _, position: _ } if true => Some(11),
+            Token::Error if true => Some(12),
+            Token::ErrorWithLambda(_) if true => Some(13),
+            Token::F64(_) if true => Some(14),
+            Token::I64(_) if true => Some(15),
+            Token::InitPeerId if true => Some(16),
+            Token::LastError if true => Some(17),
+            Token::LastErrorWithLambda(_) if true => Some(18),
+            Token::StringLiteral(_) if true => Some(19),
+            Token::ParticleId if true => Some(20),
+            Token::Scalar { name: _, position: _ } if true => Some(21),
+            Token::ScalarWithLambda { name: _, lambda: _, position: _ } if true => Some(22),
+            Token::Stream { name: _, position: _ } if true => Some(23),
+            Token::StreamMap { name: _, position: _ } if true => Some(24),
+            Token::TTL if true => Some(25),
+            Token::Timestamp if true => Some(26),
+            Token::Ap if true => Some(27),
+            Token::Call if true => Some(28),
+            Token::Canon if true => Some(29),
+            Token::Fail if true => Some(30),
+            Token::Fold if true => Some(31),
+            Token::Gt if true => Some(32),
+            Token::Gte if true => Some(33),
+            Token::Lt if true => Some(34),
+            Token::Lte if true => Some(35),
+            Token::Match if true => Some(36),
+            Token::Member if true => Some(37),
+            Token::MisMatch if true => Some(38),
+            Token::Never if true => Some(39),
+            Token::New if true => Some(40),
+            Token::Next if true => Some(41),
+            Token::Null if true => Some(42),
+            Token::Par if true => Some(43),
+            Token::Seq if true => Some(44),
+            Token::TtlGuard if true => Some(45),
+            Token::Xor if true => Some(46),
             _ => None,
         }
     }
@@ -905,32 +1339,32 @@ mod __parse__AIR {
     ) -> __Symbol<'input>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 9 | 10 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 => __Symbol::Variant0(__token),
-            4 => match __token {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 12 | 16 | 17 | 20 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 => __Symbol::Variant0(__token),
+            7 => match __token {
                 Token::Boolean(__tok0) if true => __Symbol::Variant1(__tok0),
                 _ => unreachable!(),
             },
-            5 | 13 | 15 => match __token {
-                Token::CanonStream { name: __tok0, position: __tok1 } | Token::Scalar { name: __tok0, position: __tok1 } | Token::Stream { name: __tok0, position: __tok1 } if true => __Symbol::Variant2((__tok0, __tok1)),
+            8 | 9 | 21 | 23 | 24 => match __token {
+                Token::CanonStream { name: __tok0, position: __tok1 } | Token::CanonStreamMap { name: __tok0, position: __tok1 } | Token::Scalar { name: __tok0, position: __tok1 } | Token::Stream { name: __tok0, position: __tok1 } | Token::StreamMap { name: __tok0, position: __tok1 } if true => __Symbol::Variant2((__tok0, __tok1)),
                 _ => unreachable!(),
             },
-            6 | 14 => match __token {
-                Token::CanonStreamWithLambda { name: __tok0, lambda: __tok1, position: __tok2 } | Token::ScalarWithLambda { name: __tok0, lambda: __tok1, position: __tok2 } if true => __Symbol::Variant3((__tok0, __tok1, __tok2)),
+            10 | 11 | 22 => match __token {
+                Token::CanonStreamMapWithLambda { name: __tok0, lambda: __tok1, position: __tok2 } | Token::CanonStreamWithLambda { name: __tok0, lambda: __tok1, position: __tok2 } | Token::ScalarWithLambda { name: __tok0, lambda: __tok1, position: __tok2 } if true => __Symbol::Variant3((__tok0, __tok1, __tok2)),
                 _ => unreachable!(),
             },
-            7 => match __token {
-                Token::F64(__tok0) if true => __Symbol::Variant4(__tok0),
+            13 | 18 => match __token {
+                Token::ErrorWithLambda(__tok0) | Token::LastErrorWithLambda(__tok0) if true => __Symbol::Variant4(__tok0),
                 _ => unreachable!(),
             },
-            8 => match __token {
-                Token::I64(__tok0) if true => __Symbol::Variant5(__tok0),
+            14 => match __token {
+                Token::F64(__tok0) if true => __Symbol::Variant5(__tok0),
                 _ => unreachable!(),
             },
-            11 => match __token {
-                Token::LastErrorWithLambda(__tok0) if true => __Symbol::Variant6(__tok0),
+            15 => match __token {
+                Token::I64(__tok0) if true => __Symbol::Variant6(__tok0),
                 _ => unreachable!(),
             },
-            12 => match __token {
+            19 => match __token {
                 Token::StringLiteral(__tok0) if true => __Symbol::Variant7(__tok0),
                 _ => unreachable!(),
             },
@@ -982,7 +1416,7 @@ mod __parse__AIR {
             }
             5 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 1,
                     nonterminal_produced: 3,
                 }
             }
@@ -995,19 +1429,19 @@ mod __parse__AIR {
             7 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 5,
+                    nonterminal_produced: 4,
                 }
             }
             8 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    nonterminal_produced: 5,
                 }
             }
             9 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    states_to_pop: 2,
+                    nonterminal_produced: 5,
                 }
             }
             10 => {
@@ -1019,907 +1453,1429 @@ mod __parse__AIR {
             11 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    nonterminal_produced: 7,
                 }
             }
             12 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    states_to_pop: 2,
+                    nonterminal_produced: 7,
                 }
             }
             13 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    states_to_pop: 0,
+                    nonterminal_produced: 8,
                 }
             }
             14 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    states_to_pop: 0,
+                    nonterminal_produced: 9,
                 }
             }
             15 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    nonterminal_produced: 10,
                 }
             }
             16 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 6,
+                    states_to_pop: 1,
+                    nonterminal_produced: 11,
                 }
             }
             17 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    nonterminal_produced: 11,
                 }
             }
             18 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    nonterminal_produced: 11,
                 }
             }
             19 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    nonterminal_produced: 11,
                 }
             }
             20 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    nonterminal_produced: 11,
                 }
             }
             21 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 7,
+                    nonterminal_produced: 11,
                 }
             }
             22 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 7,
+                    nonterminal_produced: 11,
                 }
             }
             23 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 8,
+                    nonterminal_produced: 11,
                 }
             }
             24 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 9,
+                    states_to_pop: 1,
+                    nonterminal_produced: 11,
                 }
             }
             25 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 9,
+                    states_to_pop: 1,
+                    nonterminal_produced: 11,
                 }
             }
             26 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 10,
+                    nonterminal_produced: 11,
                 }
             }
             27 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 10,
+                    states_to_pop: 2,
+                    nonterminal_produced: 11,
                 }
             }
             28 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 11,
                 }
             }
             29 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 11,
                 }
             }
             30 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 12,
+                    states_to_pop: 3,
+                    nonterminal_produced: 11,
                 }
             }
             31 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    nonterminal_produced: 11,
                 }
             }
             32 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    nonterminal_produced: 11,
                 }
             }
             33 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 13,
+                    states_to_pop: 1,
+                    nonterminal_produced: 11,
                 }
             }
             34 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    nonterminal_produced: 11,
                 }
             }
             35 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    nonterminal_produced: 11,
                 }
             }
             36 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 11,
                 }
             }
             37 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 12,
                 }
             }
             38 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 12,
                 }
             }
             39 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 14,
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
                 }
             }
             40 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
                 }
             }
             41 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    states_to_pop: 3,
+                    nonterminal_produced: 14,
                 }
             }
             42 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 15,
                 }
             }
             43 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 15,
                 }
             }
             44 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
+                    states_to_pop: 1,
                     nonterminal_produced: 16,
                 }
             }
             45 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
+                    states_to_pop: 0,
                     nonterminal_produced: 16,
                 }
             }
             46 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 17,
                 }
             }
             47 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
                 }
             }
             48 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
                 }
             }
             49 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
                 }
             }
             50 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
                 }
             }
             51 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
                 }
             }
             52 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
                 }
             }
             53 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
                 }
             }
             54 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    states_to_pop: 2,
+                    nonterminal_produced: 20,
                 }
             }
             55 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
                 }
             }
             56 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
                 }
             }
             57 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
                 }
             }
             58 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
                 }
             }
             59 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 22,
                 }
             }
             60 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 0,
+                    nonterminal_produced: 22,
                 }
             }
             61 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             62 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 23,
                 }
             }
             63 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 23,
                 }
             }
             64 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 2,
+                    nonterminal_produced: 23,
                 }
             }
             65 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    nonterminal_produced: 24,
                 }
             }
             66 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    states_to_pop: 6,
+                    nonterminal_produced: 25,
                 }
             }
             67 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    states_to_pop: 5,
+                    nonterminal_produced: 25,
                 }
             }
             68 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    states_to_pop: 6,
+                    nonterminal_produced: 25,
                 }
             }
             69 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    states_to_pop: 6,
+                    nonterminal_produced: 25,
                 }
             }
             70 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    states_to_pop: 5,
+                    nonterminal_produced: 25,
                 }
             }
             71 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    states_to_pop: 8,
+                    nonterminal_produced: 25,
                 }
             }
             72 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    states_to_pop: 5,
+                    nonterminal_produced: 25,
                 }
             }
             73 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    states_to_pop: 5,
+                    nonterminal_produced: 25,
                 }
             }
             74 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    states_to_pop: 3,
+                    nonterminal_produced: 25,
                 }
             }
             75 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    states_to_pop: 3,
+                    nonterminal_produced: 25,
                 }
             }
             76 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    states_to_pop: 5,
+                    nonterminal_produced: 25,
                 }
             }
             77 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    states_to_pop: 4,
+                    nonterminal_produced: 25,
                 }
             }
             78 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    states_to_pop: 8,
+                    nonterminal_produced: 25,
                 }
             }
             79 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
+                    states_to_pop: 7,
                     nonterminal_produced: 25,
                 }
             }
             80 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 7,
+                    nonterminal_produced: 25,
                 }
             }
             81 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 6,
+                    nonterminal_produced: 25,
                 }
             }
             82 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 9,
+                    nonterminal_produced: 25,
                 }
             }
             83 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 8,
+                    nonterminal_produced: 25,
                 }
             }
             84 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 8,
+                    nonterminal_produced: 25,
                 }
             }
             85 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 7,
+                    nonterminal_produced: 25,
                 }
             }
             86 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 8,
+                    nonterminal_produced: 25,
                 }
             }
             87 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 7,
+                    nonterminal_produced: 25,
                 }
             }
             88 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 26,
+                    states_to_pop: 7,
+                    nonterminal_produced: 25,
                 }
             }
             89 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 6,
+                    nonterminal_produced: 25,
                 }
             }
             90 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 4,
+                    nonterminal_produced: 25,
                 }
             }
             91 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 5,
+                    nonterminal_produced: 25,
                 }
             }
             92 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 6,
+                    nonterminal_produced: 25,
                 }
             }
-            93 => __state_machine::SimulatedReduce::Accept,
-            _ => panic!("invalid reduction index {}", __reduce_index)
-        }
-    }
-    pub struct AIRParser {
-        _priv: (),
-    }
-
-    impl AIRParser {
-        pub fn new() -> AIRParser {
-            AIRParser {
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            'err,
-            'input,
-            'v,
-            __TOKEN: __ToTriple<'err, 'input, 'v, >,
-            __TOKENS: IntoIterator<Item=__TOKEN>,
-        >(
-            &self,
-            input: &'input str,
-            errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
-            validator: &'v mut VariableValidator<'input>,
-            __tokens0: __TOKENS,
-        ) -> Result<Box<Instruction<'input>>, __lalrpop_util::ParseError<AirPos, Token<'input>, ParserError>>
-        {
-            let __tokens = __tokens0.into_iter();
-            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    input,
-                    errors,
-                    validator,
-                    __phantom: core::marker::PhantomData::<(&(), &(), &())>,
-                },
-                __tokens,
-            )
-        }
-    }
-    fn __accepts<
-        'err,
-        'input,
-        'v,
-    >(
-        input: &'input str,
-        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
-        validator: &'v mut VariableValidator<'input>,
-        __error_state: i16,
-        __states: & [i16],
-        __opt_integer: Option<usize>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
-    ) -> bool
-    {
-        let mut __states = __states.to_vec();
-        __states.push(__error_state);
-        loop {
-            let mut __states_len = __states.len();
-            let __top = __states[__states_len - 1];
-            let __action = match __opt_integer {
-                None => __EOF_ACTION[__top as usize],
-                Some(__integer) => __action(__top, __integer),
-            };
-            if __action == 0 { return false; }
-            if __action > 0 { return true; }
-            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &(), &())>) {
+            93 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop, nonterminal_produced
-                } => (states_to_pop, nonterminal_produced),
-                __state_machine::SimulatedReduce::Accept => return true,
-            };
-            __states_len -= __to_pop;
-            __states.truncate(__states_len);
-            let __top = __states[__states_len - 1];
-            let __next_state = __goto(__top, __nt);
-            __states.push(__next_state);
-        }
-    }
-    pub(crate) fn __reduce<
-        'err,
-        'input,
-        'v,
-    >(
-        input: &'input str,
-        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
-        validator: &'v mut VariableValidator<'input>,
-        __action: i16,
-        __lookahead_start: Option<&AirPos>,
-        __states: &mut alloc::vec::Vec<i16>,
-        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
-    ) -> Option<Result<Box<Instruction<'input>>,__lalrpop_util::ParseError<AirPos, Token<'input>, ParserError>>>
-    {
-        let (__pop_states, __nonterminal) = match __action {
-            0 => {
-                __reduce0(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                    states_to_pop: 6,
+                    nonterminal_produced: 25,
+                }
             }
-            1 => {
-                __reduce1(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            94 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 25,
+                }
             }
-            2 => {
-                __reduce2(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            95 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 25,
+                }
             }
-            3 => {
-                __reduce3(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            96 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 25,
+                }
             }
-            4 => {
-                __reduce4(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            97 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
+                }
             }
-            5 => {
-                __reduce5(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            98 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
+                }
             }
-            6 => {
-                __reduce6(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            99 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 26,
+                }
             }
-            7 => {
-                __reduce7(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
-            }
-            8 => {
-                __reduce8(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
-            }
-            9 => {
-                __reduce9(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            100 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
             }
-            10 => {
-                __reduce10(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            101 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
             }
-            11 => {
-                __reduce11(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            102 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
             }
-            12 => {
-                __reduce12(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            103 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
             }
-            13 => {
-                __reduce13(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            104 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
             }
-            14 => {
-                __reduce14(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            105 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
             }
-            15 => {
-                __reduce15(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            106 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
             }
-            16 => {
-                __reduce16(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            107 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
+                }
             }
-            17 => {
-                __reduce17(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            108 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 30,
+                }
             }
-            18 => {
-                __reduce18(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            109 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
             }
-            19 => {
-                __reduce19(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            110 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
             }
-            20 => {
-                __reduce20(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            111 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
             }
-            21 => {
-                __reduce21(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            112 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
             }
-            22 => {
-                __reduce22(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            113 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
             }
-            23 => {
-                __reduce23(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            114 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
             }
-            24 => {
-                __reduce24(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            115 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
             }
-            25 => {
-                __reduce25(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            116 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
             }
-            26 => {
-                __reduce26(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            117 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
             }
-            27 => {
-                __reduce27(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            118 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 33,
+                }
             }
-            28 => {
-                __reduce28(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 33,
+                }
             }
-            29 => {
-                __reduce29(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
+                }
             }
-            30 => {
-                __reduce30(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
             }
-            31 => {
-                __reduce31(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            122 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 36,
+                }
             }
-            32 => {
-                __reduce32(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            123 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
+                }
             }
-            33 => {
-                __reduce33(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            124 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
+                }
             }
-            34 => {
-                __reduce34(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            125 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
+                }
             }
-            35 => {
-                __reduce35(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            126 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
+                }
             }
-            36 => {
-                __reduce36(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            127 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
+                }
             }
-            37 => {
-                __reduce37(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            128 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 38,
+                }
             }
-            38 => {
-                __reduce38(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            129 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            39 => {
-                __reduce39(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            130 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            40 => {
-                __reduce40(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            131 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            41 => {
-                __reduce41(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            132 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            42 => {
-                __reduce42(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            133 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            43 => {
-                __reduce43(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            134 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            44 => {
-                __reduce44(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            135 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            45 => {
-                __reduce45(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            136 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            46 => {
-                __reduce46(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            137 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            47 => {
-                __reduce47(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            138 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            48 => {
-                __reduce48(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            139 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            49 => {
-                __reduce49(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            140 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 39,
+                }
             }
-            50 => {
-                __reduce50(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            141 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
+                }
             }
-            51 => {
-                __reduce51(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            142 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 39,
+                }
             }
-            52 => {
-                __reduce52(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            143 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
+                }
             }
-            53 => {
-                __reduce53(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            144 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            54 => {
-                __reduce54(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            145 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            55 => {
-                __reduce55(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            146 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            56 => {
-                __reduce56(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            147 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            57 => {
-                __reduce57(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            148 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            58 => {
-                __reduce58(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            149 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            59 => {
-                __reduce59(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            150 => __state_machine::SimulatedReduce::Accept,
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
+    }
+    pub struct AIRParser {
+        _priv: (),
+    }
+
+    impl AIRParser {
+        pub fn new() -> AIRParser {
+            AIRParser {
+                _priv: (),
             }
-            60 => {
-                __reduce60(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'err,
+            'input,
+            'v,
+            __TOKEN: __ToTriple<'err, 'input, 'v, >,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            input: &'input str,
+            errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+            validator: &'v mut VariableValidator<'input>,
+            __tokens0: __TOKENS,
+        ) -> Result<Box<Instruction<'input>>, __lalrpop_util::ParseError<AirPos, Token<'input>, ParserError>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    input,
+                    errors,
+                    validator,
+                    __phantom: core::marker::PhantomData::<(&(), &(), &())>,
+                },
+                __tokens,
+            )
+        }
+    }
+    fn __accepts<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __error_state: i16,
+        __states: & [i16],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &(), &())>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
+        }
+    }
+    pub(crate) fn __reduce<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __action: i16,
+        __lookahead_start: Option<&AirPos>,
+        __states: &mut alloc::vec::Vec<i16>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> Option<Result<Box<Instruction<'input>>,__lalrpop_util::ParseError<AirPos, Token<'input>, ParserError>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            61 => {
-                __reduce61(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            1 => {
+                __reduce1(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            62 => {
-                __reduce62(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            2 => {
+                __reduce2(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            63 => {
-                __reduce63(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            3 => {
+                __reduce3(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            64 => {
-                __reduce64(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            4 => {
+                __reduce4(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            65 => {
-                __reduce65(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            5 => {
+                __reduce5(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            66 => {
-                __reduce66(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            6 => {
+                __reduce6(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            67 => {
-                __reduce67(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            7 => {
+                __reduce7(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            68 => {
-                __reduce68(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            8 => {
+                __reduce8(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            69 => {
-                __reduce69(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            9 => {
+                __reduce9(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            70 => {
-                __reduce70(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            10 => {
+                __reduce10(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            71 => {
-                __reduce71(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            11 => {
+                __reduce11(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            72 => {
-                __reduce72(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            12 => {
+                __reduce12(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            73 => {
-                __reduce73(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            13 => {
+                __reduce13(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            74 => {
-                __reduce74(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            14 => {
+                __reduce14(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            75 => {
-                __reduce75(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            15 => {
+                __reduce15(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            76 => {
-                __reduce76(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            16 => {
+                __reduce16(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            77 => {
-                __reduce77(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            17 => {
+                __reduce17(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            78 => {
-                __reduce78(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            18 => {
+                __reduce18(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            79 => {
-                __reduce79(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            19 => {
+                __reduce19(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            80 => {
-                __reduce80(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            20 => {
+                __reduce20(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            81 => {
-                __reduce81(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            21 => {
+                __reduce21(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            82 => {
-                __reduce82(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            22 => {
+                __reduce22(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            83 => {
-                __reduce83(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            23 => {
+                __reduce23(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            84 => {
-                __reduce84(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            24 => {
+                __reduce24(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            85 => {
-                __reduce85(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            25 => {
+                __reduce25(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            86 => {
-                __reduce86(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            26 => {
+                __reduce26(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            87 => {
-                __reduce87(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            27 => {
+                __reduce27(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            88 => {
-                __reduce88(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            28 => {
+                __reduce28(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            89 => {
-                __reduce89(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            29 => {
+                __reduce29(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            90 => {
-                __reduce90(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            30 => {
+                __reduce30(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            91 => {
-                __reduce91(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            31 => {
+                __reduce31(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            92 => {
-                __reduce92(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            32 => {
+                __reduce32(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            93 => {
-                // __AIR = AIR => ActionFn(0);
-                let __sym0 = __pop_Variant12(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, errors, validator, __sym0);
-                return Some(Ok(__nt));
+            33 => {
+                __reduce33(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __states_len = __states.len();
-        __states.truncate(__states_len - __pop_states);
-        let __state = *__states.last().unwrap();
-        let __next_state = __goto(__state, __nonterminal);
-        __states.push(__next_state);
-        None
-    }
-    #[inline(never)]
-    fn __symbol_type_mismatch() -> ! {
-        panic!("symbol type mismatch")
-    }
-    fn __pop_Variant2<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
-    ) -> (AirPos, (&'input str, AirPos), AirPos)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+            34 => {
+                __reduce34(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            35 => {
+                __reduce35(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            36 => {
+                __reduce36(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            37 => {
+                __reduce37(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            38 => {
+                __reduce38(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            39 => {
+                __reduce39(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            40 => {
+                __reduce40(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            41 => {
+                __reduce41(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            42 => {
+                __reduce42(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            43 => {
+                __reduce43(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            44 => {
+                __reduce44(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            45 => {
+                __reduce45(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            46 => {
+                __reduce46(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            47 => {
+                __reduce47(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            48 => {
+                __reduce48(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            49 => {
+                __reduce49(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            50 => {
+                __reduce50(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            51 => {
+                __reduce51(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            52 => {
+                __reduce52(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            53 => {
+                __reduce53(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            54 => {
+                __reduce54(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            55 => {
+                __reduce55(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            56 => {
+                __reduce56(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            57 => {
+                __reduce57(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            58 => {
+                // FoldIterationsLimit = I64 => ActionFn(166);
+                let __sym0 = __pop_Variant6(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action166::<>(input, errors, validator, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+                (1, 21)
+            }
+            59 => {
+                __reduce59(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            60 => {
+                __reduce60(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            61 => {
+                __reduce61(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            62 => {
+                __reduce62(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            63 => {
+                __reduce63(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            64 => {
+                __reduce64(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            65 => {
+                __reduce65(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            66 => {
+                __reduce66(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            67 => {
+                __reduce67(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            68 => {
+                __reduce68(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            69 => {
+                __reduce69(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            70 => {
+                __reduce70(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            71 => {
+                __reduce71(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            72 => {
+                __reduce72(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            73 => {
+                __reduce73(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            74 => {
+                __reduce74(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            75 => {
+                __reduce75(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            76 => {
+                __reduce76(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            77 => {
+                __reduce77(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            78 => {
+                __reduce78(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            79 => {
+                __reduce79(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            80 => {
+                __reduce80(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            81 => {
+                __reduce81(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            82 => {
+                __reduce82(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            83 => {
+                __reduce83(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            84 => {
+                __reduce84(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            85 => {
+                __reduce85(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            86 => {
+                __reduce86(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            87 => {
+                __reduce87(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            88 => {
+                __reduce88(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            89 => {
+                __reduce89(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            90 => {
+                __reduce90(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            91 => {
+                __reduce91(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            92 => {
+                __reduce92(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            93 => {
+                __reduce93(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            94 => {
+                __reduce94(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            95 => {
+                __reduce95(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            96 => {
+                __reduce96(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            97 => {
+                __reduce97(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            98 => {
+                __reduce98(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            99 => {
+                __reduce99(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            100 => {
+                __reduce100(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            101 => {
+                __reduce101(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            102 => {
+                __reduce102(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            103 => {
+                __reduce103(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            104 => {
+                __reduce104(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            105 => {
+                __reduce105(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            106 => {
+                __reduce106(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            107 => {
+                __reduce107(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            108 => {
+                __reduce108(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            109 => {
+                __reduce109(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            110 => {
+                __reduce110(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            111 => {
+                __reduce111(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            112 => {
+                __reduce112(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            113 => {
+                __reduce113(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            114 => {
+                __reduce114(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            115 => {
+                __reduce115(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            116 => {
+                __reduce116(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            117 => {
+                __reduce117(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            118 => {
+                __reduce118(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            119 => {
+                __reduce119(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            120 => {
+                __reduce120(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            121 => {
+                __reduce121(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            122 => {
+                __reduce122(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            123 => {
+                __reduce123(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            124 => {
+                __reduce124(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            125 => {
+                __reduce125(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            126 => {
+                __reduce126(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            127 => {
+                __reduce127(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            128 => {
+                __reduce128(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            129 => {
+                __reduce129(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            130 => {
+                __reduce130(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            131 => {
+                __reduce131(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            132 => {
+                __reduce132(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            133 => {
+                __reduce133(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            134 => {
+                __reduce134(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            135 => {
+                __reduce135(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            136 => {
+                __reduce136(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            137 => {
+                __reduce137(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            138 => {
+                __reduce138(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            139 => {
+                __reduce139(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            140 => {
+                __reduce140(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            141 => {
+                __reduce141(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            142 => {
+                __reduce142(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            143 => {
+                __reduce143(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            144 => {
+                __reduce144(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            145 => {
+                __reduce145(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            146 => {
+                __reduce146(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            147 => {
+                __reduce147(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            148 => {
+                __reduce148(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            149 => {
+                __reduce149(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            150 => {
+                // __AIR = AIR => ActionFn(0);
+                let __sym0 = __pop_Variant14(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, errors, validator, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        let __state = *__states.last().unwrap();
+        let __next_state = __goto(__state, __nonterminal);
+        __states.push(__next_state);
+        None
     }
-    fn __pop_Variant3<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, (&'input str, AirPos), AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos)
      {
         match __symbols.pop() {
@@ -1927,91 +2883,113 @@ mod __parse__AIR {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant11<
+    fn __pop_Variant13<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, AirPos, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant15<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, ApArgument<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
+    fn __pop_Variant16<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, ApResult<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant14<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, Box<Instruction<'input>>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
+    fn __pop_Variant18<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, CallOutputValue<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
+    fn __pop_Variant20<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, CanonStream<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant19<
+    fn __pop_Variant21<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, CanonStreamMap<'input>, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant22<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, ComparisonOperator, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant23<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, Fail<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant20<
+    fn __pop_Variant26<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, FoldScalarIterable<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2026,69 +3004,102 @@ mod __parse__AIR {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant4<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, LambdaAST<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant23<
+    fn __pop_Variant29<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, NewArgument<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant24<
+    fn __pop_Variant30<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, Number, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant25<
+    fn __pop_Variant11<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, ObjectEntry<'input>, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant31<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, ResolvableToPeerIdVariable<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant21<
+    fn __pop_Variant27<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, ResolvableToStringVariable<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant26<
+    fn __pop_Variant33<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, Stream<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant34<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, StreamMap<'input>, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant35<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, StreamMapKeyClause<'input>, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2103,25 +3114,25 @@ mod __parse__AIR {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant27<
+    fn __pop_Variant36<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, Triplet<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
+    fn __pop_Variant17<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, Vec<ImmutableValue<'input>>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2147,6 +3158,17 @@ mod __parse__AIR {
             _ => __symbol_type_mismatch()
         }
     }
+    fn __pop_Variant12<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, alloc::vec::Vec<ObjectEntry<'input>>, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
     fn __pop_Variant1<
       'input,
     >(
@@ -2158,36 +3180,47 @@ mod __parse__AIR {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
+    fn __pop_Variant32<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant28<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
+    fn __pop_Variant19<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
+    fn __pop_Variant25<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
-    ) -> (AirPos, f64, AirPos)
+    ) -> (AirPos, core::option::Option<u32>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2195,13 +3228,35 @@ mod __parse__AIR {
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
-    ) -> (AirPos, i64, AirPos)
+    ) -> (AirPos, f64, AirPos)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, i64, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant24<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, u32, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
     fn __pop_Variant7<
       'input,
     >(
@@ -2226,11 +3281,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>) = Arg => ActionFn(81);
+        // (<Arg>) = Arg => ActionFn(122);
         let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action81::<>(input, errors, validator, __sym0);
+        let __nt = super::__action122::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 0)
     }
@@ -2247,10 +3302,10 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)* =  => ActionFn(79);
+        // (<Arg>)* =  => ActionFn(120);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action79::<>(input, errors, validator, &__start, &__end);
+        let __nt = super::__action120::<>(input, errors, validator, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (0, 1)
     }
@@ -2267,11 +3322,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)* = (<Arg>)+ => ActionFn(80);
+        // (<Arg>)* = (<Arg>)+ => ActionFn(121);
         let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action80::<>(input, errors, validator, __sym0);
+        let __nt = super::__action121::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (1, 1)
     }
@@ -2288,11 +3343,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)+ = Arg => ActionFn(90);
+        // (<Arg>)+ = Arg => ActionFn(137);
         let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action90::<>(input, errors, validator, __sym0);
+        let __nt = super::__action137::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (1, 2)
     }
@@ -2309,13 +3364,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)+ = (<Arg>)+, Arg => ActionFn(91);
+        // (<Arg>)+ = (<Arg>)+, Arg => ActionFn(138);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant9(__symbols);
         let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action91::<>(input, errors, validator, __sym0, __sym1);
+        let __nt = super::__action138::<>(input, errors, validator, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (2, 2)
     }
@@ -2332,12 +3387,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(87);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action87::<>(input, errors, validator, &__start, &__end);
+        // (<ObjectEntry>) = ObjectEntry => ActionFn(116);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action116::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (0, 3)
+        (1, 3)
     }
     pub(crate) fn __reduce6<
         'err,
@@ -2352,11 +3408,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(84);
+        // (<ObjectEntry>)* =  => ActionFn(114);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action84::<>(input, errors, validator, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        let __nt = super::__action114::<>(input, errors, validator, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (0, 4)
     }
     pub(crate) fn __reduce7<
@@ -2372,13 +3428,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // AIR = Instr => ActionFn(1);
+        // (<ObjectEntry>)* = (<ObjectEntry>)+ => ActionFn(115);
         let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action1::<>(input, errors, validator, __sym0);
+        let __nt = super::__action115::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 5)
+        (1, 4)
     }
     pub(crate) fn __reduce8<
         'err,
@@ -2393,13 +3449,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = InitPeerId => ActionFn(64);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<ObjectEntry>)+ = ObjectEntry => ActionFn(141);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action64::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        let __nt = super::__action141::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 5)
     }
     pub(crate) fn __reduce9<
         'err,
@@ -2414,13 +3470,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = LastError => ActionFn(65);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<ObjectEntry>)+ = (<ObjectEntry>)+, ObjectEntry => ActionFn(142);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action65::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action142::<>(input, errors, validator, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 5)
     }
     pub(crate) fn __reduce10<
         'err,
@@ -2435,12 +3493,12 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = LastErrorWithLambda => ActionFn(66);
-        let __sym0 = __pop_Variant6(__symbols);
+        // (<Value>) = Value => ActionFn(119);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action66::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        let __nt = super::__action119::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 6)
     }
     pub(crate) fn __reduce11<
@@ -2456,13 +3514,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Timestamp => ActionFn(67);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<Value>)+ = Value => ActionFn(147);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action67::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        let __nt = super::__action147::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 7)
     }
     pub(crate) fn __reduce12<
         'err,
@@ -2477,13 +3535,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = TTL => ActionFn(68);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<Value>)+ = (<Value>)+, Value => ActionFn(148);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action68::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action148::<>(input, errors, validator, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 7)
     }
     pub(crate) fn __reduce13<
         'err,
@@ -2498,13 +3558,12 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Literal => ActionFn(69);
-        let __sym0 = __pop_Variant7(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action69::<>(input, errors, validator, __sym0);
+        // @L =  => ActionFn(132);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action132::<>(input, errors, validator, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        (0, 8)
     }
     pub(crate) fn __reduce14<
         'err,
@@ -2519,13 +3578,12 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Number => ActionFn(70);
-        let __sym0 = __pop_Variant24(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action70::<>(input, errors, validator, __sym0);
+        // @R =  => ActionFn(129);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action129::<>(input, errors, validator, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        (0, 9)
     }
     pub(crate) fn __reduce15<
         'err,
@@ -2540,13 +3598,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Boolean => ActionFn(71);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AIR = Instr => ActionFn(1);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action71::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        let __nt = super::__action1::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 10)
     }
     pub(crate) fn __reduce16<
         'err,
@@ -2561,15 +3619,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = "[", "]" => ActionFn(72);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
+        // ApArgument = InitPeerId => ActionFn(90);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action72::<>(input, errors, validator, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 6)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action90::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce17<
         'err,
@@ -2584,13 +3640,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Scalar => ActionFn(73);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ApArgument = LastError => ActionFn(91);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action73::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        let __nt = super::__action91::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce18<
         'err,
@@ -2605,13 +3661,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = ScalarWithLambda => ActionFn(74);
-        let __sym0 = __pop_Variant3(__symbols);
+        // ApArgument = LastErrorWithLambda => ActionFn(92);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action74::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        let __nt = super::__action92::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce19<
         'err,
@@ -2626,13 +3682,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = CanonStream => ActionFn(75);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ApArgument = Error => ActionFn(93);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action75::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        let __nt = super::__action93::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce20<
         'err,
@@ -2647,13 +3703,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = CanonStreamWithLambda => ActionFn(76);
-        let __sym0 = __pop_Variant3(__symbols);
+        // ApArgument = ErrorWithLambda => ActionFn(94);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action76::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 6)
+        let __nt = super::__action94::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce21<
         'err,
@@ -2668,13 +3724,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApResult = Scalar => ActionFn(20);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ApArgument = Timestamp => ActionFn(95);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 7)
+        let __nt = super::__action95::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce22<
         'err,
@@ -2689,13 +3745,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApResult = Stream => ActionFn(21);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ApArgument = TTL => ActionFn(96);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 7)
+        let __nt = super::__action96::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce23<
         'err,
@@ -2710,13 +3766,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Arg = Value => ActionFn(50);
-        let __sym0 = __pop_Variant9(__symbols);
+        // ApArgument = ParticleId => ActionFn(97);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 8)
+        let __nt = super::__action97::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce24<
         'err,
@@ -2731,15 +3787,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Args = "[", "]" => ActionFn(92);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ApArgument = Literal => ActionFn(98);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action92::<>(input, errors, validator, __sym0, __sym1);
+        let __end = __sym0.2.clone();
+        let __nt = super::__action98::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (2, 9)
+        (1, 11)
     }
     pub(crate) fn __reduce25<
         'err,
@@ -2754,16 +3808,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Args = "[", (<Arg>)+, "]" => ActionFn(93);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ApArgument = Number => ActionFn(99);
+        let __sym0 = __pop_Variant30(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action93::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        let __end = __sym0.2.clone();
+        let __nt = super::__action99::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (3, 9)
+        (1, 11)
     }
     pub(crate) fn __reduce26<
         'err,
@@ -2778,13 +3829,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CallOutput = Scalar => ActionFn(22);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ApArgument = Boolean => ActionFn(100);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action22::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 10)
+        let __nt = super::__action100::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce27<
         'err,
@@ -2799,13 +3850,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CallOutput = Stream => ActionFn(23);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ApArgument = "[", "]" => ActionFn(101);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action23::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 10)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action101::<>(input, errors, validator, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 11)
     }
     pub(crate) fn __reduce28<
         'err,
@@ -2820,13 +3873,16 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CallOutput? = CallOutput => ActionFn(85);
-        let __sym0 = __pop_Variant16(__symbols);
+        // ApArgument = "[", (<Value>)+, "]" => ActionFn(102);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action85::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 11)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action102::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 11)
     }
     pub(crate) fn __reduce29<
         'err,
@@ -2841,12 +3897,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CallOutput? =  => ActionFn(86);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action86::<>(input, errors, validator, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (0, 11)
+        // ApArgument = "{", "}" => ActionFn(143);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action143::<>(input, errors, validator, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 11)
     }
     pub(crate) fn __reduce30<
         'err,
@@ -2861,13 +3920,16 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CanonStreamArgument = CanonStream => ActionFn(78);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ApArgument = "{", (<ObjectEntry>)+, "}" => ActionFn(144);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action78::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 12)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action144::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 11)
     }
     pub(crate) fn __reduce31<
         'err,
@@ -2882,13 +3944,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = Scalar => ActionFn(24);
+        // ApArgument = Scalar => ActionFn(104);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 13)
+        let __nt = super::__action104::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce32<
         'err,
@@ -2903,13 +3965,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = ScalarWithLambda => ActionFn(25);
+        // ApArgument = ScalarWithLambda => ActionFn(105);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 13)
+        let __nt = super::__action105::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce33<
         'err,
@@ -2924,15 +3986,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = I64, Literal => ActionFn(26);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // ApArgument = CanonStream => ActionFn(106);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action26::<>(input, errors, validator, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 13)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action106::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce34<
         'err,
@@ -2947,13 +4007,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = CanonStreamWithLambda => ActionFn(27);
+        // ApArgument = CanonStreamWithLambda => ActionFn(107);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action27::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 13)
+        let __nt = super::__action107::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce35<
         'err,
@@ -2968,13 +4028,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = LastError => ActionFn(104);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ApArgument = CanonStreamMap => ActionFn(108);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action104::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 13)
+        let __nt = super::__action108::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce36<
         'err,
@@ -2989,13 +4049,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = Scalar => ActionFn(29);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ApArgument = CanonStreamMapWithLambda => ActionFn(109);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action29::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 14)
+        let __nt = super::__action109::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce37<
         'err,
@@ -3010,13 +4070,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = ScalarWithLambda => ActionFn(30);
-        let __sym0 = __pop_Variant3(__symbols);
+        // ApResult = Scalar => ActionFn(29);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action30::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 14)
+        let __nt = super::__action29::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 12)
     }
     pub(crate) fn __reduce38<
         'err,
@@ -3031,13 +4091,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = CanonStream => ActionFn(31);
+        // ApResult = Stream => ActionFn(30);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action31::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 14)
+        let __nt = super::__action30::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 12)
     }
     pub(crate) fn __reduce39<
         'err,
@@ -3052,15 +4112,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = "[", "]" => ActionFn(32);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Arg = Value => ActionFn(68);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action32::<>(input, errors, validator, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (2, 14)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action68::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 13)
     }
     pub(crate) fn __reduce40<
         'err,
@@ -3075,13 +4133,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Function = ResolvableToStringVariable => ActionFn(34);
-        let __sym0 = __pop_Variant21(__symbols);
+        // Args = "[", "]" => ActionFn(139);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action34::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 15)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action139::<>(input, errors, validator, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (2, 14)
     }
     pub(crate) fn __reduce41<
         'err,
@@ -3096,19 +4156,16 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", call, Triplet, Args, CallOutput, ")" => ActionFn(114);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant16(__symbols);
-        let __sym3 = __pop_Variant15(__symbols);
-        let __sym2 = __pop_Variant27(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // Args = "[", (<Arg>)+, "]" => ActionFn(140);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action114::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action140::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (3, 14)
     }
     pub(crate) fn __reduce42<
         'err,
@@ -3123,18 +4180,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", call, Triplet, Args, ")" => ActionFn(115);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant15(__symbols);
-        let __sym2 = __pop_Variant27(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CallOutput = Scalar => ActionFn(31);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action115::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 15)
     }
     pub(crate) fn __reduce43<
         'err,
@@ -3149,19 +4201,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", canon, ResolvableToPeerIdVariable, StreamArgument, CanonStreamArgument, ")" => ActionFn(106);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant18(__symbols);
-        let __sym3 = __pop_Variant26(__symbols);
-        let __sym2 = __pop_Variant25(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CallOutput = Stream => ActionFn(32);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action106::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action32::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 15)
     }
     pub(crate) fn __reduce44<
         'err,
@@ -3176,18 +4222,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", ap, ApArgument, ApResult, ")" => ActionFn(107);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant14(__symbols);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CallOutput? = CallOutput => ActionFn(130);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action107::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action130::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 16)
     }
     pub(crate) fn __reduce45<
         'err,
@@ -3202,18 +4243,12 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", seq, Instr, Instr, ")" => ActionFn(5);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action5::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        // CallOutput? =  => ActionFn(131);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action131::<>(input, errors, validator, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (0, 16)
     }
     pub(crate) fn __reduce46<
         'err,
@@ -3228,18 +4263,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", par, Instr, Instr, ")" => ActionFn(6);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CanonStreamArgument = CanonStream => ActionFn(111);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action6::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action111::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 17)
     }
     pub(crate) fn __reduce47<
         'err,
@@ -3254,16 +4284,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", never, ")" => ActionFn(7);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CanonStreamMapArgument = CanonStreamMap => ActionFn(113);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action7::<>(input, errors, validator, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (3, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action113::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 18)
     }
     pub(crate) fn __reduce48<
         'err,
@@ -3278,16 +4305,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", null, ")" => ActionFn(8);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // ComparisonOperator = lt => ActionFn(23);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action8::<>(input, errors, validator, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (3, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action23::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce49<
         'err,
@@ -3302,18 +4326,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", new, NewArgument, Instr, ")" => ActionFn(108);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant23(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // ComparisonOperator = lte => ActionFn(24);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action108::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action24::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce50<
         'err,
@@ -3328,17 +4347,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fail, FailBody, ")" => ActionFn(10);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant19(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // ComparisonOperator = gt => ActionFn(25);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action10::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (4, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce51<
         'err,
@@ -3353,20 +4368,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, FoldScalarIterable, Scalar, Instr, Instr, ")" => ActionFn(116);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant12(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant20(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // ComparisonOperator = gte => ActionFn(26);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action116::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (7, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action26::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce52<
         'err,
@@ -3381,19 +4389,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, FoldScalarIterable, Scalar, Instr, ")" => ActionFn(117);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant20(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FailBody = Scalar => ActionFn(33);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action117::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action33::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce53<
         'err,
@@ -3408,20 +4410,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, Stream, Scalar, Instr, Instr, ")" => ActionFn(118);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant12(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FailBody = ScalarWithLambda => ActionFn(34);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action118::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (7, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce54<
         'err,
@@ -3436,19 +4431,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, Stream, Scalar, Instr, ")" => ActionFn(119);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FailBody = I64, Literal => ActionFn(35);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action119::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action35::<>(input, errors, validator, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 20)
     }
     pub(crate) fn __reduce55<
         'err,
@@ -3463,17 +4454,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", next, Scalar, ")" => ActionFn(111);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FailBody = CanonStreamWithLambda => ActionFn(36);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action111::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (4, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action36::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce56<
         'err,
@@ -3488,18 +4475,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", xor, Instr, Instr, ")" => ActionFn(14);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // FailBody = LastError => ActionFn(165);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action14::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action165::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce57<
         'err,
@@ -3514,46 +4496,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", match_, Value, Value, Instr, ")" => ActionFn(112);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant9(__symbols);
-        let __sym2 = __pop_Variant9(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action112::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
-    }
-    pub(crate) fn __reduce58<
-        'err,
-        'input,
-        'v,
-    >(
-        input: &'input str,
-        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
-        validator: &'v mut VariableValidator<'input>,
-        __lookahead_start: Option<&AirPos>,
-        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
-    ) -> (usize, usize)
-    {
-        // Instr = "(", mismatch, Value, Value, Instr, ")" => ActionFn(113);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant9(__symbols);
-        let __sym2 = __pop_Variant9(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // FailBody = Error => ActionFn(38);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action113::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action38::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce59<
         'err,
@@ -3568,13 +4517,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = error => ActionFn(17);
-        let __sym0 = __pop_Variant8(__symbols);
+        // FoldIterationsLimit? = FoldIterationsLimit => ActionFn(123);
+        let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 16)
+        let __nt = super::__action123::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 22)
     }
     pub(crate) fn __reduce60<
         'err,
@@ -3589,13 +4538,12 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr? = Instr => ActionFn(82);
-        let __sym0 = __pop_Variant12(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action82::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 17)
+        // FoldIterationsLimit? =  => ActionFn(124);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action124::<>(input, errors, validator, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 22)
     }
     pub(crate) fn __reduce61<
         'err,
@@ -3610,12 +4558,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr? =  => ActionFn(83);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action83::<>(input, errors, validator, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (0, 17)
+        // FoldScalarIterable = Scalar => ActionFn(40);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 23)
     }
     pub(crate) fn __reduce62<
         'err,
@@ -3630,13 +4579,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = Scalar => ActionFn(45);
-        let __sym0 = __pop_Variant2(__symbols);
+        // FoldScalarIterable = ScalarWithLambda => ActionFn(41);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action45::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 18)
+        let __nt = super::__action41::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 23)
     }
     pub(crate) fn __reduce63<
         'err,
@@ -3651,13 +4600,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = Stream => ActionFn(46);
+        // FoldScalarIterable = CanonStream => ActionFn(42);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action46::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 18)
+        let __nt = super::__action42::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 23)
     }
     pub(crate) fn __reduce64<
         'err,
@@ -3672,13 +4621,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = CanonStream => ActionFn(47);
-        let __sym0 = __pop_Variant2(__symbols);
+        // FoldScalarIterable = "[", "]" => ActionFn(43);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action47::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 18)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action43::<>(input, errors, validator, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (2, 23)
     }
     pub(crate) fn __reduce65<
         'err,
@@ -3693,13 +4644,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Number = I64 => ActionFn(48);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Function = ResolvableToStringVariable => ActionFn(45);
+        let __sym0 = __pop_Variant27(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 19)
+        let __nt = super::__action45::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 24)
     }
     pub(crate) fn __reduce66<
         'err,
@@ -3714,13 +4665,19 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Number = F64 => ActionFn(49);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Instr = "(", call, Triplet, Args, CallOutput, ")" => ActionFn(181);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant18(__symbols);
+        let __sym3 = __pop_Variant17(__symbols);
+        let __sym2 = __pop_Variant36(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action49::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 19)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action181::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (6, 25)
     }
     pub(crate) fn __reduce67<
         'err,
@@ -3735,13 +4692,18 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // PeerId = ResolvableToPeerIdVariable => ActionFn(33);
-        let __sym0 = __pop_Variant25(__symbols);
+        // Instr = "(", call, Triplet, Args, ")" => ActionFn(182);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant17(__symbols);
+        let __sym2 = __pop_Variant36(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action33::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 20)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action182::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (5, 25)
     }
     pub(crate) fn __reduce68<
         'err,
@@ -3756,13 +4718,19 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = InitPeerId => ActionFn(36);
+        // Instr = "(", canon, ResolvableToPeerIdVariable, StreamArgument, CanonStreamArgument, ")" => ActionFn(168);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant20(__symbols);
+        let __sym3 = __pop_Variant33(__symbols);
+        let __sym2 = __pop_Variant31(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 21)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action168::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (6, 25)
     }
     pub(crate) fn __reduce69<
         'err,
@@ -3777,13 +4745,19 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = Literal => ActionFn(37);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Instr = "(", canon, ResolvableToPeerIdVariable, StreamMapArgument, CanonStreamMapArgument, ")" => ActionFn(169);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant21(__symbols);
+        let __sym3 = __pop_Variant34(__symbols);
+        let __sym2 = __pop_Variant31(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action37::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 21)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action169::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (6, 25)
     }
     pub(crate) fn __reduce70<
         'err,
@@ -3798,13 +4772,18 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = Scalar => ActionFn(38);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Instr = "(", ap, ApArgument, ApResult, ")" => ActionFn(170);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant16(__symbols);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action38::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 21)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action170::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (5, 25)
     }
     pub(crate) fn __reduce71<
         'err,
@@ -3819,13 +4798,21 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = ScalarWithLambda => ActionFn(39);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Instr = "(", ap, "(", StreamMapKey, ApArgument, ")", StreamMapArgument, ")" => ActionFn(171);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant34(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant15(__symbols);
+        let __sym3 = __pop_Variant35(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action39::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 21)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action171::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (8, 25)
     }
     pub(crate) fn __reduce72<
         'err,
@@ -3840,13 +4827,18 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = CanonStreamWithLambda => ActionFn(40);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Instr = "(", seq, Instr, Instr, ")" => ActionFn(7);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant14(__symbols);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action40::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 21)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action7::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (5, 25)
     }
     pub(crate) fn __reduce73<
         'err,
@@ -3861,13 +4853,18 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = Literal => ActionFn(41);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Instr = "(", par, Instr, Instr, ")" => ActionFn(8);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant14(__symbols);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action41::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 22)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action8::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (5, 25)
     }
     pub(crate) fn __reduce74<
         'err,
@@ -3882,13 +4879,16 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = Scalar => ActionFn(42);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Instr = "(", never, ")" => ActionFn(9);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action42::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 22)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action9::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (3, 25)
     }
     pub(crate) fn __reduce75<
         'err,
@@ -3903,13 +4903,16 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = ScalarWithLambda => ActionFn(43);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Instr = "(", null, ")" => ActionFn(10);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 22)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action10::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (3, 25)
     }
     pub(crate) fn __reduce76<
         'err,
@@ -3924,13 +4927,18 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = CanonStreamWithLambda => ActionFn(44);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Instr = "(", new, NewArgument, Instr, ")" => ActionFn(172);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant14(__symbols);
+        let __sym2 = __pop_Variant29(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action44::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 22)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action172::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (5, 25)
     }
     pub(crate) fn __reduce77<
         'err,
@@ -3945,13 +4953,17 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ServiceId = ResolvableToStringVariable => ActionFn(35);
-        let __sym0 = __pop_Variant21(__symbols);
+        // Instr = "(", fail, FailBody, ")" => ActionFn(12);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant23(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action35::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 23)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action12::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (4, 25)
     }
     pub(crate) fn __reduce78<
         'err,
@@ -3966,13 +4978,21 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // StreamArgument = Stream => ActionFn(77);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Instr = "(", fold, FoldScalarIterable, Scalar, Scalar, Instr, Instr, ")" => ActionFn(191);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant14(__symbols);
+        let __sym5 = __pop_Variant14(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant26(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action77::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (1, 24)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action191::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (8, 25)
     }
     pub(crate) fn __reduce79<
         'err,
@@ -3987,18 +5007,20 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Triplet = PeerId, "(", ServiceId, Function, ")" => ActionFn(19);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant21(__symbols);
-        let __sym2 = __pop_Variant21(__symbols);
+        // Instr = "(", fold, FoldScalarIterable, Scalar, Instr, Instr, ")" => ActionFn(192);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant14(__symbols);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant26(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant25(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action19::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (5, 25)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action192::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (7, 25)
     }
     pub(crate) fn __reduce80<
         'err,
@@ -4013,13 +5035,20 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = InitPeerId => ActionFn(51);
+        // Instr = "(", fold, FoldScalarIterable, Scalar, Scalar, Instr, ")" => ActionFn(193);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant14(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant26(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action51::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action193::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (7, 25)
     }
     pub(crate) fn __reduce81<
         'err,
@@ -4034,13 +5063,19 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = LastError => ActionFn(52);
+        // Instr = "(", fold, FoldScalarIterable, Scalar, Instr, ")" => ActionFn(194);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant26(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action194::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (6, 25)
     }
     pub(crate) fn __reduce82<
         'err,
@@ -4055,13 +5090,22 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = LastErrorWithLambda => ActionFn(53);
-        let __sym0 = __pop_Variant6(__symbols);
+        // Instr = "(", fold, Stream, Scalar, Scalar, FoldIterationsLimit, Instr, Instr, ")" => ActionFn(195);
+        assert!(__symbols.len() >= 9);
+        let __sym8 = __pop_Variant0(__symbols);
+        let __sym7 = __pop_Variant14(__symbols);
+        let __sym6 = __pop_Variant14(__symbols);
+        let __sym5 = __pop_Variant24(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action53::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym8.2.clone();
+        let __nt = super::__action195::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (9, 25)
     }
     pub(crate) fn __reduce83<
         'err,
@@ -4076,13 +5120,21 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Literal => ActionFn(54);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Instr = "(", fold, Stream, Scalar, FoldIterationsLimit, Instr, Instr, ")" => ActionFn(196);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant14(__symbols);
+        let __sym5 = __pop_Variant14(__symbols);
+        let __sym4 = __pop_Variant24(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action196::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (8, 25)
     }
     pub(crate) fn __reduce84<
         'err,
@@ -4097,13 +5149,21 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Timestamp => ActionFn(55);
+        // Instr = "(", fold, Stream, Scalar, Scalar, FoldIterationsLimit, Instr, ")" => ActionFn(197);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant14(__symbols);
+        let __sym5 = __pop_Variant24(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action55::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action197::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (8, 25)
     }
     pub(crate) fn __reduce85<
         'err,
@@ -4118,13 +5178,20 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = TTL => ActionFn(56);
+        // Instr = "(", fold, Stream, Scalar, FoldIterationsLimit, Instr, ")" => ActionFn(198);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant14(__symbols);
+        let __sym4 = __pop_Variant24(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action56::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action198::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (7, 25)
     }
     pub(crate) fn __reduce86<
         'err,
@@ -4139,13 +5206,21 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Number => ActionFn(57);
-        let __sym0 = __pop_Variant24(__symbols);
+        // Instr = "(", fold, Stream, Scalar, Scalar, Instr, Instr, ")" => ActionFn(199);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant14(__symbols);
+        let __sym5 = __pop_Variant14(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action57::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action199::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (8, 25)
     }
     pub(crate) fn __reduce87<
         'err,
@@ -4160,13 +5235,20 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Boolean => ActionFn(58);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Instr = "(", fold, Stream, Scalar, Instr, Instr, ")" => ActionFn(200);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant14(__symbols);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action58::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action200::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (7, 25)
     }
     pub(crate) fn __reduce88<
         'err,
@@ -4181,15 +5263,20 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = "[", "]" => ActionFn(59);
-        assert!(__symbols.len() >= 2);
+        // Instr = "(", fold, Stream, Scalar, Scalar, Instr, ")" => ActionFn(201);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant14(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action59::<>(input, errors, validator, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (2, 26)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action201::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (7, 25)
     }
     pub(crate) fn __reduce89<
         'err,
@@ -4204,13 +5291,19 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Scalar => ActionFn(60);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Instr = "(", fold, Stream, Scalar, Instr, ")" => ActionFn(202);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action60::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action202::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (6, 25)
     }
     pub(crate) fn __reduce90<
         'err,
@@ -4225,13 +5318,17 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = ScalarWithLambda => ActionFn(61);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Instr = "(", next, Scalar, ")" => ActionFn(175);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action61::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action175::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (4, 25)
     }
     pub(crate) fn __reduce91<
         'err,
@@ -4246,13 +5343,18 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = CanonStream => ActionFn(62);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Instr = "(", xor, Instr, Instr, ")" => ActionFn(16);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant14(__symbols);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action62::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action16::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (5, 25)
     }
     pub(crate) fn __reduce92<
         'err,
@@ -4267,19 +5369,2663 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = CanonStreamWithLambda => ActionFn(63);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Instr = "(", match_, Value, Value, Instr, ")" => ActionFn(176);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action63::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action176::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (6, 25)
+    }
+    pub(crate) fn __reduce93<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Instr = "(", mismatch, Value, Value, Instr, ")" => ActionFn(177);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action177::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (6, 25)
+    }
+    pub(crate) fn __reduce94<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Instr = "(", ComparisonOperator, Value, Value, Instr, ")" => ActionFn(178);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant22(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action178::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (6, 25)
     }
+    pub(crate) fn __reduce95<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Instr = "(", member, Value, Value, Instr, ")" => ActionFn(179);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action179::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (6, 25)
+    }
+    pub(crate) fn __reduce96<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Instr = "(", ttl_guard, Value, Instr, ")" => ActionFn(180);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant14(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action180::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (5, 25)
+    }
+    pub(crate) fn __reduce97<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Instr = error => ActionFn(22);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action22::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 25)
+    }
+    pub(crate) fn __reduce98<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Instr? = Instr => ActionFn(125);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action125::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 26)
+    }
+    pub(crate) fn __reduce99<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Instr? =  => ActionFn(126);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action126::<>(input, errors, validator, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (0, 26)
+    }
+    pub(crate) fn __reduce100<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // NewArgument = Scalar => ActionFn(56);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action56::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 27)
+    }
+    pub(crate) fn __reduce101<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // NewArgument = Stream => ActionFn(57);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action57::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 27)
+    }
+    pub(crate) fn __reduce102<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // NewArgument = CanonStream => ActionFn(58);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action58::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 27)
+    }
+    pub(crate) fn __reduce103<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // NewArgument = StreamMap => ActionFn(59);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action59::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 27)
+    }
+    pub(crate) fn __reduce104<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // NewArgument = CanonStreamMap => ActionFn(60);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action60::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 27)
+    }
+    pub(crate) fn __reduce105<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Number = I64 => ActionFn(66);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action66::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 28)
+    }
+    pub(crate) fn __reduce106<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Number = F64 => ActionFn(67);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action67::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 28)
+    }
+    pub(crate) fn __reduce107<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ObjectEntry = Literal, ":", Value => ActionFn(89);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action89::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 29)
+    }
+    pub(crate) fn __reduce108<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // PeerId = ResolvableToPeerIdVariable => ActionFn(44);
+        let __sym0 = __pop_Variant31(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action44::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 30)
+    }
+    pub(crate) fn __reduce109<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ResolvableToPeerIdVariable = InitPeerId => ActionFn(47);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action47::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 31)
+    }
+    pub(crate) fn __reduce110<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ResolvableToPeerIdVariable = Literal => ActionFn(48);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action48::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 31)
+    }
+    pub(crate) fn __reduce111<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ResolvableToPeerIdVariable = Scalar => ActionFn(49);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action49::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 31)
+    }
+    pub(crate) fn __reduce112<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ResolvableToPeerIdVariable = ScalarWithLambda => ActionFn(50);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action50::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 31)
+    }
+    pub(crate) fn __reduce113<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ResolvableToPeerIdVariable = CanonStreamWithLambda => ActionFn(51);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action51::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 31)
+    }
+    pub(crate) fn __reduce114<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ResolvableToStringVariable = Literal => ActionFn(52);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action52::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 32)
+    }
+    pub(crate) fn __reduce115<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ResolvableToStringVariable = Scalar => ActionFn(53);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action53::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 32)
+    }
+    pub(crate) fn __reduce116<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ResolvableToStringVariable = ScalarWithLambda => ActionFn(54);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action54::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 32)
+    }
+    pub(crate) fn __reduce117<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ResolvableToStringVariable = CanonStreamWithLambda => ActionFn(55);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action55::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 32)
+    }
+    pub(crate) fn __reduce118<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Scalar? = Scalar => ActionFn(127);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action127::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 33)
+    }
+    pub(crate) fn __reduce119<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Scalar? =  => ActionFn(128);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action128::<>(input, errors, validator, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (0, 33)
+    }
+    pub(crate) fn __reduce120<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // ServiceId = ResolvableToStringVariable => ActionFn(46);
+        let __sym0 = __pop_Variant27(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action46::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 34)
+    }
+    pub(crate) fn __reduce121<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // StreamArgument = Stream => ActionFn(110);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action110::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (1, 35)
+    }
+    pub(crate) fn __reduce122<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // StreamMapArgument = StreamMap => ActionFn(112);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action112::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 36)
+    }
+    pub(crate) fn __reduce123<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // StreamMapKey = Literal => ActionFn(61);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action61::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 37)
+    }
+    pub(crate) fn __reduce124<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // StreamMapKey = I64 => ActionFn(62);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action62::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 37)
+    }
+    pub(crate) fn __reduce125<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // StreamMapKey = Scalar => ActionFn(63);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action63::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 37)
+    }
+    pub(crate) fn __reduce126<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // StreamMapKey = ScalarWithLambda => ActionFn(64);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action64::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 37)
+    }
+    pub(crate) fn __reduce127<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // StreamMapKey = CanonStreamWithLambda => ActionFn(65);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action65::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 37)
+    }
+    pub(crate) fn __reduce128<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Triplet = PeerId, "(", ServiceId, Function, ")" => ActionFn(28);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant27(__symbols);
+        let __sym2 = __pop_Variant27(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant31(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action28::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (5, 38)
+    }
+    pub(crate) fn __reduce129<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = InitPeerId => ActionFn(69);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action69::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce130<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = LastError => ActionFn(70);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce131<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = LastErrorWithLambda => ActionFn(71);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action71::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce132<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = Error => ActionFn(72);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action72::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce133<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = ErrorWithLambda => ActionFn(73);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action73::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce134<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = Literal => ActionFn(74);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action74::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce135<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = Timestamp => ActionFn(75);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action75::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce136<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = TTL => ActionFn(76);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce137<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = ParticleId => ActionFn(77);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action77::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce138<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = Number => ActionFn(78);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action78::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce139<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = Boolean => ActionFn(79);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action79::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce140<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = "[", "]" => ActionFn(80);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action80::<>(input, errors, validator, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 39)
+    }
+    pub(crate) fn __reduce141<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = "[", (<Value>)+, "]" => ActionFn(81);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action81::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 39)
+    }
+    pub(crate) fn __reduce142<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = "{", "}" => ActionFn(145);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action145::<>(input, errors, validator, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 39)
+    }
+    pub(crate) fn __reduce143<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = "{", (<ObjectEntry>)+, "}" => ActionFn(146);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action146::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 39)
+    }
+    pub(crate) fn __reduce144<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = Scalar => ActionFn(83);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action83::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce145<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = ScalarWithLambda => ActionFn(84);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action84::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce146<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = CanonStream => ActionFn(85);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action85::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce147<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = CanonStreamWithLambda => ActionFn(86);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action86::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce148<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = CanonStreamMap => ActionFn(87);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action87::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce149<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Value = CanonStreamMapWithLambda => ActionFn(88);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action88::<>(input, errors, validator, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 39)
+    }
+}
+pub use self::__parse__AIR::AIRParser;
+
+#[allow(unused_variables)]
+fn __action0<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
+) -> Box<Instruction<'input>>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
+) -> Box<Instruction<'input>>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, triplet, _): (AirPos, Triplet<'input>, AirPos),
+    (_, args, _): (AirPos, Vec<ImmutableValue<'input>>, AirPos),
+    (_, output, _): (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let args = Rc::new(args);
+        let output = output.unwrap_or(CallOutputValue::None);
+        let call = Call::new(triplet, args, output);
+        let span = Span::new(left, right);
+
+        validator.met_call(&call, span);
+
+        Box::new(Instruction::Call(call))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, peer_id, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    (_, stream, _): (AirPos, Stream<'input>, AirPos),
+    (_, canon_stream, _): (AirPos, CanonStream<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let canon = Canon::new(peer_id, stream, canon_stream);
+
+        let span = Span::new(left, right);
+        validator.met_canon(&canon, span);
+
+        Box::new(Instruction::Canon(canon))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, peer_id, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    (_, stream_map, _): (AirPos, StreamMap<'input>, AirPos),
+    (_, canon_stream_map, _): (AirPos, CanonStreamMap<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let canon_map = CanonMap::new(peer_id, stream_map, canon_stream_map);
+
+        let span = Span::new(left, right);
+        validator.met_canon_map(&canon_map, span);
+
+        Box::new(Instruction::CanonMap(canon_map))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, arg, _): (AirPos, ApArgument<'input>, AirPos),
+    (_, result, _): (AirPos, ApResult<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let apply = Ap::new(arg, result);
+
+        let span = Span::new(left, right);
+        validator.met_ap(&apply, span);
+
+        Box::new(Instruction::Ap(apply))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, key, _): (AirPos, StreamMapKeyClause<'input>, AirPos),
+    (_, value, _): (AirPos, ApArgument<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, map, _): (AirPos, StreamMap<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let ap_map = ApMap::new(key, value, map);
+
+        let span = Span::new(left, right);
+        validator.met_ap_map(&ap_map, span);
+
+        Box::new(Instruction::ApMap(ap_map))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
+{
+    Box::new(Instruction::Seq(Seq::new(l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
+{
+    Box::new(Instruction::Par(Par::new(l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+    (_, __1, _): (AirPos, Token<'input>, AirPos),
+    (_, __2, _): (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
+{
+    Box::new(Instruction::Never(Never))
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+    (_, __1, _): (AirPos, Token<'input>, AirPos),
+    (_, __2, _): (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
+{
+    Box::new(Instruction::Null(Null))
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, argument, _): (AirPos, NewArgument<'input>, AirPos),
+    (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let span = Span::new(left, right);
+        let new = New::new(argument, instruction, span);
+
+        validator.met_new(&new, span);
+
+        Box::new(Instruction::New(new))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, fail_body, _): (AirPos, Fail<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        Box::new(Instruction::Fail(fail_body))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, iterable, _): (AirPos, FoldScalarIterable<'input>, AirPos),
+    (_, iterator, _): (AirPos, (&'input str, AirPos), AirPos),
+    (_, index, _): (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, last_instruction, _): (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let iterator = Scalar::new(iterator.0, iterator.1);
+        let index = index.map(|index| Scalar::new(index.0, index.1));
+        let span = Span::new(left, right);
+        let fold = FoldScalar::new(iterable, iterator, index, *instruction, last_instruction.map(|v| *v), span);
+
+        validator.met_fold_scalar(&fold, span);
+
+        Box::new(Instruction::FoldScalar(fold))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
+    (_, iterator, _): (AirPos, (&'input str, AirPos), AirPos),
+    (_, index, _): (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    (_, max_iterations, _): (AirPos, core::option::Option<u32>, AirPos),
+    (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, last_instruction, _): (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let iterable = Stream::new(stream.0, stream.1);
+        let iterator = Scalar::new(iterator.0, iterator.1);
+        let index = index.map(|index| Scalar::new(index.0, index.1));
+        let span = Span::new(left, right);
+        let fold = FoldStream::new(iterable, iterator, index, max_iterations, *instruction, last_instruction.map(|v| *v), span);
+
+        validator.meet_fold_stream(&fold, span);
+
+        Box::new(Instruction::FoldStream(fold))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, iterator, _): (AirPos, (&'input str, AirPos), AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let iterator = Scalar::new(iterator.0, iterator.1);
+        let next = Next::new(iterator);
+        let span = Span::new(left, right);
+        validator.met_next(&next, span);
+
+        Box::new(Instruction::Next(next))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
+{
+    Box::new(Instruction::Xor(Xor(l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, l, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, r, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, i, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let match_ = Match::new(l, r, i);
+        let span = Span::new(left, right);
+        validator.met_match(&match_, span);
+
+        Box::new(Instruction::Match(match_))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, l, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, r, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, i, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let mismatch = MisMatch::new(l, r, i);
+        let span = Span::new(left, right);
+        validator.met_mismatch(&mismatch, span);
+
+        Box::new(Instruction::MisMatch(mismatch))
+     }
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, operator, _): (AirPos, ComparisonOperator, AirPos),
+    (_, l, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, r, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, i, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let compare = Compare::new(operator, l, r, i);
+        let span = Span::new(left, right);
+        validator.met_compare(&compare, span);
+
+        Box::new(Instruction::Compare(compare))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, v, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, c, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, i, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let member = Member::new(v, c, i);
+        let span = Span::new(left, right);
+        validator.met_member(&member, span);
+
+        Box::new(Instruction::Member(member))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, t, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, i, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let ttl_guard = TtlGuard::new(t, i);
+        let span = Span::new(left, right);
+        validator.met_ttl_guard(&ttl_guard, span);
+
+        Box::new(Instruction::TtlGuard(ttl_guard))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, __lalrpop_util::ErrorRecovery<AirPos, Token<'input>, ParserError>, AirPos),
+) -> Box<Instruction<'input>>
+{
+    { errors.push(__0); Box::new(Instruction::Error) }
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ComparisonOperator
+{
+    ComparisonOperator::Less
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ComparisonOperator
+{
+    ComparisonOperator::LessOrEqual
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ComparisonOperator
+{
+    ComparisonOperator::Greater
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ComparisonOperator
+{
+    ComparisonOperator::GreaterOrEqual
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, args, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+) -> Vec<ImmutableValue<'input>>
+{
+    args
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, peer_id, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, service_id, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
+    (_, function_name, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+) -> Triplet<'input>
+{
+    Triplet {
+        peer_id,
+        service_id,
+        function_name
+    }
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> ApResult<'input>
+{
+    ApResult::scalar(scalar.0, scalar.1)
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> ApResult<'input>
+{
+    ApResult::stream(stream.0, stream.1)
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> CallOutputValue<'input>
+{
+    CallOutputValue::scalar(scalar.0, scalar.1)
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> CallOutputValue<'input>
+{
+    CallOutputValue::stream(stream.0, stream.1)
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> Fail<'input>
+{
+    Fail::Scalar(Scalar::new(scalar.0, scalar.1))
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> Fail<'input>
+{
+    Fail::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, ret_code, _): (AirPos, i64, AirPos),
+    (_, error_message, _): (AirPos, &'input str, AirPos),
+) -> Fail<'input>
+{
+    Fail::Literal {
+        ret_code,
+        error_message,
+    }
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> Fail<'input>
+{
+    Fail::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, l, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Fail<'input>
+{
+    {
+        Fail::LastError
+    }
+}
+
+#[allow(unused_variables)]
+fn __action38<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> Fail<'input>
+{
+    Fail::Error
+}
+
+#[allow(unused_variables)]
+fn __action39<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, limit, _): (AirPos, i64, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Result<u32,__lalrpop_util::ParseError<AirPos,Token<'input>,ParserError>>
+{
+    <u32 as std::convert::TryFrom<i64>>::try_from(limit)
+        .ok()
+        .filter(|&limit| limit > 0)
+        .ok_or_else(|| ParseError::User {
+            error: ParserError::invalid_fold_iterations_limit(Span::new(left, right), limit)
+        })
+}
+
+#[allow(unused_variables)]
+fn __action40<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> FoldScalarIterable<'input>
+{
+    FoldScalarIterable::Scalar(Scalar::new(scalar.0, scalar.1))
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> FoldScalarIterable<'input>
+{
+    FoldScalarIterable::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> FoldScalarIterable<'input>
+{
+    FoldScalarIterable::CanonStream(CanonStream::new(canon_stream.0, canon_stream.1))
+}
+
+#[allow(unused_variables)]
+fn __action43<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+    (_, __1, _): (AirPos, Token<'input>, AirPos),
+) -> FoldScalarIterable<'input>
+{
+    FoldScalarIterable::EmptyArray
+}
+
+#[allow(unused_variables)]
+fn __action44<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+) -> ResolvableToPeerIdVariable<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action45<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
+) -> ResolvableToStringVariable<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
+) -> ResolvableToStringVariable<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action47<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ResolvableToPeerIdVariable<'input>
+{
+    ResolvableToPeerIdVariable::InitPeerId
+}
+
+#[allow(unused_variables)]
+fn __action48<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, literal, _): (AirPos, &'input str, AirPos),
+) -> ResolvableToPeerIdVariable<'input>
+{
+    ResolvableToPeerIdVariable::Literal(literal)
+}
+
+#[allow(unused_variables)]
+fn __action49<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> ResolvableToPeerIdVariable<'input>
+{
+    ResolvableToPeerIdVariable::Scalar(Scalar::new(scalar.0, scalar.1))
+}
+
+#[allow(unused_variables)]
+fn __action50<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> ResolvableToPeerIdVariable<'input>
+{
+    ResolvableToPeerIdVariable::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+}
+
+#[allow(unused_variables)]
+fn __action51<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> ResolvableToPeerIdVariable<'input>
+{
+    ResolvableToPeerIdVariable::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
+}
+
+#[allow(unused_variables)]
+fn __action52<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, literal, _): (AirPos, &'input str, AirPos),
+) -> ResolvableToStringVariable<'input>
+{
+    ResolvableToStringVariable::Literal(literal)
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> ResolvableToStringVariable<'input>
+{
+    ResolvableToStringVariable::Scalar(Scalar::new(scalar.0, scalar.1))
+}
+
+#[allow(unused_variables)]
+fn __action54<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> ResolvableToStringVariable<'input>
+{
+    ResolvableToStringVariable::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+}
+
+#[allow(unused_variables)]
+fn __action55<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> ResolvableToStringVariable<'input>
+{
+    ResolvableToStringVariable::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
+}
+
+#[allow(unused_variables)]
+fn __action56<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> NewArgument<'input>
+{
+    NewArgument::Scalar(Scalar::new(scalar.0, scalar.1))
+}
+
+#[allow(unused_variables)]
+fn __action57<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> NewArgument<'input>
+{
+    NewArgument::Stream(Stream::new(stream.0, stream.1))
+}
+
+#[allow(unused_variables)]
+fn __action58<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> NewArgument<'input>
+{
+    NewArgument::CanonStream(CanonStream::new(canon_stream.0, canon_stream.1))
+}
+
+#[allow(unused_variables)]
+fn __action59<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, stream_map, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> NewArgument<'input>
+{
+    NewArgument::StreamMap(StreamMap::new(stream_map.0, stream_map.1))
+}
+
+#[allow(unused_variables)]
+fn __action60<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, canon_stream_map, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> NewArgument<'input>
+{
+    NewArgument::CanonStreamMap(CanonStreamMap::new(canon_stream_map.0, canon_stream_map.1))
+}
+
+#[allow(unused_variables)]
+fn __action61<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, literal, _): (AirPos, &'input str, AirPos),
+) -> StreamMapKeyClause<'input>
+{
+    StreamMapKeyClause::Literal(literal)
+}
+
+#[allow(unused_variables)]
+fn __action62<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, integer, _): (AirPos, i64, AirPos),
+) -> StreamMapKeyClause<'input>
+{
+    StreamMapKeyClause::Int(integer)
+}
+
+#[allow(unused_variables)]
+fn __action63<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> StreamMapKeyClause<'input>
+{
+    StreamMapKeyClause::Scalar(Scalar::new(scalar.0, scalar.1))
+}
+
+#[allow(unused_variables)]
+fn __action64<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> StreamMapKeyClause<'input>
+{
+    StreamMapKeyClause::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+}
+
+#[allow(unused_variables)]
+fn __action65<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> StreamMapKeyClause<'input>
+{
+    StreamMapKeyClause::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
+}
+
+#[allow(unused_variables)]
+fn __action66<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, integer, _): (AirPos, i64, AirPos),
+) -> Number
+{
+    Number::Int(integer)
+}
+
+#[allow(unused_variables)]
+fn __action67<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, float, _): (AirPos, f64, AirPos),
+) -> Number
+{
+    Number::Float(float)
+}
+
+#[allow(unused_variables)]
+fn __action68<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
+) -> ImmutableValue<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action69<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ImmutableValue<'input>
+{
+    ImmutableValue::InitPeerId
+}
+
+#[allow(unused_variables)]
+fn __action70<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ImmutableValue<'input>
+{
+    ImmutableValue::LastError(None)
+}
+
+#[allow(unused_variables)]
+fn __action71<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, le, _): (AirPos, LambdaAST<'input>, AirPos),
+) -> ImmutableValue<'input>
+{
+    ImmutableValue::LastError(Some(le))
+}
+
+#[allow(unused_variables)]
+fn __action72<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ImmutableValue<'input>
+{
+    ImmutableValue::Error(None)
+}
+
+#[allow(unused_variables)]
+fn __action73<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, e, _): (AirPos, LambdaAST<'input>, AirPos),
+) -> ImmutableValue<'input>
+{
+    ImmutableValue::Error(Some(e))
+}
+
+#[allow(unused_variables)]
+fn __action74<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, l, _): (AirPos, &'input str, AirPos),
+) -> ImmutableValue<'input>
+{
+    ImmutableValue::Literal(l)
+}
+
+#[allow(unused_variables)]
+fn __action75<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ImmutableValue<'input>
+{
+    ImmutableValue::Timestamp
+}
+
+#[allow(unused_variables)]
+fn __action76<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ImmutableValue<'input>
+{
+    ImmutableValue::TTL
 }
-pub use self::__parse__AIR::AIRParser;
 
 #[allow(unused_variables)]
-fn __action0<
+fn __action77<
     'err,
     'input,
     'v,
@@ -4287,14 +8033,14 @@ fn __action0<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
-) -> Box<Instruction<'input>>
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ImmutableValue<'input>
 {
-    __0
+    ImmutableValue::ParticleId
 }
 
 #[allow(unused_variables)]
-fn __action1<
+fn __action78<
     'err,
     'input,
     'v,
@@ -4302,14 +8048,14 @@ fn __action1<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
-) -> Box<Instruction<'input>>
+    (_, n, _): (AirPos, Number, AirPos),
+) -> ImmutableValue<'input>
 {
-    __0
+    ImmutableValue::Number(n)
 }
 
 #[allow(unused_variables)]
-fn __action2<
+fn __action79<
     'err,
     'input,
     'v,
@@ -4317,30 +8063,14 @@ fn __action2<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, triplet, _): (AirPos, Triplet<'input>, AirPos),
-    (_, args, _): (AirPos, Vec<ImmutableValue<'input>>, AirPos),
-    (_, output, _): (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
+    (_, b, _): (AirPos, bool, AirPos),
+) -> ImmutableValue<'input>
 {
-    {
-        let args = Rc::new(args);
-        let output = output.unwrap_or(CallOutputValue::None);
-        let call = Call::new(triplet, args, output);
-        let span = Span::new(left, right);
-
-        validator.met_call(&call, span);
-
-        Box::new(Instruction::Call(call))
-    }
+    ImmutableValue::Boolean(b)
 }
 
 #[allow(unused_variables)]
-fn __action3<
+fn __action80<
     'err,
     'input,
     'v,
@@ -4348,28 +8078,15 @@ fn __action3<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, peer_id, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
-    (_, stream, _): (AirPos, Stream<'input>, AirPos),
-    (_, canon_stream, _): (AirPos, CanonStream<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+    (_, __1, _): (AirPos, Token<'input>, AirPos),
+) -> ImmutableValue<'input>
 {
-    {
-        let canon = Canon::new(peer_id, stream, canon_stream);
-
-        let span = Span::new(left, right);
-        validator.met_canon(&canon, span);
-
-        Box::new(Instruction::Canon(canon))
-    }
+    ImmutableValue::EmptyArray
 }
 
 #[allow(unused_variables)]
-fn __action4<
+fn __action81<
     'err,
     'input,
     'v,
@@ -4377,27 +8094,16 @@ fn __action4<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, values, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, arg, _): (AirPos, ApArgument<'input>, AirPos),
-    (_, result, _): (AirPos, ApResult<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
+) -> ImmutableValue<'input>
 {
-    {
-        let apply = Ap::new(arg, result);
-
-        let span = Span::new(left, right);
-        validator.met_ap(&apply, span);
-
-        Box::new(Instruction::Ap(apply))
-    }
+    ImmutableValue::Array(values)
 }
 
 #[allow(unused_variables)]
-fn __action5<
+fn __action82<
     'err,
     'input,
     'v,
@@ -4406,17 +8112,15 @@ fn __action5<
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
     (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, entries, _): (AirPos, alloc::vec::Vec<ObjectEntry<'input>>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-) -> Box<Instruction<'input>>
+) -> ImmutableValue<'input>
 {
-    Box::new(Instruction::Seq(Seq::new(l, r)))
+    ImmutableValue::Object(entries)
 }
 
 #[allow(unused_variables)]
-fn __action6<
+fn __action83<
     'err,
     'input,
     'v,
@@ -4424,18 +8128,14 @@ fn __action6<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-) -> Box<Instruction<'input>>
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> ImmutableValue<'input>
 {
-    Box::new(Instruction::Par(Par::new(l, r)))
+    ImmutableValue::Variable(ImmutableVariable::scalar(scalar.0, scalar.1))
 }
 
 #[allow(unused_variables)]
-fn __action7<
+fn __action84<
     'err,
     'input,
     'v,
@@ -4443,16 +8143,14 @@ fn __action7<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-    (_, __1, _): (AirPos, Token<'input>, AirPos),
-    (_, __2, _): (AirPos, Token<'input>, AirPos),
-) -> Box<Instruction<'input>>
+    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> ImmutableValue<'input>
 {
-    Box::new(Instruction::Never(Never))
+    ImmutableValue::VariableWithLambda(ImmutableVariableWithLambda::scalar(scalar.0, scalar.1, scalar.2))
 }
 
 #[allow(unused_variables)]
-fn __action8<
+fn __action85<
     'err,
     'input,
     'v,
@@ -4460,16 +8158,14 @@ fn __action8<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-    (_, __1, _): (AirPos, Token<'input>, AirPos),
-    (_, __2, _): (AirPos, Token<'input>, AirPos),
-) -> Box<Instruction<'input>>
+    (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> ImmutableValue<'input>
 {
-    Box::new(Instruction::Null(Null))
+    ImmutableValue::Variable(ImmutableVariable::canon_stream(canon_stream.0, canon_stream.1))
 }
 
 #[allow(unused_variables)]
-fn __action9<
+fn __action86<
     'err,
     'input,
     'v,
@@ -4477,27 +8173,29 @@ fn __action9<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, argument, _): (AirPos, NewArgument<'input>, AirPos),
-    (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
+    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> ImmutableValue<'input>
 {
-    {
-        let span = Span::new(left, right);
-        let new = New::new(argument, instruction, span);
-
-        validator.met_new(&new, span);
+    ImmutableValue::VariableWithLambda(ImmutableVariableWithLambda::canon_stream(canon_stream.0, canon_stream.1, canon_stream.2))
+}
 
-        Box::new(Instruction::New(new))
-    }
+#[allow(unused_variables)]
+fn __action87<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, canon_stream_map, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> ImmutableValue<'input>
+{
+    ImmutableValue::Variable(ImmutableVariable::canon_stream_map(canon_stream_map.0, canon_stream_map.1))
 }
 
 #[allow(unused_variables)]
-fn __action10<
+fn __action88<
     'err,
     'input,
     'v,
@@ -4505,19 +8203,14 @@ fn __action10<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, fail_body, _): (AirPos, Fail<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-) -> Box<Instruction<'input>>
+    (_, canon_stream_map, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> ImmutableValue<'input>
 {
-    {
-        Box::new(Instruction::Fail(fail_body))
-    }
+    ImmutableValue::VariableWithLambda(ImmutableVariableWithLambda::canon_stream_map(canon_stream_map.0, canon_stream_map.1, canon_stream_map.2))
 }
 
 #[allow(unused_variables)]
-fn __action11<
+fn __action89<
     'err,
     'input,
     'v,
@@ -4525,30 +8218,46 @@ fn __action11<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, iterable, _): (AirPos, FoldScalarIterable<'input>, AirPos),
-    (_, iterator, _): (AirPos, (&'input str, AirPos), AirPos),
-    (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, last_instruction, _): (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    (_, key, _): (AirPos, &'input str, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
+    (_, value, _): (AirPos, ImmutableValue<'input>, AirPos),
+) -> ObjectEntry<'input>
 {
-    {
-        let iterator = Scalar::new(iterator.0, iterator.1);
-        let span = Span::new(left, right);
-        let fold = FoldScalar::new(iterable, iterator, *instruction, last_instruction.map(|v| *v), span);
+    ObjectEntry::new(key, value)
+}
 
-        validator.met_fold_scalar(&fold, span);
+#[allow(unused_variables)]
+fn __action90<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ApArgument<'input>
+{
+    ApArgument::InitPeerId
+}
 
-        Box::new(Instruction::FoldScalar(fold))
-    }
+#[allow(unused_variables)]
+fn __action91<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ApArgument<'input>
+{
+    ApArgument::LastError(None)
 }
 
 #[allow(unused_variables)]
-fn __action12<
+fn __action92<
     'err,
     'input,
     'v,
@@ -4556,31 +8265,44 @@ fn __action12<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
-    (_, iterator, _): (AirPos, (&'input str, AirPos), AirPos),
-    (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, last_instruction, _): (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
+    (_, le, _): (AirPos, LambdaAST<'input>, AirPos),
+) -> ApArgument<'input>
 {
-    {
-        let iterable = Stream::new(stream.0, stream.1);
-        let iterator = Scalar::new(iterator.0, iterator.1);
-        let span = Span::new(left, right);
-        let fold = FoldStream::new(iterable, iterator, *instruction, last_instruction.map(|v| *v), span);
+    ApArgument::LastError(Some(le))
+}
 
-        validator.meet_fold_stream(&fold, span);
+#[allow(unused_variables)]
+fn __action93<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ApArgument<'input>
+{
+    ApArgument::Error(None)
+}
 
-        Box::new(Instruction::FoldStream(fold))
-    }
+#[allow(unused_variables)]
+fn __action94<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, e, _): (AirPos, LambdaAST<'input>, AirPos),
+) -> ApArgument<'input>
+{
+    ApArgument::Error(Some(e))
 }
 
 #[allow(unused_variables)]
-fn __action13<
+fn __action95<
     'err,
     'input,
     'v,
@@ -4588,26 +8310,14 @@ fn __action13<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, iterator, _): (AirPos, (&'input str, AirPos), AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ApArgument<'input>
 {
-    {
-        let iterator = Scalar::new(iterator.0, iterator.1);
-        let next = Next::new(iterator);
-        let span = Span::new(left, right);
-        validator.met_next(&next, span);
-
-        Box::new(Instruction::Next(next))
-    }
+    ApArgument::Timestamp
 }
 
 #[allow(unused_variables)]
-fn __action14<
+fn __action96<
     'err,
     'input,
     'v,
@@ -4615,18 +8325,14 @@ fn __action14<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-) -> Box<Instruction<'input>>
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ApArgument<'input>
 {
-    Box::new(Instruction::Xor(Xor(l, r)))
+    ApArgument::TTL
 }
 
 #[allow(unused_variables)]
-fn __action15<
+fn __action97<
     'err,
     'input,
     'v,
@@ -4634,27 +8340,14 @@ fn __action15<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, l, _): (AirPos, ImmutableValue<'input>, AirPos),
-    (_, r, _): (AirPos, ImmutableValue<'input>, AirPos),
-    (_, i, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+) -> ApArgument<'input>
 {
-    {
-        let match_ = Match::new(l, r, i);
-        let span = Span::new(left, right);
-        validator.met_match(&match_, span);
-
-        Box::new(Instruction::Match(match_))
-    }
+    ApArgument::ParticleId
 }
 
 #[allow(unused_variables)]
-fn __action16<
+fn __action98<
     'err,
     'input,
     'v,
@@ -4662,27 +8355,29 @@ fn __action16<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, l, _): (AirPos, ImmutableValue<'input>, AirPos),
-    (_, r, _): (AirPos, ImmutableValue<'input>, AirPos),
-    (_, i, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
+    (_, l, _): (AirPos, &'input str, AirPos),
+) -> ApArgument<'input>
 {
-    {
-        let mismatch = MisMatch::new(l, r, i);
-        let span = Span::new(left, right);
-        validator.met_mismatch(&mismatch, span);
+    ApArgument::Literal(l)
+}
 
-        Box::new(Instruction::MisMatch(mismatch))
-     }
+#[allow(unused_variables)]
+fn __action99<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, n, _): (AirPos, Number, AirPos),
+) -> ApArgument<'input>
+{
+    ApArgument::Number(n)
 }
 
 #[allow(unused_variables)]
-fn __action17<
+fn __action100<
     'err,
     'input,
     'v,
@@ -4690,14 +8385,14 @@ fn __action17<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, __lalrpop_util::ErrorRecovery<AirPos, Token<'input>, ParserError>, AirPos),
-) -> Box<Instruction<'input>>
+    (_, b, _): (AirPos, bool, AirPos),
+) -> ApArgument<'input>
 {
-    { errors.push(__0); Box::new(Instruction::Error) }
+    ApArgument::Boolean(b)
 }
 
 #[allow(unused_variables)]
-fn __action18<
+fn __action101<
     'err,
     'input,
     'v,
@@ -4705,16 +8400,15 @@ fn __action18<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, args, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-) -> Vec<ImmutableValue<'input>>
+    (_, __0, _): (AirPos, Token<'input>, AirPos),
+    (_, __1, _): (AirPos, Token<'input>, AirPos),
+) -> ApArgument<'input>
 {
-    args
+    ApArgument::EmptyArray
 }
 
 #[allow(unused_variables)]
-fn __action19<
+fn __action102<
     'err,
     'input,
     'v,
@@ -4722,22 +8416,16 @@ fn __action19<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, peer_id, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, service_id, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
-    (_, function_name, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
+    (_, values, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
-) -> Triplet<'input>
+) -> ApArgument<'input>
 {
-    Triplet {
-        peer_id,
-        service_id,
-        function_name
-    }
+    ApArgument::Array(values)
 }
 
 #[allow(unused_variables)]
-fn __action20<
+fn __action103<
     'err,
     'input,
     'v,
@@ -4745,14 +8433,16 @@ fn __action20<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> ApResult<'input>
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, entries, _): (AirPos, alloc::vec::Vec<ObjectEntry<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+) -> ApArgument<'input>
 {
-    ApResult::scalar(scalar.0, scalar.1)
+    ApArgument::Object(entries)
 }
 
 #[allow(unused_variables)]
-fn __action21<
+fn __action104<
     'err,
     'input,
     'v,
@@ -4760,14 +8450,14 @@ fn __action21<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> ApResult<'input>
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> ApArgument<'input>
 {
-    ApResult::stream(stream.0, stream.1)
+    ApArgument::Scalar(Scalar::new(scalar.0, scalar.1))
 }
 
 #[allow(unused_variables)]
-fn __action22<
+fn __action105<
     'err,
     'input,
     'v,
@@ -4775,14 +8465,14 @@ fn __action22<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> CallOutputValue<'input>
+    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> ApArgument<'input>
 {
-    CallOutputValue::scalar(scalar.0, scalar.1)
+    ApArgument::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
 }
 
 #[allow(unused_variables)]
-fn __action23<
+fn __action106<
     'err,
     'input,
     'v,
@@ -4790,14 +8480,14 @@ fn __action23<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> CallOutputValue<'input>
+    (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> ApArgument<'input>
 {
-    CallOutputValue::stream(stream.0, stream.1)
+    ApArgument::CanonStream(CanonStream::new(canon_stream.0, canon_stream.1))
 }
 
 #[allow(unused_variables)]
-fn __action24<
+fn __action107<
     'err,
     'input,
     'v,
@@ -4805,14 +8495,14 @@ fn __action24<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> Fail<'input>
+    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> ApArgument<'input>
 {
-    Fail::Scalar(Scalar::new(scalar.0, scalar.1))
+    ApArgument::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
 }
 
 #[allow(unused_variables)]
-fn __action25<
+fn __action108<
     'err,
     'input,
     'v,
@@ -4820,14 +8510,14 @@ fn __action25<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> Fail<'input>
+    (_, canon_stream_map, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> ApArgument<'input>
 {
-    Fail::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+    ApArgument::CanonStreamMap(CanonStreamMap::new(canon_stream_map.0, canon_stream_map.1))
 }
 
 #[allow(unused_variables)]
-fn __action26<
+fn __action109<
     'err,
     'input,
     'v,
@@ -4835,18 +8525,14 @@ fn __action26<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, ret_code, _): (AirPos, i64, AirPos),
-    (_, error_message, _): (AirPos, &'input str, AirPos),
-) -> Fail<'input>
+    (_, canon_stream_map, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> ApArgument<'input>
 {
-    Fail::Literal {
-        ret_code,
-        error_message,
-    }
+    ApArgument::CanonStreamMapWithLambda(CanonStreamMapWithLambda::new(canon_stream_map.0, canon_stream_map.1, canon_stream_map.2))
 }
 
 #[allow(unused_variables)]
-fn __action27<
+fn __action110<
     'err,
     'input,
     'v,
@@ -4854,14 +8540,14 @@ fn __action27<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> Fail<'input>
+    (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> Stream<'input>
 {
-    Fail::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
+    Stream::new(stream.0, stream.1)
 }
 
 #[allow(unused_variables)]
-fn __action28<
+fn __action111<
     'err,
     'input,
     'v,
@@ -4869,18 +8555,14 @@ fn __action28<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, l, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Fail<'input>
+    (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> CanonStream<'input>
 {
-    {
-        Fail::LastError
-    }
+    CanonStream::new(canon_stream.0, canon_stream.1)
 }
 
 #[allow(unused_variables)]
-fn __action29<
+fn __action112<
     'err,
     'input,
     'v,
@@ -4888,14 +8570,14 @@ fn __action29<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> FoldScalarIterable<'input>
+    (_, stream_map, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> StreamMap<'input>
 {
-    FoldScalarIterable::Scalar(Scalar::new(scalar.0, scalar.1))
+    StreamMap::new(stream_map.0, stream_map.1)
 }
 
 #[allow(unused_variables)]
-fn __action30<
+fn __action113<
     'err,
     'input,
     'v,
@@ -4903,14 +8585,14 @@ fn __action30<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> FoldScalarIterable<'input>
+    (_, canon_stream_map, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> CanonStreamMap<'input>
 {
-    FoldScalarIterable::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+    CanonStreamMap::new(canon_stream_map.0, canon_stream_map.1)
 }
 
 #[allow(unused_variables)]
-fn __action31<
+fn __action114<
     'err,
     'input,
     'v,
@@ -4918,14 +8600,15 @@ fn __action31<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> FoldScalarIterable<'input>
+    __lookbehind: &AirPos,
+    __lookahead: &AirPos,
+) -> alloc::vec::Vec<ObjectEntry<'input>>
 {
-    FoldScalarIterable::CanonStream(CanonStream::new(canon_stream.0, canon_stream.1))
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action32<
+fn __action115<
     'err,
     'input,
     'v,
@@ -4933,15 +8616,14 @@ fn __action32<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-    (_, __1, _): (AirPos, Token<'input>, AirPos),
-) -> FoldScalarIterable<'input>
+    (_, v, _): (AirPos, alloc::vec::Vec<ObjectEntry<'input>>, AirPos),
+) -> alloc::vec::Vec<ObjectEntry<'input>>
 {
-    FoldScalarIterable::EmptyArray
+    v
 }
 
 #[allow(unused_variables)]
-fn __action33<
+fn __action116<
     'err,
     'input,
     'v,
@@ -4949,14 +8631,14 @@ fn __action33<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
-) -> ResolvableToPeerIdVariable<'input>
+    (_, __0, _): (AirPos, ObjectEntry<'input>, AirPos),
+) -> ObjectEntry<'input>
 {
     __0
 }
 
 #[allow(unused_variables)]
-fn __action34<
+fn __action117<
     'err,
     'input,
     'v,
@@ -4964,14 +8646,14 @@ fn __action34<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
-) -> ResolvableToStringVariable<'input>
+    (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
-    __0
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action35<
+fn __action118<
     'err,
     'input,
     'v,
@@ -4979,14 +8661,15 @@ fn __action35<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
-) -> ResolvableToStringVariable<'input>
+    (_, v, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
+    (_, e, _): (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
-    __0
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action36<
+fn __action119<
     'err,
     'input,
     'v,
@@ -4994,29 +8677,30 @@ fn __action36<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-) -> ResolvableToPeerIdVariable<'input>
+    (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
+) -> ImmutableValue<'input>
 {
-    ResolvableToPeerIdVariable::InitPeerId
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action37<
+fn __action120<
     'err,
     'input,
     'v,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
-    validator: &'v mut VariableValidator<'input>,
-    (_, literal, _): (AirPos, &'input str, AirPos),
-) -> ResolvableToPeerIdVariable<'input>
+    validator: &'v mut VariableValidator<'input>,
+    __lookbehind: &AirPos,
+    __lookahead: &AirPos,
+) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
-    ResolvableToPeerIdVariable::Literal(literal)
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action38<
+fn __action121<
     'err,
     'input,
     'v,
@@ -5024,14 +8708,14 @@ fn __action38<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> ResolvableToPeerIdVariable<'input>
+    (_, v, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
-    ResolvableToPeerIdVariable::Scalar(Scalar::new(scalar.0, scalar.1))
+    v
 }
 
 #[allow(unused_variables)]
-fn __action39<
+fn __action122<
     'err,
     'input,
     'v,
@@ -5039,14 +8723,14 @@ fn __action39<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> ResolvableToPeerIdVariable<'input>
+    (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
+) -> ImmutableValue<'input>
 {
-    ResolvableToPeerIdVariable::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action40<
+fn __action123<
     'err,
     'input,
     'v,
@@ -5054,14 +8738,14 @@ fn __action40<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> ResolvableToPeerIdVariable<'input>
+    (_, __0, _): (AirPos, u32, AirPos),
+) -> core::option::Option<u32>
 {
-    ResolvableToPeerIdVariable::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action41<
+fn __action124<
     'err,
     'input,
     'v,
@@ -5069,14 +8753,15 @@ fn __action41<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, literal, _): (AirPos, &'input str, AirPos),
-) -> ResolvableToStringVariable<'input>
+    __lookbehind: &AirPos,
+    __lookahead: &AirPos,
+) -> core::option::Option<u32>
 {
-    ResolvableToStringVariable::Literal(literal)
+    None
 }
 
 #[allow(unused_variables)]
-fn __action42<
+fn __action125<
     'err,
     'input,
     'v,
@@ -5084,14 +8769,14 @@ fn __action42<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> ResolvableToStringVariable<'input>
+    (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
+) -> core::option::Option<Box<Instruction<'input>>>
 {
-    ResolvableToStringVariable::Scalar(Scalar::new(scalar.0, scalar.1))
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action43<
+fn __action126<
     'err,
     'input,
     'v,
@@ -5099,14 +8784,15 @@ fn __action43<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> ResolvableToStringVariable<'input>
+    __lookbehind: &AirPos,
+    __lookahead: &AirPos,
+) -> core::option::Option<Box<Instruction<'input>>>
 {
-    ResolvableToStringVariable::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+    None
 }
 
 #[allow(unused_variables)]
-fn __action44<
+fn __action127<
     'err,
     'input,
     'v,
@@ -5114,14 +8800,14 @@ fn __action44<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> ResolvableToStringVariable<'input>
+    (_, __0, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> core::option::Option<(&'input str, AirPos)>
 {
-    ResolvableToStringVariable::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action45<
+fn __action128<
     'err,
     'input,
     'v,
@@ -5129,14 +8815,15 @@ fn __action45<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> NewArgument<'input>
+    __lookbehind: &AirPos,
+    __lookahead: &AirPos,
+) -> core::option::Option<(&'input str, AirPos)>
 {
-    NewArgument::Scalar(Scalar::new(scalar.0, scalar.1))
+    None
 }
 
 #[allow(unused_variables)]
-fn __action46<
+fn __action129<
     'err,
     'input,
     'v,
@@ -5144,14 +8831,15 @@ fn __action46<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> NewArgument<'input>
+    __lookbehind: &AirPos,
+    __lookahead: &AirPos,
+) -> AirPos
 {
-    NewArgument::Stream(Stream::new(stream.0, stream.1))
+    __lookbehind.clone()
 }
 
 #[allow(unused_variables)]
-fn __action47<
+fn __action130<
     'err,
     'input,
     'v,
@@ -5159,14 +8847,14 @@ fn __action47<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> NewArgument<'input>
+    (_, __0, _): (AirPos, CallOutputValue<'input>, AirPos),
+) -> core::option::Option<CallOutputValue<'input>>
 {
-    NewArgument::CanonStream(CanonStream::new(canon_stream.0, canon_stream.1))
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action48<
+fn __action131<
     'err,
     'input,
     'v,
@@ -5174,14 +8862,15 @@ fn __action48<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, integer, _): (AirPos, i64, AirPos),
-) -> Number
+    __lookbehind: &AirPos,
+    __lookahead: &AirPos,
+) -> core::option::Option<CallOutputValue<'input>>
 {
-    Number::Int(integer)
+    None
 }
 
 #[allow(unused_variables)]
-fn __action49<
+fn __action132<
     'err,
     'input,
     'v,
@@ -5189,14 +8878,15 @@ fn __action49<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, float, _): (AirPos, f64, AirPos),
-) -> Number
+    __lookbehind: &AirPos,
+    __lookahead: &AirPos,
+) -> AirPos
 {
-    Number::Float(float)
+    __lookahead.clone()
 }
 
 #[allow(unused_variables)]
-fn __action50<
+fn __action133<
     'err,
     'input,
     'v,
@@ -5205,13 +8895,13 @@ fn __action50<
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
     (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
-) -> ImmutableValue<'input>
+) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
-    __0
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action51<
+fn __action134<
     'err,
     'input,
     'v,
@@ -5219,14 +8909,15 @@ fn __action51<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-) -> ImmutableValue<'input>
+    (_, v, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
+    (_, e, _): (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
-    ImmutableValue::InitPeerId
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action52<
+fn __action135<
     'err,
     'input,
     'v,
@@ -5234,14 +8925,14 @@ fn __action52<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-) -> ImmutableValue<'input>
+    (_, __0, _): (AirPos, ObjectEntry<'input>, AirPos),
+) -> alloc::vec::Vec<ObjectEntry<'input>>
 {
-    ImmutableValue::LastError(None)
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action53<
+fn __action136<
     'err,
     'input,
     'v,
@@ -5249,14 +8940,15 @@ fn __action53<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, le, _): (AirPos, LambdaAST<'input>, AirPos),
-) -> ImmutableValue<'input>
+    (_, v, _): (AirPos, alloc::vec::Vec<ObjectEntry<'input>>, AirPos),
+    (_, e, _): (AirPos, ObjectEntry<'input>, AirPos),
+) -> alloc::vec::Vec<ObjectEntry<'input>>
 {
-    ImmutableValue::LastError(Some(le))
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action54<
+fn __action137<
     'err,
     'input,
     'v,
@@ -5264,14 +8956,28 @@ fn __action54<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, l, _): (AirPos, &'input str, AirPos),
-) -> ImmutableValue<'input>
+    __0: (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
-    ImmutableValue::Literal(l)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action122(
+        input,
+        errors,
+        validator,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action133(
+        input,
+        errors,
+        validator,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action55<
+fn __action138<
     'err,
     'input,
     'v,
@@ -5279,14 +8985,30 @@ fn __action55<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-) -> ImmutableValue<'input>
+    __0: (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
+    __1: (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
-    ImmutableValue::Timestamp
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action122(
+        input,
+        errors,
+        validator,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action56<
+fn __action139<
     'err,
     'input,
     'v,
@@ -5294,14 +9016,32 @@ fn __action56<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-) -> ImmutableValue<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+) -> Vec<ImmutableValue<'input>>
 {
-    ImmutableValue::TTL
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action120(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action57<
+fn __action140<
     'err,
     'input,
     'v,
@@ -5309,14 +9049,32 @@ fn __action57<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, n, _): (AirPos, Number, AirPos),
-) -> ImmutableValue<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
+) -> Vec<ImmutableValue<'input>>
 {
-    ImmutableValue::Number(n)
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action121(
+        input,
+        errors,
+        validator,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action58<
+fn __action141<
     'err,
     'input,
     'v,
@@ -5324,14 +9082,28 @@ fn __action58<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, b, _): (AirPos, bool, AirPos),
-) -> ImmutableValue<'input>
+    __0: (AirPos, ObjectEntry<'input>, AirPos),
+) -> alloc::vec::Vec<ObjectEntry<'input>>
 {
-    ImmutableValue::Boolean(b)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action116(
+        input,
+        errors,
+        validator,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action135(
+        input,
+        errors,
+        validator,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action59<
+fn __action142<
     'err,
     'input,
     'v,
@@ -5339,15 +9111,30 @@ fn __action59<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-    (_, __1, _): (AirPos, Token<'input>, AirPos),
-) -> ImmutableValue<'input>
+    __0: (AirPos, alloc::vec::Vec<ObjectEntry<'input>>, AirPos),
+    __1: (AirPos, ObjectEntry<'input>, AirPos),
+) -> alloc::vec::Vec<ObjectEntry<'input>>
 {
-    ImmutableValue::EmptyArray
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action116(
+        input,
+        errors,
+        validator,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action136(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action60<
+fn __action143<
     'err,
     'input,
     'v,
@@ -5355,14 +9142,32 @@ fn __action60<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> ImmutableValue<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+) -> ApArgument<'input>
 {
-    ImmutableValue::Variable(ImmutableVariable::scalar(scalar.0, scalar.1))
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action114(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action103(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action61<
+fn __action144<
     'err,
     'input,
     'v,
@@ -5370,14 +9175,32 @@ fn __action61<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> ImmutableValue<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, alloc::vec::Vec<ObjectEntry<'input>>, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
+) -> ApArgument<'input>
 {
-    ImmutableValue::VariableWithLambda(ImmutableVariableWithLambda::scalar(scalar.0, scalar.1, scalar.2))
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action115(
+        input,
+        errors,
+        validator,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action103(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action62<
+fn __action145<
     'err,
     'input,
     'v,
@@ -5385,14 +9208,32 @@ fn __action62<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
 ) -> ImmutableValue<'input>
 {
-    ImmutableValue::Variable(ImmutableVariable::canon_stream(canon_stream.0, canon_stream.1))
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action114(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action82(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action63<
+fn __action146<
     'err,
     'input,
     'v,
@@ -5400,14 +9241,32 @@ fn __action63<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, alloc::vec::Vec<ObjectEntry<'input>>, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
 ) -> ImmutableValue<'input>
 {
-    ImmutableValue::VariableWithLambda(ImmutableVariableWithLambda::canon_stream(canon_stream.0, canon_stream.1, canon_stream.2))
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action115(
+        input,
+        errors,
+        validator,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action82(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action64<
+fn __action147<
     'err,
     'input,
     'v,
@@ -5415,14 +9274,28 @@ fn __action64<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
-    ApArgument::InitPeerId
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action119(
+        input,
+        errors,
+        validator,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action117(
+        input,
+        errors,
+        validator,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action65<
+fn __action148<
     'err,
     'input,
     'v,
@@ -5430,14 +9303,30 @@ fn __action65<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
+    __1: (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
-    ApArgument::LastError(None)
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action119(
+        input,
+        errors,
+        validator,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action118(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action66<
+fn __action149<
     'err,
     'input,
     'v,
@@ -5445,14 +9334,32 @@ fn __action66<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, le, _): (AirPos, LambdaAST<'input>, AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, AirPos, AirPos),
+) -> Fail<'input>
 {
-    ApArgument::LastError(Some(le))
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action37(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action67<
+fn __action150<
     'err,
     'input,
     'v,
@@ -5460,14 +9367,32 @@ fn __action67<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, i64, AirPos),
+    __1: (AirPos, AirPos, AirPos),
+) -> Result<u32,__lalrpop_util::ParseError<AirPos,Token<'input>,ParserError>>
 {
-    ApArgument::Timestamp
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action39(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action68<
+fn __action151<
     'err,
     'input,
     'v,
@@ -5475,14 +9400,42 @@ fn __action68<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Triplet<'input>, AirPos),
+    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
+    __4: (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    ApArgument::TTL
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action69<
+fn __action152<
     'err,
     'input,
     'v,
@@ -5490,14 +9443,42 @@ fn __action69<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, l, _): (AirPos, &'input str, AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    __3: (AirPos, Stream<'input>, AirPos),
+    __4: (AirPos, CanonStream<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    ApArgument::Literal(l)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action70<
+fn __action153<
     'err,
     'input,
     'v,
@@ -5505,14 +9486,42 @@ fn __action70<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, n, _): (AirPos, Number, AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    __3: (AirPos, StreamMap<'input>, AirPos),
+    __4: (AirPos, CanonStreamMap<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    ApArgument::Number(n)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action71<
+fn __action154<
     'err,
     'input,
     'v,
@@ -5520,14 +9529,40 @@ fn __action71<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, b, _): (AirPos, bool, AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ApArgument<'input>, AirPos),
+    __3: (AirPos, ApResult<'input>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
+    __5: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    ApArgument::Boolean(b)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action72<
+fn __action155<
     'err,
     'input,
     'v,
@@ -5535,15 +9570,46 @@ fn __action72<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-    (_, __1, _): (AirPos, Token<'input>, AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
+    __3: (AirPos, StreamMapKeyClause<'input>, AirPos),
+    __4: (AirPos, ApArgument<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, StreamMap<'input>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
+    __8: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    ApArgument::EmptyArray
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action73<
+fn __action156<
     'err,
     'input,
     'v,
@@ -5551,14 +9617,40 @@ fn __action73<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, NewArgument<'input>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
+    __5: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    ApArgument::Scalar(Scalar::new(scalar.0, scalar.1))
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action74<
+fn __action157<
     'err,
     'input,
     'v,
@@ -5566,14 +9658,46 @@ fn __action74<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
+    __8: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    ApArgument::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action75<
+fn __action158<
     'err,
     'input,
     'v,
@@ -5581,14 +9705,48 @@ fn __action75<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, core::option::Option<u32>, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __8: (AirPos, Token<'input>, AirPos),
+    __9: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    ApArgument::CanonStream(CanonStream::new(canon_stream.0, canon_stream.1))
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action76<
+fn __action159<
     'err,
     'input,
     'v,
@@ -5596,14 +9754,38 @@ fn __action76<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> ApArgument<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, Token<'input>, AirPos),
+    __4: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    ApArgument::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action15(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action77<
+fn __action160<
     'err,
     'input,
     'v,
@@ -5611,14 +9793,42 @@ fn __action77<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> Stream<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    Stream::new(stream.0, stream.1)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action78<
+fn __action161<
     'err,
     'input,
     'v,
@@ -5626,14 +9836,42 @@ fn __action78<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> CanonStream<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    CanonStream::new(canon_stream.0, canon_stream.1)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action79<
+fn __action162<
     'err,
     'input,
     'v,
@@ -5641,15 +9879,42 @@ fn __action79<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __lookbehind: &AirPos,
-    __lookahead: &AirPos,
-) -> alloc::vec::Vec<ImmutableValue<'input>>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, ComparisonOperator, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action80<
+fn __action163<
     'err,
     'input,
     'v,
@@ -5657,14 +9922,42 @@ fn __action80<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, v, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
-) -> alloc::vec::Vec<ImmutableValue<'input>>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action81<
+fn __action164<
     'err,
     'input,
     'v,
@@ -5672,14 +9965,40 @@ fn __action81<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
-) -> ImmutableValue<'input>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
+    __5: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action132(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action82<
+fn __action165<
     'err,
     'input,
     'v,
@@ -5687,14 +10006,30 @@ fn __action82<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
-) -> core::option::Option<Box<Instruction<'input>>>
+    __0: (AirPos, Token<'input>, AirPos),
+) -> Fail<'input>
 {
-    Some(__0)
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action129(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action149(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action83<
+fn __action166<
     'err,
     'input,
     'v,
@@ -5702,15 +10037,30 @@ fn __action83<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __lookbehind: &AirPos,
-    __lookahead: &AirPos,
-) -> core::option::Option<Box<Instruction<'input>>>
+    __0: (AirPos, i64, AirPos),
+) -> Result<u32,__lalrpop_util::ParseError<AirPos,Token<'input>,ParserError>>
 {
-    None
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action129(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action150(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action84<
+fn __action167<
     'err,
     'input,
     'v,
@@ -5718,15 +10068,40 @@ fn __action84<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __lookbehind: &AirPos,
-    __lookahead: &AirPos,
-) -> AirPos
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Triplet<'input>, AirPos),
+    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
+    __4: (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    __lookbehind.clone()
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action129(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action151(
+        input,
+        errors,
+        validator,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action85<
+fn __action168<
     'err,
     'input,
     'v,
@@ -5734,14 +10109,40 @@ fn __action85<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, CallOutputValue<'input>, AirPos),
-) -> core::option::Option<CallOutputValue<'input>>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    __3: (AirPos, Stream<'input>, AirPos),
+    __4: (AirPos, CanonStream<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    Some(__0)
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action129(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action152(
+        input,
+        errors,
+        validator,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action86<
+fn __action169<
     'err,
     'input,
     'v,
@@ -5749,15 +10150,40 @@ fn __action86<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __lookbehind: &AirPos,
-    __lookahead: &AirPos,
-) -> core::option::Option<CallOutputValue<'input>>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    __3: (AirPos, StreamMap<'input>, AirPos),
+    __4: (AirPos, CanonStreamMap<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    None
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action129(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action153(
+        input,
+        errors,
+        validator,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action87<
+fn __action170<
     'err,
     'input,
     'v,
@@ -5765,15 +10191,38 @@ fn __action87<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __lookbehind: &AirPos,
-    __lookahead: &AirPos,
-) -> AirPos
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ApArgument<'input>, AirPos),
+    __3: (AirPos, ApResult<'input>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    __lookahead.clone()
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action129(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action154(
+        input,
+        errors,
+        validator,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action88<
+fn __action171<
     'err,
     'input,
     'v,
@@ -5781,14 +10230,44 @@ fn __action88<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
-) -> alloc::vec::Vec<ImmutableValue<'input>>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
+    __3: (AirPos, StreamMapKeyClause<'input>, AirPos),
+    __4: (AirPos, ApArgument<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, StreamMap<'input>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    alloc::vec![__0]
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action129(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action155(
+        input,
+        errors,
+        validator,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action89<
+fn __action172<
     'err,
     'input,
     'v,
@@ -5796,15 +10275,38 @@ fn __action89<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, v, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
-    (_, e, _): (AirPos, ImmutableValue<'input>, AirPos),
-) -> alloc::vec::Vec<ImmutableValue<'input>>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, NewArgument<'input>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action129(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action156(
+        input,
+        errors,
+        validator,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action90<
+fn __action173<
     'err,
     'input,
     'v,
@@ -5812,28 +10314,44 @@ fn __action90<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __0: (AirPos, ImmutableValue<'input>, AirPos),
-) -> alloc::vec::Vec<ImmutableValue<'input>>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action81(
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action129(
         input,
         errors,
         validator,
-        __0,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action88(
+    __action157(
         input,
         errors,
         validator,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action91<
+fn __action174<
     'err,
     'input,
     'v,
@@ -5841,30 +10359,46 @@ fn __action91<
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __0: (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
-    __1: (AirPos, ImmutableValue<'input>, AirPos),
-) -> alloc::vec::Vec<ImmutableValue<'input>>
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, core::option::Option<u32>, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __8: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action81(
+    let __start0 = __8.2.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action129(
         input,
         errors,
         validator,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action89(
+    __action158(
         input,
         errors,
         validator,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action92<
+fn __action175<
     'err,
     'input,
     'v,
@@ -5874,11 +10408,13 @@ fn __action92<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-) -> Vec<ImmutableValue<'input>>
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action79(
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action129(
         input,
         errors,
         validator,
@@ -5886,18 +10422,20 @@ fn __action92<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action159(
         input,
         errors,
         validator,
         __0,
-        __temp0,
         __1,
+        __2,
+        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action93<
+fn __action176<
     'err,
     'input,
     'v,
@@ -5906,31 +10444,39 @@ fn __action93<
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
-    __1: (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
-    __2: (AirPos, Token<'input>, AirPos),
-) -> Vec<ImmutableValue<'input>>
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action80(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action129(
         input,
         errors,
         validator,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action160(
         input,
         errors,
         validator,
         __0,
-        __temp0,
+        __1,
         __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action94<
+fn __action177<
     'err,
     'input,
     'v,
@@ -5939,12 +10485,16 @@ fn __action94<
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
-    __1: (AirPos, AirPos, AirPos),
-) -> Fail<'input>
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action129(
         input,
         errors,
         validator,
@@ -5952,18 +10502,22 @@ fn __action94<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action28(
+    __action161(
         input,
         errors,
         validator,
-        __temp0,
         __0,
         __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action95<
+fn __action178<
     'err,
     'input,
     'v,
@@ -5972,17 +10526,16 @@ fn __action95<
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
-    __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, Triplet<'input>, AirPos),
-    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
-    __4: (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
+    __1: (AirPos, ComparisonOperator, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
     __5: (AirPos, Token<'input>, AirPos),
-    __6: (AirPos, AirPos, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action129(
         input,
         errors,
         validator,
@@ -5990,23 +10543,22 @@ fn __action95<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action2(
+    __action162(
         input,
         errors,
         validator,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
         __5,
-        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action96<
+fn __action179<
     'err,
     'input,
     'v,
@@ -6016,16 +10568,15 @@ fn __action96<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
-    __3: (AirPos, Stream<'input>, AirPos),
-    __4: (AirPos, CanonStream<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
     __5: (AirPos, Token<'input>, AirPos),
-    __6: (AirPos, AirPos, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action129(
         input,
         errors,
         validator,
@@ -6033,23 +10584,22 @@ fn __action96<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(
+    __action163(
         input,
         errors,
         validator,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
         __5,
-        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action97<
+fn __action180<
     'err,
     'input,
     'v,
@@ -6059,15 +10609,14 @@ fn __action97<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ApArgument<'input>, AirPos),
-    __3: (AirPos, ApResult<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
     __4: (AirPos, Token<'input>, AirPos),
-    __5: (AirPos, AirPos, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action129(
         input,
         errors,
         validator,
@@ -6075,22 +10624,21 @@ fn __action97<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
+    __action164(
         input,
         errors,
         validator,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
-        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action98<
+fn __action181<
     'err,
     'input,
     'v,
@@ -6100,38 +10648,36 @@ fn __action98<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, NewArgument<'input>, AirPos),
-    __3: (AirPos, Box<Instruction<'input>>, AirPos),
-    __4: (AirPos, Token<'input>, AirPos),
-    __5: (AirPos, AirPos, AirPos),
+    __2: (AirPos, Triplet<'input>, AirPos),
+    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
+    __4: (AirPos, CallOutputValue<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action130(
         input,
         errors,
         validator,
-        &__start0,
-        &__end0,
+        __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action9(
+    __action167(
         input,
         errors,
         validator,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
-        __4,
+        __temp0,
         __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action99<
+fn __action182<
     'err,
     'input,
     'v,
@@ -6141,17 +10687,14 @@ fn __action99<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
-    __3: (AirPos, (&'input str, AirPos), AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
-    __6: (AirPos, Token<'input>, AirPos),
-    __7: (AirPos, AirPos, AirPos),
+    __2: (AirPos, Triplet<'input>, AirPos),
+    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action131(
         input,
         errors,
         validator,
@@ -6159,24 +10702,21 @@ fn __action99<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action11(
+    __action167(
         input,
         errors,
         validator,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
+        __temp0,
         __4,
-        __5,
-        __6,
-        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action100<
+fn __action183<
     'err,
     'input,
     'v,
@@ -6188,40 +10728,40 @@ fn __action100<
     __1: (AirPos, Token<'input>, AirPos),
     __2: (AirPos, (&'input str, AirPos), AirPos),
     __3: (AirPos, (&'input str, AirPos), AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
-    __6: (AirPos, Token<'input>, AirPos),
-    __7: (AirPos, AirPos, AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, u32, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __8: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __5.0.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action123(
         input,
         errors,
         validator,
-        &__start0,
-        &__end0,
+        __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action12(
+    __action174(
         input,
         errors,
         validator,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
-        __5,
+        __temp0,
         __6,
         __7,
+        __8,
     )
 }
 
 #[allow(unused_variables)]
-fn __action101<
+fn __action184<
     'err,
     'input,
     'v,
@@ -6232,13 +10772,16 @@ fn __action101<
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
     __2: (AirPos, (&'input str, AirPos), AirPos),
-    __3: (AirPos, Token<'input>, AirPos),
-    __4: (AirPos, AirPos, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __4.2.clone();
+    let __end0 = __5.0.clone();
+    let __temp0 = __action124(
         input,
         errors,
         validator,
@@ -6246,21 +10789,24 @@ fn __action101<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action13(
+    __action174(
         input,
         errors,
         validator,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __temp0,
+        __5,
+        __6,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action102<
+fn __action185<
     'err,
     'input,
     'v,
@@ -6270,40 +10816,40 @@ fn __action102<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ImmutableValue<'input>, AirPos),
-    __3: (AirPos, ImmutableValue<'input>, AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
-    __6: (AirPos, AirPos, AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __6.0.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action125(
         input,
         errors,
         validator,
-        &__start0,
-        &__end0,
+        __6,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action15(
+    __action173(
         input,
         errors,
         validator,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
         __5,
-        __6,
+        __temp0,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action103<
+fn __action186<
     'err,
     'input,
     'v,
@@ -6313,16 +10859,16 @@ fn __action103<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ImmutableValue<'input>, AirPos),
-    __3: (AirPos, ImmutableValue<'input>, AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
-    __6: (AirPos, AirPos, AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __5.2.clone();
+    let __end0 = __6.0.clone();
+    let __temp0 = __action126(
         input,
         errors,
         validator,
@@ -6330,23 +10876,23 @@ fn __action103<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action16(
+    __action173(
         input,
         errors,
         validator,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
         __5,
+        __temp0,
         __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action104<
+fn __action187<
     'err,
     'input,
     'v,
@@ -6355,29 +10901,43 @@ fn __action104<
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
-) -> Fail<'input>
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, u32, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, Box<Instruction<'input>>, AirPos),
+    __8: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __7.0.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action125(
         input,
         errors,
         validator,
-        &__start0,
-        &__end0,
+        __7,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action94(
+    __action183(
         input,
         errors,
         validator,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
         __temp0,
+        __8,
     )
 }
 
 #[allow(unused_variables)]
-fn __action105<
+fn __action188<
     'err,
     'input,
     'v,
@@ -6387,15 +10947,17 @@ fn __action105<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, Triplet<'input>, AirPos),
-    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
-    __4: (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, u32, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __6.2.clone();
+    let __end0 = __7.0.clone();
+    let __temp0 = __action126(
         input,
         errors,
         validator,
@@ -6403,7 +10965,7 @@ fn __action105<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action95(
+    __action183(
         input,
         errors,
         validator,
@@ -6413,12 +10975,14 @@ fn __action105<
         __3,
         __4,
         __5,
+        __6,
         __temp0,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action106<
+fn __action189<
     'err,
     'input,
     'v,
@@ -6428,23 +10992,24 @@ fn __action106<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
-    __3: (AirPos, Stream<'input>, AirPos),
-    __4: (AirPos, CanonStream<'input>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __6.0.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action125(
         input,
         errors,
         validator,
-        &__start0,
-        &__end0,
+        __6,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action96(
+    __action184(
         input,
         errors,
         validator,
@@ -6455,11 +11020,12 @@ fn __action106<
         __4,
         __5,
         __temp0,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action107<
+fn __action190<
     'err,
     'input,
     'v,
@@ -6469,14 +11035,16 @@ fn __action107<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ApArgument<'input>, AirPos),
-    __3: (AirPos, ApResult<'input>, AirPos),
-    __4: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, core::option::Option<(&'input str, AirPos)>, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __4.2.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __5.2.clone();
+    let __end0 = __6.0.clone();
+    let __temp0 = __action126(
         input,
         errors,
         validator,
@@ -6484,7 +11052,7 @@ fn __action107<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action97(
+    __action184(
         input,
         errors,
         validator,
@@ -6493,12 +11061,14 @@ fn __action107<
         __2,
         __3,
         __4,
+        __5,
         __temp0,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action108<
+fn __action191<
     'err,
     'input,
     'v,
@@ -6508,22 +11078,24 @@ fn __action108<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, NewArgument<'input>, AirPos),
-    __3: (AirPos, Box<Instruction<'input>>, AirPos),
-    __4: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, (&'input str, AirPos), AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __4.2.clone();
+    let __start0 = __4.0.clone();
     let __end0 = __4.2.clone();
-    let __temp0 = __action84(
+    let __temp0 = __action127(
         input,
         errors,
         validator,
-        &__start0,
-        &__end0,
+        __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action98(
+    __action185(
         input,
         errors,
         validator,
@@ -6531,13 +11103,15 @@ fn __action108<
         __1,
         __2,
         __3,
-        __4,
         __temp0,
+        __5,
+        __6,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action109<
+fn __action192<
     'err,
     'input,
     'v,
@@ -6550,13 +11124,13 @@ fn __action109<
     __2: (AirPos, FoldScalarIterable<'input>, AirPos),
     __3: (AirPos, (&'input str, AirPos), AirPos),
     __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
     __6: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action128(
         input,
         errors,
         validator,
@@ -6564,7 +11138,7 @@ fn __action109<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action99(
+    __action185(
         input,
         errors,
         validator,
@@ -6572,15 +11146,15 @@ fn __action109<
         __1,
         __2,
         __3,
+        __temp0,
         __4,
         __5,
         __6,
-        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action110<
+fn __action193<
     'err,
     'input,
     'v,
@@ -6590,24 +11164,23 @@ fn __action110<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
     __3: (AirPos, (&'input str, AirPos), AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __4: (AirPos, (&'input str, AirPos), AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
     __6: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action127(
         input,
         errors,
         validator,
-        &__start0,
-        &__end0,
+        __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action100(
+    __action186(
         input,
         errors,
         validator,
@@ -6615,15 +11188,14 @@ fn __action110<
         __1,
         __2,
         __3,
-        __4,
+        __temp0,
         __5,
         __6,
-        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action111<
+fn __action194<
     'err,
     'input,
     'v,
@@ -6633,13 +11205,15 @@ fn __action111<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, (&'input str, AirPos), AirPos),
-    __3: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
     let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action84(
+    let __end0 = __4.0.clone();
+    let __temp0 = __action128(
         input,
         errors,
         validator,
@@ -6647,7 +11221,7 @@ fn __action111<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action101(
+    __action186(
         input,
         errors,
         validator,
@@ -6656,11 +11230,13 @@ fn __action111<
         __2,
         __3,
         __temp0,
+        __4,
+        __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action112<
+fn __action195<
     'err,
     'input,
     'v,
@@ -6670,23 +11246,25 @@ fn __action112<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ImmutableValue<'input>, AirPos),
-    __3: (AirPos, ImmutableValue<'input>, AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, (&'input str, AirPos), AirPos),
+    __5: (AirPos, u32, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, Box<Instruction<'input>>, AirPos),
+    __8: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action127(
         input,
         errors,
         validator,
-        &__start0,
-        &__end0,
+        __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action102(
+    __action187(
         input,
         errors,
         validator,
@@ -6694,14 +11272,16 @@ fn __action112<
         __1,
         __2,
         __3,
-        __4,
-        __5,
         __temp0,
+        __5,
+        __6,
+        __7,
+        __8,
     )
 }
 
 #[allow(unused_variables)]
-fn __action113<
+fn __action196<
     'err,
     'input,
     'v,
@@ -6711,15 +11291,17 @@ fn __action113<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ImmutableValue<'input>, AirPos),
-    __3: (AirPos, ImmutableValue<'input>, AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, u32, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action128(
         input,
         errors,
         validator,
@@ -6727,7 +11309,7 @@ fn __action113<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action103(
+    __action187(
         input,
         errors,
         validator,
@@ -6735,14 +11317,16 @@ fn __action113<
         __1,
         __2,
         __3,
+        __temp0,
         __4,
         __5,
-        __temp0,
+        __6,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action114<
+fn __action197<
     'err,
     'input,
     'v,
@@ -6752,22 +11336,24 @@ fn __action114<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, Triplet<'input>, AirPos),
-    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
-    __4: (AirPos, CallOutputValue<'input>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, (&'input str, AirPos), AirPos),
+    __5: (AirPos, u32, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
     let __start0 = __4.0.clone();
     let __end0 = __4.2.clone();
-    let __temp0 = __action85(
+    let __temp0 = __action127(
         input,
         errors,
         validator,
         __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action105(
+    __action188(
         input,
         errors,
         validator,
@@ -6777,11 +11363,13 @@ fn __action114<
         __3,
         __temp0,
         __5,
+        __6,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action115<
+fn __action198<
     'err,
     'input,
     'v,
@@ -6791,14 +11379,16 @@ fn __action115<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, Triplet<'input>, AirPos),
-    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
-    __4: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, u32, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
     let __start0 = __3.2.clone();
     let __end0 = __4.0.clone();
-    let __temp0 = __action86(
+    let __temp0 = __action128(
         input,
         errors,
         validator,
@@ -6806,7 +11396,7 @@ fn __action115<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action105(
+    __action188(
         input,
         errors,
         validator,
@@ -6816,11 +11406,13 @@ fn __action115<
         __3,
         __temp0,
         __4,
+        __5,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action116<
+fn __action199<
     'err,
     'input,
     'v,
@@ -6830,23 +11422,24 @@ fn __action116<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
     __3: (AirPos, (&'input str, AirPos), AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __4: (AirPos, (&'input str, AirPos), AirPos),
     __5: (AirPos, Box<Instruction<'input>>, AirPos),
-    __6: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, Box<Instruction<'input>>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __5.0.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action82(
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action127(
         input,
         errors,
         validator,
-        __5,
+        __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action109(
+    __action189(
         input,
         errors,
         validator,
@@ -6854,14 +11447,15 @@ fn __action116<
         __1,
         __2,
         __3,
-        __4,
         __temp0,
+        __5,
         __6,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action117<
+fn __action200<
     'err,
     'input,
     'v,
@@ -6871,15 +11465,16 @@ fn __action117<
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
     __3: (AirPos, (&'input str, AirPos), AirPos),
     __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __4.2.clone();
-    let __end0 = __5.0.clone();
-    let __temp0 = __action83(
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action128(
         input,
         errors,
         validator,
@@ -6887,7 +11482,7 @@ fn __action117<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action109(
+    __action189(
         input,
         errors,
         validator,
@@ -6895,14 +11490,15 @@ fn __action117<
         __1,
         __2,
         __3,
-        __4,
         __temp0,
+        __4,
         __5,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action118<
+fn __action201<
     'err,
     'input,
     'v,
@@ -6914,21 +11510,21 @@ fn __action118<
     __1: (AirPos, Token<'input>, AirPos),
     __2: (AirPos, (&'input str, AirPos), AirPos),
     __3: (AirPos, (&'input str, AirPos), AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __4: (AirPos, (&'input str, AirPos), AirPos),
     __5: (AirPos, Box<Instruction<'input>>, AirPos),
     __6: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __5.0.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action82(
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action127(
         input,
         errors,
         validator,
-        __5,
+        __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action110(
+    __action190(
         input,
         errors,
         validator,
@@ -6936,14 +11532,14 @@ fn __action118<
         __1,
         __2,
         __3,
-        __4,
         __temp0,
+        __5,
         __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action119<
+fn __action202<
     'err,
     'input,
     'v,
@@ -6959,9 +11555,9 @@ fn __action119<
     __5: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __4.2.clone();
-    let __end0 = __5.0.clone();
-    let __temp0 = __action83(
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action128(
         input,
         errors,
         validator,
@@ -6969,7 +11565,7 @@ fn __action119<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action110(
+    __action190(
         input,
         errors,
         validator,
@@ -6977,8 +11573,8 @@ fn __action119<
         __1,
         __2,
         __3,
-        __4,
         __temp0,
+        __4,
         __5,
     )
 }
@@ -46,6 +46,9 @@ pub enum ParserError {
         "multiple next instructions for iterator '{iterator_name}' found for one fold, that is prohibited"
     )]
     MultipleNextInFold { span: Span, iterator_name: String },
+
+    #[error("fold iterations limit must be a positive 32-bit integer, but got {limit}")]
+    InvalidFoldIterationsLimit { span: Span, limit: i64 },
 }
 
 impl ParserError {
@@ -59,6 +62,7 @@ impl ParserError {
             Self::IteratorRestrictionNotAllowed { span, .. } => *span,
             Self::MultipleIterableValuesForOneIterator { span, .. } => *span,
             Self::MultipleNextInFold { span, .. } => *span,
+            Self::InvalidFoldIterationsLimit { span, .. } => *span,
         }
     }
 
@@ -96,6 +100,10 @@ impl ParserError {
             iterator_name: iterator_name.into(),
         }
     }
+
+    pub fn invalid_fold_iterations_limit(span: Span, limit: i64) -> Self {
+        Self::InvalidFoldIterationsLimit { span, limit }
+    }
 }
 
 impl From<std::convert::Infallible> for ParserError {
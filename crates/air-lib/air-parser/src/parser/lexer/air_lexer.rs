@@ -54,6 +54,10 @@ impl<'input> AIRLexer<'input> {
                 '[' => return Some(Ok((start_pos, Token::OpenSquareBracket, start_pos + 1))),
                 ']' => return Some(Ok((start_pos, Token::CloseSquareBracket, start_pos + 1))),
 
+                '{' => return Some(Ok((start_pos, Token::OpenCurlyBracket, start_pos + 1))),
+                '}' => return Some(Ok((start_pos, Token::CloseCurlyBracket, start_pos + 1))),
+                ':' => return Some(Ok((start_pos, Token::Colon, start_pos + 1))),
+
                 ';' => self.skip_comment(),
 
                 ch if ch.is_whitespace() => {}
@@ -125,19 +129,25 @@ impl<'input> AIRLexer<'input> {
         let mut end_pos = start_pos;
         let mut round_brackets_balance: i64 = 0;
         let mut square_brackets_balance = i64::from(square_met);
+        // brackets inside string literals of lambda filters, e.g. [?field == "a]b"], are not counted
+        let mut inside_string_literal = false;
 
         while let Some((pos, ch)) = self.chars.peek() {
             end_pos = (*pos).into();
             let ch = *ch;
 
-            update_brackets_count(
-                ch,
-                &mut round_brackets_balance,
-                &mut square_brackets_balance,
-            );
-
-            if should_stop(ch, round_brackets_balance, square_brackets_balance) {
-                break;
+            if ch == '"' && square_brackets_balance > 0 {
+                inside_string_literal = !inside_string_literal;
+            } else if !inside_string_literal {
+                update_brackets_count(
+                    ch,
+                    &mut round_brackets_balance,
+                    &mut square_brackets_balance,
+                );
+
+                if should_stop(ch, round_brackets_balance, square_brackets_balance) {
+                    break;
+                }
             }
 
             self.chars.next();
@@ -172,7 +182,11 @@ fn update_brackets_count(
 }
 
 fn should_stop(ch: char, round_brackets_balance: i64, open_square_brackets_balance: i64) -> bool {
-    ch.is_whitespace() || round_brackets_balance < 0 || open_square_brackets_balance < 0
+    // whitespaces are allowed inside square brackets of lambda, e.g. in [?field == "value"]
+    let whitespace_met = ch.is_whitespace() && open_square_brackets_balance == 0;
+
+    // a closing curly bracket can't be a part of a token, so it always ends an object literal
+    whitespace_met || ch == '}' || round_brackets_balance < 0 || open_square_brackets_balance < 0
 }
 
 fn string_to_token(input: &str, start_pos: AirPos) -> LexerResult<Token> {
@@ -193,11 +207,19 @@ fn string_to_token(input: &str, start_pos: AirPos) -> LexerResult<Token> {
         NULL_INSTR => Ok(Token::Null),
         MATCH_INSTR => Ok(Token::Match),
         MISMATCH_INSTR => Ok(Token::MisMatch),
+        LT_INSTR => Ok(Token::Lt),
+        LTE_INSTR => Ok(Token::Lte),
+        GT_INSTR => Ok(Token::Gt),
+        GTE_INSTR => Ok(Token::Gte),
+        MEMBER_INSTR => Ok(Token::Member),
+        TTL_GUARD_INSTR => Ok(Token::TtlGuard),
 
         INIT_PEER_ID => Ok(Token::InitPeerId),
         _ if input.starts_with(LAST_ERROR) => parse_last_error(input, start_pos),
+        _ if input.starts_with(ERROR) => parse_error(input, start_pos),
         TIMESTAMP => Ok(Token::Timestamp),
         TTL => Ok(Token::TTL),
+        PARTICLE_ID => Ok(Token::ParticleId),
 
         TRUE_VALUE => Ok(Token::Boolean(true)),
         FALSE_VALUE => Ok(Token::Boolean(false)),
@@ -230,6 +252,22 @@ fn parse_last_error(input: &str, start_pos: AirPos) -> LexerResult<Token<'_>> {
     Ok(last_error_token)
 }
 
+fn parse_error(input: &str, start_pos: AirPos) -> LexerResult<Token<'_>> {
+    let error_size = ERROR.len();
+    if input.len() == error_size {
+        return Ok(Token::Error);
+    }
+
+    let error_accessor = crate::parse_lambda(&input[error_size..]).map_err(|e| {
+        LexerError::lambda_parser_error(
+            start_pos + error_size..start_pos + input.len(),
+            e.to_string(),
+        )
+    })?;
+
+    Ok(Token::ErrorWithLambda(error_accessor))
+}
+
 const CALL_INSTR: &str = "call";
 const CANON_INSTR: &str = "canon";
 const AP_INSTR: &str = "ap";
@@ -244,11 +282,19 @@ const NEXT_INSTR: &str = "next";
 const NULL_INSTR: &str = "null";
 const MATCH_INSTR: &str = "match";
 const MISMATCH_INSTR: &str = "mismatch";
+const LT_INSTR: &str = "lt";
+const LTE_INSTR: &str = "lte";
+const GT_INSTR: &str = "gt";
+const GTE_INSTR: &str = "gte";
+const MEMBER_INSTR: &str = "member";
+const TTL_GUARD_INSTR: &str = "ttl_guard";
 
 const INIT_PEER_ID: &str = "%init_peer_id%";
 const LAST_ERROR: &str = "%last_error%";
+const ERROR: &str = "%error%";
 const TIMESTAMP: &str = "%timestamp%";
 const TTL: &str = "%ttl%";
+const PARTICLE_ID: &str = "%particle_id%";
 
 const TRUE_VALUE: &str = "true";
 const FALSE_VALUE: &str = "false";
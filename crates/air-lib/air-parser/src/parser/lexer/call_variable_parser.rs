@@ -35,6 +35,8 @@ enum MetTag {
     None,
     Stream,
     CanonStream,
+    StreamMap,
+    CanonStreamMap,
 }
 
 #[derive(Debug)]
@@ -43,6 +45,7 @@ struct ParserState {
     pub(self) non_numeric_met: bool,
     pub(self) digit_met: bool,
     pub(self) flattening_met: bool,
+    pub(self) string_literal_met: bool,
     pub(self) met_tag: MetTag,
     pub(self) is_first_char: bool,
     pub(self) current_char: char,
@@ -69,6 +72,7 @@ impl<'input> CallVariableParser<'input> {
             non_numeric_met: false,
             digit_met: false,
             flattening_met: false,
+            string_literal_met: false,
             is_first_char: true,
             met_tag: MetTag::None,
             current_char,
@@ -186,6 +190,10 @@ impl<'input> CallVariableParser<'input> {
     }
 
     fn try_parse_as_stream_start(&mut self) -> LexerResult<bool> {
+        if self.try_parse_as_canon_stream_map_start()? {
+            return Ok(true);
+        }
+
         let stream_tag = MetTag::from_tag(self.current_char());
         if self.current_offset() == 0 && stream_tag.is_tag() {
             if self.string_to_parse.len() == 1 {
@@ -200,6 +208,24 @@ impl<'input> CallVariableParser<'input> {
         Ok(false)
     }
 
+    // canon stream maps have a two-char tag #%, so its second char is checked here
+    fn try_parse_as_canon_stream_map_start(&mut self) -> LexerResult<bool> {
+        let is_map_tag = self.current_offset() == 1
+            && self.current_char() == '%'
+            && matches!(self.state.met_tag, MetTag::CanonStream);
+        if !is_map_tag {
+            return Ok(false);
+        }
+
+        if self.string_to_parse.len() == 2 {
+            let error_pos = self.pos_in_string_to_parse();
+            return Err(LexerError::empty_stream_name(error_pos..error_pos));
+        }
+
+        self.state.met_tag = MetTag::CanonStreamMap;
+        Ok(true)
+    }
+
     fn try_parse_as_json_path_start(&mut self) -> LexerResult<bool> {
         self.try_parse_first_met_dot()
     }
@@ -214,6 +240,14 @@ impl<'input> CallVariableParser<'input> {
     }
 
     fn try_parse_as_json_path(&mut self) -> LexerResult<()> {
+        // string literals of filter predicates could contain any chars
+        if self.current_char() == '"' {
+            self.state.string_literal_met = !self.state.string_literal_met;
+            return Ok(());
+        } else if self.state.string_literal_met {
+            return Ok(());
+        }
+
         if !self.json_path_allowed_char() && !self.try_parse_as_flattening() {
             let error_pos = self.pos_in_string_to_parse();
             return Err(LexerError::invalid_lambda(error_pos..error_pos));
@@ -291,6 +325,14 @@ impl<'input> CallVariableParser<'input> {
                 name,
                 position: self.start_pos,
             },
+            MetTag::StreamMap => Token::StreamMap {
+                name,
+                position: self.start_pos,
+            },
+            MetTag::CanonStreamMap => Token::CanonStreamMap {
+                name,
+                position: self.start_pos,
+            },
         }
     }
 
@@ -311,6 +353,16 @@ impl<'input> CallVariableParser<'input> {
                 lambda,
                 position: self.start_pos,
             },
+            MetTag::StreamMap => Token::StreamMapWithLambda {
+                name,
+                lambda,
+                position: self.start_pos,
+            },
+            MetTag::CanonStreamMap => Token::CanonStreamMapWithLambda {
+                name,
+                lambda,
+                position: self.start_pos,
+            },
         }
     }
 
@@ -378,6 +430,7 @@ impl MetTag {
         match tag {
             '$' => Self::Stream,
             '#' => Self::CanonStream,
+            '%' => Self::StreamMap,
             _ => Self::None,
         }
     }
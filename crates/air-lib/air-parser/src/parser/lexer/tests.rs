@@ -21,6 +21,8 @@ use super::AIRLexer;
 use super::LexerError;
 use super::Token;
 
+use air_lambda_parser::FilterPredicate;
+use air_lambda_parser::FilterValue;
 use air_lambda_parser::LambdaAST;
 use air_lambda_parser::ValueAccessor;
 
@@ -152,6 +154,16 @@ fn air_instructions() {
             Ok((9.into(), Token::CloseRoundBracket, 10.into())),
         ]),
     );
+
+    lexer_test("lt", Single(Ok((0.into(), Token::Lt, 2.into()))));
+    lexer_test("lte", Single(Ok((0.into(), Token::Lte, 3.into()))));
+    lexer_test("gt", Single(Ok((0.into(), Token::Gt, 2.into()))));
+    lexer_test("gte", Single(Ok((0.into(), Token::Gte, 3.into()))));
+    lexer_test("member", Single(Ok((0.into(), Token::Member, 6.into()))));
+    lexer_test(
+        "ttl_guard",
+        Single(Ok((0.into(), Token::TtlGuard, 9.into()))),
+    );
 }
 
 #[test]
@@ -181,6 +193,16 @@ fn ttl() {
     lexer_test(TTL, Single(Ok((0.into(), Token::TTL, TTL.len().into()))));
 }
 
+#[test]
+fn particle_id() {
+    const PARTICLE_ID: &str = "%particle_id%";
+
+    lexer_test(
+        PARTICLE_ID,
+        Single(Ok((0.into(), Token::ParticleId, PARTICLE_ID.len().into()))),
+    );
+}
+
 #[test]
 fn stream() {
     const STREAM: &str = "$stream____asdasd";
@@ -215,6 +237,50 @@ fn canon_stream() {
     );
 }
 
+#[test]
+fn stream_map() {
+    const STREAM_MAP: &str = "%stream_map";
+
+    lexer_test(
+        STREAM_MAP,
+        Single(Ok((
+            0.into(),
+            Token::StreamMap {
+                name: STREAM_MAP,
+                position: 0.into(),
+            },
+            STREAM_MAP.len().into(),
+        ))),
+    );
+}
+
+#[test]
+fn canon_stream_map() {
+    const CANON_STREAM_MAP: &str = "#%stream_map";
+
+    lexer_test(
+        CANON_STREAM_MAP,
+        Single(Ok((
+            0.into(),
+            Token::CanonStreamMap {
+                name: CANON_STREAM_MAP,
+                position: 0.into(),
+            },
+            CANON_STREAM_MAP.len().into(),
+        ))),
+    );
+}
+
+#[test]
+fn empty_canon_stream_map_name() {
+    const CANON_STREAM_MAP: &str = "#%";
+
+    lexer_test(
+        CANON_STREAM_MAP,
+        Single(Err(LexerError::empty_stream_name(1.into()..1.into()))),
+    );
+}
+
 #[test]
 fn canon_stream_with_functor() {
     let canon_stream_name = "#canon_stream";
@@ -391,6 +457,59 @@ fn lambda() {
     );
 }
 
+#[test]
+fn lambda_with_filter() {
+    // whitespaces are allowed inside square brackets of a lambda
+    const LAMBDA: &str = r#"value.$.[?peer_id == "some_peer"].[*].name"#;
+
+    lexer_test(
+        LAMBDA,
+        Single(Ok((
+            0.into(),
+            Token::ScalarWithLambda {
+                name: "value",
+                lambda: LambdaAST::try_from_accessors(vec![
+                    ValueAccessor::Filter(FilterPredicate::new(
+                        "peer_id",
+                        FilterValue::String("some_peer"),
+                    )),
+                    ValueAccessor::Wildcard,
+                    ValueAccessor::FieldAccessByName { field_name: "name" },
+                ])
+                .unwrap(),
+                position: 0.into(),
+            },
+            LAMBDA.len().into(),
+        ))),
+    );
+}
+
+#[test]
+fn lambda_with_filter_by_string_with_brackets() {
+    // brackets inside a string literal don't close the lambda
+    const LAMBDA: &str = r#"value.$.[?name == "a]b}"].[0]"#;
+
+    lexer_test(
+        LAMBDA,
+        Single(Ok((
+            0.into(),
+            Token::ScalarWithLambda {
+                name: "value",
+                lambda: LambdaAST::try_from_accessors(vec![
+                    ValueAccessor::Filter(FilterPredicate::new(
+                        "name",
+                        FilterValue::String("a]b}"),
+                    )),
+                    ValueAccessor::ArrayAccess { idx: 0 },
+                ])
+                .unwrap(),
+                position: 0.into(),
+            },
+            LAMBDA.len().into(),
+        ))),
+    );
+}
+
 #[test]
 fn lambda_path_numbers() {
     const LAMBDA: &str = r#"12345.$[$@[]():?.*,"]"#;
@@ -557,6 +676,29 @@ fn last_error_non_standard_field() {
     );
 }
 
+#[test]
+fn scoped_error() {
+    const ERROR: &str = r#"%error%"#;
+
+    lexer_test(
+        ERROR,
+        Single(Ok((0.into(), Token::Error, ERROR.len().into()))),
+    );
+}
+
+#[test]
+fn scoped_error_message() {
+    const ERROR: &str = r#"%error%.$.message"#;
+
+    let token = Token::ErrorWithLambda(
+        LambdaAST::try_from_accessors(vec![ValueAccessor::FieldAccessByName {
+            field_name: "message",
+        }])
+        .unwrap(),
+    );
+    lexer_test(ERROR, Single(Ok((0.into(), token, ERROR.len().into()))));
+}
+
 #[test]
 fn booleans() {
     const TRUE_BOOL_CONST: &str = "true";
@@ -596,6 +738,29 @@ fn booleans() {
     );
 }
 
+#[test]
+fn object_literal() {
+    const OBJECT_LITERAL: &str = r#"{"key": value}"#;
+
+    lexer_test(
+        OBJECT_LITERAL,
+        All(vec![
+            Ok((0.into(), Token::OpenCurlyBracket, 1.into())),
+            Ok((1.into(), Token::StringLiteral("key"), 6.into())),
+            Ok((6.into(), Token::Colon, 7.into())),
+            Ok((
+                8.into(),
+                Token::Scalar {
+                    name: "value",
+                    position: 8.into(),
+                },
+                13.into(),
+            )),
+            Ok((13.into(), Token::CloseCurlyBracket, 14.into())),
+        ]),
+    );
+}
+
 #[test]
 fn match_with_empty_array__() {
     const MATCH_WITH_EMPTY_ARRAY: &str = "(match scalar []
@@ -27,6 +27,9 @@ pub enum Token<'input> {
     CloseRoundBracket,
     OpenSquareBracket,
     CloseSquareBracket,
+    OpenCurlyBracket,
+    CloseCurlyBracket,
+    Colon,
 
     Scalar {
         name: &'input str,
@@ -55,6 +58,24 @@ pub enum Token<'input> {
         lambda: LambdaAST<'input>,
         position: AirPos,
     },
+    StreamMap {
+        name: &'input str,
+        position: AirPos,
+    },
+    StreamMapWithLambda {
+        name: &'input str,
+        lambda: LambdaAST<'input>,
+        position: AirPos,
+    },
+    CanonStreamMap {
+        name: &'input str,
+        position: AirPos,
+    },
+    CanonStreamMapWithLambda {
+        name: &'input str,
+        lambda: LambdaAST<'input>,
+        position: AirPos,
+    },
 
     StringLiteral(&'input str),
     I64(i64),
@@ -64,8 +85,11 @@ pub enum Token<'input> {
     InitPeerId,
     LastError,
     LastErrorWithLambda(LambdaAST<'input>),
+    Error,
+    ErrorWithLambda(LambdaAST<'input>),
     Timestamp,
     TTL,
+    ParticleId,
 
     Call,
     Canon,
@@ -81,4 +105,10 @@ pub enum Token<'input> {
     Null,
     Match,
     MisMatch,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Member,
+    TtlGuard,
 }
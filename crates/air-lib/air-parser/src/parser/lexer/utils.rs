@@ -37,6 +37,9 @@ pub(super) fn is_json_path_allowed_char(ch: char) -> bool {
         ',' => true,
         '"' => true,
         '\'' => true,
+        // used in filter predicates
+        '=' => true,
+        ' ' => true,
         ch => is_air_alphanumeric(ch),
     }
 }
@@ -36,4 +36,6 @@ pub use lexer::AIRLexer;
 pub use span::Span;
 pub use validator::VariableValidator;
 
+pub(crate) use validator::validate_ast;
+
 use errors::ParserError;
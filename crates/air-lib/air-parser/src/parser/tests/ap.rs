@@ -102,6 +102,57 @@ fn ap_with_empty_array() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn ap_with_array_literal() {
+    let source_code = r#"
+        (ap [scalar "b" 1 array.$.[0]] $stream)
+    "#;
+
+    let actual = parse(source_code);
+    let expected = ap(
+        ApArgument::Array(vec![
+            ImmutableValue::Variable(ImmutableVariable::scalar("scalar", 14.into())),
+            ImmutableValue::Literal("b"),
+            ImmutableValue::Number(Number::Int(1)),
+            ImmutableValue::VariableWithLambda(ImmutableVariableWithLambda::scalar(
+                "array",
+                LambdaAST::try_from_accessors(vec![ValueAccessor::ArrayAccess { idx: 0 }]).unwrap(),
+                27.into(),
+            )),
+        ]),
+        ApResult::Stream(Stream::new("$stream", 40.into())),
+    );
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ap_with_object_literal() {
+    let source_code = r#"
+        (ap {"a": scalar "b": [1 []]} $stream)
+    "#;
+
+    let actual = parse(source_code);
+    let expected = ap(
+        ApArgument::Object(vec![
+            ObjectEntry::new(
+                "a",
+                ImmutableValue::Variable(ImmutableVariable::scalar("scalar", 19.into())),
+            ),
+            ObjectEntry::new(
+                "b",
+                ImmutableValue::Array(vec![
+                    ImmutableValue::Number(Number::Int(1)),
+                    ImmutableValue::EmptyArray,
+                ]),
+            ),
+        ]),
+        ApResult::Stream(Stream::new("$stream", 39.into())),
+    );
+
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn ap_with_init_peer_id() {
     let source_code = r#"
@@ -147,6 +198,21 @@ fn ap_with_ttl() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn ap_with_particle_id() {
+    let source_code = r#"
+        (ap %particle_id% $stream)
+    "#;
+
+    let actual = parse(source_code);
+    let expected = ap(
+        ApArgument::ParticleId,
+        ApResult::Stream(Stream::new("$stream", 27.into())),
+    );
+
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn ap_with_canon_stream() {
     let canon_stream = "#canon_stream";
@@ -94,6 +94,63 @@ fn parse_empty_array_2() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn parse_array_and_object_literals() {
+    let source_code = r#"
+        (call peer_id ("service_id" "function_name") [["a" arg] {"k": 1 "n": {}}])
+        "#;
+
+    let actual = parse(source_code);
+    let expected = call(
+        ResolvableToPeerIdVariable::Scalar(Scalar::new("peer_id", 15.into())),
+        ResolvableToStringVariable::Literal("service_id"),
+        ResolvableToStringVariable::Literal("function_name"),
+        Rc::new(vec![
+            ImmutableValue::Array(vec![
+                ImmutableValue::Literal("a"),
+                ImmutableValue::Variable(ImmutableVariable::scalar("arg", 60.into())),
+            ]),
+            ImmutableValue::Object(vec![
+                ObjectEntry::new("k", ImmutableValue::Number(Number::Int(1))),
+                ObjectEntry::new("n", ImmutableValue::Object(vec![])),
+            ]),
+        ]),
+        CallOutputValue::None,
+    );
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn parse_object_literal_with_undefined_variable() {
+    let source_code = r#"
+        (call "" ("" "f") [{"k": [name]}])
+    "#;
+
+    let lexer = crate::AIRLexer::new(source_code);
+
+    let parser = crate::AIRParser::new();
+    let mut errors = Vec::new();
+    let mut validator = crate::parser::VariableValidator::new();
+    parser
+        .parse(source_code, &mut errors, &mut validator, lexer)
+        .expect("parser shouldn't fail");
+
+    let errors = validator.finalize();
+
+    assert_eq!(errors.len(), 1);
+    let error = &errors[0].error;
+    let parser_error = match error {
+        ParseError::User { error } => error,
+        _ => panic!("unexpected error type"),
+    };
+
+    assert!(matches!(
+        parser_error,
+        ParserError::UndefinedVariable { .. }
+    ));
+}
+
 #[test]
 fn parse_undefined_variable() {
     let source_code = r#"
@@ -356,6 +413,24 @@ fn parse_ttl() {
     assert_eq!(instruction, expected);
 }
 
+#[test]
+fn parse_particle_id() {
+    let source_code = r#"
+        (call "peer_id" ("service_id" "fn_name") [%particle_id%])
+        "#;
+
+    let instruction = parse(source_code);
+    let expected = call(
+        ResolvableToPeerIdVariable::Literal("peer_id"),
+        ResolvableToStringVariable::Literal("service_id"),
+        ResolvableToStringVariable::Literal("fn_name"),
+        Rc::new(vec![ImmutableValue::ParticleId]),
+        CallOutputValue::None,
+    );
+
+    assert_eq!(instruction, expected);
+}
+
 #[test]
 fn parse_last_error() {
     let source_code = r#"
@@ -380,6 +455,38 @@ fn parse_last_error() {
     assert_eq!(instruction, expected);
 }
 
+#[test]
+fn parse_scoped_error() {
+    let source_code = r#"
+        (seq
+            (call %init_peer_id% ("service_id" "fn_name") [%error% %error%.$.message])
+            (null)
+        )"#
+    .to_string();
+
+    let instruction = parse(&source_code);
+    let expected = seq(
+        call(
+            ResolvableToPeerIdVariable::InitPeerId,
+            ResolvableToStringVariable::Literal("service_id"),
+            ResolvableToStringVariable::Literal("fn_name"),
+            Rc::new(vec![
+                ImmutableValue::Error(None),
+                ImmutableValue::Error(Some(
+                    LambdaAST::try_from_accessors(vec![ValueAccessor::FieldAccessByName {
+                        field_name: "message",
+                    }])
+                    .unwrap(),
+                )),
+            ]),
+            CallOutputValue::None,
+        ),
+        null(),
+    );
+
+    assert_eq!(instruction, expected);
+}
+
 #[test]
 fn canon_stream_in_args() {
     let service_id = "service_id";
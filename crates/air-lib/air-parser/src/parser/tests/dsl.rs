@@ -92,6 +92,10 @@ pub(super) fn fail_last_error() -> Instruction<'static> {
     Instruction::Fail(Fail::LastError)
 }
 
+pub(super) fn fail_error() -> Instruction<'static> {
+    Instruction::Fail(Fail::Error)
+}
+
 pub(super) fn fold_scalar_variable<'i>(
     scalar: Scalar<'i>,
     iterator: Scalar<'i>,
@@ -102,6 +106,7 @@ pub(super) fn fold_scalar_variable<'i>(
     Instruction::FoldScalar(FoldScalar {
         iterable: FoldScalarIterable::Scalar(scalar),
         iterator,
+        index: None,
         instruction: Rc::new(instruction),
         last_instruction: last_instruction.map(Rc::new),
         span,
@@ -118,6 +123,7 @@ pub(super) fn fold_scalar_variable_wl<'i>(
     Instruction::FoldScalar(FoldScalar {
         iterable: FoldScalarIterable::ScalarWithLambda(scalar),
         iterator,
+        index: None,
         instruction: Rc::new(instruction),
         last_instruction: last_instruction.map(Rc::new),
         span,
@@ -134,6 +140,7 @@ pub(super) fn fold_scalar_canon_stream<'i>(
     Instruction::FoldScalar(FoldScalar {
         iterable: FoldScalarIterable::CanonStream(canon_stream),
         iterator,
+        index: None,
         instruction: Rc::new(instruction),
         last_instruction: last_instruction.map(Rc::new),
         span,
@@ -149,6 +156,7 @@ pub(super) fn fold_scalar_empty_array<'i>(
     Instruction::FoldScalar(FoldScalar {
         iterable: FoldScalarIterable::EmptyArray,
         iterator,
+        index: None,
         instruction: Rc::new(instruction),
         last_instruction: last_instruction.map(Rc::new),
         span,
@@ -165,6 +173,8 @@ pub(super) fn fold_stream<'i>(
     Instruction::FoldStream(FoldStream {
         iterable,
         iterator,
+        index: None,
+        max_iterations: None,
         instruction: Rc::new(instruction),
         last_instruction: last_instruction.map(Rc::new),
         span,
@@ -195,10 +205,54 @@ pub(super) fn mismatch<'i>(
     })
 }
 
+pub(super) fn compare<'i>(
+    operator: ComparisonOperator,
+    left_value: ImmutableValue<'i>,
+    right_value: ImmutableValue<'i>,
+    instruction: Instruction<'i>,
+) -> Instruction<'i> {
+    Instruction::Compare(Compare {
+        operator,
+        left_value,
+        right_value,
+        instruction: Box::new(instruction),
+    })
+}
+
+pub(super) fn member<'i>(
+    value: ImmutableValue<'i>,
+    collection: ImmutableValue<'i>,
+    instruction: Instruction<'i>,
+) -> Instruction<'i> {
+    Instruction::Member(Member {
+        value,
+        collection,
+        instruction: Box::new(instruction),
+    })
+}
+
+pub(super) fn ttl_guard<'i>(
+    min_remaining_ttl: ImmutableValue<'i>,
+    instruction: Instruction<'i>,
+) -> Instruction<'i> {
+    Instruction::TtlGuard(TtlGuard {
+        min_remaining_ttl,
+        instruction: Box::new(instruction),
+    })
+}
+
 pub(super) fn ap<'i>(argument: ApArgument<'i>, result: ApResult<'i>) -> Instruction<'i> {
     Instruction::Ap(Ap { argument, result })
 }
 
+pub(super) fn ap_map<'i>(
+    key: StreamMapKeyClause<'i>,
+    value: ApArgument<'i>,
+    map: StreamMap<'i>,
+) -> Instruction<'i> {
+    Instruction::ApMap(ApMap { key, value, map })
+}
+
 pub(super) fn canon<'i>(
     peer_pk: ResolvableToPeerIdVariable<'i>,
     stream: Stream<'i>,
@@ -32,6 +32,16 @@ fn parse_fail_last_error() {
     assert_eq!(instruction, expected)
 }
 
+#[test]
+fn parse_fail_error() {
+    let source_code = r#"
+           (fail %error%)
+        "#;
+    let instruction = parse(source_code);
+    let expected = fail_error();
+    assert_eq!(instruction, expected)
+}
+
 #[test]
 fn parse_fail_literals() {
     let source_code = r#"
@@ -445,3 +445,91 @@ fn parse_fold_with_xor_par_seq() {
         assert_eq!(instruction, expected);
     }
 }
+
+#[test]
+fn fold_on_stream_with_index_and_iterations_limit() {
+    let source_code = r#"
+        (fold $stream v i 2 (null))
+    "#;
+
+    let instruction = parse(source_code);
+    let expected = Instruction::FoldStream(FoldStream {
+        iterable: Stream::new("$stream", 15.into()),
+        iterator: Scalar::new("v", 23.into()),
+        index: Some(Scalar::new("i", 25.into())),
+        max_iterations: Some(2),
+        instruction: std::rc::Rc::new(null()),
+        last_instruction: None,
+        span: Span::new(9.into(), 36.into()),
+    });
+    assert_eq!(instruction, expected);
+}
+
+#[test]
+fn fold_on_canon_stream_with_index() {
+    let source_code = r#"
+        (fold #canon v i (null))
+    "#;
+
+    let instruction = parse(source_code);
+    let expected = Instruction::FoldScalar(FoldScalar {
+        iterable: FoldScalarIterable::CanonStream(CanonStream::new("#canon", 15.into())),
+        iterator: Scalar::new("v", 22.into()),
+        index: Some(Scalar::new("i", 24.into())),
+        instruction: std::rc::Rc::new(null()),
+        last_instruction: None,
+        span: Span::new(9.into(), 33.into()),
+    });
+    assert_eq!(instruction, expected);
+}
+
+#[test]
+fn fold_index_is_defined_inside_fold() {
+    let source_code = r#"
+        (seq
+            (call "" ("" "") [] $stream)
+            (fold $stream v i
+                (seq
+                    (call "" ("" "") [v i])
+                    (next v)
+                )
+            )
+        )
+        "#;
+
+    let lexer = crate::AIRLexer::new(source_code);
+
+    let parser = crate::AIRParser::new();
+    let mut errors = Vec::new();
+    let mut validator = crate::parser::VariableValidator::new();
+    parser
+        .parse(source_code, &mut errors, &mut validator, lexer)
+        .expect("parser shouldn't fail");
+
+    let errors = validator.finalize();
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn fold_with_non_positive_iterations_limit() {
+    let source_code = r#"
+        (fold $stream v 0 (null))
+        "#;
+
+    let lexer = crate::AIRLexer::new(source_code);
+
+    let parser = crate::AIRParser::new();
+    let mut errors = Vec::new();
+    let mut validator = crate::parser::VariableValidator::new();
+    let result = parser.parse(source_code, &mut errors, &mut validator, lexer);
+
+    let parser_error = match result {
+        Err(ParseError::User { error }) => error,
+        _ => panic!("unexpected parsing result"),
+    };
+
+    assert!(matches!(
+        parser_error,
+        ParserError::InvalidFoldIterationsLimit { limit: 0, .. }
+    ));
+}
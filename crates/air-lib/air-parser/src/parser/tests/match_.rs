@@ -125,6 +125,92 @@ fn parse_mismatch() {
     assert_eq!(instruction, expected);
 }
 
+#[test]
+fn parse_comparisons() {
+    let source_code = r#"
+        (lt v1 10
+            (gte "abc" v2
+                (null)
+            )
+        )
+        "#;
+    let instruction = parse(source_code);
+    let expected = compare(
+        ComparisonOperator::Less,
+        ImmutableValue::Variable(ImmutableVariable::scalar("v1", 13.into())),
+        ImmutableValue::Number(Number::Int(10)),
+        compare(
+            ComparisonOperator::GreaterOrEqual,
+            ImmutableValue::Literal("abc"),
+            ImmutableValue::Variable(ImmutableVariable::scalar("v2", 42.into())),
+            null(),
+        ),
+    );
+    assert_eq!(instruction, expected);
+}
+
+#[test]
+fn parse_member() {
+    let source_code = r#"
+        (member %init_peer_id% #allowed
+            (null)
+        )
+        "#;
+    let instruction = parse(source_code);
+    let expected = member(
+        ImmutableValue::InitPeerId,
+        ImmutableValue::Variable(ImmutableVariable::canon_stream("#allowed", 32.into())),
+        null(),
+    );
+    assert_eq!(instruction, expected);
+}
+
+#[test]
+fn parse_ttl_guard() {
+    let source_code = r#"
+        (ttl_guard 1000
+            (null)
+        )
+        "#;
+    let instruction = parse(source_code);
+    let expected = ttl_guard(ImmutableValue::Number(Number::Int(1000)), null());
+    assert_eq!(instruction, expected);
+}
+
+#[test]
+fn parse_ttl_guard_with_variable() {
+    let source_code = r#"
+        (seq
+            (call "peer_id" ("service_id" "fn_name") [] min_ttl)
+            (ttl_guard min_ttl.$.value
+                (null)
+            )
+        )
+        "#;
+    let instruction = parse(source_code);
+    let expected = seq(
+        call(
+            ResolvableToPeerIdVariable::Literal("peer_id"),
+            ResolvableToStringVariable::Literal("service_id"),
+            ResolvableToStringVariable::Literal("fn_name"),
+            std::rc::Rc::new(vec![]),
+            CallOutputValue::Scalar(Scalar::new("min_ttl", 70.into())),
+        ),
+        ttl_guard(
+            ImmutableValue::VariableWithLambda(ImmutableVariableWithLambda::scalar(
+                "min_ttl",
+                LambdaAST::try_from_accessors(vec![ValueAccessor::FieldAccessByName {
+                    field_name: "value",
+                }])
+                .unwrap(),
+                102.into(),
+            )),
+            null(),
+        ),
+    );
+    assert_eq!(instruction, expected);
+}
+
 #[test]
 fn match_with_bool() {
     let source_code = r#"
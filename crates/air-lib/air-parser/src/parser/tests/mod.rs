@@ -26,6 +26,7 @@ mod new;
 mod null;
 mod par;
 mod seq;
+mod stream_map;
 
 use crate::ast::Instruction;
 use crate::parser::AIRParser;
@@ -0,0 +1,125 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::dsl::*;
+use super::parse;
+use crate::ast::*;
+
+use air_lambda_ast::{LambdaAST, ValueAccessor};
+
+#[test]
+fn ap_map_with_literals() {
+    let source_code = r#"
+        (ap ("key" "value") %map)
+    "#;
+
+    let actual = parse(source_code);
+    let expected = ap_map(
+        StreamMapKeyClause::Literal("key"),
+        ApArgument::Literal("value"),
+        StreamMap::new("%map", 29.into()),
+    );
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ap_map_with_int_key() {
+    let source_code = r#"
+        (ap (42 true) %map)
+    "#;
+
+    let actual = parse(source_code);
+    let expected = ap_map(
+        StreamMapKeyClause::Int(42),
+        ApArgument::Boolean(true),
+        StreamMap::new("%map", 23.into()),
+    );
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ap_map_with_scalars() {
+    let source_code = r#"
+        (ap (key value) %map)
+    "#;
+
+    let actual = parse(source_code);
+    let expected = ap_map(
+        StreamMapKeyClause::Scalar(Scalar::new("key", 14.into())),
+        ApArgument::Scalar(Scalar::new("value", 18.into())),
+        StreamMap::new("%map", 25.into()),
+    );
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn canon_map() {
+    let source_code = r#"
+        (canon "peer_id" %map #%map)
+    "#;
+
+    let actual = parse(source_code);
+    let expected = Instruction::CanonMap(CanonMap::new(
+        ResolvableToPeerIdVariable::Literal("peer_id"),
+        StreamMap::new("%map", 26.into()),
+        CanonStreamMap::new("#%map", 31.into()),
+    ));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ap_with_canon_stream_map_with_lambda() {
+    let source_code = r#"
+        (ap #%map.$.key scalar)
+    "#;
+
+    let actual = parse(source_code);
+    let expected = ap(
+        ApArgument::CanonStreamMapWithLambda(CanonStreamMapWithLambda::new(
+            "#%map",
+            LambdaAST::try_from_accessors(vec![ValueAccessor::FieldAccessByName {
+                field_name: "key",
+            }])
+            .unwrap(),
+            13.into(),
+        )),
+        ApResult::Scalar(Scalar::new("scalar", 25.into())),
+    );
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn new_with_stream_map() {
+    let source_code = r#"
+        (new %map
+            (null)
+        )
+    "#;
+
+    let actual = parse(source_code);
+    let expected = new(
+        NewArgument::StreamMap(StreamMap::new("%map", 14.into())),
+        null(),
+        Span::new(9.into(), 47.into()),
+    );
+
+    assert_eq!(actual, expected);
+}
@@ -22,6 +22,7 @@ use crate::parser::ParserError;
 use crate::parser::Span;
 
 use air_lambda_ast::LambdaAST;
+use air_lambda_ast::OptionalAccessor;
 use air_lambda_ast::ValueAccessor;
 use lalrpop_util::ErrorRecovery;
 use lalrpop_util::ParseError;
@@ -95,6 +96,20 @@ impl<'i> VariableValidator<'i> {
         self.met_matchable(&mismatch.right_value, span);
     }
 
+    pub(super) fn met_compare(&mut self, compare: &Compare<'i>, span: Span) {
+        self.met_matchable(&compare.left_value, span);
+        self.met_matchable(&compare.right_value, span);
+    }
+
+    pub(super) fn met_member(&mut self, member: &Member<'i>, span: Span) {
+        self.met_matchable(&member.value, span);
+        self.met_matchable(&member.collection, span);
+    }
+
+    pub(super) fn met_ttl_guard(&mut self, ttl_guard: &TtlGuard<'i>, span: Span) {
+        self.met_matchable(&ttl_guard.min_remaining_ttl, span);
+    }
+
     pub(super) fn met_fold_scalar(&mut self, fold: &FoldScalar<'i>, span: Span) {
         use FoldScalarIterable::*;
 
@@ -105,11 +120,13 @@ impl<'i> VariableValidator<'i> {
             EmptyArray => {}
         };
         self.met_iterator_definition(&fold.iterator, span);
+        self.met_fold_index_definition(fold.index.as_ref(), span);
     }
 
     pub(super) fn meet_fold_stream(&mut self, fold: &FoldStream<'i>, span: Span) {
         self.met_variable_name(fold.iterable.name, span);
         self.met_iterator_definition(&fold.iterator, span);
+        self.met_fold_index_definition(fold.index.as_ref(), span);
     }
 
     pub(super) fn met_new(&mut self, new: &New<'i>, span: Span) {
@@ -129,23 +146,56 @@ impl<'i> VariableValidator<'i> {
     }
 
     pub(super) fn met_ap(&mut self, ap: &Ap<'i>, span: Span) {
-        match &ap.argument {
+        self.met_ap_argument(&ap.argument, span);
+        self.met_variable_name_definition(ap.result.name(), span);
+    }
+
+    pub(super) fn met_ap_map(&mut self, ap_map: &ApMap<'i>, span: Span) {
+        use StreamMapKeyClause::*;
+
+        match &ap_map.key {
+            Literal(_) | Int(_) => {}
+            Scalar(scalar) => self.met_scalar(scalar, span),
+            ScalarWithLambda(scalar) => self.met_scalar_wl(scalar, span),
+            CanonStreamWithLambda(canon_stream) => self.met_canon_stream_wl(canon_stream, span),
+        }
+        self.met_ap_argument(&ap_map.value, span);
+        self.met_variable_name_definition(ap_map.map.name, span);
+    }
+
+    // canon doesn't check stream map to be defined by the same reason as for streams
+    pub(super) fn met_canon_map(&mut self, canon_map: &CanonMap<'i>, span: Span) {
+        self.met_variable_name_definition(canon_map.canon_stream_map.name, span);
+    }
+
+    fn met_ap_argument(&mut self, argument: &ApArgument<'i>, span: Span) {
+        match argument {
             ApArgument::Number(_)
             | ApArgument::Timestamp
             | ApArgument::TTL
+            | ApArgument::ParticleId
             | ApArgument::InitPeerId
             | ApArgument::Boolean(_)
             | ApArgument::Literal(_)
             | ApArgument::EmptyArray
-            | ApArgument::LastError(_) => {}
+            | ApArgument::LastError(_)
+            | ApArgument::Error(_) => {}
+            ApArgument::Array(values) => self.met_args(values, span),
+            ApArgument::Object(entries) => self.met_object_entries(entries, span),
             ApArgument::Scalar(scalar) => self.met_scalar(scalar, span),
             ApArgument::ScalarWithLambda(scalar) => self.met_scalar_wl(scalar, span),
             ApArgument::CanonStream(canon_stream) => self.met_canon_stream(canon_stream, span),
             ApArgument::CanonStreamWithLambda(canon_stream) => {
                 self.met_canon_stream_wl(canon_stream, span)
             }
+            ApArgument::CanonStreamMap(canon_stream_map) => {
+                self.met_variable_name(canon_stream_map.name, span)
+            }
+            ApArgument::CanonStreamMapWithLambda(canon_stream_map) => {
+                self.met_variable_name(canon_stream_map.name, span);
+                self.met_lambda(&canon_stream_map.lambda, span);
+            }
         }
-        self.met_variable_name_definition(ap.result.name(), span);
     }
 
     pub(super) fn finalize(self) -> Vec<ErrorRecovery<AirPos, Token<'i>, ParserError>> {
@@ -198,13 +248,21 @@ impl<'i> VariableValidator<'i> {
         use ImmutableValue::*;
 
         match instr_arg_value {
-            InitPeerId | LastError(_) | Timestamp | TTL | Literal(_) | Number(_) | Boolean(_)
-            | EmptyArray => {}
+            InitPeerId | LastError(_) | Error(_) | Timestamp | TTL | ParticleId | Literal(_)
+            | Number(_) | Boolean(_) | EmptyArray => {}
+            Array(values) => self.met_args(values, span),
+            Object(entries) => self.met_object_entries(entries, span),
             Variable(variable) => self.met_variable(variable, span),
             VariableWithLambda(variable) => self.met_variable_wl(variable, span),
         }
     }
 
+    fn met_object_entries(&mut self, entries: &[ObjectEntry<'i>], span: Span) {
+        for entry in entries {
+            self.met_instr_arg_value(&entry.value, span);
+        }
+    }
+
     fn met_variable(&mut self, variable: &ImmutableVariable<'i>, span: Span) {
         self.met_variable_name(variable.name(), span);
     }
@@ -246,10 +304,16 @@ impl<'i> VariableValidator<'i> {
 
         for accessor in accessors.iter() {
             match accessor {
-                &ValueAccessor::FieldAccessByScalar { scalar_name } => {
+                &ValueAccessor::FieldAccessByScalar { scalar_name }
+                | &ValueAccessor::Optional(OptionalAccessor::FieldAccessByScalar { scalar_name }) => {
                     self.met_variable_name(scalar_name, span)
                 }
                 ValueAccessor::ArrayAccess { .. }
+                | ValueAccessor::ArrayAccessFromEnd { .. }
+                | ValueAccessor::ArraySlice(_)
+                | ValueAccessor::Wildcard
+                | ValueAccessor::Filter(_)
+                | ValueAccessor::Optional(_)
                 | ValueAccessor::FieldAccessByName { .. }
                 | ValueAccessor::Error => {}
             }
@@ -291,11 +355,15 @@ impl<'i> VariableValidator<'i> {
             ImmutableValue::InitPeerId
             | ImmutableValue::Timestamp
             | ImmutableValue::TTL
+            | ImmutableValue::ParticleId
             | ImmutableValue::Number(_)
             | ImmutableValue::Boolean(_)
             | ImmutableValue::Literal(_)
             | ImmutableValue::LastError(_)
+            | ImmutableValue::Error(_)
             | ImmutableValue::EmptyArray => {}
+            ImmutableValue::Array(values) => self.met_args(values, span),
+            ImmutableValue::Object(entries) => self.met_object_entries(entries, span),
             ImmutableValue::Variable(variable) => self.met_variable(variable, span),
             ImmutableValue::VariableWithLambda(variable) => self.met_variable_wl(variable, span),
         }
@@ -304,6 +372,108 @@ impl<'i> VariableValidator<'i> {
     fn met_iterator_definition(&mut self, iterator: &Scalar<'i>, span: Span) {
         self.met_iterator_definitions.insert(iterator.name, span);
     }
+
+    fn met_fold_index_definition(&mut self, index: Option<&Scalar<'i>>, span: Span) {
+        if let Some(index) = index {
+            self.met_variable_name_definition(index.name, span);
+        }
+    }
+}
+
+/// Validates variables of an AST that wasn't produced by the parser, e.g. decoded from
+/// the binary form, and returns messages of the found errors. Such an AST doesn't keep spans
+/// of all instructions, so every instruction gets a synthetic span that preserves its order
+/// and nesting in a script, it's enough to check definitions of variables and iterators.
+pub(crate) fn validate_ast(air: &Instruction<'_>) -> Vec<String> {
+    let mut walker = AstWalker {
+        validator: VariableValidator::new(),
+        position: 0,
+    };
+    walker.walk(air);
+
+    walker
+        .validator
+        .finalize()
+        .into_iter()
+        .map(|error| match error.error {
+            ParseError::User { error } => error.to_string(),
+            error => format!("{error:?}"),
+        })
+        .collect()
+}
+
+struct AstWalker<'i> {
+    validator: VariableValidator<'i>,
+    position: usize,
+}
+
+impl<'i> AstWalker<'i> {
+    // nested instructions are met before their parents as it happens in the parser
+    fn walk(&mut self, instruction: &Instruction<'i>) {
+        use Instruction::*;
+
+        let left = self.next_position();
+        match instruction {
+            Seq(seq) => self.walk_pair(&seq.0, &seq.1),
+            Par(par) => self.walk_pair(&par.0, &par.1),
+            Xor(xor) => self.walk_pair(&xor.0, &xor.1),
+            Match(match_) => self.walk(&match_.instruction),
+            MisMatch(mismatch) => self.walk(&mismatch.instruction),
+            Compare(compare) => self.walk(&compare.instruction),
+            Member(member) => self.walk(&member.instruction),
+            TtlGuard(ttl_guard) => self.walk(&ttl_guard.instruction),
+            New(new) => self.walk(&new.instruction),
+            FoldScalar(fold) => {
+                self.walk_fold_body(&fold.instruction, fold.last_instruction.as_deref())
+            }
+            FoldStream(fold) => {
+                self.walk_fold_body(&fold.instruction, fold.last_instruction.as_deref())
+            }
+            Call(_) | Ap(_) | ApMap(_) | Canon(_) | CanonMap(_) | Fail(_) | Never(_) | Next(_)
+            | Null(_) | Error => {}
+        }
+        let span = Span::new(left, self.next_position());
+
+        let validator = &mut self.validator;
+        match instruction {
+            Call(call) => validator.met_call(call, span),
+            Ap(ap) => validator.met_ap(ap, span),
+            ApMap(ap_map) => validator.met_ap_map(ap_map, span),
+            Canon(canon) => validator.met_canon(canon, span),
+            CanonMap(canon_map) => validator.met_canon_map(canon_map, span),
+            Match(match_) => validator.met_match(match_, span),
+            MisMatch(mismatch) => validator.met_mismatch(mismatch, span),
+            Compare(compare) => validator.met_compare(compare, span),
+            Member(member) => validator.met_member(member, span),
+            TtlGuard(ttl_guard) => validator.met_ttl_guard(ttl_guard, span),
+            New(new) => validator.met_new(new, span),
+            FoldScalar(fold) => validator.met_fold_scalar(fold, span),
+            FoldStream(fold) => validator.meet_fold_stream(fold, span),
+            Next(next) => validator.met_next(next, span),
+            Seq(_) | Par(_) | Xor(_) | Fail(_) | Never(_) | Null(_) | Error => {}
+        }
+    }
+
+    fn walk_pair(&mut self, left: &Instruction<'i>, right: &Instruction<'i>) {
+        self.walk(left);
+        self.walk(right);
+    }
+
+    fn walk_fold_body(
+        &mut self,
+        instruction: &Instruction<'i>,
+        last_instruction: Option<&Instruction<'i>>,
+    ) {
+        self.walk(instruction);
+        if let Some(last_instruction) = last_instruction {
+            self.walk(last_instruction);
+        }
+    }
+
+    fn next_position(&mut self) -> AirPos {
+        self.position += 1;
+        self.position.into()
+    }
 }
 
 struct ValidatorErrorBuilder<'i> {
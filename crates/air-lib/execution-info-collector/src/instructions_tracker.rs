@@ -25,14 +25,17 @@ use std::collections::HashMap;
 pub struct InstructionTracker {
     pub ap: ApTracker,
     pub call: CallTracker,
+    pub compare_count: u32,
     pub fold: FoldTracker,
     pub match_count: u32,
+    pub member_count: u32,
     pub mismatch_count: u32,
     pub new_tracker: NewTracker,
     pub next_count: u32,
     pub null_count: u32,
     pub par: ParTracker,
     pub seq_count: u32,
+    pub ttl_guard_count: u32,
     pub xor_count: u32,
 }
 
@@ -85,6 +88,10 @@ impl InstructionTracker {
         self.call.executed_count += 1;
     }
 
+    pub fn meet_compare(&mut self) {
+        self.compare_count += 1;
+    }
+
     pub fn meet_fold_scalar(&mut self) {
         self.fold.seen_scalar_count += 1;
     }
@@ -97,6 +104,10 @@ impl InstructionTracker {
         self.match_count += 1;
     }
 
+    pub fn meet_member(&mut self) {
+        self.member_count += 1;
+    }
+
     pub fn meet_mismatch(&mut self) {
         self.mismatch_count += 1;
     }
@@ -121,6 +132,10 @@ impl InstructionTracker {
         self.seq_count += 1;
     }
 
+    pub fn meet_ttl_guard(&mut self) {
+        self.ttl_guard_count += 1;
+    }
+
     pub fn meet_xor(&mut self) {
         self.xor_count += 1;
     }
@@ -0,0 +1,325 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value as JValue;
+
+use std::io::Write;
+
+/// Serializes a value to the canonical JSON form based on RFC 8785 (JCS),
+/// so the same value produces the same bytes regardless of an implementation:
+///  - no whitespace,
+///  - object keys are sorted by their UTF-16 code units,
+///  - floats are formatted as ECMAScript does,
+///  - integers are written exactly, unlike RFC 8785 that formats them as doubles, because
+///    rounding integers above 2^53 would give different values the same CID,
+///  - only `"`, `\` and control characters are escaped in strings.
+///
+/// Shared conformance vectors for other implementations are in `vectors/canonical_json.json`,
+/// ones of messages signed by peers are in `vectors/signed_message.json`.
+pub fn to_canonical_json<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    let value = serde_json::to_value(value)?;
+
+    let mut output = Vec::new();
+    write_value(&mut output, &value);
+    Ok(output)
+}
+
+fn write_value(output: &mut Vec<u8>, value: &JValue) {
+    match value {
+        JValue::Null => output.extend_from_slice(b"null"),
+        JValue::Bool(true) => output.extend_from_slice(b"true"),
+        JValue::Bool(false) => output.extend_from_slice(b"false"),
+        JValue::Number(number) => write_number(output, number),
+        JValue::String(string) => write_string(output, string),
+        JValue::Array(array) => write_array(output, array),
+        JValue::Object(object) => write_object(output, object),
+    }
+}
+
+fn write_array(output: &mut Vec<u8>, array: &[JValue]) {
+    output.push(b'[');
+    for (id, value) in array.iter().enumerate() {
+        if id != 0 {
+            output.push(b',');
+        }
+        write_value(output, value);
+    }
+    output.push(b']');
+}
+
+fn write_object(output: &mut Vec<u8>, object: &Map<String, JValue>) {
+    let mut entries = object.iter().collect::<Vec<_>>();
+    entries.sort_by(|(lhs, _), (rhs, _)| lhs.encode_utf16().cmp(rhs.encode_utf16()));
+
+    output.push(b'{');
+    for (id, (key, value)) in entries.into_iter().enumerate() {
+        if id != 0 {
+            output.push(b',');
+        }
+        write_string(output, key);
+        output.push(b':');
+        write_value(output, value);
+    }
+    output.push(b'}');
+}
+
+fn write_string(output: &mut Vec<u8>, string: &str) {
+    output.push(b'"');
+    for ch in string.chars() {
+        match ch {
+            '"' => output.extend_from_slice(b"\\\""),
+            '\\' => output.extend_from_slice(b"\\\\"),
+            '\u{8}' => output.extend_from_slice(b"\\b"),
+            '\u{c}' => output.extend_from_slice(b"\\f"),
+            '\n' => output.extend_from_slice(b"\\n"),
+            '\r' => output.extend_from_slice(b"\\r"),
+            '\t' => output.extend_from_slice(b"\\t"),
+            ch if ch < '\u{20}' => {
+                // writing to a vector can't fail
+                write!(output, "\\u{:04x}", ch as u32).unwrap();
+            }
+            ch => {
+                let mut buffer = [0u8; 4];
+                output.extend_from_slice(ch.encode_utf8(&mut buffer).as_bytes());
+            }
+        }
+    }
+    output.push(b'"');
+}
+
+fn write_number(output: &mut Vec<u8>, number: &Number) {
+    // writing to a vector can't fail
+    if let Some(number) = number.as_u64() {
+        write!(output, "{}", number).unwrap();
+    } else if let Some(number) = number.as_i64() {
+        write!(output, "{}", number).unwrap();
+    } else if let Some(number) = number.as_f64() {
+        output.extend_from_slice(format_f64(number).as_bytes());
+    }
+}
+
+/// Formats a finite float as ECMAScript `Number.prototype.toString` does.
+fn format_f64(number: f64) -> String {
+    if number == 0.0 {
+        // it also covers -0
+        return "0".to_string();
+    }
+
+    let sign = if number < 0.0 { "-" } else { "" };
+
+    // Rust formats floats with the shortest digits that roundtrip, the same as ECMAScript does,
+    // only the layout of these digits differs
+    let scientific = format!("{:e}", number.abs());
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("scientific notation always contains an exponent");
+    let digits = mantissa.replace('.', "");
+    let exponent = exponent
+        .parse::<i32>()
+        .expect("scientific notation contains a valid exponent");
+
+    // the value is 0.digits * 10^point_pos
+    let digits_count = digits.len() as i32;
+    let point_pos = exponent + 1;
+
+    let formatted = if digits_count <= point_pos && point_pos <= 21 {
+        format!(
+            "{}{}",
+            digits,
+            "0".repeat((point_pos - digits_count) as usize)
+        )
+    } else if 0 < point_pos && point_pos <= 21 {
+        let (integer, fraction) = digits.split_at(point_pos as usize);
+        format!("{}.{}", integer, fraction)
+    } else if -6 < point_pos && point_pos <= 0 {
+        format!("0.{}{}", "0".repeat(-point_pos as usize), digits)
+    } else {
+        let (first_digit, rest_digits) = digits.split_at(1);
+        let fraction = if rest_digits.is_empty() {
+            String::new()
+        } else {
+            format!(".{}", rest_digits)
+        };
+        let exponent_sign = if exponent < 0 { "-" } else { "+" };
+        format!(
+            "{}{}e{}{}",
+            first_digit,
+            fraction,
+            exponent_sign,
+            exponent.abs()
+        )
+    };
+
+    format!("{}{}", sign, formatted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use std::collections::HashMap;
+
+    fn canonical_str(value: &JValue) -> String {
+        String::from_utf8(to_canonical_json(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn rfc8785_sample() {
+        // numbers aren't parsed from a string, because the default serde_json parser
+        // doesn't round floats correctly
+        let value = json!({
+            "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+            "string": "\u{20ac}$\u{f}\nA'B\"\\\\\"/",
+            "literals": [null, true, false]
+        });
+
+        let expected = concat!(
+            r#"{"literals":[null,true,false],"#,
+            r#""numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"#,
+            r#""string":"€$\u000f\nA'B\"\\\\\"/"}"#
+        );
+        assert_eq!(canonical_str(&value), expected);
+    }
+
+    #[derive(Deserialize)]
+    struct ConformanceVectors {
+        vectors: Vec<ConformanceVector>,
+    }
+
+    #[derive(Deserialize)]
+    struct ConformanceVector {
+        description: String,
+        input: String,
+        canonical: String,
+        cids: HashMap<String, String>,
+    }
+
+    #[test]
+    fn conformance_vectors() {
+        let vectors: ConformanceVectors =
+            serde_json::from_str(include_str!("../vectors/canonical_json.json")).unwrap();
+
+        for vector in vectors.vectors {
+            let value: JValue = serde_json::from_str(&vector.input).unwrap();
+            assert_eq!(
+                canonical_str(&value),
+                vector.canonical,
+                "{}",
+                vector.description
+            );
+
+            for (hash_algorithm, expected_cid) in &vector.cids {
+                let hash_algorithm = hash_algorithm.parse().unwrap();
+                let cid = crate::value_to_cid(&value, crate::JSON_CODEC, hash_algorithm).unwrap();
+                assert_eq!(cid.as_str(), expected_cid, "{}", vector.description);
+            }
+        }
+    }
+
+    #[test]
+    fn keys_are_sorted_by_utf16() {
+        let value = json!({
+            "\u{20ac}": "Euro Sign",
+            "\r": "Carriage Return",
+            "\u{fb33}": "Hebrew Letter Dalet With Dagesh",
+            "1": "One",
+            "\u{1f600}": "Emoji: Grinning Face",
+            "\u{80}": "Control",
+            "\u{f6}": "Latin Small Letter O With Diaeresis"
+        });
+
+        let expected = concat!(
+            r#"{"\r":"Carriage Return","1":"One","#,
+            "\"\u{80}\":\"Control\",",
+            "\"\u{f6}\":\"Latin Small Letter O With Diaeresis\",",
+            "\"\u{20ac}\":\"Euro Sign\",",
+            "\"\u{1f600}\":\"Emoji: Grinning Face\",",
+            "\"\u{fb33}\":\"Hebrew Letter Dalet With Dagesh\"}"
+        );
+        assert_eq!(canonical_str(&value), expected);
+    }
+
+    #[test]
+    fn numbers_are_normalized() {
+        let vectors = [
+            (json!(0.0), "0"),
+            (json!(-0.0), "0"),
+            (json!(1.0), "1"),
+            (json!(-1.5), "-1.5"),
+            (json!(1e20), "100000000000000000000"),
+            (json!(1e21), "1e+21"),
+            (json!(123456789012.5), "123456789012.5"),
+            (json!(0.000001), "0.000001"),
+            (json!(0.0000001), "1e-7"),
+            (json!(1.5e-7), "1.5e-7"),
+            (json!(5e-324), "5e-324"),
+            (json!(f64::MAX), "1.7976931348623157e+308"),
+            (json!(u64::MAX), "18446744073709551615"),
+            (json!(i64::MIN), "-9223372036854775808"),
+        ];
+
+        for (value, expected) in vectors {
+            assert_eq!(canonical_str(&value), expected, "value: {:?}", value);
+        }
+    }
+
+    #[test]
+    fn control_characters_are_escaped() {
+        let value = json!("\u{0}\u{8}\t\n\u{b}\u{c}\r\u{1f} \u{7f}/");
+        let expected = "\"\\u0000\\b\\t\\n\\u000b\\f\\r\\u001f \u{7f}/\"";
+        assert_eq!(canonical_str(&value), expected);
+    }
+
+    #[test]
+    fn struct_fields_are_sorted() {
+        #[derive(Serialize)]
+        struct Tetraplet {
+            peer_pk: &'static str,
+            service_id: &'static str,
+            function_name: &'static str,
+        }
+
+        let tetraplet = Tetraplet {
+            peer_pk: "peer",
+            service_id: "service",
+            function_name: "function",
+        };
+
+        let actual = String::from_utf8(to_canonical_json(&tetraplet).unwrap()).unwrap();
+        let expected = r#"{"function_name":"function","peer_pk":"peer","service_id":"service"}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn cid_doesnt_depend_on_key_order() {
+        let value = json!({"b": [1.0, "\u{1f600}"], "a": {"d": null, "c": -0.0}});
+        let reordered_value: JValue =
+            serde_json::from_str(r#"{"a":{"c":0,"d":null},"b":[1,"\ud83d\ude00"]}"#).unwrap();
+
+        let cid = crate::value_to_json_cid(&value).unwrap();
+        let reordered_cid = crate::value_to_json_cid(&reordered_value).unwrap();
+        assert_eq!(cid, reordered_cid);
+        assert_eq!(
+            cid.into_inner(),
+            "bagaaiera4nlfgbiqqvxl5dgvditervomon4odp43fnwli6z4yzyhha76poaq"
+        );
+    }
+}
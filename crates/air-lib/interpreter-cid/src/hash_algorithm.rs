@@ -0,0 +1,141 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use multihash::Code;
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// Hash function used to calculate CIDs, its multihash code is recorded in each CID,
+/// so CIDs calculated by any of these functions are accepted regardless of this setting.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CidHashAlgorithm {
+    /// The current IPFS default.
+    #[default]
+    Sha2_256 = 0,
+
+    /// Faster than SHA2-256, especially on big values.
+    Blake3_256 = 1,
+}
+
+impl CidHashAlgorithm {
+    /// Converts a raw value passed in `RunParameters`, since Marine records can't contain enums.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Sha2_256),
+            1 => Some(Self::Blake3_256),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn from_multihash_code(code: u64) -> Option<Self> {
+        match Code::try_from(code) {
+            Ok(Code::Sha2_256) => Some(Self::Sha2_256),
+            Ok(Code::Blake3_256) => Some(Self::Blake3_256),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn multihash_code(self) -> Code {
+        match self {
+            Self::Sha2_256 => Code::Sha2_256,
+            Self::Blake3_256 => Code::Blake3_256,
+        }
+    }
+}
+
+impl From<CidHashAlgorithm> for u8 {
+    fn from(hash_algorithm: CidHashAlgorithm) -> Self {
+        hash_algorithm as u8
+    }
+}
+
+impl FromStr for CidHashAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sha2-256" => Ok(Self::Sha2_256),
+            "blake3-256" => Ok(Self::Blake3_256),
+            _ => Err(format!(
+                "unknown hash algorithm `{s}`, expected `sha2-256` or `blake3-256`"
+            )),
+        }
+    }
+}
+
+impl Display for CidHashAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sha2_256 => write!(f, "sha2-256"),
+            Self::Blake3_256 => write!(f, "blake3-256"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_to_cid;
+    use crate::CID;
+    use crate::JSON_CODEC;
+
+    use serde_json::json;
+
+    #[test]
+    fn hash_algorithm_is_recorded_in_cid() {
+        let value = json!({"key": [1, 2, 3]});
+
+        let sha2_cid = value_to_cid(&value, JSON_CODEC, CidHashAlgorithm::Sha2_256).unwrap();
+        let blake3_cid = value_to_cid(&value, JSON_CODEC, CidHashAlgorithm::Blake3_256).unwrap();
+        assert_ne!(sha2_cid, blake3_cid);
+
+        assert_eq!(
+            sha2_cid.codec_and_hash_algorithm(),
+            Some((JSON_CODEC, CidHashAlgorithm::Sha2_256))
+        );
+        assert_eq!(
+            blake3_cid.codec_and_hash_algorithm(),
+            Some((JSON_CODEC, CidHashAlgorithm::Blake3_256))
+        );
+    }
+
+    #[test]
+    fn unsupported_cids_are_rejected() {
+        let malformed_cid = CID::<()>::new("not a cid");
+        assert_eq!(malformed_cid.codec_and_hash_algorithm(), None);
+
+        use multihash::MultihashDigest;
+
+        let sha2_512_digest = Code::Sha2_512.digest(b"value");
+        let sha2_512_cid =
+            CID::<()>::new(cid::Cid::new_v1(JSON_CODEC, sha2_512_digest).to_string());
+        assert_eq!(sha2_512_cid.codec_and_hash_algorithm(), None);
+    }
+
+    #[test]
+    fn raw_value_conversion() {
+        for hash_algorithm in [CidHashAlgorithm::Sha2_256, CidHashAlgorithm::Blake3_256] {
+            let raw_value: u8 = hash_algorithm.into();
+            assert_eq!(CidHashAlgorithm::from_u8(raw_value), Some(hash_algorithm));
+        }
+        assert_eq!(CidHashAlgorithm::from_u8(42), None);
+    }
+}
@@ -26,9 +26,16 @@
     unreachable_patterns
 )]
 
+mod canonical_json;
+mod hash_algorithm;
+
+pub use canonical_json::to_canonical_json;
+pub use hash_algorithm::CidHashAlgorithm;
+
 use serde::Deserialize;
 use serde::Serialize;
 
+use std::convert::TryFrom;
 use std::fmt;
 use std::marker::PhantomData;
 
@@ -44,6 +51,19 @@ impl<T: ?Sized> CID<T> {
     pub fn into_inner(self) -> String {
         self.0
     }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the codec and the hash algorithm recorded in this CID,
+    /// None is returned if it's malformed or its hash algorithm is unsupported.
+    pub fn codec_and_hash_algorithm(&self) -> Option<(u64, CidHashAlgorithm)> {
+        let cid = cid::Cid::try_from(self.0.as_str()).ok()?;
+        let hash_algorithm = CidHashAlgorithm::from_multihash_code(cid.hash().code())?;
+
+        Some((cid.codec(), hash_algorithm))
+    }
 }
 
 impl<T: ?Sized> fmt::Debug for CID<T> {
@@ -73,19 +93,28 @@ impl<T: ?Sized> From<CID<T>> for String {
     }
 }
 
+// seems to be better than RAW_CODEC = 0x55
+pub const JSON_CODEC: u64 = 0x0200;
+
 // TODO we might refactor this to `SerializationFormat` trait
 // that both transform data to binary/text form (be it JSON, CBOR or something else)
 // and produces CID too
 pub fn json_data_cid<Val: ?Sized>(data: &[u8]) -> CID<Val> {
+    data_cid(data, JSON_CODEC, CidHashAlgorithm::default())
+}
+
+/// Calculate a CID of data, the codec and the hash algorithm are recorded in the CID.
+pub fn data_cid<Val: ?Sized>(
+    data: &[u8],
+    codec: u64,
+    hash_algorithm: CidHashAlgorithm,
+) -> CID<Val> {
     use cid::Cid;
-    use multihash::{Code, MultihashDigest};
+    use multihash::MultihashDigest;
 
-    // the Sha2_256 is current IPFS default hash
-    let digest = Code::Sha2_256.digest(data);
-    // seems to be better than RAW_CODEC = 0x55
-    const JSON_CODEC: u64 = 0x0200;
+    let digest = hash_algorithm.multihash_code().digest(data);
 
-    let cid = Cid::new_v1(JSON_CODEC, digest);
+    let cid = Cid::new_v1(codec, digest);
     CID::new(cid.to_string())
 }
 
@@ -115,8 +144,18 @@ impl std::error::Error for CidCalculationError {
     }
 }
 
-/// Calculate a CID of JSON-serialized value.
+/// Calculate a CID of a value serialized to the canonical JSON form.
 pub fn value_to_json_cid<Val: Serialize>(value: &Val) -> Result<CID<Val>, CidCalculationError> {
-    let data = serde_json::to_vec(value)?;
-    Ok(json_data_cid(&data))
+    value_to_cid(value, JSON_CODEC, CidHashAlgorithm::default())
+}
+
+/// Calculate a CID of a value serialized to the canonical JSON form
+/// with the provided codec and hash algorithm.
+pub fn value_to_cid<Val: Serialize>(
+    value: &Val,
+    codec: u64,
+    hash_algorithm: CidHashAlgorithm,
+) -> Result<CID<Val>, CidCalculationError> {
+    let data = to_canonical_json(value)?;
+    Ok(data_cid(&data, codec, hash_algorithm))
 }
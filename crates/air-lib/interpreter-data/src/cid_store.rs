@@ -16,11 +16,14 @@
 
 use crate::JValue;
 
-use air_interpreter_cid::value_to_json_cid;
+use air_interpreter_cid::value_to_cid;
 use air_interpreter_cid::CidCalculationError;
+use air_interpreter_cid::CidHashAlgorithm;
 use air_interpreter_cid::CID;
+use air_interpreter_cid::JSON_CODEC;
 use serde::Deserialize;
 use serde::Serialize;
+use thiserror::Error as ThisError;
 
 use std::{collections::HashMap, rc::Rc};
 
@@ -45,6 +48,60 @@ impl<Val> CidStore<Val> {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Retains only values which CIDs satisfy the predicate.
+    pub fn retain(&mut self, mut predicate: impl FnMut(&CID<Val>) -> bool) {
+        self.0.retain(|cid, _| predicate(cid));
+    }
+}
+
+impl<Val: Serialize> CidStore<Val> {
+    /// Recomputes CIDs of all stored values with the hash algorithm recorded in their CIDs
+    /// and checks that they match their keys, so a peer can't bind an arbitrary value
+    /// to an existing CID. Values are always stored as canonical JSON, so CIDs of other
+    /// codecs are rejected.
+    pub fn verify(&self) -> Result<(), CidVerificationError> {
+        for (cid, value) in &self.0 {
+            let (codec, hash_algorithm) = cid
+                .codec_and_hash_algorithm()
+                .ok_or_else(|| CidVerificationError::UnsupportedCid(cid.as_str().to_owned()))?;
+            if codec != JSON_CODEC {
+                return Err(CidVerificationError::UnsupportedCodec {
+                    cid: cid.as_str().to_owned(),
+                    codec,
+                });
+            }
+            let actual_cid = value_to_cid(&**value, codec, hash_algorithm).map_err(|error| {
+                CidVerificationError::CalculationFailed(cid.as_str().to_owned(), error)
+            })?;
+            if actual_cid != **cid {
+                return Err(CidVerificationError::Mismatch {
+                    expected_cid: cid.as_str().to_owned(),
+                    actual_cid: actual_cid.into(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum CidVerificationError {
+    #[error("value stored under CID {expected_cid} has CID {actual_cid}")]
+    Mismatch {
+        expected_cid: String,
+        actual_cid: String,
+    },
+
+    #[error("CID of value stored under CID {0} can't be calculated: {1}")]
+    CalculationFailed(String, CidCalculationError),
+
+    #[error("CID {0} is malformed or uses an unsupported hash algorithm")]
+    UnsupportedCid(String),
+
+    #[error("CID {cid} uses codec {codec:#x}, but stored values are encoded as JSON")]
+    UnsupportedCodec { cid: String, codec: u64 },
 }
 
 impl<Val> Default for CidStore<Val> {
@@ -56,6 +113,7 @@ impl<Val> Default for CidStore<Val> {
 #[derive(Clone, Debug)]
 pub struct CidTracker<Val = JValue> {
     cids: HashMap<Rc<CID<Val>>, Rc<Val>>,
+    hash_algorithm: CidHashAlgorithm,
 }
 
 impl<Val> CidTracker<Val> {
@@ -63,13 +121,22 @@ impl<Val> CidTracker<Val> {
         Self::default()
     }
 
-    pub fn from_cid_stores(prev_cid_map: CidStore<Val>, current_cid_map: CidStore<Val>) -> Self {
+    /// Values are looked up by CIDs calculated with any supported hash algorithm,
+    /// the provided one is used only for newly recorded values.
+    pub fn from_cid_stores(
+        prev_cid_map: CidStore<Val>,
+        current_cid_map: CidStore<Val>,
+        hash_algorithm: CidHashAlgorithm,
+    ) -> Self {
         let mut cids = prev_cid_map.0;
         for (cid, val) in current_cid_map.0 {
-            // TODO check that values matches?
+            // values from the current data are verified on the preparation step
             cids.insert(cid, val);
         }
-        Self { cids }
+        Self {
+            cids,
+            hash_algorithm,
+        }
     }
 
     pub fn get(&self, cid: &CID<Val>) -> Option<Rc<Val>> {
@@ -83,16 +150,23 @@ impl<Val: Serialize> CidTracker<Val> {
         value: impl Into<Rc<Val>>,
     ) -> Result<Rc<CID<Val>>, CidCalculationError> {
         let value = value.into();
-        let cid = Rc::new(value_to_json_cid(&*value)?);
+        let cid = self.calculate_cid(&value)?;
         self.cids.insert(cid.clone(), value);
         Ok(cid)
     }
+
+    /// Calculates a CID of the value the same way as `record_value` does, but doesn't keep it.
+    pub fn calculate_cid(&self, value: &Val) -> Result<Rc<CID<Val>>, CidCalculationError> {
+        let cid = value_to_cid(value, JSON_CODEC, self.hash_algorithm)?;
+        Ok(Rc::new(cid))
+    }
 }
 
 impl<Val> Default for CidTracker<Val> {
     fn default() -> Self {
         Self {
             cids: Default::default(),
+            hash_algorithm: Default::default(),
         }
     }
 }
@@ -162,6 +236,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verify() {
+        let mut tracker = CidTracker::new();
+        tracker.record_value(json!("test")).unwrap();
+        tracker.record_value(json!([1, 2, 3])).unwrap();
+        let store = CidStore::from(tracker);
+        assert!(store.verify().is_ok());
+
+        let forged_cid = "bagaaierajwlhumardpzj6dv2ahcerm3vyfrjwl7nahg7zq5o3eprwv6v3vpa";
+        let forged_store = CidStore(HashMap::from_iter(vec![(
+            Rc::new(CID::new(forged_cid)),
+            Rc::new(json!("forged")),
+        )]));
+        let result = forged_store.verify();
+        assert!(matches!(
+            result,
+            Err(CidVerificationError::Mismatch { ref expected_cid, .. }) if expected_cid == forged_cid
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_other_codecs() {
+        const RAW_CODEC: u64 = 0x55;
+
+        let value = json!("test");
+        let raw_cid = value_to_cid(&value, RAW_CODEC, CidHashAlgorithm::default()).unwrap();
+        let raw_cid_str = raw_cid.as_str().to_owned();
+        let store = CidStore(HashMap::from_iter(vec![(Rc::new(raw_cid), Rc::new(value))]));
+
+        let result = store.verify();
+        assert!(matches!(
+            result,
+            Err(CidVerificationError::UnsupportedCodec { ref cid, codec: RAW_CODEC }) if *cid == raw_cid_str
+        ));
+    }
+
+    #[test]
+    fn test_verify_any_hash_algorithm() {
+        let mut sha2_tracker = CidTracker::new();
+        let sha2_cid = sha2_tracker.record_value(json!("test")).unwrap();
+
+        let mut blake3_tracker = CidTracker::from_cid_stores(
+            sha2_tracker.into(),
+            <_>::default(),
+            CidHashAlgorithm::Blake3_256,
+        );
+        let blake3_cid = blake3_tracker.record_value(json!("test")).unwrap();
+        assert_ne!(sha2_cid, blake3_cid);
+
+        let store = CidStore::from(blake3_tracker);
+        assert_eq!(store.len(), 2);
+        assert!(store.verify().is_ok());
+    }
+
     #[test]
     fn test_store() {
         let mut tracker = CidTracker::new();
@@ -0,0 +1,186 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_utils::measure;
+use thiserror::Error as ThisError;
+
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+/// Prefix of compressed data, it's followed by the decompressed size as u32 in little endian
+/// and a LZ4 block. It doesn't clash with JSON and CBOR data markers.
+pub const COMPRESSED_DATA_MARKER: &[u8] = b"\0LZ4";
+
+const DECOMPRESSED_SIZE_LEN: usize = std::mem::size_of::<u32>();
+const HEADER_SIZE: usize = COMPRESSED_DATA_MARKER.len() + DECOMPRESSED_SIZE_LEN;
+
+/// LZ4 can't compress data better than this, it bounds a decompressed size declared in a header,
+/// so malformed data can't make the interpreter allocate a huge buffer.
+const MAX_LZ4_COMPRESSION_RATIO: usize = 255;
+
+#[derive(Debug, ThisError)]
+pub enum DecompressionError {
+    #[error("header of compressed data is truncated")]
+    TruncatedHeader,
+
+    #[error("{compressed_size} compressed bytes can't be decompressed to declared {declared_size} bytes")]
+    InvalidDeclaredSize {
+        declared_size: usize,
+        compressed_size: usize,
+    },
+
+    #[error(transparent)]
+    Lz4(#[from] lz4_flex::block::DecompressError),
+}
+
+/// Compresses data if it's not smaller than the threshold and compression actually reduces its size,
+/// otherwise returns the data as is. Zero threshold disables compression.
+pub fn compress_data(data: Vec<u8>, threshold: u32) -> Vec<u8> {
+    if threshold == 0 || data.len() < threshold as usize {
+        return data;
+    }
+
+    let decompressed_size = match u32::try_from(data.len()) {
+        Ok(size) => size,
+        Err(_) => return data,
+    };
+
+    let compressed_block = measure!(
+        lz4_flex::block::compress(&data),
+        tracing::Level::INFO,
+        "lz4_flex::block::compress"
+    );
+    if HEADER_SIZE + compressed_block.len() >= data.len() {
+        return data;
+    }
+
+    let mut compressed_data = Vec::with_capacity(HEADER_SIZE + compressed_block.len());
+    compressed_data.extend_from_slice(COMPRESSED_DATA_MARKER);
+    compressed_data.extend_from_slice(&decompressed_size.to_le_bytes());
+    compressed_data.extend(compressed_block);
+
+    compressed_data
+}
+
+/// Decompresses data if it starts with the compressed data marker, otherwise returns it as is.
+pub fn decompress_data(data: &[u8]) -> Result<Cow<'_, [u8]>, DecompressionError> {
+    if !is_compressed(data) {
+        return Ok(Cow::Borrowed(data));
+    }
+
+    let (header, compressed_block) = match data.len() {
+        len if len >= HEADER_SIZE => data.split_at(HEADER_SIZE),
+        _ => return Err(DecompressionError::TruncatedHeader),
+    };
+
+    let mut declared_size = [0u8; DECOMPRESSED_SIZE_LEN];
+    declared_size.copy_from_slice(&header[COMPRESSED_DATA_MARKER.len()..]);
+    let declared_size = u32::from_le_bytes(declared_size) as usize;
+
+    let max_size = compressed_block
+        .len()
+        .saturating_mul(MAX_LZ4_COMPRESSION_RATIO);
+    if declared_size > max_size {
+        return Err(DecompressionError::InvalidDeclaredSize {
+            declared_size,
+            compressed_size: compressed_block.len(),
+        });
+    }
+
+    let decompressed_data = measure!(
+        lz4_flex::block::decompress(compressed_block, declared_size)?,
+        tracing::Level::INFO,
+        "lz4_flex::block::decompress"
+    );
+
+    Ok(Cow::Owned(decompressed_data))
+}
+
+pub fn is_compressed(data: &[u8]) -> bool {
+    data.starts_with(COMPRESSED_DATA_MARKER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compressible_data() -> Vec<u8> {
+        br#"{"trace":[{"call":{"executed":{"scalar":"value"}}}]}"#.repeat(64)
+    }
+
+    #[test]
+    fn compressed_data_roundtrip() {
+        let data = compressible_data();
+
+        let compressed_data = compress_data(data.clone(), 1024);
+        assert!(is_compressed(&compressed_data));
+        assert!(compressed_data.len() < data.len());
+
+        let decompressed_data = decompress_data(&compressed_data).unwrap();
+        assert_eq!(decompressed_data.as_ref(), data.as_slice());
+    }
+
+    #[test]
+    fn small_data_isnt_compressed() {
+        let data = compressible_data();
+
+        let not_compressed_data = compress_data(data.clone(), data.len() as u32 + 1);
+        assert_eq!(not_compressed_data, data);
+
+        let not_compressed_data = compress_data(data.clone(), 0);
+        assert_eq!(not_compressed_data, data);
+    }
+
+    #[test]
+    fn incompressible_data_isnt_compressed() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        let not_compressed_data = compress_data(data.clone(), 1);
+        assert_eq!(not_compressed_data, data);
+    }
+
+    #[test]
+    fn not_compressed_data_is_borrowed() {
+        let data = compressible_data();
+
+        let decompressed_data = decompress_data(&data).unwrap();
+        assert!(matches!(decompressed_data, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn malformed_data_is_rejected() {
+        let truncated_data = COMPRESSED_DATA_MARKER.to_vec();
+        let result = decompress_data(&truncated_data);
+        assert!(matches!(result, Err(DecompressionError::TruncatedHeader)));
+
+        let mut huge_declared_size = COMPRESSED_DATA_MARKER.to_vec();
+        huge_declared_size.extend_from_slice(&u32::MAX.to_le_bytes());
+        huge_declared_size.extend_from_slice(&[0, 1, 2]);
+        let result = decompress_data(&huge_declared_size);
+        assert!(matches!(
+            result,
+            Err(DecompressionError::InvalidDeclaredSize {
+                declared_size,
+                compressed_size: 3,
+            }) if declared_size == u32::MAX as usize
+        ));
+
+        let mut invalid_block = compress_data(compressible_data(), 1);
+        invalid_block.truncate(invalid_block.len() - 10);
+        let result = decompress_data(&invalid_block);
+        assert!(matches!(result, Err(DecompressionError::Lz4(_))));
+    }
+}
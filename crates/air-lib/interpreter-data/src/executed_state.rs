@@ -53,6 +53,10 @@ pub enum CallResult {
     /// A corresponding call's been already executed with such value as a result.
     Executed(ValueRef),
 
+    /// A corresponding call without an output variable's been already executed with a value
+    /// of such CID as a result, the value itself isn't kept in data.
+    Unused(Rc<CID<JValue>>),
+
     /// call_service ended with a service error.
     #[serde(rename = "failed")]
     CallServiceFailed(i32, Rc<String>),
@@ -130,6 +134,14 @@ pub struct ApResult {
     pub res_generations: Vec<u32>,
 }
 
+/// Decision of a ttl_guard instruction made by the first peer executed it, it's kept
+/// in a trace, because the decision depends on the host time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct TtlGuardResult {
+    pub passed: bool,
+}
+
 /// Contains ids of element that were on a stream at the moment of an appropriate canon call.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -154,4 +166,10 @@ pub enum ExecutedState {
     Fold(FoldResult),
     Ap(ApResult),
     Canon(CanonResult),
+    /// Result of applying a key-value pair to a stream map, it has the same merging rules
+    /// as Ap, because stream maps are streams of key-value pairs.
+    ApMap(ApResult),
+    /// Canonicalized stream map, it has the same merging rules as Canon.
+    CanonMap(CanonResult),
+    TtlGuard(TtlGuardResult),
 }
@@ -54,6 +54,10 @@ impl CallResult {
         CallResult::Executed(value)
     }
 
+    pub fn unused(cid: Rc<CID<JValue>>) -> CallResult {
+        CallResult::Unused(cid)
+    }
+
     pub fn failed(ret_code: i32, error_msg: impl Into<String>) -> CallResult {
         CallResult::CallServiceFailed(ret_code, Rc::new(error_msg.into()))
     }
@@ -87,6 +91,12 @@ impl ApResult {
     }
 }
 
+impl TtlGuardResult {
+    pub fn new(passed: bool) -> Self {
+        Self { passed }
+    }
+}
+
 impl CanonResult {
     pub fn new(
         tetraplet: Rc<CID<SecurityTetraplet>>,
@@ -110,6 +120,7 @@ impl std::fmt::Display for ExecutedState {
             Call(Executed(value)) => {
                 write!(f, "executed({value})")
             }
+            Call(Unused(cid)) => write!(f, "unused({cid:?})"),
             Call(CallServiceFailed(ret_code, err_msg)) => {
                 write!(f, r#"call_service_failed({ret_code}, "{err_msg}")"#)
             }
@@ -134,6 +145,15 @@ impl std::fmt::Display for ExecutedState {
             Canon(_) => {
                 write!(f, "canon [<object>]")
             }
+            ApMap(ap) => {
+                write!(f, "ap_map: _ -> {:?}", ap.res_generations)
+            }
+            CanonMap(_) => {
+                write!(f, "canon_map [<object>]")
+            }
+            TtlGuard(TtlGuardResult { passed }) => {
+                write!(f, "ttl_guard({passed})")
+            }
         }
     }
 }
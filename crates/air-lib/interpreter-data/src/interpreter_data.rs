@@ -17,11 +17,18 @@
 use super::GlobalStreamGens;
 use super::RestrictedStreamGens;
 use crate::cid_store::CidStore;
+use crate::serialization::deserialize_data;
+use crate::serialization::serialize_data;
 use crate::CanonCidAggregate;
+use crate::CidVerificationError;
+use crate::DataDeError;
+use crate::DataDiagnostic;
+use crate::DataSeError;
 use crate::ExecutionTrace;
 use crate::JValue;
 
-use air_utils::measure;
+use air_interpreter_interface::DataFormat;
+use air_interpreter_signatures::SignatureStore;
 use polyplets::SecurityTetraplet;
 
 use serde::Deserialize;
@@ -59,6 +66,10 @@ pub struct InterpreterData {
 
     /// CID-to-somethings mappings.
     pub cid_info: CidInfo,
+
+    /// Signatures of CIDs of states produced by peers that sign their states.
+    #[serde(default)]
+    pub signatures: SignatureStore,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -82,6 +93,7 @@ impl InterpreterData {
             last_call_request_id: 0,
             restricted_streams: RestrictedStreamGens::new(),
             cid_info: <_>::default(),
+            signatures: <_>::default(),
         }
     }
 
@@ -91,6 +103,7 @@ impl InterpreterData {
         streams: GlobalStreamGens,
         restricted_streams: RestrictedStreamGens,
         cid_info: CidInfo,
+        signatures: SignatureStore,
         last_call_request_id: u32,
         interpreter_version: semver::Version,
     ) -> Self {
@@ -103,21 +116,41 @@ impl InterpreterData {
             last_call_request_id,
             restricted_streams,
             cid_info,
+            signatures,
         }
     }
 
-    /// Tries to de InterpreterData from slice according to the data version.
-    pub fn try_from_slice(slice: &[u8]) -> Result<Self, serde_json::Error> {
-        measure!(
-            serde_json::from_slice(slice),
-            tracing::Level::INFO,
-            "serde_json::from_slice"
-        )
+    /// Tries to de InterpreterData from slice according to the data format marker,
+    /// data of older versions is migrated to the current version.
+    pub fn try_from_slice(slice: &[u8]) -> Result<Self, DataDeError> {
+        // the data is parsed only once to a JSON value, versions are read from it
+        // to migrate older data, and then the same value is converted to InterpreterData
+        let mut data = deserialize_data::<JValue>(slice)?;
+        // unreadable versions are reported with a detailed error by the conversion
+        let migration_needed = Versions::deserialize(&data).map_or(false, |versions| {
+            crate::is_migration_needed(&versions.data_version)
+        });
+        if migration_needed {
+            crate::migrate_data(&mut data)
+                .map_err(|error| DataDeError::Migration(Box::new(error)))?;
+        }
+
+        serde_json::from_value(data).map_err(Into::into)
     }
 
     /// Tries to de only versions part of interpreter data.
-    pub fn try_get_versions(slice: &[u8]) -> Result<Versions, serde_json::Error> {
-        serde_json::from_slice(slice)
+    pub fn try_get_versions(slice: &[u8]) -> Result<Versions, DataDeError> {
+        deserialize_data(slice)
+    }
+
+    /// Serializes InterpreterData to the provided format.
+    pub fn serialize(&self, format: DataFormat) -> Result<Vec<u8>, DataSeError> {
+        serialize_data(self, format)
+    }
+
+    /// Checks structural consistency of the trace and CID stores, see `check_data_sanity`.
+    pub fn check_sanity(&self) -> Vec<DataDiagnostic> {
+        crate::check_data_sanity(self)
     }
 }
 
@@ -142,6 +175,15 @@ pub struct CidInfo {
     pub canon_store: CidStore<CanonCidAggregate>,
 }
 
+impl CidInfo {
+    /// Checks that all values correspond to their CIDs.
+    pub fn verify(&self) -> Result<(), CidVerificationError> {
+        self.value_store.verify()?;
+        self.tetraplet_store.verify()?;
+        self.canon_store.verify()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -187,4 +229,54 @@ mod tests {
         let data_0_6_0 = serde_json::from_slice::<InterpreterData0_6_0>(&data_0_6_1_se);
         assert!(data_0_6_0.is_ok());
     }
+
+    #[test]
+    fn cbor_data_roundtrip() {
+        use crate::CallResult;
+        use crate::CidTracker;
+        use crate::ExecutedState;
+        use serde_json::json;
+
+        let mut value_tracker = CidTracker::new();
+        let value_cid = value_tracker
+            .record_value(json!({"array": [1, -2, 3.5], "null": null, "string": "value"}))
+            .unwrap();
+
+        let mut data = InterpreterData::new(semver::Version::new(1, 1, 1));
+        data.trace = vec![
+            ExecutedState::par(1, 0),
+            ExecutedState::Call(CallResult::executed_scalar(value_cid)),
+        ]
+        .into();
+        data.global_streams.insert("$stream".to_string(), 2);
+        data.last_call_request_id = 42;
+        data.cid_info.value_store = value_tracker.into();
+
+        let serialized_data = data.serialize(DataFormat::Cbor).unwrap();
+        assert!(serialized_data.starts_with(crate::CBOR_DATA_MARKER));
+
+        let deserialized_data = InterpreterData::try_from_slice(&serialized_data).unwrap();
+        assert_eq!(deserialized_data.trace, data.trace);
+        assert_eq!(deserialized_data.global_streams, data.global_streams);
+        assert_eq!(deserialized_data.last_call_request_id, 42);
+        assert_eq!(
+            deserialized_data.cid_info.value_store,
+            data.cid_info.value_store
+        );
+    }
+
+    #[test]
+    fn versions_are_read_from_both_formats() {
+        let interpreter_version = semver::Version::new(1, 2, 3);
+        let data = InterpreterData::new(interpreter_version.clone());
+
+        for format in [DataFormat::Json, DataFormat::Cbor] {
+            let serialized_data = data.serialize(format).unwrap();
+            assert_eq!(crate::detect_data_format(&serialized_data), format);
+
+            let versions = InterpreterData::try_get_versions(&serialized_data).unwrap();
+            assert_eq!(versions.interpreter_version, interpreter_version);
+            assert_eq!(&versions.data_version, crate::data_version());
+        }
+    }
 }
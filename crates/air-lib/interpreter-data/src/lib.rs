@@ -27,15 +27,23 @@
 )]
 
 mod cid_store;
+mod compression;
 mod executed_state;
 mod interpreter_data;
+mod migration;
+mod sanity_check;
+mod serialization;
 mod stream_generations;
 mod trace;
 mod trace_pos;
 
 pub use cid_store::*;
+pub use compression::*;
 pub use executed_state::*;
 pub use interpreter_data::*;
+pub use migration::*;
+pub use sanity_check::*;
+pub use serialization::*;
 pub use stream_generations::*;
 pub use trace::*;
 pub use trace_pos::*;
@@ -0,0 +1,401 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use crate::JValue;
+
+use air_interpreter_cid::value_to_cid;
+use air_interpreter_cid::CID;
+use serde_json::Map;
+use thiserror::Error as ThisError;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// A step upgrading data of `from_version` or any later version before `to_version`
+/// to `to_version`. Steps operate on generic JSON values, because data of older versions
+/// can't be deserialized to the current `InterpreterData`.
+#[derive(Debug)]
+pub struct Migration {
+    pub from_version: semver::Version,
+    pub to_version: semver::Version,
+    pub description: &'static str,
+    migrate: fn(&mut Map<String, JValue>) -> Result<(), String>,
+}
+
+/// Registered migrations ordered by their versions. A new data version requires a new migration
+/// only if it's incompatible with the previous one, data of a compatible version is accepted as is.
+/// The oldest supported data is of the 0.2.2 version, which already keeps values in `cid_info`.
+///
+/// Unused call results introduced in 0.7.0 need no migration of their own: it isn't known which
+/// calls have no output variable without the AIR script produced them, and older scalar states
+/// are still accepted for such calls, so data older than 0.7.0 only needs its CIDs recomputed.
+static MIGRATIONS: &[Migration] = &[Migration {
+    from_version: semver::Version::new(0, 2, 2),
+    to_version: semver::Version::new(0, 8, 0),
+    description: "recompute CIDs from the canonical JSON form of values",
+    migrate: recompute_cids,
+}];
+
+#[derive(Debug, ThisError)]
+pub enum MigrationError {
+    #[error("data doesn't contain a valid data version")]
+    NoDataVersion,
+
+    #[error("data of {0} version can't be migrated, the oldest supported data version is {1}")]
+    UnsupportedVersion(semver::Version, semver::Version),
+
+    #[error("data of {0} version is newer than the current {1} data version")]
+    NewerVersion(semver::Version, semver::Version),
+
+    #[error("data can't be migrated from {from_version} to {to_version} version: {reason}")]
+    StepFailed {
+        from_version: semver::Version,
+        to_version: semver::Version,
+        reason: String,
+    },
+}
+
+/// Returns all registered migrations ordered by their versions.
+pub fn migrations() -> &'static [Migration] {
+    MIGRATIONS
+}
+
+/// Returns true if data of the provided version should be migrated before deserialization.
+pub fn is_migration_needed(data_version: &semver::Version) -> bool {
+    MIGRATIONS
+        .last()
+        .map_or(false, |migration| data_version < &migration.to_version)
+}
+
+/// Upgrades data to the current data version step by step and returns the applied migrations.
+pub fn migrate_data(data: &mut JValue) -> Result<Vec<&'static Migration>, MigrationError> {
+    let data = data.as_object_mut().ok_or(MigrationError::NoDataVersion)?;
+    let mut data_version = read_data_version(data)?;
+
+    let current_version = crate::data_version();
+    if &data_version > current_version {
+        return Err(MigrationError::NewerVersion(
+            data_version,
+            current_version.clone(),
+        ));
+    }
+
+    let mut applied_migrations = vec![];
+    while is_migration_needed(&data_version) {
+        let migration = MIGRATIONS
+            .iter()
+            .find(|migration| {
+                migration.from_version <= data_version && data_version < migration.to_version
+            })
+            .ok_or_else(|| {
+                MigrationError::UnsupportedVersion(
+                    data_version.clone(),
+                    MIGRATIONS[0].from_version.clone(),
+                )
+            })?;
+
+        (migration.migrate)(data).map_err(|reason| MigrationError::StepFailed {
+            from_version: data_version.clone(),
+            to_version: migration.to_version.clone(),
+            reason,
+        })?;
+        data_version = migration.to_version.clone();
+        applied_migrations.push(migration);
+    }
+
+    write_data_version(data, current_version);
+    Ok(applied_migrations)
+}
+
+fn read_data_version(data: &Map<String, JValue>) -> Result<semver::Version, MigrationError> {
+    data.get("version")
+        .and_then(JValue::as_str)
+        .and_then(|version| semver::Version::from_str(version).ok())
+        .ok_or(MigrationError::NoDataVersion)
+}
+
+fn write_data_version(data: &mut Map<String, JValue>, version: &semver::Version) {
+    data.insert("version".to_owned(), JValue::String(version.to_string()));
+}
+
+fn trace_states_mut(data: &mut Map<String, JValue>) -> Result<&mut Vec<JValue>, String> {
+    data.get_mut("trace")
+        .and_then(JValue::as_array_mut)
+        .ok_or_else(|| "trace isn't an array".to_owned())
+}
+
+/// CIDs used to be calculated from the default serde_json form of values, so they depended on
+/// the field order of structs and the formatting of floats. Stores are recomputed bottom-up,
+/// because canon values refer to CIDs of values and tetraplets. The codec and the hash algorithm
+/// recorded in each CID are kept. Values of unused call results aren't kept, so their CIDs are
+/// recomputed only if the same value is stored for another state, other ones are left as is.
+fn recompute_cids(data: &mut Map<String, JValue>) -> Result<(), String> {
+    let cid_info = data
+        .get_mut("cid_info")
+        .and_then(JValue::as_object_mut)
+        .ok_or_else(|| "cid_info isn't an object".to_owned())?;
+
+    let value_cids = recompute_store(cid_info, "value_store", |_| {})?;
+    let tetraplet_cids = recompute_store(cid_info, "tetraplet_store", |_| {})?;
+    let canon_cids = recompute_store(cid_info, "canon_store", |canon_value| {
+        replace_cid(canon_value.get_mut("value"), &value_cids);
+        replace_cid(canon_value.get_mut("tetraplet"), &tetraplet_cids);
+    })?;
+
+    for state in trace_states_mut(data)? {
+        if let Some(executed) = state.pointer_mut("/call/executed") {
+            replace_cid(executed.get_mut("scalar"), &value_cids);
+            replace_cid(executed.pointer_mut("/stream/cid"), &value_cids);
+        }
+        replace_cid(state.pointer_mut("/call/unused"), &value_cids);
+
+        for canon_kind in ["canon", "canon_map"].iter() {
+            if let Some(canon) = state.get_mut(*canon_kind) {
+                replace_cid(canon.get_mut("tetraplet"), &tetraplet_cids);
+                if let Some(values) = canon.get_mut("values").and_then(JValue::as_array_mut) {
+                    for value in values {
+                        replace_cid(Some(value), &canon_cids);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Recomputes CIDs of values of the store after applying `update_refs` to them,
+/// returns a mapping from old CIDs to new ones.
+fn recompute_store(
+    cid_info: &mut Map<String, JValue>,
+    store_name: &str,
+    update_refs: impl Fn(&mut JValue),
+) -> Result<HashMap<String, String>, String> {
+    let store = match cid_info.get_mut(store_name) {
+        Some(JValue::Object(store)) => store,
+        Some(_) => return Err(format!("{} isn't an object", store_name)),
+        None => return Ok(<_>::default()),
+    };
+
+    let mut new_cids = HashMap::with_capacity(store.len());
+    for (cid, mut value) in std::mem::take(store) {
+        update_refs(&mut value);
+
+        let (codec, hash_algorithm) = CID::<JValue>::new(cid.as_str())
+            .codec_and_hash_algorithm()
+            .ok_or_else(|| format!("CID {} in {} is malformed", cid, store_name))?;
+        let new_cid = value_to_cid(&value, codec, hash_algorithm)
+            .map_err(|error| error.to_string())?
+            .into_inner();
+
+        store.insert(new_cid.clone(), value);
+        new_cids.insert(cid, new_cid);
+    }
+
+    Ok(new_cids)
+}
+
+fn replace_cid(cid: Option<&mut JValue>, new_cids: &HashMap<String, String>) {
+    if let Some(JValue::String(cid)) = cid {
+        if let Some(new_cid) = new_cids.get(cid) {
+            *cid = new_cid.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CallResult;
+    use crate::ExecutedState;
+    use crate::InterpreterData;
+
+    use air_interpreter_cid::value_to_json_cid;
+    use serde_json::json;
+
+    use std::rc::Rc;
+
+    // produced by the 0.35.0 interpreter
+    const DATA_0_6_0: &[u8] =
+        include_bytes!("../../../../benches/performance_metering/network_explore/cur_data.json");
+    // produced by the 0.35.1 interpreter
+    const DATA_0_2_2: &[u8] =
+        include_bytes!("../../../../benches/performance_metering/big_values_data/prev_data.json");
+
+    #[test]
+    fn data_is_migrated() {
+        let mut data: JValue = serde_json::from_slice(DATA_0_6_0).unwrap();
+        let legacy_trace = data["trace"].clone();
+        let legacy_scalar_cid = legacy_trace[0]["call"]["executed"]["scalar"].clone();
+        let legacy_value =
+            data["cid_info"]["value_store"][legacy_scalar_cid.as_str().unwrap()].clone();
+
+        let applied_versions = migrate_data(&mut data)
+            .unwrap()
+            .into_iter()
+            .map(|migration| migration.to_version.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(applied_versions, ["0.8.0"]);
+        assert_eq!(data["version"], json!(crate::data_version().to_string()));
+
+        let data: InterpreterData = serde_json::from_value(data).unwrap();
+        assert_eq!(data.trace.len(), legacy_trace.as_array().unwrap().len());
+        assert_eq!(data.last_call_request_id, 5);
+        assert!(data.cid_info.verify().is_ok());
+        assert!(data.check_sanity().is_empty());
+
+        let scalar_cid = Rc::new(value_to_json_cid(&legacy_value).unwrap());
+        let trace: &[ExecutedState] = &data.trace;
+        assert_eq!(
+            trace[0],
+            ExecutedState::Call(CallResult::executed_scalar(scalar_cid.clone()))
+        );
+        assert_eq!(
+            data.cid_info.value_store.get(&scalar_cid).as_deref(),
+            Some(&legacy_value)
+        );
+    }
+
+    #[test]
+    fn old_data_is_migrated_on_deserialization() {
+        for raw_data in [DATA_0_2_2, DATA_0_6_0] {
+            let legacy_data: JValue = serde_json::from_slice(raw_data).unwrap();
+            let data = InterpreterData::try_from_slice(raw_data).unwrap();
+            assert_eq!(&data.versions.data_version, crate::data_version());
+            assert_eq!(
+                data.cid_info.value_store.len(),
+                legacy_data["cid_info"]["value_store"]
+                    .as_object()
+                    .unwrap()
+                    .len()
+            );
+            assert!(data.cid_info.verify().is_ok());
+            assert!(data.check_sanity().is_empty());
+        }
+    }
+
+    #[test]
+    fn compatible_data_isnt_migrated() {
+        let mut data =
+            serde_json::to_value(InterpreterData::new(semver::Version::new(1, 1, 1))).unwrap();
+        data["version"] = json!("0.8.0");
+        let expected_data = data.clone();
+
+        assert!(migrate_data(&mut data).unwrap().is_empty());
+        assert_eq!(data["version"], json!(crate::data_version().to_string()));
+        data["version"] = json!("0.8.0");
+        assert_eq!(data, expected_data);
+    }
+
+    #[test]
+    fn legacy_cids_are_recomputed() {
+        // produced by the 0.37.0 interpreter, which calculated CIDs from the default JSON form
+        let raw_data = include_bytes!("../fixtures/data_0_6_3.json");
+        let legacy_data: JValue = serde_json::from_slice(raw_data).unwrap();
+        let legacy_tetraplet_cid = legacy_data["trace"][4]["canon"]["tetraplet"].clone();
+
+        let data = InterpreterData::try_from_slice(raw_data).unwrap();
+        assert!(data.cid_info.verify().is_ok());
+        assert!(data.check_sanity().is_empty());
+        let trace: &[ExecutedState] = &data.trace;
+
+        let value = json!({"float": 1.5e30, "int": 42, "list": [1.0, "\u{1f600}"]});
+        let value_cid = Rc::new(value_to_json_cid(&value).unwrap());
+        assert_eq!(
+            trace[0],
+            ExecutedState::Call(CallResult::executed_scalar(value_cid.clone()))
+        );
+        assert_eq!(
+            trace[3],
+            ExecutedState::Call(CallResult::executed_stream(value_cid.clone(), 1))
+        );
+
+        let canon = match &trace[4] {
+            ExecutedState::Canon(canon) => canon,
+            state => panic!("expected a canon state, got {:?}", state),
+        };
+        assert_ne!(json!(canon.tetraplet.as_str()), legacy_tetraplet_cid);
+        assert_eq!(
+            data.cid_info
+                .tetraplet_store
+                .get(&canon.tetraplet)
+                .unwrap()
+                .peer_pk,
+            "peer_1_id"
+        );
+        assert_eq!(canon.values.len(), 2);
+        let canon_value = data.cid_info.canon_store.get(&canon.values[0]).unwrap();
+        assert_eq!(canon_value.value, value_cid);
+        assert_eq!(
+            data.cid_info.value_store.get(&canon_value.value).as_deref(),
+            Some(&value)
+        );
+    }
+
+    #[test]
+    fn legacy_cids_of_unused_results_are_recomputed() {
+        // 0.7.0 data with CIDs calculated from the default JSON form, the second state is
+        // an unused result of a stored value, the third one is of a value that isn't stored
+        let raw_data = include_bytes!("../fixtures/data_0_7_0.json");
+        let legacy_data: JValue = serde_json::from_slice(raw_data).unwrap();
+        let legacy_unused_cid = legacy_data["trace"][2]["call"]["unused"].as_str().unwrap();
+
+        let data = InterpreterData::try_from_slice(raw_data).unwrap();
+        assert!(data.cid_info.verify().is_ok());
+        assert!(data.check_sanity().is_empty());
+        let trace: &[ExecutedState] = &data.trace;
+
+        let value = json!({"float": 1.5e30, "int": 42, "list": [1.0, "\u{1f600}"]});
+        let value_cid = Rc::new(value_to_json_cid(&value).unwrap());
+        assert_eq!(
+            trace[0],
+            ExecutedState::Call(CallResult::executed_scalar(value_cid.clone()))
+        );
+        assert_eq!(trace[1], ExecutedState::Call(CallResult::unused(value_cid)));
+        assert_eq!(
+            trace[2],
+            ExecutedState::Call(CallResult::unused(Rc::new(CID::new(legacy_unused_cid))))
+        );
+    }
+
+    #[test]
+    fn unsupported_data_is_rejected() {
+        let mut data: JValue = serde_json::from_slice(DATA_0_6_0).unwrap();
+        data["version"] = json!("0.2.1");
+        assert!(matches!(
+            migrate_data(&mut data),
+            Err(MigrationError::UnsupportedVersion(version, _)) if version == semver::Version::new(0, 2, 1)
+        ));
+
+        data["version"] = json!("42.0.0");
+        assert!(matches!(
+            migrate_data(&mut data),
+            Err(MigrationError::NewerVersion(..))
+        ));
+
+        data.as_object_mut().unwrap().remove("version");
+        assert!(matches!(
+            migrate_data(&mut data),
+            Err(MigrationError::NoDataVersion)
+        ));
+
+        let mut data: JValue = serde_json::from_slice(DATA_0_6_0).unwrap();
+        data["cid_info"] = json!([]);
+        assert!(matches!(
+            migrate_data(&mut data),
+            Err(MigrationError::StepFailed { to_version, .. }) if to_version == semver::Version::new(0, 8, 0)
+        ));
+    }
+}
@@ -0,0 +1,605 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use crate::ApResult;
+use crate::CallResult;
+use crate::CanonResult;
+use crate::ExecutedState;
+use crate::FoldResult;
+use crate::InterpreterData;
+use crate::ParResult;
+use crate::SubTraceDesc;
+use crate::TracePos;
+use crate::ValueRef;
+
+use thiserror::Error as ThisError;
+
+use std::collections::HashSet;
+
+/// Count of subtraces each fold lore should have, see `FoldSubTraceLore` for details.
+const SUBTRACE_DESC_COUNT: usize = 2;
+
+/// Structural inconsistency of interpreter data found by the sanity check,
+/// every diagnostic contains a trace position of a state it relates to.
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+pub enum DataDiagnostic {
+    #[error(
+        "par at {position} describes {} and {} states, but only {available} states left in its subtrace",
+        .par.left_size,
+        .par.right_size
+    )]
+    ParSizeOverflow {
+        position: TracePos,
+        par: ParResult,
+        available: usize,
+    },
+
+    #[error("fold at {position} describes {states_count} states, but only {available} states left in its subtrace")]
+    FoldSizeOverflow {
+        position: TracePos,
+        states_count: usize,
+        available: usize,
+    },
+
+    #[error("total length of subtraces of fold at {position} overflows")]
+    FoldSubtraceLenOverflow { position: TracePos },
+
+    #[error("lore of fold at {position} for value at {value_pos} contains {count} subtraces instead of {SUBTRACE_DESC_COUNT}")]
+    IncorrectSubtracesCount {
+        position: TracePos,
+        value_pos: TracePos,
+        count: usize,
+    },
+
+    #[error("fold at {position} contains several lores for value at {value_pos}")]
+    SeveralLoresWithSamePos {
+        position: TracePos,
+        value_pos: TracePos,
+    },
+
+    #[error("lore of fold at {position} refers to {value_pos}, which isn't a stream state")]
+    InvalidLoreValuePos {
+        position: TracePos,
+        value_pos: TracePos,
+    },
+
+    #[error(
+        "subtrace of {} states at {} of fold at {position} for value at {value_pos} lies outside of states of this fold",
+        .subtrace.subtrace_len,
+        .subtrace.begin_pos
+    )]
+    SubtraceOutOfFold {
+        position: TracePos,
+        value_pos: TracePos,
+        subtrace: SubTraceDesc,
+    },
+
+    #[error("ap result at {position} contains {count} generations instead of one")]
+    InvalidApGenerations { position: TracePos, count: usize },
+
+    #[error("value with CID {cid} referenced at {position} isn't found in the value store")]
+    MissingValue { position: TracePos, cid: String },
+
+    #[error(
+        "tetraplet with CID {cid} referenced at {position} isn't found in the tetraplet store"
+    )]
+    MissingTetraplet { position: TracePos, cid: String },
+
+    #[error("canon value with CID {cid} referenced at {position} isn't found in the canon store")]
+    MissingCanonValue { position: TracePos, cid: String },
+}
+
+/// Checks that sizes of par and fold states fit into their subtraces, fold lores are well-formed,
+/// ap results have exactly one generation and all CIDs referenced from the trace are present
+/// in CID stores. It allows to reject malformed data before execution instead of failing
+/// somewhere in the middle of it, and doesn't depend on an AIR script, so it's also usable by tools.
+pub fn check_data_sanity(data: &InterpreterData) -> Vec<DataDiagnostic> {
+    let mut checker = SanityChecker {
+        data,
+        diagnostics: Vec::new(),
+    };
+    checker.check();
+    checker.diagnostics
+}
+
+struct SanityChecker<'data> {
+    data: &'data InterpreterData,
+    diagnostics: Vec<DataDiagnostic>,
+}
+
+impl SanityChecker<'_> {
+    fn check(&mut self) {
+        // subtraces are kept in a queue instead of recursion,
+        // so deeply nested pars can't overflow the stack
+        let mut subtraces = vec![(0, self.data.trace.len())];
+
+        while let Some((begin, end)) = subtraces.pop() {
+            let mut position = begin;
+            while position < end {
+                position = self.check_state(position, end, &mut subtraces);
+            }
+        }
+    }
+
+    /// Checks a state and returns a position of the next state in the same subtrace.
+    fn check_state(
+        &mut self,
+        position: usize,
+        end: usize,
+        subtraces: &mut Vec<(usize, usize)>,
+    ) -> usize {
+        let trace_pos = TracePos::from(position);
+        // a nested state always starts right after its parent one
+        let available = end - position - 1;
+
+        match &self.data.trace[trace_pos] {
+            ExecutedState::Par(par) => {
+                match par.size().filter(|&size| size <= available) {
+                    Some(size) => {
+                        let left_end = position + 1 + par.left_size as usize;
+                        // the left subtrace is popped first to report diagnostics in trace order
+                        subtraces.push((left_end, position + 1 + size));
+                        subtraces.push((position + 1, left_end));
+                        position + 1 + size
+                    }
+                    None => {
+                        self.diagnostics.push(DataDiagnostic::ParSizeOverflow {
+                            position: trace_pos,
+                            par: *par,
+                            available,
+                        });
+                        // states after a malformed par can't be attributed to any subtrace
+                        end
+                    }
+                }
+            }
+            ExecutedState::Fold(fold) => match self.check_fold(trace_pos, fold, available) {
+                Some(states_count) => {
+                    subtraces.push((position + 1, position + 1 + states_count));
+                    position + 1 + states_count
+                }
+                None => end,
+            },
+            ExecutedState::Call(CallResult::Executed(
+                ValueRef::Scalar(cid) | ValueRef::Stream { cid, .. },
+            )) => {
+                if self.data.cid_info.value_store.get(cid).is_none() {
+                    self.diagnostics.push(DataDiagnostic::MissingValue {
+                        position: trace_pos,
+                        cid: cid.as_str().to_owned(),
+                    });
+                }
+                position + 1
+            }
+            ExecutedState::Call(_) | ExecutedState::TtlGuard(_) => position + 1,
+            ExecutedState::Ap(ap_result) | ExecutedState::ApMap(ap_result) => {
+                if !is_ap_result_valid(ap_result) {
+                    self.diagnostics.push(DataDiagnostic::InvalidApGenerations {
+                        position: trace_pos,
+                        count: ap_result.res_generations.len(),
+                    });
+                }
+                position + 1
+            }
+            ExecutedState::Canon(canon_result) | ExecutedState::CanonMap(canon_result) => {
+                self.check_canon(trace_pos, canon_result);
+                position + 1
+            }
+        }
+    }
+
+    /// Checks lores of a fold and returns a count of states it describes,
+    /// if they fit into the available states.
+    fn check_fold(
+        &mut self,
+        position: TracePos,
+        fold: &FoldResult,
+        available: usize,
+    ) -> Option<usize> {
+        let mut value_positions = HashSet::with_capacity(fold.lore.len());
+        let mut states_count: usize = 0;
+
+        for lore in &fold.lore {
+            let value_pos = lore.value_pos;
+            let count = lore.subtraces_desc.len();
+            if count != SUBTRACE_DESC_COUNT {
+                self.diagnostics
+                    .push(DataDiagnostic::IncorrectSubtracesCount {
+                        position,
+                        value_pos,
+                        count,
+                    });
+            }
+            if !value_positions.insert(value_pos) {
+                self.diagnostics
+                    .push(DataDiagnostic::SeveralLoresWithSamePos {
+                        position,
+                        value_pos,
+                    });
+            }
+            if !self.is_stream_state(value_pos) {
+                self.diagnostics.push(DataDiagnostic::InvalidLoreValuePos {
+                    position,
+                    value_pos,
+                });
+            }
+
+            for subtrace in &lore.subtraces_desc {
+                states_count = match states_count.checked_add(subtrace.subtrace_len as usize) {
+                    Some(states_count) => states_count,
+                    None => {
+                        self.diagnostics
+                            .push(DataDiagnostic::FoldSubtraceLenOverflow { position });
+                        return None;
+                    }
+                };
+            }
+        }
+
+        if states_count > available {
+            self.diagnostics.push(DataDiagnostic::FoldSizeOverflow {
+                position,
+                states_count,
+                available,
+            });
+            return None;
+        }
+
+        // all states of all iterations directly follow the fold state
+        let fold_begin = position + 1;
+        let fold_end = fold_begin + states_count;
+        for lore in &fold.lore {
+            for subtrace in &lore.subtraces_desc {
+                // an empty subtrace doesn't refer to any state
+                if subtrace.subtrace_len == 0 {
+                    continue;
+                }
+
+                let subtrace_end = subtrace
+                    .begin_pos
+                    .checked_add(subtrace.subtrace_len as usize);
+                let fits = subtrace.begin_pos >= fold_begin
+                    && matches!(subtrace_end, Some(subtrace_end) if subtrace_end <= fold_end);
+                if !fits {
+                    self.diagnostics.push(DataDiagnostic::SubtraceOutOfFold {
+                        position,
+                        value_pos: lore.value_pos,
+                        subtrace: *subtrace,
+                    });
+                }
+            }
+        }
+
+        Some(states_count)
+    }
+
+    fn check_canon(&mut self, position: TracePos, canon_result: &CanonResult) {
+        let cid_info = &self.data.cid_info;
+
+        if cid_info
+            .tetraplet_store
+            .get(&canon_result.tetraplet)
+            .is_none()
+        {
+            self.diagnostics.push(DataDiagnostic::MissingTetraplet {
+                position,
+                cid: canon_result.tetraplet.as_str().to_owned(),
+            });
+        }
+
+        for value_cid in &canon_result.values {
+            let aggregate = match cid_info.canon_store.get(value_cid) {
+                Some(aggregate) => aggregate,
+                None => {
+                    self.diagnostics.push(DataDiagnostic::MissingCanonValue {
+                        position,
+                        cid: value_cid.as_str().to_owned(),
+                    });
+                    continue;
+                }
+            };
+
+            if cid_info.value_store.get(&aggregate.value).is_none() {
+                self.diagnostics.push(DataDiagnostic::MissingValue {
+                    position,
+                    cid: aggregate.value.as_str().to_owned(),
+                });
+            }
+            if cid_info.tetraplet_store.get(&aggregate.tetraplet).is_none() {
+                self.diagnostics.push(DataDiagnostic::MissingTetraplet {
+                    position,
+                    cid: aggregate.tetraplet.as_str().to_owned(),
+                });
+            }
+        }
+    }
+
+    /// Fold lores refer to states that put values into an iterated stream.
+    fn is_stream_state(&self, position: TracePos) -> bool {
+        match self.data.trace.get(position) {
+            Some(ExecutedState::Call(CallResult::Executed(ValueRef::Stream { .. }))) => true,
+            Some(ExecutedState::Ap(ap_result) | ExecutedState::ApMap(ap_result)) => {
+                is_ap_result_valid(ap_result)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn is_ap_result_valid(ap_result: &ApResult) -> bool {
+    ap_result.res_generations.len() == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CanonCidAggregate;
+    use crate::CidStore;
+    use crate::CidTracker;
+    use crate::FoldSubTraceLore;
+
+    use polyplets::SecurityTetraplet;
+    use serde_json::json;
+
+    fn subtrace_lore(value_pos: usize, subtraces: &[(usize, u32)]) -> FoldSubTraceLore {
+        FoldSubTraceLore {
+            value_pos: value_pos.into(),
+            subtraces_desc: subtraces
+                .iter()
+                .map(|&(begin_pos, subtrace_len)| SubTraceDesc {
+                    begin_pos: begin_pos.into(),
+                    subtrace_len,
+                })
+                .collect(),
+        }
+    }
+
+    fn fold(lore: Vec<FoldSubTraceLore>) -> ExecutedState {
+        ExecutedState::Fold(FoldResult { lore })
+    }
+
+    fn data_from_trace(trace: Vec<ExecutedState>) -> InterpreterData {
+        let mut data = InterpreterData::new(semver::Version::new(1, 1, 1));
+        data.trace = trace.into();
+        data
+    }
+
+    /// A fold over a stream of two values with one call before next in each iteration,
+    /// wrapped into a par.
+    fn well_formed_data() -> InterpreterData {
+        let mut value_tracker = CidTracker::new();
+        let value_1 = value_tracker.record_value(json!(1)).unwrap();
+        let value_2 = value_tracker.record_value(json!(2)).unwrap();
+        let result = value_tracker.record_value(json!("result")).unwrap();
+
+        let mut tetraplet_tracker = CidTracker::<SecurityTetraplet>::new();
+        let tetraplet = tetraplet_tracker
+            .record_value(SecurityTetraplet::literal_tetraplet("peer"))
+            .unwrap();
+        let mut canon_tracker = CidTracker::<CanonCidAggregate>::new();
+        let canon_value = canon_tracker
+            .record_value(CanonCidAggregate {
+                value: value_1.clone(),
+                tetraplet: tetraplet.clone(),
+            })
+            .unwrap();
+
+        let trace = vec![
+            ExecutedState::par(6, 1),
+            ExecutedState::Call(CallResult::executed_stream(value_1, 0)),
+            ExecutedState::Ap(ApResult::new(1)),
+            fold(vec![
+                subtrace_lore(1, &[(4, 1), (6, 0)]),
+                subtrace_lore(2, &[(5, 1), (6, 0)]),
+            ]),
+            ExecutedState::Call(CallResult::executed_scalar(result.clone())),
+            ExecutedState::Call(CallResult::executed_scalar(result)),
+            ExecutedState::Canon(CanonResult::new(tetraplet, vec![canon_value])),
+            ExecutedState::Call(CallResult::executed_stream(value_2, 1)),
+        ];
+
+        let mut data = InterpreterData::new(semver::Version::new(1, 1, 1));
+        data.trace = trace.into();
+        data.cid_info.value_store = value_tracker.into();
+        data.cid_info.tetraplet_store = tetraplet_tracker.into();
+        data.cid_info.canon_store = canon_tracker.into();
+        data
+    }
+
+    #[test]
+    fn well_formed_data_passes_check() {
+        assert_eq!(well_formed_data().check_sanity(), vec![]);
+        assert_eq!(
+            InterpreterData::new(semver::Version::new(1, 1, 1)).check_sanity(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn oversized_par_is_reported() {
+        let mut data = well_formed_data();
+        data.trace[0.into()] = ExecutedState::par(6, 2);
+        assert_eq!(
+            data.check_sanity(),
+            vec![DataDiagnostic::ParSizeOverflow {
+                position: 0.into(),
+                par: ParResult::new(6, 2),
+                available: 7,
+            }]
+        );
+
+        let mut data = well_formed_data();
+        data.trace[0.into()] = ExecutedState::Par(ParResult::new(u32::MAX, 1));
+        assert_eq!(
+            data.check_sanity(),
+            vec![DataDiagnostic::ParSizeOverflow {
+                position: 0.into(),
+                par: ParResult::new(u32::MAX, 1),
+                available: 7,
+            }]
+        );
+    }
+
+    #[test]
+    fn nested_par_is_checked_against_its_subtrace() {
+        let mut data = data_from_trace(vec![
+            ExecutedState::par(1, 1),
+            ExecutedState::par(1, 0),
+            ExecutedState::Call(CallResult::sent_peer_id("peer".to_string().into())),
+        ]);
+        assert_eq!(
+            data.check_sanity(),
+            vec![DataDiagnostic::ParSizeOverflow {
+                position: 1.into(),
+                par: ParResult::new(1, 0),
+                available: 0,
+            }]
+        );
+
+        data.trace[0.into()] = ExecutedState::par(2, 0);
+        assert_eq!(data.check_sanity(), vec![]);
+    }
+
+    #[test]
+    fn oversized_fold_is_reported() {
+        let mut data = well_formed_data();
+        data.trace[3.into()] = fold(vec![
+            subtrace_lore(1, &[(4, 1), (6, 0)]),
+            subtrace_lore(2, &[(5, 3), (8, 0)]),
+        ]);
+        assert_eq!(
+            data.check_sanity(),
+            vec![DataDiagnostic::FoldSizeOverflow {
+                position: 3.into(),
+                states_count: 4,
+                available: 3,
+            }]
+        );
+
+        data.trace[3.into()] = fold(vec![
+            subtrace_lore(1, &[(4, u32::MAX), (6, 0)]),
+            subtrace_lore(2, &[(5, u32::MAX), (6, u32::MAX)]),
+        ]);
+        let diagnostics = data.check_sanity();
+        if usize::BITS > u32::BITS {
+            assert!(matches!(
+                diagnostics.as_slice(),
+                [DataDiagnostic::FoldSizeOverflow { .. }]
+            ));
+        } else {
+            assert!(matches!(
+                diagnostics.as_slice(),
+                [DataDiagnostic::FoldSubtraceLenOverflow { .. }]
+            ));
+        }
+    }
+
+    #[test]
+    fn malformed_fold_lore_is_reported() {
+        let mut data = well_formed_data();
+        data.trace[3.into()] = fold(vec![
+            subtrace_lore(1, &[(4, 1)]),
+            subtrace_lore(1, &[(5, 1), (6, 0)]),
+            subtrace_lore(4, &[(4, 0), (6, 0)]),
+        ]);
+        assert_eq!(
+            data.check_sanity(),
+            vec![
+                DataDiagnostic::IncorrectSubtracesCount {
+                    position: 3.into(),
+                    value_pos: 1.into(),
+                    count: 1,
+                },
+                DataDiagnostic::SeveralLoresWithSamePos {
+                    position: 3.into(),
+                    value_pos: 1.into(),
+                },
+                DataDiagnostic::InvalidLoreValuePos {
+                    position: 3.into(),
+                    value_pos: 4.into(),
+                },
+            ]
+        );
+
+        data.trace[3.into()] = fold(vec![
+            subtrace_lore(1, &[(3, 1), (6, 0)]),
+            subtrace_lore(42, &[(5, 1), (6, 0)]),
+        ]);
+        assert_eq!(
+            data.check_sanity(),
+            vec![
+                DataDiagnostic::InvalidLoreValuePos {
+                    position: 3.into(),
+                    value_pos: 42.into(),
+                },
+                DataDiagnostic::SubtraceOutOfFold {
+                    position: 3.into(),
+                    value_pos: 1.into(),
+                    subtrace: SubTraceDesc::new(3.into(), 1),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_ap_generations_are_reported() {
+        let mut data = well_formed_data();
+        data.trace[2.into()] = ExecutedState::Ap(ApResult {
+            res_generations: vec![],
+        });
+        assert_eq!(
+            data.check_sanity(),
+            vec![
+                DataDiagnostic::InvalidApGenerations {
+                    position: 2.into(),
+                    count: 0,
+                },
+                DataDiagnostic::InvalidLoreValuePos {
+                    position: 3.into(),
+                    value_pos: 2.into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_cids_are_reported() {
+        let mut data = well_formed_data();
+        data.cid_info.value_store = CidStore::new();
+        data.cid_info.tetraplet_store = CidStore::new();
+
+        let diagnostics = data.check_sanity();
+        let missing_values = diagnostics
+            .iter()
+            .filter(|diagnostic| matches!(diagnostic, DataDiagnostic::MissingValue { .. }))
+            .count();
+        let missing_tetraplets = diagnostics
+            .iter()
+            .filter(|diagnostic| matches!(diagnostic, DataDiagnostic::MissingTetraplet { .. }))
+            .count();
+        // two stream values, two scalar results and a canonicalized value
+        assert_eq!(missing_values, 5);
+        // tetraplets of the canon state and of its value
+        assert_eq!(missing_tetraplets, 2);
+        assert_eq!(diagnostics.len(), 7);
+
+        let mut data = well_formed_data();
+        data.cid_info.canon_store = CidStore::new();
+        assert!(matches!(
+            data.check_sanity().as_slice(),
+            [DataDiagnostic::MissingCanonValue { position, .. }] if *position == 6.into()
+        ));
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::compression::decompress_data;
+use crate::compression::DecompressionError;
+use crate::migration::MigrationError;
+
+use air_interpreter_interface::DataFormat;
+use air_utils::measure;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error as ThisError;
+
+/// Prefix of data serialized to CBOR, a JSON document can't start with a zero byte,
+/// so it unambiguously distinguishes these formats.
+pub const CBOR_DATA_MARKER: &[u8] = b"\0CBOR";
+
+// some variants are boxed to keep errors containing this one small
+#[derive(Debug, ThisError)]
+pub enum DataDeError {
+    #[error("JSON data can't be deserialized: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("CBOR data can't be deserialized: {0}")]
+    Cbor(Box<ciborium::de::Error<std::io::Error>>),
+
+    #[error("data can't be decompressed: {0}")]
+    Decompression(Box<DecompressionError>),
+
+    #[error("data can't be migrated to the current version: {0}")]
+    Migration(Box<MigrationError>),
+}
+
+#[derive(Debug, ThisError)]
+pub enum DataSeError {
+    #[error("data can't be serialized to JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("data can't be serialized to CBOR: {0}")]
+    Cbor(#[from] ciborium::ser::Error<std::io::Error>),
+}
+
+/// Determines format of serialized and decompressed data by its marker,
+/// data without a marker is JSON.
+pub fn detect_data_format(slice: &[u8]) -> DataFormat {
+    if slice.starts_with(CBOR_DATA_MARKER) {
+        DataFormat::Cbor
+    } else {
+        DataFormat::Json
+    }
+}
+
+pub(crate) fn serialize_data<T: Serialize>(
+    value: &T,
+    format: DataFormat,
+) -> Result<Vec<u8>, DataSeError> {
+    match format {
+        DataFormat::Json => measure!(
+            serde_json::to_vec(value).map_err(Into::into),
+            tracing::Level::INFO,
+            "serde_json::to_vec"
+        ),
+        DataFormat::Cbor => {
+            let mut data = CBOR_DATA_MARKER.to_vec();
+            measure!(
+                ciborium::ser::into_writer(value, &mut data)?,
+                tracing::Level::INFO,
+                "ciborium::ser::into_writer"
+            );
+            Ok(data)
+        }
+    }
+}
+
+pub(crate) fn deserialize_data<T: DeserializeOwned>(slice: &[u8]) -> Result<T, DataDeError> {
+    let slice =
+        decompress_data(slice).map_err(|error| DataDeError::Decompression(Box::new(error)))?;
+    let slice = slice.as_ref();
+
+    match detect_data_format(slice) {
+        DataFormat::Json => measure!(
+            serde_json::from_slice(slice).map_err(Into::into),
+            tracing::Level::INFO,
+            "serde_json::from_slice"
+        ),
+        DataFormat::Cbor => measure!(
+            ciborium::de::from_reader(&slice[CBOR_DATA_MARKER.len()..])
+                .map_err(|error| DataDeError::Cbor(Box::new(error))),
+            tracing::Level::INFO,
+            "ciborium::de::from_reader"
+        ),
+    }
+}
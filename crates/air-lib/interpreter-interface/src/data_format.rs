@@ -0,0 +1,74 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// Format of interpreter data produced by the interpreter.
+/// Data in any of these formats is accepted as input regardless of this setting.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DataFormat {
+    /// Human-readable JSON.
+    #[default]
+    Json = 0,
+
+    /// Binary CBOR prefixed with a format marker.
+    Cbor = 1,
+}
+
+impl DataFormat {
+    /// Converts a raw value passed in `RunParameters`, since Marine records can't contain enums.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Json),
+            1 => Some(Self::Cbor),
+            _ => None,
+        }
+    }
+}
+
+impl From<DataFormat> for u8 {
+    fn from(data_format: DataFormat) -> Self {
+        data_format as u8
+    }
+}
+
+impl FromStr for DataFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "cbor" => Ok(Self::Cbor),
+            _ => Err(format!(
+                "unknown data format `{s}`, expected `json` or `cbor`"
+            )),
+        }
+    }
+}
+
+impl Display for DataFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json => write!(f, "json"),
+            Self::Cbor => write!(f, "cbor"),
+        }
+    }
+}
@@ -28,10 +28,12 @@
 
 mod call_request_parameters;
 mod call_service_result;
+mod data_format;
 mod interpreter_outcome;
 mod run_parameters;
 
 pub use call_request_parameters::*;
 pub use call_service_result::*;
+pub use data_format::*;
 pub use interpreter_outcome::*;
 pub use run_parameters::*;
@@ -25,7 +25,7 @@ use serde::Serialize;
 
 /// Parameters that a host side should pass to an interpreter and that necessary for execution.
 #[cfg_attr(feature = "marine", marine)]
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct RunParameters {
     /// Peer id of a peer that start this particle.
     pub init_peer_id: String,
@@ -39,18 +39,42 @@ pub struct RunParameters {
 
     /// TTL set by init peer id in milliseconds.
     pub ttl: u32,
+
+    /// Id of a particle being executed.
+    pub particle_id: String,
+
+    /// Unix timestamp of a host in milliseconds at the moment of execution.
+    /// It's used to check how much of particle TTL remains.
+    pub current_timestamp: u64,
+
+    /// Format of produced data, it's `DataFormat` converted to u8.
+    pub data_format: u8,
+
+    /// Produced data not smaller than this size in bytes is compressed, 0 disables compression.
+    pub compression_threshold: u32,
+
+    /// Disables verification of CIDs in the current data, it could be set if the data
+    /// is received from a trusted peer.
+    pub skip_cid_verification: bool,
+
+    /// Hash algorithm used to calculate CIDs of produced values, it's `CidHashAlgorithm` converted to u8.
+    /// Values with CIDs calculated by any supported algorithm are accepted regardless of this setting.
+    pub cid_hash_algorithm: u8,
+
+    /// Ed25519 secret key of the current peer used to sign CIDs of produced states,
+    /// an empty key disables signing.
+    pub signing_key: Vec<u8>,
+
+    /// Rejects states of peers that don't sign them. Signatures present in data are verified
+    /// regardless, so it should be set only if all peers of a particle sign their states.
+    pub require_signatures: bool,
+
+    /// Enables tracking of where states of the result trace came from, the report is returned
+    /// by the separate `merge_provenance` export. It's intended for debugging merge issues.
+    pub track_merge_provenance: bool,
 }
 
 impl RunParameters {
-    pub fn new(init_peer_id: String, current_peer_id: String, timestamp: u64, ttl: u32) -> Self {
-        Self {
-            init_peer_id,
-            current_peer_id,
-            timestamp,
-            ttl,
-        }
-    }
-
     #[cfg(feature = "marine")]
     pub fn into_ivalue(self) -> IValue {
         let run_parameters = vec![
@@ -58,9 +82,42 @@ impl RunParameters {
             IValue::String(self.current_peer_id),
             IValue::U64(self.timestamp),
             IValue::U32(self.ttl),
+            IValue::String(self.particle_id),
+            IValue::U64(self.current_timestamp),
+            IValue::U8(self.data_format),
+            IValue::U32(self.compression_threshold),
+            IValue::Boolean(self.skip_cid_verification),
+            IValue::U8(self.cid_hash_algorithm),
+            IValue::ByteArray(self.signing_key),
+            IValue::Boolean(self.require_signatures),
+            IValue::Boolean(self.track_merge_provenance),
         ];
         // unwrap is safe here because run_parameters is non-empty array
         let run_parameters = NEVec::new(run_parameters).unwrap();
         IValue::Record(run_parameters)
     }
 }
+
+// the signing key is secret, so it's never written to logs
+impl std::fmt::Debug for RunParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RunParameters")
+            .field("init_peer_id", &self.init_peer_id)
+            .field("current_peer_id", &self.current_peer_id)
+            .field("timestamp", &self.timestamp)
+            .field("ttl", &self.ttl)
+            .field("particle_id", &self.particle_id)
+            .field("current_timestamp", &self.current_timestamp)
+            .field("data_format", &self.data_format)
+            .field("compression_threshold", &self.compression_threshold)
+            .field("skip_cid_verification", &self.skip_cid_verification)
+            .field("cid_hash_algorithm", &self.cid_hash_algorithm)
+            .field(
+                "signing_key",
+                &format_args!("<{} bytes>", self.signing_key.len()),
+            )
+            .field("require_signatures", &self.require_signatures)
+            .field("track_merge_provenance", &self.track_merge_provenance)
+            .finish()
+    }
+}
@@ -0,0 +1,120 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use ed25519_dalek::PublicKey;
+use ed25519_dalek::SecretKey;
+use ed25519_dalek::Signature;
+use ed25519_dalek::SignatureError;
+use ed25519_dalek::Signer;
+use thiserror::Error as ThisError;
+
+/// Prefix of a libp2p peer id containing an ed25519 public key:
+/// identity multihash of 36 bytes length, followed by the protobuf encoded
+/// public key of the ed25519 type and 32 bytes length.
+const ED25519_PEER_ID_PREFIX: [u8; 6] = [0x00, 0x24, 0x08, 0x01, 0x12, 0x20];
+const ED25519_PEER_ID_LEN: usize = ED25519_PEER_ID_PREFIX.len() + ed25519_dalek::PUBLIC_KEY_LENGTH;
+
+#[derive(Debug, ThisError)]
+pub enum KeyError {
+    #[error("ed25519 secret key is invalid: {0}")]
+    InvalidSecretKey(SignatureError),
+
+    #[error("peer id `{0}` doesn't contain an ed25519 public key")]
+    InvalidPeerId(String),
+}
+
+/// Ed25519 key pair of a peer, its public key is also recoverable from the peer id.
+pub struct KeyPair(ed25519_dalek::Keypair);
+
+impl KeyPair {
+    /// Creates a key pair from a 32 bytes ed25519 secret key.
+    pub fn from_secret_key(secret_key: &[u8]) -> Result<Self, KeyError> {
+        let secret = SecretKey::from_bytes(secret_key).map_err(KeyError::InvalidSecretKey)?;
+        let public = PublicKey::from(&secret);
+
+        Ok(Self(ed25519_dalek::Keypair { secret, public }))
+    }
+
+    /// Returns a base58 encoded libp2p peer id corresponding to this key pair.
+    pub fn peer_id(&self) -> String {
+        let mut peer_id = ED25519_PEER_ID_PREFIX.to_vec();
+        peer_id.extend_from_slice(self.0.public.as_bytes());
+
+        bs58::encode(peer_id).into_string()
+    }
+
+    pub(crate) fn sign(&self, message: &[u8]) -> Signature {
+        self.0.sign(message)
+    }
+}
+
+// never reveal the secret key in logs
+impl std::fmt::Debug for KeyPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("KeyPair").field(&self.peer_id()).finish()
+    }
+}
+
+/// Recovers a public key from a libp2p peer id, only ed25519 peer ids are supported.
+pub(crate) fn public_key_from_peer_id(peer_id: &str) -> Result<PublicKey, KeyError> {
+    let invalid_peer_id = || KeyError::InvalidPeerId(peer_id.to_string());
+
+    let peer_id_bytes = bs58::decode(peer_id)
+        .into_vec()
+        .map_err(|_| invalid_peer_id())?;
+    if peer_id_bytes.len() != ED25519_PEER_ID_LEN
+        || !peer_id_bytes.starts_with(&ED25519_PEER_ID_PREFIX)
+    {
+        return Err(invalid_peer_id());
+    }
+
+    PublicKey::from_bytes(&peer_id_bytes[ED25519_PEER_ID_PREFIX.len()..])
+        .map_err(|_| invalid_peer_id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peer_id_roundtrip() {
+        let key_pair = KeyPair::from_secret_key(&[42; 32]).unwrap();
+        let peer_id = key_pair.peer_id();
+        assert!(peer_id.starts_with("12D3KooW"), "{}", peer_id);
+
+        let public_key = public_key_from_peer_id(&peer_id).unwrap();
+        assert_eq!(public_key, key_pair.0.public);
+    }
+
+    #[test]
+    fn invalid_keys_are_rejected() {
+        let result = KeyPair::from_secret_key(&[42; 31]);
+        assert!(matches!(result, Err(KeyError::InvalidSecretKey(_))));
+
+        for peer_id in [
+            "peer_id",
+            "",
+            "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N",
+        ] {
+            let result = public_key_from_peer_id(peer_id);
+            assert!(
+                matches!(result, Err(KeyError::InvalidPeerId(_))),
+                "{}",
+                peer_id
+            );
+        }
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![forbid(unsafe_code)]
+#![warn(rust_2018_idioms)]
+#![deny(
+    dead_code,
+    nonstandard_style,
+    unused_imports,
+    unused_mut,
+    unused_variables,
+    unused_unsafe,
+    unreachable_patterns
+)]
+
+mod key_pair;
+mod signature_store;
+mod signature_tracker;
+
+pub use key_pair::KeyError;
+pub use key_pair::KeyPair;
+pub use signature_store::SignatureStore;
+pub use signature_store::SignatureVerificationError;
+pub use signature_store::SignedCids;
+pub use signature_tracker::SignatureTracker;
+
+use air_interpreter_cid::to_canonical_json;
+use air_interpreter_cid::value_to_json_cid;
+use air_interpreter_cid::CidCalculationError;
+use serde::Serialize;
+
+/// A message a peer signs, it binds produced states to a particle, so signatures
+/// can't be replayed in other particles. It's signed in the canonical JSON form, so other
+/// implementations produce the same bytes, see `vectors/signed_message.json` of the CID crate.
+#[derive(Serialize)]
+struct SignedMessage<'a, Cids> {
+    particle_id: &'a str,
+    peer_id: &'a str,
+    cids: Cids,
+}
+
+fn signed_message<'a, Cids: Serialize>(
+    particle_id: &'a str,
+    peer_id: &'a str,
+    cids: Cids,
+) -> Vec<u8> {
+    let message = SignedMessage {
+        particle_id,
+        peer_id,
+        cids,
+    };
+
+    // a struct of strings and a sequence of strings is always serializable
+    to_canonical_json(&message).expect("default serializer shouldn't fail")
+}
+
+/// CID of a state a peer signs, it's always calculated by the default hash algorithm,
+/// so signatures don't depend on a hash algorithm used by a peer for CIDs in data.
+fn state_cid<State: Serialize>(state: &State) -> Result<String, CidCalculationError> {
+    value_to_json_cid(state).map(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+    use serde_json::Value as JValue;
+
+    #[derive(Deserialize)]
+    struct CrossCheckVectors {
+        vectors: Vec<CrossCheckVector>,
+    }
+
+    #[derive(Deserialize)]
+    struct CrossCheckVector {
+        description: String,
+        secret_key: String,
+        peer_id: String,
+        particle_id: String,
+        states: Vec<String>,
+        cids: Vec<String>,
+        message: String,
+        signature: String,
+    }
+
+    #[test]
+    fn cross_check_vectors() {
+        let vectors: CrossCheckVectors = serde_json::from_str(include_str!(
+            "../../interpreter-cid/vectors/signed_message.json"
+        ))
+        .unwrap();
+
+        for vector in vectors.vectors {
+            let secret_key = bs58::decode(&vector.secret_key).into_vec().unwrap();
+            let key_pair = KeyPair::from_secret_key(&secret_key).unwrap();
+            assert_eq!(key_pair.peer_id(), vector.peer_id, "{}", vector.description);
+
+            let mut tracker = SignatureTracker::new();
+            for (state, expected_cid) in vector.states.iter().zip(&vector.cids) {
+                let state: JValue = serde_json::from_str(state).unwrap();
+                assert_eq!(
+                    &state_cid(&state).unwrap(),
+                    expected_cid,
+                    "{}",
+                    vector.description
+                );
+                tracker.register_state(&state).unwrap();
+            }
+
+            let message = signed_message(&vector.particle_id, &vector.peer_id, &vector.cids);
+            assert_eq!(
+                String::from_utf8(message).unwrap(),
+                vector.message,
+                "{}",
+                vector.description
+            );
+
+            let signed_cids = tracker
+                .into_signed_cids(&key_pair, &vector.particle_id)
+                .unwrap();
+            assert_eq!(
+                signed_cids.signature, vector.signature,
+                "{}",
+                vector.description
+            );
+        }
+    }
+}
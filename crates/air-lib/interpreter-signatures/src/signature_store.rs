@@ -0,0 +1,319 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::key_pair::public_key_from_peer_id;
+use super::KeyError;
+
+use air_interpreter_cid::CidCalculationError;
+use ed25519_dalek::Signature;
+use ed25519_dalek::Verifier;
+use serde::Deserialize;
+use serde::Serialize;
+use thiserror::Error as ThisError;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+
+/// CIDs of states produced by a peer during one run and its signature of them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedCids {
+    pub cids: BTreeSet<String>,
+
+    /// Base58 encoded ed25519 signature of these CIDs, a particle id and a peer id.
+    pub signature: String,
+}
+
+/// Maps a peer id to signed CIDs of states produced by this peer, each of its runs
+/// producing states adds a new record.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SignatureStore(BTreeMap<String, Vec<SignedCids>>);
+
+#[derive(Debug, ThisError)]
+pub enum SignatureVerificationError {
+    #[error(transparent)]
+    InvalidPeerId(#[from] KeyError),
+
+    #[error("signature of `{peer_id}` is malformed")]
+    MalformedSignature { peer_id: String },
+
+    #[error("signature of `{peer_id}` doesn't correspond to CIDs signed by it")]
+    InvalidSignature { peer_id: String },
+
+    #[error("state with CID `{cid}` is produced by `{peer_id}`, but it isn't signed by this peer")]
+    UnsignedState { peer_id: String, cid: String },
+
+    #[error("CID of a state produced by `{0}` can't be calculated: {1}")]
+    CidCalculationFailed(String, CidCalculationError),
+}
+
+impl SignatureStore {
+    pub fn new() -> Self {
+        <_>::default()
+    }
+
+    /// Merges records of both stores skipping duplicates.
+    pub fn merge(prev: Self, current: Self) -> Self {
+        let mut merged = prev;
+        for (peer_id, records) in current.0 {
+            for signed_cids in records {
+                merged.put(peer_id.clone(), signed_cids);
+            }
+        }
+
+        merged
+    }
+
+    pub fn put(&mut self, peer_id: String, signed_cids: SignedCids) {
+        let records = self.0.entry(peer_id).or_default();
+        if !records.contains(&signed_cids) {
+            records.push(signed_cids);
+        }
+    }
+
+    pub fn get(&self, peer_id: &str) -> Option<&[SignedCids]> {
+        self.0.get(peer_id).map(Vec::as_slice)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Vec<SignedCids>)> {
+        self.0.iter()
+    }
+
+    /// Checks signatures of all records against public keys recovered from peer ids,
+    /// records also present in the trusted store aren't checked again.
+    pub fn verify(
+        &self,
+        trusted: &Self,
+        particle_id: &str,
+    ) -> Result<(), SignatureVerificationError> {
+        for (peer_id, records) in &self.0 {
+            let trusted_records = trusted.get(peer_id).unwrap_or_default();
+
+            let mut untrusted_records = records
+                .iter()
+                .filter(|signed_cids| !trusted_records.contains(signed_cids))
+                .peekable();
+            if untrusted_records.peek().is_none() {
+                continue;
+            }
+
+            let public_key = public_key_from_peer_id(peer_id)?;
+            for signed_cids in untrusted_records {
+                let signature = decode_signature(&signed_cids.signature).ok_or_else(|| {
+                    SignatureVerificationError::MalformedSignature {
+                        peer_id: peer_id.clone(),
+                    }
+                })?;
+
+                let message = super::signed_message(particle_id, peer_id, &signed_cids.cids);
+                public_key.verify(&message, &signature).map_err(|_| {
+                    SignatureVerificationError::InvalidSignature {
+                        peer_id: peer_id.clone(),
+                    }
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that a state produced by the peer is signed by it. If signatures aren't required,
+    /// states of peers without any record are accepted, because such peers don't sign their states.
+    pub fn verify_state<State: Serialize>(
+        &self,
+        peer_id: &str,
+        state: &State,
+        signatures_required: bool,
+    ) -> Result<(), SignatureVerificationError> {
+        let records = match self.get(peer_id) {
+            Some(records) => records,
+            None if !signatures_required => return Ok(()),
+            None => &[],
+        };
+
+        let cid = super::state_cid(state).map_err(|error| {
+            SignatureVerificationError::CidCalculationFailed(peer_id.to_string(), error)
+        })?;
+        if records
+            .iter()
+            .any(|signed_cids| signed_cids.cids.contains(&cid))
+        {
+            return Ok(());
+        }
+
+        Err(SignatureVerificationError::UnsignedState {
+            peer_id: peer_id.to_string(),
+            cid,
+        })
+    }
+}
+
+fn decode_signature(signature: &str) -> Option<Signature> {
+    let signature = bs58::decode(signature).into_vec().ok()?;
+    Signature::try_from(signature.as_slice()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeyPair;
+    use crate::SignatureTracker;
+
+    use serde_json::json;
+
+    const PARTICLE_ID: &str = "particle_id";
+
+    fn signed_store(key_pair: &KeyPair, states: &[serde_json::Value]) -> SignatureStore {
+        let mut tracker = SignatureTracker::new();
+        for state in states {
+            tracker.register_state(state).unwrap();
+        }
+
+        let mut store = SignatureStore::new();
+        store.put(
+            key_pair.peer_id(),
+            tracker.into_signed_cids(key_pair, PARTICLE_ID).unwrap(),
+        );
+        store
+    }
+
+    #[test]
+    fn signed_states_are_verified() {
+        let key_pair = KeyPair::from_secret_key(&[1; 32]).unwrap();
+        let peer_id = key_pair.peer_id();
+        let store = signed_store(&key_pair, &[json!(1), json!({"a": "b"})]);
+
+        store.verify(&SignatureStore::new(), PARTICLE_ID).unwrap();
+        store
+            .verify_state(&peer_id, &json!({"a": "b"}), true)
+            .unwrap();
+
+        let result = store.verify_state(&peer_id, &json!(2), false);
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::UnsignedState { .. })
+        ));
+    }
+
+    #[test]
+    fn states_of_peers_without_records() {
+        let key_pair = KeyPair::from_secret_key(&[1; 32]).unwrap();
+        let store = signed_store(&key_pair, &[json!(1)]);
+
+        // peers without records don't sign their states, if signatures aren't required
+        store
+            .verify_state("other_peer_id", &json!(2), false)
+            .unwrap();
+
+        let result = store.verify_state("other_peer_id", &json!(2), true);
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::UnsignedState { peer_id, .. }) if peer_id == "other_peer_id"
+        ));
+        let result = SignatureStore::new().verify_state(&key_pair.peer_id(), &json!(1), true);
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::UnsignedState { .. })
+        ));
+    }
+
+    #[test]
+    fn signatures_are_bound_to_particle() {
+        let key_pair = KeyPair::from_secret_key(&[1; 32]).unwrap();
+        let store = signed_store(&key_pair, &[json!(1)]);
+
+        let result = store.verify(&SignatureStore::new(), "other_particle_id");
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::InvalidSignature { .. })
+        ));
+    }
+
+    #[test]
+    fn forged_records_are_rejected() {
+        let key_pair = KeyPair::from_secret_key(&[1; 32]).unwrap();
+        let other_key_pair = KeyPair::from_secret_key(&[2; 32]).unwrap();
+        let peer_id = key_pair.peer_id();
+
+        let mut forged_cids = signed_store(&key_pair, &[json!(1)]);
+        forged_cids.0.get_mut(&peer_id).unwrap()[0]
+            .cids
+            .insert("forged_cid".to_string());
+        let result = forged_cids.verify(&SignatureStore::new(), PARTICLE_ID);
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::InvalidSignature { .. })
+        ));
+
+        // records signed by a key not corresponding to a peer id
+        let other_records = signed_store(&other_key_pair, &[json!(1)])
+            .0
+            .remove(&other_key_pair.peer_id());
+        let mut forged_signer = SignatureStore::new();
+        forged_signer.put(peer_id.clone(), other_records.unwrap().remove(0));
+        let result = forged_signer.verify(&SignatureStore::new(), PARTICLE_ID);
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::InvalidSignature { .. })
+        ));
+
+        let mut malformed_signature = signed_store(&key_pair, &[json!(1)]);
+        malformed_signature.0.get_mut(&peer_id).unwrap()[0].signature = "0OIl".to_string();
+        let result = malformed_signature.verify(&SignatureStore::new(), PARTICLE_ID);
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::MalformedSignature { .. })
+        ));
+
+        let mut unknown_key = SignatureStore::new();
+        unknown_key.put(
+            "peer_id".to_string(),
+            forged_cids.0.remove(&peer_id).unwrap().remove(0),
+        );
+        let result = unknown_key.verify(&SignatureStore::new(), PARTICLE_ID);
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::InvalidPeerId(_))
+        ));
+    }
+
+    #[test]
+    fn trusted_records_arent_verified_again() {
+        let key_pair = KeyPair::from_secret_key(&[1; 32]).unwrap();
+        let store = signed_store(&key_pair, &[json!(1)]);
+
+        // the signature is bound to another particle, but the record is trusted
+        store.verify(&store, "other_particle_id").unwrap();
+    }
+
+    #[test]
+    fn merge_skips_duplicates() {
+        let key_pair = KeyPair::from_secret_key(&[1; 32]).unwrap();
+        let prev_store = signed_store(&key_pair, &[json!(1)]);
+        let current_store = signed_store(&key_pair, &[json!(2)]);
+
+        let merged = SignatureStore::merge(prev_store.clone(), current_store.clone());
+        let merged = SignatureStore::merge(merged, current_store);
+        assert_eq!(merged.get(&key_pair.peer_id()).unwrap().len(), 2);
+
+        merged.verify(&prev_store, PARTICLE_ID).unwrap();
+    }
+}
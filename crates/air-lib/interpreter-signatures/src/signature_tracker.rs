@@ -0,0 +1,65 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::KeyPair;
+use super::SignedCids;
+
+use air_interpreter_cid::CidCalculationError;
+use serde::Serialize;
+
+use std::collections::BTreeSet;
+
+/// Collects CIDs of states produced by the current peer during a run to sign them at its end.
+#[derive(Debug, Default, Clone)]
+pub struct SignatureTracker {
+    cids: BTreeSet<String>,
+}
+
+impl SignatureTracker {
+    pub fn new() -> Self {
+        <_>::default()
+    }
+
+    /// Registers a state produced by the current peer.
+    pub fn register_state<State: Serialize>(
+        &mut self,
+        state: &State,
+    ) -> Result<(), CidCalculationError> {
+        let cid = super::state_cid(state)?;
+        self.cids.insert(cid);
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cids.is_empty()
+    }
+
+    /// Signs all registered CIDs, None is returned if no state was produced.
+    pub fn into_signed_cids(self, key_pair: &KeyPair, particle_id: &str) -> Option<SignedCids> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let message = super::signed_message(particle_id, &key_pair.peer_id(), &self.cids);
+        let signature = key_pair.sign(&message);
+
+        let signed_cids = SignedCids {
+            cids: self.cids,
+            signature: bs58::encode(signature).into_string(),
+        };
+        Some(signed_cids)
+    }
+}
@@ -35,22 +35,95 @@ pub enum LambdaAST<'input> {
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum ValueAccessor<'input> {
     // (.)?[$idx]
-    ArrayAccess { idx: u32 },
+    ArrayAccess {
+        idx: u32,
+    },
+
+    // (.)?[-$idx], where idx_from_end is counted from 1 for the last element
+    ArrayAccessFromEnd {
+        idx_from_end: u32,
+    },
+
+    // (.)?[$start?:$end?(:$step?)?]
+    ArraySlice(ArraySlice),
+
+    // (.)?[*], applies the rest of a path to each element and collects results into an array
+    Wildcard,
+
+    // (.)?[?field == literal]
+    #[serde(borrow)]
+    Filter(FilterPredicate<'input>),
 
     // .field
-    FieldAccessByName { field_name: &'input str },
+    FieldAccessByName {
+        field_name: &'input str,
+    },
 
     // (.)?[field]
-    FieldAccessByScalar { scalar_name: &'input str },
+    FieldAccessByScalar {
+        scalar_name: &'input str,
+    },
+
+    // (.)?[$idx]?, (.)?[-$idx]?, .field?, (.)?[field]?
+    #[serde(borrow)]
+    Optional(OptionalAccessor<'input>),
 
     // needed to allow parser catch all errors from a lambda expression without stopping
     // on the very first one. Although, this variant is guaranteed not to be present in a lambda.
     Error,
 }
 
+/// Accessors that could be marked as optional, such an accessor turns the whole lambda
+/// into null instead of an error if a value doesn't contain the requested element.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum OptionalAccessor<'input> {
+    ArrayAccess { idx: u32 },
+    ArrayAccessFromEnd { idx_from_end: u32 },
+    FieldAccessByName { field_name: &'input str },
+    FieldAccessByScalar { scalar_name: &'input str },
+}
+
+/// Represents a python-like slice of an array, negative bounds are counted from the end
+/// of an array, step is always positive.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct ArraySlice {
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+    pub step: Option<u32>,
+}
+
+/// Represents a predicate that selects array elements which are objects
+/// with a field equal to the provided literal.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct FilterPredicate<'input> {
+    pub field_name: &'input str,
+    #[serde(borrow)]
+    pub value: FilterValue<'input>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum FilterValue<'input> {
+    String(&'input str),
+    Number(i64),
+    Boolean(bool),
+    Null,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum Functor {
     /// Returns a length of a value if this value has array type (json array or canon stream)
     /// or a error if not.
     Length,
+    /// Returns an array of keys of a value if this value has object type or a error if not.
+    Keys,
+    /// Returns an array of values of a value if this value has object type or a error if not.
+    Values,
+    /// Returns true if a value is an empty array-like value or an empty object.
+    IsEmpty,
+    /// Returns the first element of a value if this value has array type (json array
+    /// or canon stream) and isn't empty or a error if not.
+    First,
+    /// Returns the last element of a value if this value has array type (json array
+    /// or canon stream) and isn't empty or a error if not.
+    Last,
 }
@@ -14,8 +14,12 @@
  * limitations under the License.
  */
 
+use crate::ArraySlice;
+use crate::FilterPredicate;
+use crate::FilterValue;
 use crate::Functor;
 use crate::LambdaAST;
+use crate::OptionalAccessor;
 use crate::ValueAccessor;
 
 pub use non_empty_vec::EmptyError;
@@ -35,3 +39,32 @@ impl<'input> LambdaAST<'input> {
         Self::Functor(functor)
     }
 }
+
+impl ArraySlice {
+    pub fn new(start: Option<i64>, end: Option<i64>, step: Option<u32>) -> Self {
+        Self { start, end, step }
+    }
+}
+
+impl<'input> FilterPredicate<'input> {
+    pub fn new(field_name: &'input str, value: FilterValue<'input>) -> Self {
+        Self { field_name, value }
+    }
+}
+
+impl<'input> From<OptionalAccessor<'input>> for ValueAccessor<'input> {
+    fn from(accessor: OptionalAccessor<'input>) -> Self {
+        match accessor {
+            OptionalAccessor::ArrayAccess { idx } => Self::ArrayAccess { idx },
+            OptionalAccessor::ArrayAccessFromEnd { idx_from_end } => {
+                Self::ArrayAccessFromEnd { idx_from_end }
+            }
+            OptionalAccessor::FieldAccessByName { field_name } => {
+                Self::FieldAccessByName { field_name }
+            }
+            OptionalAccessor::FieldAccessByScalar { scalar_name } => {
+                Self::FieldAccessByScalar { scalar_name }
+            }
+        }
+    }
+}
@@ -36,19 +36,74 @@ impl fmt::Display for ValueAccessor<'_> {
 
         match self {
             ArrayAccess { idx } => write!(f, "[{idx}]"),
+            ArrayAccessFromEnd { idx_from_end } => write!(f, "[-{idx_from_end}]"),
+            ArraySlice(slice) => write!(f, "[{slice}]"),
+            Wildcard => write!(f, "[*]"),
+            Filter(predicate) => write!(f, "[{predicate}]"),
             FieldAccessByName { field_name } => write!(f, "{field_name}"),
             FieldAccessByScalar { scalar_name } => write!(f, "[{scalar_name}]"),
+            Optional(accessor) => write!(f, "{accessor}"),
             Error => write!(f, "a parser error occurred while parsing lambda expression"),
         }
     }
 }
 
+impl fmt::Display for OptionalAccessor<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}?", ValueAccessor::from(*self))
+    }
+}
+
+impl fmt::Display for ArraySlice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn display_bound<T: fmt::Display>(bound: &Option<T>) -> String {
+            bound.as_ref().map(ToString::to_string).unwrap_or_default()
+        }
+
+        write!(
+            f,
+            "{}:{}",
+            display_bound(&self.start),
+            display_bound(&self.end)
+        )?;
+
+        match self.step {
+            Some(step) => write!(f, ":{step}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for FilterPredicate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "?{} == {}", self.field_name, self.value)
+    }
+}
+
+impl fmt::Display for FilterValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use FilterValue::*;
+
+        match self {
+            String(str) => write!(f, r#""{str}""#),
+            Number(number) => write!(f, "{number}"),
+            Boolean(bool) => write!(f, "{bool}"),
+            Null => write!(f, "null"),
+        }
+    }
+}
+
 impl fmt::Display for Functor {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Functor::*;
 
         match self {
             Length => write!(f, "length"),
+            Keys => write!(f, "keys"),
+            Values => write!(f, "values"),
+            IsEmpty => write!(f, "is_empty"),
+            First => write!(f, "first"),
+            Last => write!(f, "last"),
         }
     }
 }
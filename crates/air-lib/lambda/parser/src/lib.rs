@@ -32,6 +32,10 @@ pub use parser::LambdaASTLexer;
 pub use parser::LambdaParserError;
 pub use parser::LexerError;
 
+pub use air_lambda_ast::ArraySlice;
+pub use air_lambda_ast::FilterPredicate;
+pub use air_lambda_ast::FilterValue;
 pub use air_lambda_ast::Functor;
 pub use air_lambda_ast::LambdaAST;
+pub use air_lambda_ast::OptionalAccessor;
 pub use air_lambda_ast::ValueAccessor;
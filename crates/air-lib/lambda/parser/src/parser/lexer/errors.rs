@@ -25,4 +25,13 @@ pub enum LexerError {
 
     #[error("{2}")]
     ParseIntError(usize, usize, #[source] ParseIntError),
+
+    #[error("unclosed quote in a string literal")]
+    UnclosedQuote(usize, usize),
+
+    #[error("unexpected keyword, only true, false and null could be used as a filter value")]
+    UnexpectedKeyword(usize, usize),
+
+    #[error("array slice step can't be zero")]
+    ZeroSliceStep(usize, usize),
 }
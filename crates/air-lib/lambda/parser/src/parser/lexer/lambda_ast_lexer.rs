@@ -23,6 +23,11 @@ use std::str::CharIndices;
 
 const ARRAY_IDX_BASE: u32 = 10;
 const LENGTH_FUNCTOR: &str = ".length";
+const KEYS_FUNCTOR: &str = ".keys";
+const VALUES_FUNCTOR: &str = ".values";
+const IS_EMPTY_FUNCTOR: &str = ".is_empty";
+const FIRST_FUNCTOR: &str = ".first";
+const LAST_FUNCTOR: &str = ".last";
 const VALUE_PATH_STARTER: &str = ".$";
 
 pub type Spanned<Token, Loc, Error> = Result<(Loc, Token, Loc), Error>;
@@ -31,6 +36,7 @@ pub struct LambdaASTLexer<'input> {
     input: &'input str,
     chars: Peekable<CharIndices<'input>>,
     is_first_token: bool,
+    is_inside_filter: bool,
 }
 
 impl<'input> Iterator for LambdaASTLexer<'input> {
@@ -47,6 +53,7 @@ impl<'input> LambdaASTLexer<'input> {
             input,
             chars: input.char_indices().peekable(),
             is_first_token: true,
+            is_inside_filter: false,
         }
     }
 
@@ -60,11 +67,27 @@ impl<'input> LambdaASTLexer<'input> {
             return Some(self.try_parse_first_token());
         }
 
+        if self.is_inside_filter {
+            self.skip_whitespaces();
+        }
+
         self.chars.next().map(|(start_offset, ch)| match ch {
-            '[' => Ok((start_offset, Token::OpenSquareBracket, start_offset + 1)),
-            ']' => Ok((start_offset, Token::CloseSquareBracket, start_offset + 1)),
+            '[' => {
+                self.is_inside_filter = matches!(self.chars.peek(), Some((_, '?')));
+                Ok((start_offset, Token::OpenSquareBracket, start_offset + 1))
+            }
+            ']' => {
+                self.is_inside_filter = false;
+                Ok((start_offset, Token::CloseSquareBracket, start_offset + 1))
+            }
 
             '.' => Ok((start_offset, Token::ValuePathSelector, start_offset + 1)),
+            ':' => Ok((start_offset, Token::Colon, start_offset + 1)),
+            '-' if self.is_digit_next() => Ok((start_offset, Token::MinusSign, start_offset + 1)),
+            '*' => Ok((start_offset, Token::Asterisk, start_offset + 1)),
+            '?' => Ok((start_offset, Token::QuestionMark, start_offset + 1)),
+            '=' => self.tokenize_equal(start_offset),
+            '"' => self.tokenize_string_literal(start_offset),
 
             d if d.is_digit(ARRAY_IDX_BASE) => self.tokenize_arrays_idx(start_offset),
             s if is_air_alphanumeric(s) => self.tokenize_field_name(start_offset),
@@ -106,6 +129,36 @@ impl<'input> LambdaASTLexer<'input> {
         ))
     }
 
+    fn tokenize_equal(&mut self, start_offset: usize) -> Spanned<Token<'input>, usize, LexerError> {
+        match self.chars.next_if(|(_, ch)| *ch == '=') {
+            Some(_) => Ok((start_offset, Token::Equal, start_offset + 2)),
+            None => Err(LexerError::UnexpectedSymbol(start_offset, start_offset + 1)),
+        }
+    }
+
+    fn tokenize_string_literal(
+        &mut self,
+        start_offset: usize,
+    ) -> Spanned<Token<'input>, usize, LexerError> {
+        for (pos, ch) in &mut self.chars {
+            if ch == '"' {
+                let literal = &self.input[start_offset + 1..pos];
+                return Ok((start_offset, Token::StringLiteral(literal), pos + 1));
+            }
+        }
+
+        Err(LexerError::UnclosedQuote(start_offset, self.input.len()))
+    }
+
+    // whitespaces are allowed only inside filter predicates like [?field == value]
+    fn skip_whitespaces(&mut self) {
+        while self.chars.next_if(|(_, ch)| ch.is_whitespace()).is_some() {}
+    }
+
+    fn is_digit_next(&mut self) -> bool {
+        matches!(self.chars.peek(), Some((_, ch)) if ch.is_digit(ARRAY_IDX_BASE))
+    }
+
     fn tokenize_until(
         &mut self,
         start_offset: usize,
@@ -124,12 +177,17 @@ impl<'input> LambdaASTLexer<'input> {
     }
 
     fn try_parse_first_token(&mut self) -> Spanned<Token<'input>, usize, LexerError> {
-        let (token, token_size) = if self.input == LENGTH_FUNCTOR {
-            (Token::LengthFunctor, LENGTH_FUNCTOR.len())
-        } else if self.input.starts_with(VALUE_PATH_STARTER) {
-            (Token::ValuePathStarter, VALUE_PATH_STARTER.len())
-        } else {
-            return Err(LexerError::UnexpectedSymbol(0, self.input.len()));
+        let (token, token_size) = match self.input {
+            LENGTH_FUNCTOR => (Token::LengthFunctor, LENGTH_FUNCTOR.len()),
+            KEYS_FUNCTOR => (Token::KeysFunctor, KEYS_FUNCTOR.len()),
+            VALUES_FUNCTOR => (Token::ValuesFunctor, VALUES_FUNCTOR.len()),
+            IS_EMPTY_FUNCTOR => (Token::IsEmptyFunctor, IS_EMPTY_FUNCTOR.len()),
+            FIRST_FUNCTOR => (Token::FirstFunctor, FIRST_FUNCTOR.len()),
+            LAST_FUNCTOR => (Token::LastFunctor, LAST_FUNCTOR.len()),
+            input if input.starts_with(VALUE_PATH_STARTER) => {
+                (Token::ValuePathStarter, VALUE_PATH_STARTER.len())
+            }
+            _ => return Err(LexerError::UnexpectedSymbol(0, self.input.len())),
         };
 
         self.advance_by(token_size);
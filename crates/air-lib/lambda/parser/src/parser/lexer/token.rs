@@ -20,6 +20,11 @@ use serde::Serialize;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Token<'input> {
     LengthFunctor,
+    KeysFunctor,
+    ValuesFunctor,
+    IsEmptyFunctor,
+    FirstFunctor,
+    LastFunctor,
 
     //.$
     ValuePathStarter,
@@ -32,6 +37,18 @@ pub enum Token<'input> {
     NumberAccessor(u32),
     StringAccessor(&'input str),
 
+    // - right before a number accessor
+    MinusSign,
+    // :
+    Colon,
+    // *
+    Asterisk,
+    // ?
+    QuestionMark,
+    // ==
+    Equal,
+    StringLiteral(&'input str),
+
     // !
     FlatteningSign,
 }
@@ -16,6 +16,10 @@
 
 use crate::parser::lambda_parser::RawLambdaAST;
 use crate::parser::va_lambda::RawLambdaASTParser;
+use crate::ArraySlice;
+use crate::FilterPredicate;
+use crate::FilterValue;
+use crate::OptionalAccessor;
 use crate::ValueAccessor;
 use air_lambda_ast::Functor;
 
@@ -245,6 +249,186 @@ fn many_array_field_scalar_access() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn array_access_from_end() {
+    let lambda = ".$.[-1].field";
+
+    let actual = parse_to_accessors(lambda);
+    let expected = vec![
+        ValueAccessor::ArrayAccessFromEnd { idx_from_end: 1 },
+        ValueAccessor::FieldAccessByName {
+            field_name: "field",
+        },
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn array_slice() {
+    let lambda = ".$.[1:3]";
+
+    let actual = parse_to_accessors(lambda);
+    let expected = vec![ValueAccessor::ArraySlice(ArraySlice::new(
+        Some(1),
+        Some(3),
+        None,
+    ))];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn array_slice_with_negative_bounds() {
+    let lambda = ".$.[-3:-1]";
+
+    let actual = parse_to_accessors(lambda);
+    let expected = vec![ValueAccessor::ArraySlice(ArraySlice::new(
+        Some(-3),
+        Some(-1),
+        None,
+    ))];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn array_slice_with_step() {
+    let lambda = ".$.[::2].[0]";
+
+    let actual = parse_to_accessors(lambda);
+    let expected = vec![
+        ValueAccessor::ArraySlice(ArraySlice::new(None, None, Some(2))),
+        ValueAccessor::ArrayAccess { idx: 0 },
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn array_slice_with_zero_step() {
+    let lambda = ".$.[1::0]";
+
+    let actual = TEST_PARSER.with(|parser| {
+        let mut errors = Vec::new();
+        let lexer = crate::parser::LambdaASTLexer::new(lambda);
+        parser.parse(lambda, &mut errors, lexer)
+    });
+
+    assert!(matches!(
+        actual,
+        Err(lalrpop_util::ParseError::User {
+            error: crate::LexerError::ZeroSliceStep(..)
+        })
+    ))
+}
+
+#[test]
+fn wildcard() {
+    let lambda = ".$.[*].name";
+
+    let actual = parse_to_accessors(lambda);
+    let expected = vec![
+        ValueAccessor::Wildcard,
+        ValueAccessor::FieldAccessByName { field_name: "name" },
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn filter_by_string() {
+    let lambda = r#".$.[?peer_id == "some_peer"].[0]"#;
+
+    let actual = parse_to_accessors(lambda);
+    let expected = vec![
+        ValueAccessor::Filter(FilterPredicate::new(
+            "peer_id",
+            FilterValue::String("some_peer"),
+        )),
+        ValueAccessor::ArrayAccess { idx: 0 },
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn filter_by_number_bool_and_null() {
+    let actual = parse_to_accessors(".$.[?weight == -1]");
+    let expected = vec![ValueAccessor::Filter(FilterPredicate::new(
+        "weight",
+        FilterValue::Number(-1),
+    ))];
+    assert_eq!(actual, expected);
+
+    let actual = parse_to_accessors(".$.[?online==true]");
+    let expected = vec![ValueAccessor::Filter(FilterPredicate::new(
+        "online",
+        FilterValue::Boolean(true),
+    ))];
+    assert_eq!(actual, expected);
+
+    let actual = parse_to_accessors(".$.[?error == null]");
+    let expected = vec![ValueAccessor::Filter(FilterPredicate::new(
+        "error",
+        FilterValue::Null,
+    ))];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn filter_by_unknown_keyword() {
+    let lambda = ".$.[?online == yes]";
+
+    let actual = TEST_PARSER.with(|parser| {
+        let mut errors = Vec::new();
+        let lexer = crate::parser::LambdaASTLexer::new(lambda);
+        parser.parse(lambda, &mut errors, lexer)
+    });
+
+    assert!(matches!(
+        actual,
+        Err(lalrpop_util::ParseError::User {
+            error: crate::LexerError::UnexpectedKeyword(..)
+        })
+    ))
+}
+
+#[test]
+fn whitespaces_outside_filter_are_rejected() {
+    assert!(crate::parse(".$[ 1 ]").is_err());
+    assert!(crate::parse(".$ .a").is_err());
+    assert!(crate::parse(".$.[1:3 ]").is_err());
+
+    let actual = parse_to_accessors(".$.[? online == true ].[1]");
+    let expected = vec![
+        ValueAccessor::Filter(FilterPredicate::new("online", FilterValue::Boolean(true))),
+        ValueAccessor::ArrayAccess { idx: 1 },
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn optional_accessors() {
+    let lambda = ".$.field?.[1]?.[-1]?.[scalar]?.[0]";
+
+    let actual = parse_to_accessors(lambda);
+    let expected = vec![
+        ValueAccessor::Optional(OptionalAccessor::FieldAccessByName {
+            field_name: "field",
+        }),
+        ValueAccessor::Optional(OptionalAccessor::ArrayAccess { idx: 1 }),
+        ValueAccessor::Optional(OptionalAccessor::ArrayAccessFromEnd { idx_from_end: 1 }),
+        ValueAccessor::Optional(OptionalAccessor::FieldAccessByScalar {
+            scalar_name: "scalar",
+        }),
+        ValueAccessor::ArrayAccess { idx: 0 },
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn optional_accessor_display() {
+    let lambda = ".$.field?.[1]?.[-1].[scalar]?";
+
+    let actual = crate::parse(lambda).expect("parsing should be successful");
+    assert_eq!(actual.to_string(), ".$.field?.[1]?.[-1].[scalar]?");
+}
+
 #[test]
 fn parse_length_functor() {
     let lambda = ".length";
@@ -254,6 +438,27 @@ fn parse_length_functor() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn parse_keys_and_values_functors() {
+    assert_eq!(parse_to_functor(".keys"), Functor::Keys);
+    assert_eq!(parse_to_functor(".values"), Functor::Values);
+}
+
+#[test]
+fn parse_is_empty_functor() {
+    let lambda = ".is_empty";
+
+    let actual = parse_to_functor(lambda);
+    let expected = Functor::IsEmpty;
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn parse_first_and_last_functors() {
+    assert_eq!(parse_to_functor(".first"), Functor::First);
+    assert_eq!(parse_to_functor(".last"), Functor::Last);
+}
+
 #[test]
 fn parse_length_functor_with_following_accessors() {
     let lambda = ".length.[0]";
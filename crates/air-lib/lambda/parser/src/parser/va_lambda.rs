@@ -1,11 +1,16 @@
 // auto-generated: "lalrpop 0.19.8"
-// sha3: 592663f5597c21f4cc9cb3ad5cb93fa0a7db3635fc4d677c2b6130d33b6e22fd
+// sha3: 0bb5a0f7b2ee195aea8d48b05aa1bb8d248c3cd4e746cf11aa8f92d01de676e9
+use crate::ArraySlice;
+use crate::FilterPredicate;
+use crate::FilterValue;
+use crate::OptionalAccessor;
 use crate::ValueAccessor;
 use crate::parser::lambda_parser::RawLambdaAST;
 use crate::Functor;
 use crate::parser::lexer::LexerError;
 use crate::parser::lexer::Token;
 use lalrpop_util::ErrorRecovery;
+use lalrpop_util::ParseError;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
@@ -17,12 +22,17 @@ extern crate alloc;
 mod __parse__RawLambdaAST {
     #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
 
+    use crate::ArraySlice;
+    use crate::FilterPredicate;
+    use crate::FilterValue;
+    use crate::OptionalAccessor;
     use crate::ValueAccessor;
     use crate::parser::lambda_parser::RawLambdaAST;
     use crate::Functor;
     use crate::parser::lexer::LexerError;
     use crate::parser::lexer::Token;
     use lalrpop_util::ErrorRecovery;
+    use lalrpop_util::ParseError;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
@@ -38,142 +48,387 @@ mod __parse__RawLambdaAST {
         Variant2(&'input str),
         Variant3(__lalrpop_util::ErrorRecovery<usize, Token<'input>, LexerError>),
         Variant4(core::option::Option<Token<'input>>),
-        Variant5(RawLambdaAST<'input>),
-        Variant6(ValueAccessor<'input>),
-        Variant7(alloc::vec::Vec<ValueAccessor<'input>>),
+        Variant5(usize),
+        Variant6(ArraySlice),
+        Variant7(FilterValue<'input>),
+        Variant8(OptionalAccessor<'input>),
+        Variant9(RawLambdaAST<'input>),
+        Variant10(i64),
+        Variant11(core::option::Option<i64>),
+        Variant12(ValueAccessor<'input>),
+        Variant13(alloc::vec::Vec<ValueAccessor<'input>>),
+        Variant14(core::option::Option<u32>),
     }
     const __ACTION: &[i8] = &[
         // State 0
-        0, 0, 2, 0, 0, 5, 0, 0, 6,
+        0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 11, 12, 13, 14, 15, 0, 0, 0, 16, 17,
         // State 1
-        0, 8, 0, 9, 0, 0, 0, 0, 10,
+        0, 0, 0, 20, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21,
         // State 2
-        0, 8, 0, 9, 0, 0, 0, 0, 10,
+        0, 0, 0, 20, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 28, 29, 0, 0, 6, 0, 30, 0, 0, 0, 0, 0, 0, 0, 31, 32, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 35, 36, 0, 0, 6, 0, 37, 0, 0, 0, 0, 0, 0, 0, 38, 39, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 44, 0, 0, 45, 0, 0, 0, -10, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0,
         // State 6
-        0, -22, 0, -22, 0, 0, 0, 0, -22,
+        0, 0, 44, 0, 0, 58, 0, 0, 0, -8, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 12, 0, 0, 0, 13, 0,
+        0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 72, 73, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 14, 15, 0,
+        0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 72, 73, 0, 0,
         // State 9
-        0, -19, 0, -19, 0, 0, 0, 0, -19,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        0, -23, 0, -23, 0, 0, 0, 0, -23,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        0, 0, 0, 0, 0, 0, 16, 17, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        18, -18, 0, -18, 0, 0, 0, 0, -18,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        0, 0, 0, 0, 19, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 0, 0, 20, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 0, 0, 0, 21, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
-        0, 0, 0, 0, 22, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, -17, 0, -17, 0, 0, 0, 0, -17,
+        22, 0, 0, -43, 0, 0, 0, 23, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43,
         // State 18
-        23, -12, 0, -12, 0, 0, 0, 0, -12,
+        0, 0, 0, -61, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61,
         // State 19
-        24, -16, 0, -16, 0, 0, 0, 0, -16,
+        0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0,
         // State 20
-        25, -11, 0, -11, 0, 0, 0, 0, -11,
+        0, 0, 0, -58, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58,
         // State 21
-        26, -15, 0, -15, 0, 0, 0, 0, -15,
+        0, 0, 0, -42, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42,
         // State 22
-        0, -10, 0, -10, 0, 0, 0, 0, -10,
+        33, 0, 0, -45, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45,
         // State 23
-        0, -14, 0, -14, 0, 0, 0, 0, -14,
+        0, 0, 0, -62, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62,
         // State 24
-        0, -9, 0, -9, 0, 0, 0, 0, -9,
+        -28, 0, 0, -28, 0, 0, 0, -28, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28,
         // State 25
-        0, -13, 0, -13, 0, 0, 0, 0, -13,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 26
+        0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 27
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 28
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0,
+        // State 29
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0,
+        // State 30
+        0, 0, 0, 0, 0, -38, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 31
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 32
+        0, 0, 0, -44, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44,
+        // State 33
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 35
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0,
+        // State 36
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0,
+        // State 37
+        0, 0, 0, 0, 0, -38, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 38
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 39
+        56, 0, 0, -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49,
+        // State 40
+        59, 0, 0, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53,
+        // State 41
+        0, 0, 0, 0, 0, -39, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 42
+        0, 0, 0, 0, 0, 61, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 43
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0,
+        // State 44
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0,
+        // State 45
+        0, 0, 0, 0, 0, -38, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 46
+        0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 47
+        -23, 0, 0, -23, 0, 0, 0, -23, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23,
+        // State 48
+        -27, 0, 0, -27, 0, 0, 0, -27, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27,
+        // State 49
+        64, 0, 0, -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48,
+        // State 50
+        65, 0, 0, -52, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52,
+        // State 51
+        0, 0, 0, 0, 0, -39, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 52
+        0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 53
+        -22, 0, 0, -22, 0, 0, 0, -22, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22,
+        // State 54
+        -26, 0, 0, -26, 0, 0, 0, -26, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26,
+        // State 55
+        0, 0, 0, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47,
+        // State 56
+        0, 0, 0, 0, 0, 67, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0,
+        // State 58
+        0, 0, 0, -51, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51,
+        // State 59
+        -25, 0, 0, -25, 0, 0, 0, -25, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25,
+        // State 60
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, -39, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 63
+        0, 0, 0, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46,
+        // State 64
+        0, 0, 0, -50, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50,
+        // State 65
+        -24, 0, 0, -24, 0, 0, 0, -24, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -12, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 75
+        78, 0, 0, -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57,
+        // State 76
+        79, 0, 0, -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56,
+        // State 77
+        0, 0, 0, -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55,
+        // State 78
+        0, 0, 0, -54, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54,
     ];
     fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 9 + integer]
+        __ACTION[(state as usize) * 20 + integer]
     }
     const __EOF_ACTION: &[i8] = &[
         // State 0
         0,
         // State 1
-        -5,
+        -29,
         // State 2
-        -6,
+        -30,
         // State 3
-        -24,
+        0,
         // State 4
-        -7,
+        0,
         // State 5
-        -8,
+        0,
         // State 6
-        -22,
+        0,
         // State 7
         0,
         // State 8
         0,
         // State 9
-        -19,
+        -63,
         // State 10
-        -23,
+        -35,
         // State 11
-        0,
+        -34,
         // State 12
-        -18,
+        -32,
         // State 13
-        0,
+        -36,
         // State 14
-        0,
+        -31,
         // State 15
-        0,
+        -33,
         // State 16
-        0,
+        -37,
         // State 17
-        -17,
+        -43,
         // State 18
-        -12,
+        -61,
         // State 19
-        -16,
+        0,
         // State 20
-        -11,
+        -58,
         // State 21
-        -15,
+        -42,
         // State 22
-        -10,
+        -45,
         // State 23
-        -14,
+        -62,
         // State 24
-        -9,
+        -28,
         // State 25
-        -13,
+        0,
+        // State 26
+        0,
+        // State 27
+        0,
+        // State 28
+        0,
+        // State 29
+        0,
+        // State 30
+        0,
+        // State 31
+        0,
+        // State 32
+        -44,
+        // State 33
+        0,
+        // State 34
+        0,
+        // State 35
+        0,
+        // State 36
+        0,
+        // State 37
+        0,
+        // State 38
+        0,
+        // State 39
+        -49,
+        // State 40
+        -53,
+        // State 41
+        0,
+        // State 42
+        0,
+        // State 43
+        0,
+        // State 44
+        0,
+        // State 45
+        0,
+        // State 46
+        0,
+        // State 47
+        -23,
+        // State 48
+        -27,
+        // State 49
+        -48,
+        // State 50
+        -52,
+        // State 51
+        0,
+        // State 52
+        0,
+        // State 53
+        -22,
+        // State 54
+        -26,
+        // State 55
+        -47,
+        // State 56
+        0,
+        // State 57
+        0,
+        // State 58
+        -51,
+        // State 59
+        -25,
+        // State 60
+        0,
+        // State 61
+        0,
+        // State 62
+        0,
+        // State 63
+        -46,
+        // State 64
+        -50,
+        // State 65
+        -24,
+        // State 66
+        0,
+        // State 67
+        0,
+        // State 68
+        0,
+        // State 69
+        0,
+        // State 70
+        0,
+        // State 71
+        0,
+        // State 72
+        0,
+        // State 73
+        0,
+        // State 74
+        0,
+        // State 75
+        -57,
+        // State 76
+        -56,
+        // State 77
+        -55,
+        // State 78
+        -54,
     ];
     fn __goto(state: i8, nt: usize) -> i8 {
         match nt {
-            2 => 3,
-            3 => match state {
-                2 => 10,
-                _ => 6,
+            4 => match state {
+                4 => 33,
+                _ => 25,
+            },
+            5 => match state {
+                8 => 73,
+                _ => 69,
             },
-            5 => 2,
+            6 => 17,
+            7 => 9,
+            8 => match state {
+                5 => 42,
+                6 => 56,
+                7..=8 => 70,
+                _ => 26,
+            },
+            10 => match state {
+                2 => 23,
+                _ => 18,
+            },
+            12 => 2,
             _ => 0,
         }
     }
     fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
         const __TERMINAL: &[&str] = &[
             r###""!""###,
+            r###""*""###,
+            r###""-""###,
             r###"".""###,
             r###"".$""###,
+            r###"":""###,
+            r###""==""###,
+            r###""?""###,
             r###""[""###,
             r###""]""###,
+            r###"first_functor"###,
+            r###"is_empty_functor"###,
+            r###"keys_functor"###,
+            r###"last_functor"###,
             r###"length_functor"###,
             r###"number_accessor"###,
             r###"string_accessor"###,
+            r###"string_literal"###,
+            r###"values_functor"###,
         ];
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
             let next_state = __action(__state, index);
@@ -227,7 +482,7 @@ mod __parse__RawLambdaAST {
 
         #[inline]
         fn error_action(&self, state: i8) -> i8 {
-            __action(state, 9 - 1)
+            __action(state, 20 - 1)
         }
 
         #[inline]
@@ -293,13 +548,24 @@ mod __parse__RawLambdaAST {
     {
         match *__token {
             Token::FlatteningSign if true => Some(0),
-            Token::ValuePathSelector if true => Some(1),
-            Token::ValuePathStarter if true => Some(2),
-            Token::OpenSquareBracket if true => Some(3),
-            Token::CloseSquareBracket if true => Some(4),
-            Token::LengthFunctor if true => Some(5),
-            Token::NumberAccessor(_) if true => Some(6),
-            Token::StringAccessor(_) if true => Some(7),
+            Token::Asterisk if true => Some(1),
+            Token::MinusSign if true => Some(2),
+            Token::ValuePathSelector if true => Some(3),
+            Token::ValuePathStarter if true => Some(4),
+            Token::Colon if true => Some(5),
+            Token::Equal if true => Some(6),
+            Token::QuestionMark if true => Some(7),
+            Token::OpenSquareBracket if true => Some(8),
+            Token::CloseSquareBracket if true => Some(9),
+            Token::FirstFunctor if true => Some(10),
+            Token::IsEmptyFunctor if true => Some(11),
+            Token::KeysFunctor if true => Some(12),
+            Token::LastFunctor if true => Some(13),
+            Token::LengthFunctor if true => Some(14),
+            Token::NumberAccessor(_) if true => Some(15),
+            Token::StringAccessor(_) if true => Some(16),
+            Token::StringLiteral(_) if true => Some(17),
+            Token::ValuesFunctor if true => Some(18),
             _ => None,
         }
     }
@@ -313,13 +579,13 @@ mod __parse__RawLambdaAST {
     ) -> __Symbol<'input>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 => __Symbol::Variant0(__token),
-            6 => match __token {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 18 => __Symbol::Variant0(__token),
+            15 => match __token {
                 Token::NumberAccessor(__tok0) if true => __Symbol::Variant1(__tok0),
                 _ => unreachable!(),
             },
-            7 => match __token {
-                Token::StringAccessor(__tok0) if true => __Symbol::Variant2(__tok0),
+            16 | 17 => match __token {
+                Token::StringAccessor(__tok0) | Token::StringLiteral(__tok0) if true => __Symbol::Variant2(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -362,261 +628,607 @@ mod __parse__RawLambdaAST {
             }
             4 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 2,
                 }
             }
             5 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 2,
+                    states_to_pop: 0,
+                    nonterminal_produced: 3,
                 }
             }
             6 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 2,
+                    states_to_pop: 3,
+                    nonterminal_produced: 4,
                 }
             }
             7 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 2,
+                    states_to_pop: 2,
+                    nonterminal_produced: 4,
                 }
             }
             8 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 3,
+                    states_to_pop: 2,
+                    nonterminal_produced: 4,
                 }
             }
             9 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 3,
+                    states_to_pop: 1,
+                    nonterminal_produced: 4,
                 }
             }
             10 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 3,
+                    states_to_pop: 5,
+                    nonterminal_produced: 4,
                 }
             }
             11 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 3,
+                    states_to_pop: 4,
+                    nonterminal_produced: 4,
                 }
             }
             12 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 3,
+                    states_to_pop: 4,
+                    nonterminal_produced: 4,
                 }
             }
             13 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 3,
+                    states_to_pop: 3,
+                    nonterminal_produced: 4,
                 }
             }
             14 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 4,
-                    nonterminal_produced: 3,
+                    nonterminal_produced: 4,
                 }
             }
             15 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 3,
+                    nonterminal_produced: 4,
                 }
             }
             16 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 3,
+                    nonterminal_produced: 4,
                 }
             }
             17 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 3,
+                    nonterminal_produced: 4,
                 }
             }
             18 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 3,
+                    nonterminal_produced: 5,
                 }
             }
             19 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 4,
+                    states_to_pop: 1,
+                    nonterminal_produced: 5,
                 }
             }
             20 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 4,
+                    nonterminal_produced: 5,
                 }
             }
             21 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 5,
+                    states_to_pop: 4,
+                    nonterminal_produced: 6,
                 }
             }
             22 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 5,
+                    states_to_pop: 3,
+                    nonterminal_produced: 6,
                 }
             }
-            23 => __state_machine::SimulatedReduce::Accept,
-            _ => panic!("invalid reduction index {}", __reduce_index)
-        }
-    }
-    pub(crate) struct RawLambdaASTParser {
-        _priv: (),
-    }
-
-    impl RawLambdaASTParser {
-        pub(crate) fn new() -> RawLambdaASTParser {
-            RawLambdaASTParser {
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub(crate) fn parse<
-            'err,
-            'input,
-            __TOKEN: __ToTriple<'err, 'input, >,
-            __TOKENS: IntoIterator<Item=__TOKEN>,
-        >(
-            &self,
-            input: &'input str,
-            errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-            __tokens0: __TOKENS,
-        ) -> Result<RawLambdaAST<'input>, __lalrpop_util::ParseError<usize, Token<'input>, LexerError>>
-        {
-            let __tokens = __tokens0.into_iter();
-            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    input,
-                    errors,
-                    __phantom: core::marker::PhantomData::<(&(), &())>,
-                },
-                __tokens,
-            )
-        }
-    }
-    fn __accepts<
-        'err,
-        'input,
-    >(
-        input: &'input str,
-        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-        __error_state: i8,
-        __states: & [i8],
-        __opt_integer: Option<usize>,
-        _: core::marker::PhantomData<(&'err (), &'input ())>,
-    ) -> bool
-    {
-        let mut __states = __states.to_vec();
-        __states.push(__error_state);
-        loop {
-            let mut __states_len = __states.len();
-            let __top = __states[__states_len - 1];
-            let __action = match __opt_integer {
-                None => __EOF_ACTION[__top as usize],
-                Some(__integer) => __action(__top, __integer),
-            };
-            if __action == 0 { return false; }
-            if __action > 0 { return true; }
-            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &())>) {
+            23 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop, nonterminal_produced
-                } => (states_to_pop, nonterminal_produced),
-                __state_machine::SimulatedReduce::Accept => return true,
-            };
-            __states_len -= __to_pop;
-            __states.truncate(__states_len);
-            let __top = __states[__states_len - 1];
-            let __next_state = __goto(__top, __nt);
-            __states.push(__next_state);
-        }
-    }
-    pub(crate) fn __reduce<
-        'err,
-        'input,
-    >(
-        input: &'input str,
-        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-        __action: i8,
-        __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i8>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'err (), &'input ())>,
-    ) -> Option<Result<RawLambdaAST<'input>,__lalrpop_util::ParseError<usize, Token<'input>, LexerError>>>
-    {
-        let (__pop_states, __nonterminal) = match __action {
-            0 => {
-                __reduce0(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+                    states_to_pop: 5,
+                    nonterminal_produced: 6,
+                }
             }
-            1 => {
-                __reduce1(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            24 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 6,
+                }
             }
-            2 => {
-                __reduce2(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            25 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 6,
+                }
             }
-            3 => {
-                __reduce3(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            26 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 6,
+                }
             }
-            4 => {
-                __reduce4(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            27 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 6,
+                }
             }
-            5 => {
-                __reduce5(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            28 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
             }
-            6 => {
-                __reduce6(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            29 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 7,
+                }
             }
-            7 => {
-                __reduce7(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            30 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
             }
-            8 => {
-                __reduce8(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            31 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
             }
-            9 => {
-                __reduce9(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            32 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
             }
-            10 => {
-                __reduce10(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            33 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
+            }
+            34 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
+            }
+            35 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
+            }
+            36 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
+            }
+            37 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 8,
+                }
+            }
+            38 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 8,
+                }
+            }
+            39 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 9,
+                }
+            }
+            40 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 9,
+                }
+            }
+            41 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 10,
+                }
+            }
+            42 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 10,
+                }
+            }
+            43 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
+                }
+            }
+            44 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 10,
+                }
+            }
+            45 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 10,
+                }
+            }
+            46 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 10,
+                }
+            }
+            47 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 10,
+                }
+            }
+            48 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
+                }
+            }
+            49 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 10,
+                }
+            }
+            50 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 10,
+                }
+            }
+            51 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 10,
+                }
+            }
+            52 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
+                }
+            }
+            53 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 8,
+                    nonterminal_produced: 10,
+                }
+            }
+            54 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 10,
+                }
+            }
+            55 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 10,
+                }
+            }
+            56 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 10,
+                }
+            }
+            57 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 10,
+                }
+            }
+            58 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 11,
+                }
+            }
+            59 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 11,
+                }
+            }
+            60 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 12,
+                }
+            }
+            61 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 12,
+                }
+            }
+            62 => __state_machine::SimulatedReduce::Accept,
+            63 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
+                }
+            }
+            64 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 14,
+                }
+            }
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
+    }
+    pub(crate) struct RawLambdaASTParser {
+        _priv: (),
+    }
+
+    impl RawLambdaASTParser {
+        pub(crate) fn new() -> RawLambdaASTParser {
+            RawLambdaASTParser {
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub(crate) fn parse<
+            'err,
+            'input,
+            __TOKEN: __ToTriple<'err, 'input, >,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            input: &'input str,
+            errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+            __tokens0: __TOKENS,
+        ) -> Result<RawLambdaAST<'input>, __lalrpop_util::ParseError<usize, Token<'input>, LexerError>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    input,
+                    errors,
+                    __phantom: core::marker::PhantomData::<(&(), &())>,
+                },
+                __tokens,
+            )
+        }
+    }
+    fn __accepts<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __error_state: i8,
+        __states: & [i8],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &())>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
+        }
+    }
+    pub(crate) fn __reduce<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __action: i8,
+        __lookahead_start: Option<&usize>,
+        __states: &mut alloc::vec::Vec<i8>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> Option<Result<RawLambdaAST<'input>,__lalrpop_util::ParseError<usize, Token<'input>, LexerError>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            1 => {
+                __reduce1(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            2 => {
+                __reduce2(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            3 => {
+                __reduce3(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            4 => {
+                __reduce4(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            5 => {
+                __reduce5(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            6 => {
+                __reduce6(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            7 => {
+                __reduce7(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            8 => {
+                __reduce8(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            9 => {
+                __reduce9(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            10 => {
+                // ArraySlice = SignedNumber, ":", SignedNumber, ":", number_accessor => ActionFn(82);
+                assert!(__symbols.len() >= 5);
+                let __sym4 = __pop_Variant1(__symbols);
+                let __sym3 = __pop_Variant0(__symbols);
+                let __sym2 = __pop_Variant10(__symbols);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = match super::__action82::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+                (5, 4)
             }
             11 => {
-                __reduce11(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+                // ArraySlice = SignedNumber, ":", SignedNumber, ":" => ActionFn(83);
+                assert!(__symbols.len() >= 4);
+                let __sym3 = __pop_Variant0(__symbols);
+                let __sym2 = __pop_Variant10(__symbols);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = match super::__action83::<>(input, errors, __sym0, __sym1, __sym2, __sym3) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+                (4, 4)
             }
             12 => {
-                __reduce12(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+                // ArraySlice = SignedNumber, ":", ":", number_accessor => ActionFn(84);
+                assert!(__symbols.len() >= 4);
+                let __sym3 = __pop_Variant1(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = match super::__action84::<>(input, errors, __sym0, __sym1, __sym2, __sym3) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+                (4, 4)
             }
             13 => {
-                __reduce13(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+                // ArraySlice = SignedNumber, ":", ":" => ActionFn(85);
+                assert!(__symbols.len() >= 3);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = match super::__action85::<>(input, errors, __sym0, __sym1, __sym2) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+                (3, 4)
             }
             14 => {
-                __reduce14(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+                // ArraySlice = ":", SignedNumber, ":", number_accessor => ActionFn(86);
+                assert!(__symbols.len() >= 4);
+                let __sym3 = __pop_Variant1(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant10(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = match super::__action86::<>(input, errors, __sym0, __sym1, __sym2, __sym3) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+                (4, 4)
             }
             15 => {
-                __reduce15(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+                // ArraySlice = ":", SignedNumber, ":" => ActionFn(87);
+                assert!(__symbols.len() >= 3);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant10(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = match super::__action87::<>(input, errors, __sym0, __sym1, __sym2) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+                (3, 4)
             }
             16 => {
-                __reduce16(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+                // ArraySlice = ":", ":", number_accessor => ActionFn(88);
+                assert!(__symbols.len() >= 3);
+                let __sym2 = __pop_Variant1(__symbols);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = match super::__action88::<>(input, errors, __sym0, __sym1, __sym2) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+                (3, 4)
             }
             17 => {
-                __reduce17(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+                // ArraySlice = ":", ":" => ActionFn(89);
+                assert!(__symbols.len() >= 2);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = match super::__action89::<>(input, errors, __sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+                (2, 4)
             }
             18 => {
                 __reduce18(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
@@ -625,7 +1237,16 @@ mod __parse__RawLambdaAST {
                 __reduce19(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             20 => {
-                __reduce20(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+                // FilterValue = string_accessor => ActionFn(71);
+                let __sym0 = __pop_Variant2(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action71::<>(input, errors, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+                (1, 5)
             }
             21 => {
                 __reduce21(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
@@ -634,17 +1255,140 @@ mod __parse__RawLambdaAST {
                 __reduce22(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             23 => {
-                // __RawLambdaAST = RawLambdaAST => ActionFn(0);
-                let __sym0 = __pop_Variant5(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, errors, __sym0);
-                return Some(Ok(__nt));
+                __reduce23(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __states_len = __states.len();
-        __states.truncate(__states_len - __pop_states);
+            24 => {
+                __reduce24(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            25 => {
+                __reduce25(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            26 => {
+                __reduce26(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            27 => {
+                __reduce27(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            28 => {
+                __reduce28(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            29 => {
+                __reduce29(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            30 => {
+                __reduce30(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            31 => {
+                __reduce31(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            32 => {
+                __reduce32(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            33 => {
+                __reduce33(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            34 => {
+                __reduce34(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            35 => {
+                __reduce35(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            36 => {
+                __reduce36(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            37 => {
+                __reduce37(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            38 => {
+                __reduce38(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            39 => {
+                __reduce39(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            40 => {
+                __reduce40(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            41 => {
+                __reduce41(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            42 => {
+                __reduce42(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            43 => {
+                __reduce43(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            44 => {
+                __reduce44(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            45 => {
+                __reduce45(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            46 => {
+                __reduce46(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            47 => {
+                __reduce47(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            48 => {
+                __reduce48(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            49 => {
+                __reduce49(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            50 => {
+                __reduce50(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            51 => {
+                __reduce51(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            52 => {
+                __reduce52(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            53 => {
+                __reduce53(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            54 => {
+                __reduce54(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            55 => {
+                __reduce55(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            56 => {
+                __reduce56(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            57 => {
+                __reduce57(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            58 => {
+                __reduce58(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            59 => {
+                __reduce59(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            60 => {
+                __reduce60(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            61 => {
+                __reduce61(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            62 => {
+                // __RawLambdaAST = RawLambdaAST => ActionFn(0);
+                let __sym0 = __pop_Variant9(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, errors, __sym0);
+                return Some(Ok(__nt));
+            }
+            63 => {
+                __reduce63(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            64 => {
+                __reduce64(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
         let __state = *__states.last().unwrap();
         let __next_state = __goto(__state, __nonterminal);
         __states.push(__next_state);
@@ -654,14 +1398,47 @@ mod __parse__RawLambdaAST {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant5<
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ArraySlice, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant7<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, FilterValue<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, OptionalAccessor<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, RawLambdaAST<'input>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -676,14 +1453,14 @@ mod __parse__RawLambdaAST {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant12<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, ValueAccessor<'input>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -698,14 +1475,14 @@ mod __parse__RawLambdaAST {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant7<
+    fn __pop_Variant13<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<ValueAccessor<'input>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -720,6 +1497,39 @@ mod __parse__RawLambdaAST {
             _ => __symbol_type_mismatch()
         }
     }
+    fn __pop_Variant11<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<i64>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<u32>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i64, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
     fn __pop_Variant1<
       'input,
     >(
@@ -731,6 +1541,17 @@ mod __parse__RawLambdaAST {
             _ => __symbol_type_mismatch()
         }
     }
+    fn __pop_Variant5<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
     fn __pop_Variant2<
       'input,
     >(
@@ -753,11 +1574,11 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // "!"? = "!" => ActionFn(8);
+        // "!"? = "!" => ActionFn(34);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, errors, __sym0);
+        let __nt = super::__action34::<>(input, errors, __sym0);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 0)
     }
@@ -772,10 +1593,10 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // "!"? =  => ActionFn(9);
+        // "!"? =  => ActionFn(35);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action9::<>(input, errors, &__start, &__end);
+        let __nt = super::__action35::<>(input, errors, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (0, 0)
     }
@@ -790,11 +1611,11 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // "."? = "." => ActionFn(10);
+        // "."? = "." => ActionFn(32);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(input, errors, __sym0);
+        let __nt = super::__action32::<>(input, errors, __sym0);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 1)
     }
@@ -809,10 +1630,10 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // "."? =  => ActionFn(11);
+        // "."? =  => ActionFn(33);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action11::<>(input, errors, &__start, &__end);
+        let __nt = super::__action33::<>(input, errors, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (0, 1)
     }
@@ -827,13 +1648,12 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // RawLambdaAST = ".$" => ActionFn(30);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action30::<>(input, errors, __sym0);
+        // @L =  => ActionFn(29);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action29::<>(input, errors, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (1, 2)
+        (0, 2)
     }
     pub(crate) fn __reduce5<
         'err,
@@ -846,15 +1666,12 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // RawLambdaAST = ".$", ValueAccessor+ => ActionFn(31);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action31::<>(input, errors, __sym0, __sym1);
+        // @R =  => ActionFn(26);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action26::<>(input, errors, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 2)
+        (0, 3)
     }
     pub(crate) fn __reduce6<
         'err,
@@ -867,13 +1684,16 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // RawLambdaAST = length_functor => ActionFn(2);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ArraySlice = SignedNumber, ":", SignedNumber => ActionFn(72);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, errors, __sym0);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (1, 2)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action72::<>(input, errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 4)
     }
     pub(crate) fn __reduce7<
         'err,
@@ -886,13 +1706,15 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // RawLambdaAST = error => ActionFn(3);
-        let __sym0 = __pop_Variant3(__symbols);
+        // ArraySlice = SignedNumber, ":" => ActionFn(73);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action3::<>(input, errors, __sym0);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (1, 2)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action73::<>(input, errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 4)
     }
     pub(crate) fn __reduce8<
         'err,
@@ -905,18 +1727,15 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", "[", number_accessor, "]", "!" => ActionFn(22);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // ArraySlice = ":", SignedNumber => ActionFn(74);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant10(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action22::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __end = __sym1.2.clone();
+        let __nt = super::__action74::<>(input, errors, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (5, 3)
+        (2, 4)
     }
     pub(crate) fn __reduce9<
         'err,
@@ -929,19 +1748,53 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = "[", number_accessor, "]", "!" => ActionFn(23);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        // ArraySlice = ":" => ActionFn(75);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action23::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        let __end = __sym0.2.clone();
+        let __nt = super::__action75::<>(input, errors, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (4, 3)
+        (1, 4)
+    }
+    pub(crate) fn __reduce18<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // FilterValue = string_literal => ActionFn(23);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action23::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 5)
+    }
+    pub(crate) fn __reduce19<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // FilterValue = SignedNumber => ActionFn(24);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action24::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 5)
     }
-    pub(crate) fn __reduce10<
+    pub(crate) fn __reduce21<
         'err,
         'input,
     >(
@@ -952,7 +1805,7 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", "[", number_accessor, "]" => ActionFn(24);
+        // OptionalAccessor = ".", "[", number_accessor, "]" => ActionFn(50);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant1(__symbols);
@@ -960,11 +1813,11 @@ mod __parse__RawLambdaAST {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym3.2.clone();
-        let __nt = super::__action24::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (4, 3)
+        let __nt = super::__action50::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 6)
     }
-    pub(crate) fn __reduce11<
+    pub(crate) fn __reduce22<
         'err,
         'input,
     >(
@@ -975,18 +1828,18 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = "[", number_accessor, "]" => ActionFn(25);
+        // OptionalAccessor = "[", number_accessor, "]" => ActionFn(51);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action25::<>(input, errors, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 3)
+        let __nt = super::__action51::<>(input, errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 6)
     }
-    pub(crate) fn __reduce12<
+    pub(crate) fn __reduce23<
         'err,
         'input,
     >(
@@ -997,20 +1850,20 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", "[", string_accessor, "]", "!" => ActionFn(26);
+        // OptionalAccessor = ".", "[", "-", number_accessor, "]" => ActionFn(52);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym4.2.clone();
-        let __nt = super::__action26::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (5, 3)
+        let __nt = super::__action52::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (5, 6)
     }
-    pub(crate) fn __reduce13<
+    pub(crate) fn __reduce24<
         'err,
         'input,
     >(
@@ -1021,19 +1874,19 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = "[", string_accessor, "]", "!" => ActionFn(27);
+        // OptionalAccessor = "[", "-", number_accessor, "]" => ActionFn(53);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym3.2.clone();
-        let __nt = super::__action27::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (4, 3)
+        let __nt = super::__action53::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 6)
     }
-    pub(crate) fn __reduce14<
+    pub(crate) fn __reduce25<
         'err,
         'input,
     >(
@@ -1044,7 +1897,7 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", "[", string_accessor, "]" => ActionFn(28);
+        // OptionalAccessor = ".", "[", string_accessor, "]" => ActionFn(54);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant2(__symbols);
@@ -1052,11 +1905,11 @@ mod __parse__RawLambdaAST {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym3.2.clone();
-        let __nt = super::__action28::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (4, 3)
+        let __nt = super::__action54::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 6)
     }
-    pub(crate) fn __reduce15<
+    pub(crate) fn __reduce26<
         'err,
         'input,
     >(
@@ -1067,18 +1920,18 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = "[", string_accessor, "]" => ActionFn(29);
+        // OptionalAccessor = "[", string_accessor, "]" => ActionFn(55);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action29::<>(input, errors, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 3)
+        let __nt = super::__action55::<>(input, errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 6)
     }
-    pub(crate) fn __reduce16<
+    pub(crate) fn __reduce27<
         'err,
         'input,
     >(
@@ -1089,18 +1942,36 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", string_accessor, "!" => ActionFn(20);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
+        // OptionalAccessor = ".", string_accessor => ActionFn(18);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action20::<>(input, errors, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 3)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action18::<>(input, errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 6)
+    }
+    pub(crate) fn __reduce28<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // RawLambdaAST = ".$" => ActionFn(80);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action80::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 7)
     }
-    pub(crate) fn __reduce17<
+    pub(crate) fn __reduce29<
         'err,
         'input,
     >(
@@ -1111,17 +1982,17 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", string_accessor => ActionFn(21);
+        // RawLambdaAST = ".$", ValueAccessor+ => ActionFn(81);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action21::<>(input, errors, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 3)
+        let __nt = super::__action81::<>(input, errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 7)
     }
-    pub(crate) fn __reduce18<
+    pub(crate) fn __reduce30<
         'err,
         'input,
     >(
@@ -1132,15 +2003,15 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = error => ActionFn(7);
-        let __sym0 = __pop_Variant3(__symbols);
+        // RawLambdaAST = length_functor => ActionFn(2);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, errors, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 3)
+        let __nt = super::__action2::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 7)
     }
-    pub(crate) fn __reduce19<
+    pub(crate) fn __reduce31<
         'err,
         'input,
     >(
@@ -1151,14 +2022,15 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor* =  => ActionFn(12);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action12::<>(input, errors, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 4)
+        // RawLambdaAST = keys_functor => ActionFn(3);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 7)
     }
-    pub(crate) fn __reduce20<
+    pub(crate) fn __reduce32<
         'err,
         'input,
     >(
@@ -1169,15 +2041,15 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor* = ValueAccessor+ => ActionFn(13);
-        let __sym0 = __pop_Variant7(__symbols);
+        // RawLambdaAST = values_functor => ActionFn(4);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(input, errors, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 4)
+        let __nt = super::__action4::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 7)
     }
-    pub(crate) fn __reduce21<
+    pub(crate) fn __reduce33<
         'err,
         'input,
     >(
@@ -1188,15 +2060,15 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor+ = ValueAccessor => ActionFn(14);
-        let __sym0 = __pop_Variant6(__symbols);
+        // RawLambdaAST = is_empty_functor => ActionFn(5);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, errors, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 5)
+        let __nt = super::__action5::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 7)
     }
-    pub(crate) fn __reduce22<
+    pub(crate) fn __reduce34<
         'err,
         'input,
     >(
@@ -1207,744 +2079,2887 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor+ = ValueAccessor+, ValueAccessor => ActionFn(15);
+        // RawLambdaAST = first_functor => ActionFn(6);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 7)
+    }
+    pub(crate) fn __reduce35<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // RawLambdaAST = last_functor => ActionFn(7);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 7)
+    }
+    pub(crate) fn __reduce36<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // RawLambdaAST = error => ActionFn(8);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action8::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 7)
+    }
+    pub(crate) fn __reduce37<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // SignedNumber = number_accessor => ActionFn(21);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action21::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 8)
+    }
+    pub(crate) fn __reduce38<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // SignedNumber = "-", number_accessor => ActionFn(22);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action15::<>(input, errors, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (2, 5)
+        let __nt = super::__action22::<>(input, errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 8)
+    }
+    pub(crate) fn __reduce39<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // SignedNumber? = SignedNumber => ActionFn(30);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 9)
+    }
+    pub(crate) fn __reduce40<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // SignedNumber? =  => ActionFn(31);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action31::<>(input, errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 9)
+    }
+    pub(crate) fn __reduce41<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = OptionalAccessor, "!" => ActionFn(40);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action40::<>(input, errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 10)
+    }
+    pub(crate) fn __reduce42<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = OptionalAccessor => ActionFn(41);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 10)
+    }
+    pub(crate) fn __reduce43<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = OptionalAccessor, "?", "!" => ActionFn(42);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action42::<>(input, errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 10)
+    }
+    pub(crate) fn __reduce44<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = OptionalAccessor, "?" => ActionFn(43);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action43::<>(input, errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 10)
+    }
+    pub(crate) fn __reduce45<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = ".", "[", ArraySlice, "]", "!" => ActionFn(56);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action56::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 10)
+    }
+    pub(crate) fn __reduce46<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = "[", ArraySlice, "]", "!" => ActionFn(57);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action57::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (4, 10)
+    }
+    pub(crate) fn __reduce47<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = ".", "[", ArraySlice, "]" => ActionFn(58);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action58::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (4, 10)
+    }
+    pub(crate) fn __reduce48<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = "[", ArraySlice, "]" => ActionFn(59);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action59::<>(input, errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 10)
+    }
+    pub(crate) fn __reduce49<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = ".", "[", "*", "]", "!" => ActionFn(60);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action60::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 10)
+    }
+    pub(crate) fn __reduce50<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = "[", "*", "]", "!" => ActionFn(61);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action61::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (4, 10)
     }
+    pub(crate) fn __reduce51<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = ".", "[", "*", "]" => ActionFn(62);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action62::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (4, 10)
+    }
+    pub(crate) fn __reduce52<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = "[", "*", "]" => ActionFn(63);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action63::<>(input, errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 10)
+    }
+    pub(crate) fn __reduce53<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = ".", "[", "?", string_accessor, "==", FilterValue, "]", "!" => ActionFn(64);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant7(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action64::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (8, 10)
+    }
+    pub(crate) fn __reduce54<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = "[", "?", string_accessor, "==", FilterValue, "]", "!" => ActionFn(65);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant7(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action65::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (7, 10)
+    }
+    pub(crate) fn __reduce55<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = ".", "[", "?", string_accessor, "==", FilterValue, "]" => ActionFn(66);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant7(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action66::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (7, 10)
+    }
+    pub(crate) fn __reduce56<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = "[", "?", string_accessor, "==", FilterValue, "]" => ActionFn(67);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant7(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action67::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 10)
+    }
+    pub(crate) fn __reduce57<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = error => ActionFn(14);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action14::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 10)
+    }
+    pub(crate) fn __reduce58<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor* =  => ActionFn(36);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action36::<>(input, errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (0, 11)
+    }
+    pub(crate) fn __reduce59<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor* = ValueAccessor+ => ActionFn(37);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action37::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce60<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor+ = ValueAccessor => ActionFn(38);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action38::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 12)
+    }
+    pub(crate) fn __reduce61<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor+ = ValueAccessor+, ValueAccessor => ActionFn(39);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action39::<>(input, errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 12)
+    }
+    pub(crate) fn __reduce63<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // number_accessor? = number_accessor => ActionFn(27);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action27::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 14)
+    }
+    pub(crate) fn __reduce64<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // number_accessor? =  => ActionFn(28);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action28::<>(input, errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 14)
+    }
+}
+pub(crate) use self::__parse__RawLambdaAST::RawLambdaASTParser;
+
+#[allow(unused_variables)]
+fn __action0<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, RawLambdaAST<'input>, usize),
+) -> RawLambdaAST<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, value_path_starter, _): (usize, Token<'input>, usize),
+    (_, accessors, _): (usize, alloc::vec::Vec<ValueAccessor<'input>>, usize),
+) -> RawLambdaAST<'input>
+{
+    RawLambdaAST::ValuePath(accessors)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> RawLambdaAST<'input>
+{
+    RawLambdaAST::Functor(Functor::Length)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> RawLambdaAST<'input>
+{
+    RawLambdaAST::Functor(Functor::Keys)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> RawLambdaAST<'input>
+{
+    RawLambdaAST::Functor(Functor::Values)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> RawLambdaAST<'input>
+{
+    RawLambdaAST::Functor(Functor::IsEmpty)
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> RawLambdaAST<'input>
+{
+    RawLambdaAST::Functor(Functor::First)
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> RawLambdaAST<'input>
+{
+    RawLambdaAST::Functor(Functor::Last)
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, LexerError>, usize),
+) -> RawLambdaAST<'input>
+{
+    { errors.push(__0); RawLambdaAST::Error }
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, accessor, _): (usize, OptionalAccessor<'input>, usize),
+    (_, maybe_flatten_sign, _): (usize, core::option::Option<Token<'input>>, usize),
+) -> ValueAccessor<'input>
+{
+    accessor.into()
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, accessor, _): (usize, OptionalAccessor<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, maybe_flatten_sign, _): (usize, core::option::Option<Token<'input>>, usize),
+) -> ValueAccessor<'input>
+{
+    ValueAccessor::Optional(accessor)
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, maybe_dot_selector, _): (usize, core::option::Option<Token<'input>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, slice, _): (usize, ArraySlice, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, maybe_flatten_sign, _): (usize, core::option::Option<Token<'input>>, usize),
+) -> ValueAccessor<'input>
+{
+    {
+        ValueAccessor::ArraySlice(slice)
+    }
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, maybe_dot_selector, _): (usize, core::option::Option<Token<'input>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, maybe_flatten_sign, _): (usize, core::option::Option<Token<'input>>, usize),
+) -> ValueAccessor<'input>
+{
+    {
+        ValueAccessor::Wildcard
+    }
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, maybe_dot_selector, _): (usize, core::option::Option<Token<'input>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, field_name, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, value, _): (usize, FilterValue<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, maybe_flatten_sign, _): (usize, core::option::Option<Token<'input>>, usize),
+) -> ValueAccessor<'input>
+{
+    {
+        ValueAccessor::Filter(FilterPredicate::new(field_name, value))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, LexerError>, usize),
+) -> ValueAccessor<'input>
+{
+    { errors.push(__0); ValueAccessor::Error }
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, maybe_dot_selector, _): (usize, core::option::Option<Token<'input>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, idx, _): (usize, u32, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> OptionalAccessor<'input>
+{
+    {
+        OptionalAccessor::ArrayAccess { idx }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, maybe_dot_selector, _): (usize, core::option::Option<Token<'input>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, idx_from_end, _): (usize, u32, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> OptionalAccessor<'input>
+{
+    {
+        OptionalAccessor::ArrayAccessFromEnd { idx_from_end }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, maybe_dot_selector, _): (usize, core::option::Option<Token<'input>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, scalar_name, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> OptionalAccessor<'input>
+{
+    {
+        OptionalAccessor::FieldAccessByScalar { scalar_name }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, field_name, _): (usize, &'input str, usize),
+) -> OptionalAccessor<'input>
+{
+    {
+        OptionalAccessor::FieldAccessByName { field_name }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, start, _): (usize, core::option::Option<i64>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, end, _): (usize, core::option::Option<i64>, usize),
+) -> ArraySlice
+{
+    ArraySlice::new(start, end, None)
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, start, _): (usize, core::option::Option<i64>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, end, _): (usize, core::option::Option<i64>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, left, _): (usize, usize, usize),
+    (_, step, _): (usize, core::option::Option<u32>, usize),
+    (_, right, _): (usize, usize, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
+{
+    {
+        match step {
+            Some(0) => Err(ParseError::User { error: LexerError::ZeroSliceStep(left, right) }),
+            step => Ok(ArraySlice::new(start, end, step)),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, number, _): (usize, u32, usize),
+) -> i64
+{
+    number as i64
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, number, _): (usize, u32, usize),
+) -> i64
+{
+    -(number as i64)
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, literal, _): (usize, &'input str, usize),
+) -> FilterValue<'input>
+{
+    FilterValue::String(literal)
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, number, _): (usize, i64, usize),
+) -> FilterValue<'input>
+{
+    FilterValue::Number(number)
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, left, _): (usize, usize, usize),
+    (_, keyword, _): (usize, &'input str, usize),
+    (_, right, _): (usize, usize, usize),
+) -> Result<FilterValue<'input>,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
+{
+    match keyword {
+        "true" => Ok(FilterValue::Boolean(true)),
+        "false" => Ok(FilterValue::Boolean(false)),
+        "null" => Ok(FilterValue::Null),
+        _ => Err(ParseError::User { error: LexerError::UnexpectedKeyword(left, right) }),
+    }
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, u32, usize),
+) -> core::option::Option<u32>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<u32>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, i64, usize),
+) -> core::option::Option<i64>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<i64>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> core::option::Option<Token<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Token<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> core::option::Option<Token<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Token<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<ValueAccessor<'input>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, v, _): (usize, alloc::vec::Vec<ValueAccessor<'input>>, usize),
+) -> alloc::vec::Vec<ValueAccessor<'input>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action38<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, ValueAccessor<'input>, usize),
+) -> alloc::vec::Vec<ValueAccessor<'input>>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action39<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, v, _): (usize, alloc::vec::Vec<ValueAccessor<'input>>, usize),
+    (_, e, _): (usize, ValueAccessor<'input>, usize),
+) -> alloc::vec::Vec<ValueAccessor<'input>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action40<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, OptionalAccessor<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action34(
+        input,
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        input,
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, OptionalAccessor<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action35(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        input,
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, OptionalAccessor<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action34(
+        input,
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        input,
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action43<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, OptionalAccessor<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action35(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        input,
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action44<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ArraySlice, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action34(
+        input,
+        errors,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        input,
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action45<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ArraySlice, usize),
+    __3: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action35(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        input,
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action34(
+        input,
+        errors,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        input,
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action47<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action35(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        input,
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action48<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, FilterValue<'input>, usize),
+    __6: (usize, Token<'input>, usize),
+    __7: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __7.0.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action34(
+        input,
+        errors,
+        __7,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        input,
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action49<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, FilterValue<'input>, usize),
+    __6: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action35(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        input,
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action50<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, u32, usize),
+    __3: (usize, Token<'input>, usize),
+) -> OptionalAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action32(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action15(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action51<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, u32, usize),
+    __2: (usize, Token<'input>, usize),
+) -> OptionalAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action33(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action15(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action52<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, u32, usize),
+    __4: (usize, Token<'input>, usize),
+) -> OptionalAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action32(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, u32, usize),
+    __3: (usize, Token<'input>, usize),
+) -> OptionalAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action33(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
-pub(crate) use self::__parse__RawLambdaAST::RawLambdaASTParser;
 
 #[allow(unused_variables)]
-fn __action0<
+fn __action54<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, __0, _): (usize, RawLambdaAST<'input>, usize),
-) -> RawLambdaAST<'input>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Token<'input>, usize),
+) -> OptionalAccessor<'input>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action32(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action1<
+fn __action55<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, value_path_starter, _): (usize, Token<'input>, usize),
-    (_, accessors, _): (usize, alloc::vec::Vec<ValueAccessor<'input>>, usize),
-) -> RawLambdaAST<'input>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+) -> OptionalAccessor<'input>
 {
-    RawLambdaAST::ValuePath(accessors)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action33(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action2<
+fn __action56<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> RawLambdaAST<'input>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ArraySlice, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
 {
-    RawLambdaAST::Functor(Functor::Length)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action32(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action44(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action3<
+fn __action57<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, __0, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, LexerError>, usize),
-) -> RawLambdaAST<'input>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, ArraySlice, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
 {
-    { errors.push(__0); RawLambdaAST::Error }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action33(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action44(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action4<
+fn __action58<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, maybe_dot_selector, _): (usize, core::option::Option<Token<'input>>, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, idx, _): (usize, u32, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, maybe_flatten_sign, _): (usize, core::option::Option<Token<'input>>, usize),
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ArraySlice, usize),
+    __3: (usize, Token<'input>, usize),
 ) -> ValueAccessor<'input>
 {
-    {
-        ValueAccessor::ArrayAccess { idx }
-    }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action32(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action5<
+fn __action59<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, maybe_dot_selector, _): (usize, core::option::Option<Token<'input>>, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, scalar_name, _): (usize, &'input str, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, maybe_flatten_sign, _): (usize, core::option::Option<Token<'input>>, usize),
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, ArraySlice, usize),
+    __2: (usize, Token<'input>, usize),
 ) -> ValueAccessor<'input>
 {
-    {
-        ValueAccessor::FieldAccessByScalar { scalar_name }
-    }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action33(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action60<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action32(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action46(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action61<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action33(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action46(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action62<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action32(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action47(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action6<
+fn __action63<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, field_name, _): (usize, &'input str, usize),
-    (_, maybe_flatten_sign, _): (usize, core::option::Option<Token<'input>>, usize),
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
 ) -> ValueAccessor<'input>
 {
-    {
-        ValueAccessor::FieldAccessByName { field_name }
-    }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action33(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action47(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action7<
+fn __action64<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, __0, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, LexerError>, usize),
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, FilterValue<'input>, usize),
+    __6: (usize, Token<'input>, usize),
+    __7: (usize, Token<'input>, usize),
 ) -> ValueAccessor<'input>
 {
-    { errors.push(__0); ValueAccessor::Error }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action32(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action8<
+fn __action65<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> core::option::Option<Token<'input>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, FilterValue<'input>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action33(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action9<
+fn __action66<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Token<'input>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, FilterValue<'input>, usize),
+    __6: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action32(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action49(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action10<
+fn __action67<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> core::option::Option<Token<'input>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, FilterValue<'input>, usize),
+    __5: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action33(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action49(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action11<
+fn __action68<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Token<'input>>
+    __0: (usize, core::option::Option<i64>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, core::option::Option<i64>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, core::option::Option<u32>, usize),
+    __5: (usize, usize, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    None
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action29(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        input,
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action12<
+fn __action69<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<ValueAccessor<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Result<FilterValue<'input>,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action29(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action25(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action13<
+fn __action70<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, v, _): (usize, alloc::vec::Vec<ValueAccessor<'input>>, usize),
-) -> alloc::vec::Vec<ValueAccessor<'input>>
+    __0: (usize, core::option::Option<i64>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, core::option::Option<i64>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, core::option::Option<u32>, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    v
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action26(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action68(
+        input,
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action14<
+fn __action71<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, __0, _): (usize, ValueAccessor<'input>, usize),
-) -> alloc::vec::Vec<ValueAccessor<'input>>
+    __0: (usize, &'input str, usize),
+) -> Result<FilterValue<'input>,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    alloc::vec![__0]
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action26(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action69(
+        input,
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action15<
+fn __action72<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    (_, v, _): (usize, alloc::vec::Vec<ValueAccessor<'input>>, usize),
-    (_, e, _): (usize, ValueAccessor<'input>, usize),
-) -> alloc::vec::Vec<ValueAccessor<'input>>
+    __0: (usize, i64, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, i64, usize),
+) -> ArraySlice
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __start1 = __2.0.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action30(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action30(
+        input,
+        errors,
+        __2,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action19(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __temp1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action16<
+fn __action73<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __0: (usize, i64, usize),
     __1: (usize, Token<'input>, usize),
-    __2: (usize, u32, usize),
-    __3: (usize, Token<'input>, usize),
-    __4: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+) -> ArraySlice
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __start1 = __1.2.clone();
+    let __end1 = __1.2.clone();
+    let __temp0 = __action30(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action31(
+        input,
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action19(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action74<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, i64, usize),
+) -> ArraySlice
 {
-    let __start0 = __4.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action8(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __1.0.clone();
+    let __end1 = __1.2.clone();
+    let __temp0 = __action31(
         input,
         errors,
-        __4,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
+    let __temp1 = __action30(
         input,
         errors,
-        __0,
         __1,
-        __2,
-        __3,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action19(
+        input,
+        errors,
         __temp0,
+        __0,
+        __temp1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action17<
+fn __action75<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __0: (usize, core::option::Option<Token<'input>>, usize),
-    __1: (usize, Token<'input>, usize),
-    __2: (usize, u32, usize),
-    __3: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+    __0: (usize, Token<'input>, usize),
+) -> ArraySlice
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action9(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __0.2.clone();
+    let __temp0 = __action31(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
+    let __temp1 = __action31(
+        input,
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action19(
         input,
         errors,
-        __0,
-        __1,
-        __2,
-        __3,
         __temp0,
+        __0,
+        __temp1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action18<
+fn __action76<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __0: (usize, i64, usize),
     __1: (usize, Token<'input>, usize),
-    __2: (usize, &'input str, usize),
+    __2: (usize, i64, usize),
     __3: (usize, Token<'input>, usize),
-    __4: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+    __4: (usize, core::option::Option<u32>, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __4.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action8(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __start1 = __2.0.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action30(
         input,
         errors,
-        __4,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action5(
+    let __temp1 = __action30(
         input,
         errors,
-        __0,
-        __1,
         __2,
-        __3,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action70(
+        input,
+        errors,
         __temp0,
+        __1,
+        __temp1,
+        __3,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action19<
+fn __action77<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __0: (usize, i64, usize),
     __1: (usize, Token<'input>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, core::option::Option<u32>, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action9(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __start1 = __1.2.clone();
+    let __end1 = __2.0.clone();
+    let __temp0 = __action30(
         input,
         errors,
-        &__start0,
-        &__end0,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action5(
+    let __temp1 = __action31(
         input,
         errors,
-        __0,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action70(
+        input,
+        errors,
+        __temp0,
         __1,
+        __temp1,
         __2,
         __3,
-        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action20<
+fn __action78<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, i64, usize),
     __2: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+    __3: (usize, core::option::Option<u32>, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action8(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __1.0.clone();
+    let __end1 = __1.2.clone();
+    let __temp0 = __action31(
         input,
         errors,
-        __2,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    let __temp1 = __action30(
         input,
         errors,
-        __0,
         __1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action70(
+        input,
+        errors,
         __temp0,
+        __0,
+        __temp1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action21<
+fn __action79<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, &'input str, usize),
-) -> ValueAccessor<'input>
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, core::option::Option<u32>, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __1.2.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action9(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __temp0 = __action31(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    let __temp1 = __action31(
+        input,
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action70(
         input,
         errors,
+        __temp0,
         __0,
+        __temp1,
         __1,
-        __temp0,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action22<
+fn __action80<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, Token<'input>, usize),
-    __2: (usize, u32, usize),
-    __3: (usize, Token<'input>, usize),
-    __4: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+) -> RawLambdaAST<'input>
 {
-    let __start0 = __0.0.clone();
+    let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action10(
+    let __temp0 = __action36(
         input,
         errors,
-        __0,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action16(
+    __action1(
         input,
         errors,
+        __0,
         __temp0,
-        __1,
-        __2,
-        __3,
-        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action23<
+fn __action81<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, u32, usize),
-    __2: (usize, Token<'input>, usize),
-    __3: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+    __1: (usize, alloc::vec::Vec<ValueAccessor<'input>>, usize),
+) -> RawLambdaAST<'input>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action11(
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action37(
         input,
         errors,
-        &__start0,
-        &__end0,
+        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action16(
+    __action1(
         input,
         errors,
-        __temp0,
         __0,
-        __1,
-        __2,
-        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action24<
+fn __action82<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __0: (usize, Token<'input>, usize),
+    __0: (usize, i64, usize),
     __1: (usize, Token<'input>, usize),
-    __2: (usize, u32, usize),
+    __2: (usize, i64, usize),
     __3: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+    __4: (usize, u32, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action10(
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action27(
         input,
         errors,
-        __0,
+        __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action17(
+    __action76(
         input,
         errors,
-        __temp0,
+        __0,
         __1,
         __2,
         __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action25<
+fn __action83<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, u32, usize),
-    __2: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+    __0: (usize, i64, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, i64, usize),
+    __3: (usize, Token<'input>, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action11(
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action28(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action17(
+    __action76(
         input,
         errors,
-        __temp0,
         __0,
         __1,
         __2,
+        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action26<
+fn __action84<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __0: (usize, Token<'input>, usize),
+    __0: (usize, i64, usize),
     __1: (usize, Token<'input>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Token<'input>, usize),
-    __4: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, u32, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action10(
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action27(
         input,
         errors,
-        __0,
+        __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action77(
         input,
         errors,
-        __temp0,
+        __0,
         __1,
         __2,
-        __3,
-        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action27<
+fn __action85<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, &'input str, usize),
+    __0: (usize, i64, usize),
+    __1: (usize, Token<'input>, usize),
     __2: (usize, Token<'input>, usize),
-    __3: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action11(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action28(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action77(
         input,
         errors,
-        __temp0,
         __0,
         __1,
         __2,
-        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action28<
+fn __action86<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, Token<'input>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+    __1: (usize, i64, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, u32, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action10(
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action27(
         input,
         errors,
-        __0,
+        __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action19(
+    __action78(
         input,
         errors,
-        __temp0,
+        __0,
         __1,
         __2,
-        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action29<
+fn __action87<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, i64, usize),
     __2: (usize, Token<'input>, usize),
-) -> ValueAccessor<'input>
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action11(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action28(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action19(
+    __action78(
         input,
         errors,
-        __temp0,
         __0,
         __1,
         __2,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action30<
+fn __action88<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
     __0: (usize, Token<'input>, usize),
-) -> RawLambdaAST<'input>
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, u32, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action12(
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action27(
         input,
         errors,
-        &__start0,
-        &__end0,
+        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action1(
+    __action79(
         input,
         errors,
         __0,
+        __1,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action31<
+fn __action89<
     'err,
     'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, alloc::vec::Vec<ValueAccessor<'input>>, usize),
-) -> RawLambdaAST<'input>
+    __1: (usize, Token<'input>, usize),
+) -> Result<ArraySlice,__lalrpop_util::ParseError<usize,Token<'input>,LexerError>>
 {
-    let __start0 = __1.0.clone();
+    let __start0 = __1.2.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action13(
+    let __temp0 = __action28(
         input,
         errors,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action1(
+    __action79(
         input,
         errors,
         __0,
+        __1,
         __temp0,
     )
 }
@@ -66,6 +66,18 @@ impl SecurityTetraplet {
         }
     }
 
+    /// Create a tetraplet for read-only particle metadata provided by a host
+    /// such as `%particle_id%`, function name contains the name of this metadata.
+    pub fn host_tetraplet(host_peer_id: impl Into<String>, value_name: impl Into<String>) -> Self {
+        Self {
+            // this value is provided by the peer running the interpreter
+            peer_pk: host_peer_id.into(),
+            service_id: String::new(),
+            function_name: value_name.into(),
+            json_path: String::new(),
+        }
+    }
+
     pub fn from_triplet(triplet: ResolvedTriplet) -> Self {
         Self {
             peer_pk: triplet.peer_pk,
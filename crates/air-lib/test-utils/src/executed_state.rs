@@ -31,6 +31,7 @@ use crate::SubTraceDesc;
 use air_interpreter_cid::value_to_json_cid;
 use air_interpreter_data::CanonCidAggregate;
 use air_interpreter_data::CidTracker;
+use air_interpreter_data::TtlGuardResult;
 use avm_server::SecurityTetraplet;
 use serde::Deserialize;
 use serde::Serialize;
@@ -112,6 +113,23 @@ pub fn stream_string_array(result: Vec<impl Into<String>>, generation: u32) -> E
     stream(value, generation)
 }
 
+pub fn unused(result: JValue) -> ExecutedState {
+    let cid = value_to_json_cid(&result)
+        .unwrap_or_else(|e| panic!("{:?}: failed to compute CID of {:?}", e, result));
+    ExecutedState::Call(CallResult::unused(Rc::new(cid)))
+}
+
+pub fn unused_string(result: impl Into<String>) -> ExecutedState {
+    let result = JValue::String(result.into());
+    unused(result)
+}
+
+pub fn unused_number(result: impl Into<serde_json::Number>) -> ExecutedState {
+    let result = JValue::Number(result.into());
+
+    unused(result)
+}
+
 pub fn request_sent_by(sender: impl Into<String>) -> ExecutedState {
     ExecutedState::Call(CallResult::RequestSentBy(Sender::PeerId(Rc::new(
         sender.into(),
@@ -162,6 +180,16 @@ pub fn ap(generation: u32) -> ExecutedState {
     ExecutedState::Ap(ap_result)
 }
 
+pub fn ap_map(generation: u32) -> ExecutedState {
+    let ap_result = ApResult::new(generation);
+    ExecutedState::ApMap(ap_result)
+}
+
+pub fn ttl_guard(passed: bool) -> ExecutedState {
+    let ttl_guard_result = TtlGuardResult::new(passed);
+    ExecutedState::TtlGuard(ttl_guard_result)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ValueAggregateAlike {
     pub result: Rc<JValue>,
@@ -90,7 +90,7 @@ pub fn trace_from_result(result: &RawAVMOutcome) -> ExecutionTrace {
 }
 
 pub fn data_from_result(result: &RawAVMOutcome) -> InterpreterData {
-    serde_json::from_slice(&result.data).expect("default serializer shouldn't fail")
+    InterpreterData::try_from_slice(&result.data).expect("default serializer shouldn't fail")
 }
 
 pub fn raw_data_from_trace(trace: impl Into<ExecutionTrace>, value_tracker: CidTracker) -> Vec<u8> {
@@ -103,6 +103,7 @@ pub fn raw_data_from_trace(trace: impl Into<ExecutionTrace>, value_tracker: CidT
             tetraplet_store: CidStore::<_>::default(),
             canon_store: CidStore::<_>::default(),
         },
+        <_>::default(),
         0,
         semver::Version::new(1, 1, 1),
     );
@@ -124,6 +125,7 @@ pub fn raw_data_from_trace_with_canon(
             tetraplet_store: tetraplet_tracker.into(),
             canon_store: canon_tracker.into(),
         },
+        <_>::default(),
         0,
         semver::Version::new(1, 1, 1),
     );
@@ -15,6 +15,7 @@
  */
 
 use crate::test_runner::AirRunner;
+use crate::test_runner::TestRunParameters;
 use air_interpreter_interface::RunParameters;
 use avm_server::avm_runner::*;
 use avm_server::into_raw_result;
@@ -35,32 +36,24 @@ impl AirRunner for NativeAirRunner {
         air: impl Into<String>,
         prev_data: impl Into<Vec<u8>>,
         data: impl Into<Vec<u8>>,
-        init_peer_id: impl Into<String>,
-        timestamp: u64,
-        ttl: u32,
-        override_current_peer_id: Option<String>,
+        test_run_params: &TestRunParameters,
         call_results: avm_server::CallResults,
     ) -> Result<RawAVMOutcome, Box<dyn std::error::Error>> {
         // some inner parts transformations
         let raw_call_results = into_raw_result(call_results);
         let raw_call_results = serde_json::to_vec(&raw_call_results).unwrap();
 
-        let current_peer_id =
-            override_current_peer_id.unwrap_or_else(|| self.current_peer_id.clone());
+        let run_options = test_run_params.to_run_options(&self.current_peer_id);
 
         let outcome = air::execute_air(
             air.into(),
             prev_data.into(),
             data.into(),
-            RunParameters {
-                init_peer_id: init_peer_id.into(),
-                current_peer_id,
-                timestamp,
-                ttl,
-            },
+            RunParameters::from(run_options),
             raw_call_results,
         );
-        let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+        let mut outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+        outcome.merge_provenance = air::take_merge_provenance();
 
         Ok(outcome)
     }
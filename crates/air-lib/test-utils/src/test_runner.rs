@@ -21,6 +21,9 @@ use crate::wasm_test_runner::WasmAirRunner as AirRunnerImpl;
 
 use super::CallServiceClosure;
 use avm_server::avm_runner::*;
+use avm_server::CidHashAlgorithm;
+use avm_server::DataFormat;
+use avm_server::RunOptions;
 
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -28,16 +31,12 @@ use std::collections::HashSet;
 pub trait AirRunner {
     fn new(current_call_id: impl Into<String>) -> Self;
 
-    #[allow(clippy::too_many_arguments)]
     fn call(
         &mut self,
         air: impl Into<String>,
         prev_data: impl Into<Vec<u8>>,
         data: impl Into<Vec<u8>>,
-        init_peer_id: impl Into<String>,
-        timestamp: u64,
-        ttl: u32,
-        override_current_peer_id: Option<String>,
+        test_run_params: &TestRunParameters,
         call_results: avm_server::CallResults,
     ) -> Result<RawAVMOutcome, Box<dyn std::error::Error>>;
 }
@@ -50,8 +49,17 @@ pub struct TestRunner<R = AirRunnerImpl> {
 #[derive(Debug, Default, Clone)]
 pub struct TestRunParameters {
     pub init_peer_id: String,
+    pub particle_id: String,
     pub timestamp: u64,
     pub ttl: u32,
+    pub current_timestamp: u64,
+    pub data_format: DataFormat,
+    pub compression_threshold: u32,
+    pub skip_cid_verification: bool,
+    pub cid_hash_algorithm: CidHashAlgorithm,
+    pub signing_key: Vec<u8>,
+    pub require_signatures: bool,
+    pub track_merge_provenance: bool,
     pub override_current_peer_id: Option<String>,
 }
 
@@ -67,29 +75,13 @@ impl<R: AirRunner> TestRunner<R> {
         let mut prev_data = prev_data.into();
         let mut data = data.into();
 
-        let TestRunParameters {
-            init_peer_id,
-            timestamp,
-            ttl,
-            override_current_peer_id,
-        } = test_run_params;
-
         let mut call_results = HashMap::new();
         let mut next_peer_pks = HashSet::new();
 
         loop {
             let mut outcome: RawAVMOutcome = self
                 .runner
-                .call(
-                    air.clone(),
-                    prev_data,
-                    data,
-                    init_peer_id.clone(),
-                    timestamp,
-                    ttl,
-                    override_current_peer_id.clone(),
-                    call_results,
-                )
+                .call(air.clone(), prev_data, data, &test_run_params, call_results)
                 .map_err(|e| e.to_string())?;
 
             next_peer_pks.extend(outcome.next_peer_pks);
@@ -130,8 +122,17 @@ impl TestRunParameters {
     pub fn new(init_peer_id: impl Into<String>, timestamp: u64, ttl: u32) -> Self {
         Self {
             init_peer_id: init_peer_id.into(),
+            particle_id: String::new(),
             timestamp,
             ttl,
+            current_timestamp: 0,
+            data_format: DataFormat::Json,
+            compression_threshold: 0,
+            skip_cid_verification: false,
+            cid_hash_algorithm: CidHashAlgorithm::Sha2_256,
+            signing_key: vec![],
+            require_signatures: false,
+            track_merge_provenance: false,
             override_current_peer_id: None,
         }
     }
@@ -139,8 +140,17 @@ impl TestRunParameters {
     pub fn from_init_peer_id(init_peer_id: impl Into<String>) -> Self {
         Self {
             init_peer_id: init_peer_id.into(),
+            particle_id: String::new(),
             timestamp: 0,
             ttl: 0,
+            current_timestamp: 0,
+            data_format: DataFormat::Json,
+            compression_threshold: 0,
+            skip_cid_verification: false,
+            cid_hash_algorithm: CidHashAlgorithm::Sha2_256,
+            signing_key: vec![],
+            require_signatures: false,
+            track_merge_provenance: false,
             override_current_peer_id: None,
         }
     }
@@ -148,8 +158,17 @@ impl TestRunParameters {
     pub fn from_timestamp(timestamp: u64) -> Self {
         Self {
             init_peer_id: String::new(),
+            particle_id: String::new(),
             timestamp,
             ttl: 0,
+            current_timestamp: 0,
+            data_format: DataFormat::Json,
+            compression_threshold: 0,
+            skip_cid_verification: false,
+            cid_hash_algorithm: CidHashAlgorithm::Sha2_256,
+            signing_key: vec![],
+            require_signatures: false,
+            track_merge_provenance: false,
             override_current_peer_id: None,
         }
     }
@@ -157,11 +176,44 @@ impl TestRunParameters {
     pub fn from_ttl(ttl: u32) -> Self {
         Self {
             init_peer_id: String::new(),
+            particle_id: String::new(),
             timestamp: 0,
             ttl,
+            current_timestamp: 0,
+            data_format: DataFormat::Json,
+            compression_threshold: 0,
+            skip_cid_verification: false,
+            cid_hash_algorithm: CidHashAlgorithm::Sha2_256,
+            signing_key: vec![],
+            require_signatures: false,
+            track_merge_provenance: false,
             override_current_peer_id: None,
         }
     }
+
+    /// Returns options of a run on a peer with the provided id, unless it's overridden.
+    pub fn to_run_options(&self, current_peer_id: &str) -> RunOptions {
+        let current_peer_id = self
+            .override_current_peer_id
+            .as_deref()
+            .unwrap_or(current_peer_id);
+
+        RunOptions {
+            init_peer_id: self.init_peer_id.clone(),
+            current_peer_id: current_peer_id.to_owned(),
+            particle_id: self.particle_id.clone(),
+            timestamp: self.timestamp,
+            ttl: self.ttl,
+            current_timestamp: self.current_timestamp,
+            data_format: self.data_format,
+            compression_threshold: self.compression_threshold,
+            skip_cid_verification: self.skip_cid_verification,
+            cid_hash_algorithm: self.cid_hash_algorithm,
+            signing_key: self.signing_key.clone(),
+            require_signatures: self.require_signatures,
+            track_merge_provenance: self.track_merge_provenance,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -191,7 +243,13 @@ mod tests {
 
         let current_result_1 = client
             .runner
-            .call(&script, "", "", spell_id, 0, 0, None, HashMap::new())
+            .call(
+                &script,
+                "",
+                "",
+                &TestRunParameters::from_init_peer_id(spell_id),
+                HashMap::new(),
+            )
             .expect("call should be success");
 
         let expected_current_call_requests = HashMap::new();
@@ -212,10 +270,10 @@ mod tests {
                 script,
                 "",
                 "",
-                spell_id,
-                0,
-                0,
-                Some(spell_id.to_owned()),
+                &TestRunParameters {
+                    override_current_peer_id: Some(spell_id.to_owned()),
+                    ..TestRunParameters::from_init_peer_id(spell_id)
+                },
                 HashMap::new(),
             )
             .expect("call should be success");
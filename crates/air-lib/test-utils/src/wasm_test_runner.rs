@@ -15,6 +15,7 @@
  */
 
 use crate::test_runner::AirRunner;
+use crate::test_runner::TestRunParameters;
 use avm_server::avm_runner::*;
 
 use once_cell::sync::OnceCell;
@@ -65,24 +66,13 @@ impl AirRunner for WasmAirRunner {
         air: impl Into<String>,
         prev_data: impl Into<Vec<u8>>,
         data: impl Into<Vec<u8>>,
-        init_peer_id: impl Into<String>,
-        timestamp: u64,
-        ttl: u32,
-        override_current_peer_id: Option<String>,
+        test_run_params: &TestRunParameters,
         call_results: avm_server::CallResults,
     ) -> Result<RawAVMOutcome, Box<dyn std::error::Error>> {
-        let current_peer_id =
-            override_current_peer_id.unwrap_or_else(|| self.current_peer_id.clone());
+        let run_options = test_run_params.to_run_options(&self.current_peer_id);
 
-        Ok(self.runner.call(
-            air,
-            prev_data,
-            data,
-            init_peer_id,
-            timestamp,
-            ttl,
-            current_peer_id,
-            call_results,
-        )?)
+        Ok(self
+            .runner
+            .call(air, prev_data, data, run_options, call_results)?)
     }
 }
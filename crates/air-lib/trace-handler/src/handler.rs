@@ -21,6 +21,7 @@ use merger::*;
 pub struct TraceHandler {
     data_keeper: DataKeeper,
     fsm_keeper: FSMKeeper,
+    provenance: Option<ProvenanceTracker>,
 }
 
 impl TraceHandler {
@@ -30,9 +31,21 @@ impl TraceHandler {
         Self {
             data_keeper,
             fsm_keeper: <_>::default(),
+            provenance: None,
         }
     }
 
+    /// Enables tracking of where states of the result trace came from. It's intended
+    /// for debugging merge issues and so is disabled by default.
+    pub fn track_provenance(&mut self) {
+        self.provenance = Some(<_>::default());
+    }
+
+    /// Returns a report about the result trace provenance, if its tracking was enabled.
+    pub fn provenance_report(&self) -> Option<&MergeProvenanceReport> {
+        self.provenance.as_ref().map(ProvenanceTracker::report)
+    }
+
     /// Returns size of elements inside result trace and intended to provide
     /// a position of next inserted elements.
     pub fn trace_pos(&self) -> TracePos {
@@ -66,7 +79,9 @@ impl TraceHandler {
             .ok_or_else(|| GenerationCompatificationError::points_to_nowhere(trace_pos))?;
 
         match state {
-            ExecutedState::Ap(ap_result) => ap_result.res_generations = vec![generation],
+            ExecutedState::Ap(ap_result) | ExecutedState::ApMap(ap_result) => {
+                ap_result.res_generations = vec![generation]
+            }
             ExecutedState::Call(CallResult::Executed(ValueRef::Stream {
                 generation: call_generation,
                 ..
@@ -84,9 +99,14 @@ impl TraceHandler {
 }
 
 impl TraceHandler {
-    /// Should be called at the beginning of a call execution.
-    pub fn meet_call_start(&mut self) -> TraceHandlerResult<MergerCallResult> {
-        try_merge_next_state_as_call(&mut self.data_keeper).map_err(Into::into)
+    /// Should be called at the beginning of a call execution. Stored values are used to compare
+    /// results of the same call, if their CIDs were calculated differently.
+    pub fn meet_call_start(&mut self, value_tracker: &CidTracker) -> TraceHandlerResult<MergerCallResult> {
+        let positions = SlidersPositions::from_keeper(&self.data_keeper);
+        let merger_result = try_merge_next_state_as_call(&mut self.data_keeper, value_tracker)?;
+        self.track_value_start(positions);
+
+        Ok(merger_result)
     }
 
     /// Should be called when a call instruction was executed successfully. It adds the supplied
@@ -97,33 +117,91 @@ impl TraceHandler {
             "  adding new call executed state {:?}",
             call_result
         );
+        self.track_value_end("call");
         self.data_keeper.result_trace.push(ExecutedState::Call(call_result));
     }
 }
 
 impl TraceHandler {
     pub fn meet_ap_start(&mut self) -> TraceHandlerResult<MergerApResult> {
-        try_merge_next_state_as_ap(&mut self.data_keeper).map_err(Into::into)
+        let positions = SlidersPositions::from_keeper(&self.data_keeper);
+        let merger_result = try_merge_next_state_as_ap(&mut self.data_keeper)?;
+        self.track_value_start(positions);
+
+        Ok(merger_result)
     }
 
     pub fn meet_ap_end(&mut self, ap_result: ApResult) {
+        self.track_value_end("ap");
         self.data_keeper.result_trace.push(ExecutedState::Ap(ap_result));
     }
+
+    pub fn meet_ap_map_start(&mut self) -> TraceHandlerResult<MergerApResult> {
+        let positions = SlidersPositions::from_keeper(&self.data_keeper);
+        let merger_result = try_merge_next_state_as_ap_map(&mut self.data_keeper)?;
+        self.track_value_start(positions);
+
+        Ok(merger_result)
+    }
+
+    pub fn meet_ap_map_end(&mut self, ap_result: ApResult) {
+        self.track_value_end("ap_map");
+        self.data_keeper.result_trace.push(ExecutedState::ApMap(ap_result));
+    }
 }
 
 impl TraceHandler {
     pub fn meet_canon_start(&mut self) -> TraceHandlerResult<MergerCanonResult> {
-        try_merge_next_state_as_canon(&mut self.data_keeper).map_err(Into::into)
+        let positions = SlidersPositions::from_keeper(&self.data_keeper);
+        let merger_result = try_merge_next_state_as_canon(&mut self.data_keeper)?;
+        self.track_value_start(positions);
+
+        Ok(merger_result)
     }
 
     pub fn meet_canon_end(&mut self, canon_result: CanonResult) {
+        self.track_value_end("canon");
         self.data_keeper.result_trace.push(ExecutedState::Canon(canon_result));
     }
+
+    pub fn meet_canon_map_start(&mut self) -> TraceHandlerResult<MergerCanonResult> {
+        let positions = SlidersPositions::from_keeper(&self.data_keeper);
+        let merger_result = try_merge_next_state_as_canon_map(&mut self.data_keeper)?;
+        self.track_value_start(positions);
+
+        Ok(merger_result)
+    }
+
+    pub fn meet_canon_map_end(&mut self, canon_result: CanonResult) {
+        self.track_value_end("canon_map");
+        self.data_keeper
+            .result_trace
+            .push(ExecutedState::CanonMap(canon_result));
+    }
+}
+
+impl TraceHandler {
+    pub fn meet_ttl_guard_start(&mut self) -> TraceHandlerResult<MergerTtlGuardResult> {
+        let positions = SlidersPositions::from_keeper(&self.data_keeper);
+        let merger_result = try_merge_next_state_as_ttl_guard(&mut self.data_keeper)?;
+        self.track_value_start(positions);
+
+        Ok(merger_result)
+    }
+
+    pub fn meet_ttl_guard_end(&mut self, ttl_guard_result: TtlGuardResult) {
+        self.track_value_end("ttl_guard");
+        self.data_keeper
+            .result_trace
+            .push(ExecutedState::TtlGuard(ttl_guard_result));
+    }
 }
 
 impl TraceHandler {
     pub fn meet_par_start(&mut self) -> TraceHandlerResult<()> {
+        let positions = SlidersPositions::from_keeper(&self.data_keeper);
         let ingredients = merger::try_merge_next_state_as_par(&mut self.data_keeper)?;
+        self.track_subgraph_start("par", positions);
         let par_fsm = ParFSM::from_left_started(ingredients, &mut self.data_keeper)?;
         self.fsm_keeper.push_par(par_fsm);
 
@@ -148,7 +226,9 @@ impl TraceHandler {
 
 impl TraceHandler {
     pub fn meet_fold_start(&mut self, fold_id: u32) -> TraceHandlerResult<()> {
+        let positions = SlidersPositions::from_keeper(&self.data_keeper);
         let ingredients = try_merge_next_state_as_fold(&mut self.data_keeper)?;
+        self.track_subgraph_start("fold", positions);
         let fold_fsm = FoldFSM::from_fold_start(ingredients, &mut self.data_keeper)?;
         self.fsm_keeper.add_fold(fold_id, fold_fsm);
 
@@ -190,3 +270,23 @@ impl TraceHandler {
         Ok(())
     }
 }
+
+impl TraceHandler {
+    fn track_value_start(&mut self, positions: SlidersPositions) {
+        if let Some(tracker) = &mut self.provenance {
+            tracker.meet_value_start(positions.consumed(&self.data_keeper));
+        }
+    }
+
+    fn track_value_end(&mut self, instruction: &'static str) {
+        if let Some(tracker) = &mut self.provenance {
+            tracker.meet_value_end(instruction, &self.data_keeper);
+        }
+    }
+
+    fn track_subgraph_start(&mut self, instruction: &'static str, positions: SlidersPositions) {
+        if let Some(tracker) = &mut self.provenance {
+            tracker.meet_subgraph_start(instruction, positions.consumed(&self.data_keeper), &self.data_keeper);
+        }
+    }
+}
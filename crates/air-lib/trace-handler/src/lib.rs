@@ -30,11 +30,15 @@ mod data_keeper;
 mod errors;
 mod handler;
 pub mod merger;
+mod provenance;
 mod state_automata;
 
 pub use errors::GenerationCompatificationError;
 pub use errors::TraceHandlerError;
 pub use handler::TraceHandler;
+pub use provenance::MergeProvenanceReport;
+pub use provenance::StateProvenance;
+pub use provenance::StateSource;
 pub use state_automata::SubgraphType;
 
 pub type TraceHandlerResult<T> = std::result::Result<T, TraceHandlerError>;
@@ -45,6 +49,8 @@ use data_keeper::MergeCtx;
 use merger::MergerFoldResult;
 use merger::ResolvedFold;
 use merger::ResolvedSubTraceDescs;
+use provenance::ProvenanceTracker;
+use provenance::SlidersPositions;
 use state_automata::FSMKeeper;
 use state_automata::FoldFSM;
 use state_automata::ParFSM;
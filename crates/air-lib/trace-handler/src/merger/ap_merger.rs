@@ -17,6 +17,7 @@
 use super::*;
 
 const EXPECTED_STATE_NAME: &str = "ap";
+const EXPECTED_MAP_STATE_NAME: &str = "ap_map";
 
 #[derive(Debug, Clone)]
 pub enum MergerApResult {
@@ -35,27 +36,62 @@ pub struct MetApResult {
 }
 
 pub(crate) fn try_merge_next_state_as_ap(data_keeper: &mut DataKeeper) -> MergeResult<MergerApResult> {
-    use ExecutedState::Ap;
+    try_merge_next_state(data_keeper, ApStateKind::Ap)
+}
+
+pub(crate) fn try_merge_next_state_as_ap_map(data_keeper: &mut DataKeeper) -> MergeResult<MergerApResult> {
+    try_merge_next_state(data_keeper, ApStateKind::ApMap)
+}
+
+/// Ap and ap map states are merged in the same way and differ only by an executed state variant.
+#[derive(Debug, Clone, Copy)]
+enum ApStateKind {
+    Ap,
+    ApMap,
+}
+
+fn try_merge_next_state(data_keeper: &mut DataKeeper, kind: ApStateKind) -> MergeResult<MergerApResult> {
     use PreparationScheme::*;
 
     let prev_state = data_keeper.prev_slider_mut().next_state();
     let current_state = data_keeper.current_slider_mut().next_state();
 
-    match (prev_state, current_state) {
-        (Some(Ap(prev_ap)), Some(Ap(_))) => prepare_merge_result(prev_ap, Both, data_keeper),
-        (Some(Ap(prev_ap)), None) => prepare_merge_result(prev_ap, Previous, data_keeper),
+    let prev_ap = prev_state.as_ref().map(|state| kind.as_ap_result(state));
+    let current_ap = current_state.as_ref().map(|state| kind.as_ap_result(state));
+
+    match (prev_ap, current_ap) {
+        (Some(Some(prev_ap)), Some(Some(_))) => prepare_merge_result(prev_ap.clone(), Both, data_keeper),
+        (Some(Some(prev_ap)), None) => prepare_merge_result(prev_ap.clone(), Previous, data_keeper),
         // check that current state is Ap, but it's impossible to use it, because prev_data
         // could not have streams with such generations
-        (None, Some(Ap(current_ap))) => prepare_merge_result(current_ap, Current, data_keeper),
+        (None, Some(Some(current_ap))) => prepare_merge_result(current_ap.clone(), Current, data_keeper),
         (None, None) => Ok(MergerApResult::NotMet),
-        (prev_state, current_state) => Err(MergeError::incompatible_states(
+        _ => Err(MergeError::incompatible_states(
             prev_state,
             current_state,
-            EXPECTED_STATE_NAME,
+            kind.state_name(),
         )),
     }
 }
 
+impl ApStateKind {
+    fn as_ap_result(self, state: &ExecutedState) -> Option<&ApResult> {
+        match (self, state) {
+            (ApStateKind::Ap, ExecutedState::Ap(ap_result)) | (ApStateKind::ApMap, ExecutedState::ApMap(ap_result)) => {
+                Some(ap_result)
+            }
+            _ => None,
+        }
+    }
+
+    fn state_name(self) -> &'static str {
+        match self {
+            ApStateKind::Ap => EXPECTED_STATE_NAME,
+            ApStateKind::ApMap => EXPECTED_MAP_STATE_NAME,
+        }
+    }
+}
+
 macro_rules! to_maybe_generation {
     ($ap_result:ident, $generations:expr, $error_ty:ident) => {
         match $generations.len() {
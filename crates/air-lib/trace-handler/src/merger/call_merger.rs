@@ -39,7 +39,10 @@ pub struct MetCallResult {
     pub source: ValueSource,
 }
 
-pub(crate) fn try_merge_next_state_as_call(data_keeper: &mut DataKeeper) -> MergeResult<MergerCallResult> {
+pub(crate) fn try_merge_next_state_as_call(
+    data_keeper: &mut DataKeeper,
+    value_tracker: &CidTracker,
+) -> MergeResult<MergerCallResult> {
     use ExecutedState::Call;
     use PreparationScheme::*;
 
@@ -48,7 +51,7 @@ pub(crate) fn try_merge_next_state_as_call(data_keeper: &mut DataKeeper) -> Merg
 
     match (prev_state, current_state) {
         (Some(Call(prev_call)), Some(Call(current_call))) => {
-            let (merged_call, scheme) = merge_call_results(prev_call, current_call)?;
+            let (merged_call, scheme) = merge_call_results(prev_call, current_call, value_tracker)?;
             Ok(prepare_call_result(merged_call, scheme, data_keeper))
         }
         (None, Some(Call(current_call))) => Ok(prepare_call_result(current_call, Current, data_keeper)),
@@ -62,7 +65,11 @@ pub(crate) fn try_merge_next_state_as_call(data_keeper: &mut DataKeeper) -> Merg
     }
 }
 
-fn merge_call_results(prev_call: CallResult, current_call: CallResult) -> MergeResult<(CallResult, PreparationScheme)> {
+fn merge_call_results(
+    prev_call: CallResult,
+    current_call: CallResult,
+    value_tracker: &CidTracker,
+) -> MergeResult<(CallResult, PreparationScheme)> {
     use CallResult::*;
     use PreparationScheme::*;
 
@@ -79,6 +86,12 @@ fn merge_call_results(prev_call: CallResult, current_call: CallResult) -> MergeR
         (RequestSentBy(_), current @ Executed(_)) => (current, Current),
         (previous @ Executed(..), RequestSentBy(_)) => (previous, Previous),
         (Executed(prev_value), Executed(current_value)) => (merge_executed(prev_value, current_value)?, Both),
+        (RequestSentBy(_), current @ Unused(_)) => (current, Current),
+        (previous @ Unused(_), RequestSentBy(_)) => (previous, Previous),
+        (prev @ Unused(_), current @ (Unused(_) | Executed(ValueRef::Scalar(_))))
+        | (prev @ Executed(ValueRef::Scalar(_)), current @ Unused(_)) => {
+            (merge_unused(prev, current, value_tracker)?, Both)
+        }
         (prev_call, current_call) => return Err(CallResultError::incompatible_calls(prev_call, current_call)),
     };
 
@@ -16,6 +16,7 @@
 
 use super::*;
 
+use air_interpreter_cid::value_to_cid;
 use air_interpreter_cid::CID;
 use serde_json::Value as JValue;
 
@@ -61,6 +62,46 @@ fn are_streams_equal(
     ))
 }
 
+/// Data produced before unused call results were introduced contains scalars for calls
+/// without an output variable, such results are compatible with unused ones of the same value.
+pub(super) fn merge_unused(
+    prev_call: CallResult,
+    current_call: CallResult,
+    value_tracker: &CidTracker<JValue>,
+) -> MergeResult<CallResult> {
+    use CallResult::*;
+
+    match (&prev_call, &current_call) {
+        (
+            Unused(prev_cid) | Executed(ValueRef::Scalar(prev_cid)),
+            Unused(current_cid) | Executed(ValueRef::Scalar(current_cid)),
+        ) if are_cids_of_same_value(prev_cid, current_cid, value_tracker) => Ok(CallResult::unused(prev_cid.clone())),
+        _ => Err(CallResultError::incompatible_calls(prev_call, current_call)),
+    }
+}
+
+/// CIDs of the same value differ, if they were calculated with different hash algorithms or
+/// by an interpreter using another JSON form, so a CID is re-derived from the value stored under
+/// the other one. Values of unused results aren't stored, so two unused results are compared by CIDs.
+fn are_cids_of_same_value(
+    prev_cid: &CID<JValue>,
+    current_cid: &CID<JValue>,
+    value_tracker: &CidTracker<JValue>,
+) -> bool {
+    prev_cid == current_cid
+        || is_cid_of_stored_value(prev_cid, current_cid, value_tracker)
+        || is_cid_of_stored_value(current_cid, prev_cid, value_tracker)
+}
+
+fn is_cid_of_stored_value(cid: &CID<JValue>, stored_cid: &CID<JValue>, value_tracker: &CidTracker<JValue>) -> bool {
+    let (value, (codec, hash_algorithm)) = match (value_tracker.get(stored_cid), cid.codec_and_hash_algorithm()) {
+        (Some(value), Some(codec_and_hash_algorithm)) => (value, codec_and_hash_algorithm),
+        _ => return false,
+    };
+
+    value_to_cid(&*value, codec, hash_algorithm).map_or(false, |value_cid| &value_cid == cid)
+}
+
 pub(super) fn check_equal(prev_call: &CallResult, current_call: &CallResult) -> MergeResult<()> {
     if prev_call != current_call {
         Err(CallResultError::incompatible_calls(
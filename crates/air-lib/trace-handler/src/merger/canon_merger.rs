@@ -23,6 +23,7 @@ use polyplets::SecurityTetraplet;
 use std::rc::Rc;
 
 const EXPECTED_STATE_NAME: &str = "canon";
+const EXPECTED_MAP_STATE_NAME: &str = "canon_map";
 
 #[derive(Debug, Clone)]
 pub enum MergerCanonResult {
@@ -38,24 +39,59 @@ pub enum MergerCanonResult {
 }
 
 pub(crate) fn try_merge_next_state_as_canon(data_keeper: &mut DataKeeper) -> MergeResult<MergerCanonResult> {
-    use ExecutedState::Canon;
+    try_merge_next_state(data_keeper, CanonStateKind::Canon)
+}
+
+pub(crate) fn try_merge_next_state_as_canon_map(data_keeper: &mut DataKeeper) -> MergeResult<MergerCanonResult> {
+    try_merge_next_state(data_keeper, CanonStateKind::CanonMap)
+}
 
+/// Canon and canon map states are merged in the same way and differ only by an executed state variant.
+#[derive(Debug, Clone, Copy)]
+enum CanonStateKind {
+    Canon,
+    CanonMap,
+}
+
+fn try_merge_next_state(data_keeper: &mut DataKeeper, kind: CanonStateKind) -> MergeResult<MergerCanonResult> {
     let prev_state = data_keeper.prev_slider_mut().next_state();
     let current_state = data_keeper.current_slider_mut().next_state();
 
-    match (prev_state, current_state) {
-        (Some(Canon(prev_canon)), Some(Canon(current_canon))) => prepare_both_canon_result(prev_canon, &current_canon),
-        (Some(Canon(prev_canon)), None) => prepare_single_canon_result(prev_canon),
-        (None, Some(Canon(current_canon))) => prepare_single_canon_result(current_canon),
+    let prev_canon = prev_state.as_ref().map(|state| kind.as_canon_result(state));
+    let current_canon = current_state.as_ref().map(|state| kind.as_canon_result(state));
+
+    match (prev_canon, current_canon) {
+        (Some(Some(prev_canon)), Some(Some(current_canon))) => {
+            prepare_both_canon_result(prev_canon.clone(), current_canon)
+        }
+        (Some(Some(prev_canon)), None) => prepare_single_canon_result(prev_canon.clone()),
+        (None, Some(Some(current_canon))) => prepare_single_canon_result(current_canon.clone()),
         (None, None) => Ok(MergerCanonResult::Empty),
-        (prev_state, current_state) => Err(MergeError::incompatible_states(
+        _ => Err(MergeError::incompatible_states(
             prev_state,
             current_state,
-            EXPECTED_STATE_NAME,
+            kind.state_name(),
         )),
     }
 }
 
+impl CanonStateKind {
+    fn as_canon_result(self, state: &ExecutedState) -> Option<&CanonResult> {
+        match (self, state) {
+            (CanonStateKind::Canon, ExecutedState::Canon(canon_result))
+            | (CanonStateKind::CanonMap, ExecutedState::CanonMap(canon_result)) => Some(canon_result),
+            _ => None,
+        }
+    }
+
+    fn state_name(self) -> &'static str {
+        match self {
+            CanonStateKind::Canon => EXPECTED_STATE_NAME,
+            CanonStateKind::CanonMap => EXPECTED_MAP_STATE_NAME,
+        }
+    }
+}
+
 fn prepare_both_canon_result(
     prev_canon_result: CanonResult,
     current_canon_result: &CanonResult,
@@ -21,6 +21,7 @@ mod errors;
 mod fold_merger;
 mod par_merger;
 mod position_mapping;
+mod ttl_guard_merger;
 
 pub use ap_merger::MergerApResult;
 pub use ap_merger::MetApResult;
@@ -30,6 +31,7 @@ pub use canon_merger::MergerCanonResult;
 pub use fold_merger::MergerFoldResult;
 pub use par_merger::MergerParResult;
 pub use position_mapping::PreparationScheme;
+pub use ttl_guard_merger::MergerTtlGuardResult;
 
 pub use errors::ApResultError;
 pub use errors::CallResultError;
@@ -40,10 +42,13 @@ pub use fold_merger::ResolvedFold;
 pub use fold_merger::ResolvedSubTraceDescs;
 
 pub(super) use ap_merger::try_merge_next_state_as_ap;
+pub(super) use ap_merger::try_merge_next_state_as_ap_map;
 pub(super) use call_merger::try_merge_next_state_as_call;
 pub(super) use canon_merger::try_merge_next_state_as_canon;
+pub(super) use canon_merger::try_merge_next_state_as_canon_map;
 pub(crate) use fold_merger::try_merge_next_state_as_fold;
 pub(crate) use par_merger::try_merge_next_state_as_par;
+pub(super) use ttl_guard_merger::try_merge_next_state_as_ttl_guard;
 
 use position_mapping::prepare_positions_mapping;
 
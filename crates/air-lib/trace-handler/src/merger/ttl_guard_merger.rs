@@ -0,0 +1,55 @@
+/*
+ * Copyright 2022 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+const EXPECTED_STATE_NAME: &str = "ttl_guard";
+
+#[derive(Debug, Clone)]
+pub enum MergerTtlGuardResult {
+    /// There is no corresponding state in a trace for this ttl_guard.
+    NotMet,
+
+    /// There was a state in at least one of the contexts. If there were two states in
+    /// both contexts, they were successfully merged.
+    Met(TtlGuardResult),
+}
+
+pub(crate) fn try_merge_next_state_as_ttl_guard(data_keeper: &mut DataKeeper) -> MergeResult<MergerTtlGuardResult> {
+    let prev_state = data_keeper.prev_slider_mut().next_state();
+    let current_state = data_keeper.current_slider_mut().next_state();
+
+    match (&prev_state, &current_state) {
+        (Some(ExecutedState::TtlGuard(prev_result)), Some(ExecutedState::TtlGuard(current_result))) => {
+            if prev_result != current_result {
+                return Err(MergeError::incompatible_states(
+                    prev_state,
+                    current_state,
+                    EXPECTED_STATE_NAME,
+                ));
+            }
+            Ok(MergerTtlGuardResult::Met(*prev_result))
+        }
+        (Some(ExecutedState::TtlGuard(prev_result)), None) => Ok(MergerTtlGuardResult::Met(*prev_result)),
+        (None, Some(ExecutedState::TtlGuard(current_result))) => Ok(MergerTtlGuardResult::Met(*current_result)),
+        (None, None) => Ok(MergerTtlGuardResult::NotMet),
+        _ => Err(MergeError::incompatible_states(
+            prev_state,
+            current_state,
+            EXPECTED_STATE_NAME,
+        )),
+    }
+}
@@ -0,0 +1,269 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::data_keeper::DataKeeper;
+use crate::merger::ValueSource;
+
+use air_interpreter_data::TracePos;
+use serde::Serialize;
+
+/// Describes where a state of the result trace came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StateSource {
+    /// The state was taken from previous data.
+    PreviousData,
+
+    /// The state was taken from current data.
+    CurrentData,
+
+    /// The state was merged from states of both data.
+    Both,
+
+    /// There was no corresponding state in both data, the state was produced by this execution.
+    New,
+}
+
+/// Provenance of one state of the result trace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StateProvenance {
+    /// Position of the state in the result trace.
+    pub trace_pos: TracePos,
+
+    pub source: StateSource,
+
+    /// Position of the consumed state in previous data, if any.
+    pub prev_pos: Option<TracePos>,
+
+    /// Position of the consumed state in current data, if any.
+    pub current_pos: Option<TracePos>,
+
+    /// Name of the instruction that consumed the states.
+    pub instruction: &'static str,
+}
+
+/// Describes how the result trace was obtained from previous and current traces,
+/// intended for debugging merge issues.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct MergeProvenanceReport {
+    pub states: Vec<StateProvenance>,
+}
+
+/// Positions of states consumed from previous and current data by one merge.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ConsumedPositions {
+    prev_pos: Option<TracePos>,
+    current_pos: Option<TracePos>,
+}
+
+/// Positions of trace sliders saved before a merge.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SlidersPositions {
+    prev_pos: TracePos,
+    current_pos: TracePos,
+}
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ProvenanceTracker {
+    report: MergeProvenanceReport,
+    // states consumed by the last met call, ap or canon, they are recorded when its state is added
+    pending: ConsumedPositions,
+}
+
+impl SlidersPositions {
+    pub(crate) fn from_keeper(data_keeper: &DataKeeper) -> Self {
+        Self {
+            prev_pos: data_keeper.prev_slider().position(),
+            current_pos: data_keeper.current_slider().position(),
+        }
+    }
+
+    /// Returns positions of states consumed since these positions were saved,
+    /// sliders move only forward by merging.
+    pub(crate) fn consumed(self, data_keeper: &DataKeeper) -> ConsumedPositions {
+        let consumed = |before: TracePos, after: TracePos| (before != after).then_some(before);
+
+        ConsumedPositions {
+            prev_pos: consumed(self.prev_pos, data_keeper.prev_slider().position()),
+            current_pos: consumed(self.current_pos, data_keeper.current_slider().position()),
+        }
+    }
+}
+
+impl ProvenanceTracker {
+    pub(crate) fn report(&self) -> &MergeProvenanceReport {
+        &self.report
+    }
+
+    pub(crate) fn meet_value_start(&mut self, consumed: ConsumedPositions) {
+        self.pending = consumed;
+    }
+
+    /// Records a state of call, ap or canon which is going to be added to the result trace.
+    pub(crate) fn meet_value_end(&mut self, instruction: &'static str, data_keeper: &DataKeeper) {
+        let trace_pos = data_keeper.result_trace_next_pos();
+        let consumed = std::mem::take(&mut self.pending);
+
+        // value sources are tracked by mergers, the mapping takes precedence over consumed states,
+        // because a merger could take a state only from one of the data
+        let prev_mapped = data_keeper.new_to_prev_pos.contains_left(&trace_pos);
+        let current_mapped = data_keeper.new_to_current_pos.contains_left(&trace_pos);
+        let source = match (prev_mapped, current_mapped) {
+            (true, true) => StateSource::Both,
+            (true, false) => ValueSource::PreviousData.into(),
+            (false, true) => ValueSource::CurrentData.into(),
+            (false, false) => consumed.source(),
+        };
+
+        self.record(trace_pos, source, consumed, instruction);
+    }
+
+    /// Records a par or fold state, which position is reserved at the beginning of an instruction.
+    pub(crate) fn meet_subgraph_start(
+        &mut self,
+        instruction: &'static str,
+        consumed: ConsumedPositions,
+        data_keeper: &DataKeeper,
+    ) {
+        let trace_pos = data_keeper.result_trace_next_pos();
+        self.record(trace_pos, consumed.source(), consumed, instruction);
+    }
+
+    fn record(
+        &mut self,
+        trace_pos: TracePos,
+        source: StateSource,
+        consumed: ConsumedPositions,
+        instruction: &'static str,
+    ) {
+        let provenance = StateProvenance {
+            trace_pos,
+            source,
+            prev_pos: consumed.prev_pos,
+            current_pos: consumed.current_pos,
+            instruction,
+        };
+        self.report.states.push(provenance);
+    }
+}
+
+impl ConsumedPositions {
+    fn source(&self) -> StateSource {
+        match (self.prev_pos, self.current_pos) {
+            (Some(_), Some(_)) => StateSource::Both,
+            (Some(_), None) => StateSource::PreviousData,
+            (None, Some(_)) => StateSource::CurrentData,
+            (None, None) => StateSource::New,
+        }
+    }
+}
+
+impl From<ValueSource> for StateSource {
+    fn from(source: ValueSource) -> Self {
+        match source {
+            ValueSource::PreviousData => StateSource::PreviousData,
+            ValueSource::CurrentData => StateSource::CurrentData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SubgraphType;
+    use crate::TraceHandler;
+
+    use air_interpreter_data::CallResult;
+    use air_interpreter_data::ExecutedState;
+    use air_interpreter_data::ExecutionTrace;
+
+    use std::rc::Rc;
+
+    fn provenance(
+        trace_pos: usize,
+        source: StateSource,
+        prev_pos: Option<usize>,
+        current_pos: Option<usize>,
+        instruction: &'static str,
+    ) -> StateProvenance {
+        StateProvenance {
+            trace_pos: trace_pos.into(),
+            source,
+            prev_pos: prev_pos.map(Into::into),
+            current_pos: current_pos.map(Into::into),
+            instruction,
+        }
+    }
+
+    #[test]
+    fn provenance_is_not_tracked_by_default() {
+        let mut trace_handler = TraceHandler::from_trace(<_>::default(), <_>::default());
+        trace_handler.meet_call_start(&<_>::default()).unwrap();
+        trace_handler.meet_call_end(CallResult::failed(1, "error"));
+
+        assert_eq!(trace_handler.provenance_report(), None);
+    }
+
+    #[test]
+    fn merged_states_provenance() {
+        let peer_id = Rc::new(String::from("peer_id"));
+        let prev_trace: ExecutionTrace = vec![
+            ExecutedState::par(1, 0),
+            ExecutedState::Call(CallResult::sent_peer_id(peer_id.clone())),
+        ]
+        .into();
+        let current_trace: ExecutionTrace = vec![
+            ExecutedState::par(1, 1),
+            ExecutedState::Call(CallResult::failed(1, "error")),
+            ExecutedState::Call(CallResult::sent_peer_id(peer_id.clone())),
+        ]
+        .into();
+
+        let mut trace_handler = TraceHandler::from_trace(prev_trace, current_trace);
+        trace_handler.track_provenance();
+
+        trace_handler.meet_par_start().unwrap();
+        trace_handler.meet_call_start(&<_>::default()).unwrap();
+        trace_handler.meet_call_end(CallResult::failed(1, "error"));
+        trace_handler.meet_par_subgraph_end(SubgraphType::Left).unwrap();
+        trace_handler.meet_call_start(&<_>::default()).unwrap();
+        trace_handler.meet_call_end(CallResult::sent_peer_id(peer_id.clone()));
+        trace_handler.meet_par_subgraph_end(SubgraphType::Right).unwrap();
+        trace_handler.meet_call_start(&<_>::default()).unwrap();
+        trace_handler.meet_call_end(CallResult::sent_peer_id(peer_id));
+
+        let actual_report = trace_handler.provenance_report().unwrap();
+        let expected_report = MergeProvenanceReport {
+            states: vec![
+                provenance(0, StateSource::Both, Some(0), Some(0), "par"),
+                provenance(1, StateSource::CurrentData, Some(1), Some(1), "call"),
+                provenance(2, StateSource::CurrentData, None, Some(2), "call"),
+                provenance(3, StateSource::New, None, None, "call"),
+            ],
+        };
+        assert_eq!(actual_report, &expected_report);
+
+        let actual_json = serde_json::to_value(&actual_report.states[1]).unwrap();
+        let expected_json = serde_json::json!({
+            "trace_pos": 1,
+            "source": "current_data",
+            "prev_pos": 1,
+            "current_pos": 1,
+            "instruction": "call",
+        });
+        assert_eq!(actual_json, expected_json);
+    }
+}
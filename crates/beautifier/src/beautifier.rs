@@ -125,12 +125,17 @@ impl<W: io::Write> Beautifier<W> {
         match node {
             ast::Instruction::Call(call) => self.beautify_call(call, indent),
             ast::Instruction::Ap(ap) => self.beautify_simple(ap, indent),
+            ast::Instruction::ApMap(ap_map) => self.beautify_simple(ap_map, indent),
             ast::Instruction::Canon(canon) => self.beautify_simple(canon, indent),
+            ast::Instruction::CanonMap(canon_map) => self.beautify_simple(canon_map, indent),
             ast::Instruction::Seq(seq) => self.beautify_seq(seq, indent),
             ast::Instruction::Par(par) => self.beautify_par(par, indent),
             ast::Instruction::Xor(xor) => self.beautify_xor(xor, indent),
             ast::Instruction::Match(match_) => self.beautify_match(match_, indent),
             ast::Instruction::MisMatch(mismatch) => self.beautify_mismatch(mismatch, indent),
+            ast::Instruction::Compare(compare) => self.beautify_compare(compare, indent),
+            ast::Instruction::Member(member) => self.beautify_member(member, indent),
+            ast::Instruction::TtlGuard(ttl_guard) => self.beautify_ttl_guard(ttl_guard, indent),
             ast::Instruction::Fail(fail) => self.beautify_simple(fail, indent),
             ast::Instruction::FoldScalar(fold_scalar) => {
                 self.beautify_fold_scalar(fold_scalar, indent)
@@ -199,6 +204,22 @@ impl<W: io::Write> Beautifier<W> {
         compound!(self, indent, mismatch)
     }
 
+    fn beautify_compare(&mut self, compare: &ast::Compare<'_>, indent: usize) -> io::Result<()> {
+        compound!(self, indent, compare)
+    }
+
+    fn beautify_member(&mut self, member: &ast::Member<'_>, indent: usize) -> io::Result<()> {
+        compound!(self, indent, member)
+    }
+
+    fn beautify_ttl_guard(
+        &mut self,
+        ttl_guard: &ast::TtlGuard<'_>,
+        indent: usize,
+    ) -> io::Result<()> {
+        compound!(self, indent, ttl_guard)
+    }
+
     fn beautify_fold_scalar(
         &mut self,
         fold: &ast::FoldScalar<'_>,
@@ -179,6 +179,51 @@ match a b:
     );
 }
 
+#[test]
+fn compare() {
+    let script = r#"(seq
+  (call "a" ("" "") [] a)
+  (gte a 5 (null)))"#;
+    let output = beautify_to_string(script).unwrap();
+
+    assert_eq!(
+        output,
+        r#"a <- call "a" ("", "") []
+gte a 5:
+    null
+"#,
+    );
+}
+
+#[test]
+fn member() {
+    let script = r#"(seq
+  (call "a" ("" "") [] a)
+  (member a ["a" "b"] (null)))"#;
+    let output = beautify_to_string(script).unwrap();
+
+    assert_eq!(
+        output,
+        r#"a <- call "a" ("", "") []
+member a ["a" "b"]:
+    null
+"#,
+    );
+}
+
+#[test]
+fn ttl_guard() {
+    let script = r#"(ttl_guard 1000 (null))"#;
+    let output = beautify_to_string(script).unwrap();
+
+    assert_eq!(
+        output,
+        r#"ttl_guard 1000:
+    null
+"#,
+    );
+}
+
 #[test]
 fn mismatch() {
     let script = r#"(seq
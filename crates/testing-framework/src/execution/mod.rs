@@ -361,8 +361,8 @@ mod tests {
                 &*trace,
                 vec![
                     executed_state::scalar(json!(["peer2", "peer3"])),
-                    executed_state::scalar(json!(42)),
-                    executed_state::scalar(json!(43)),
+                    executed_state::unused(json!(42)),
+                    executed_state::unused(json!(43)),
                 ]
             );
         }
@@ -525,11 +525,11 @@ mod tests {
 
         assert_eq!(
             trace_from_result(&trace1),
-            ExecutionTrace::from(vec![scalar_number(42)]),
+            ExecutionTrace::from(vec![unused_number(42)]),
         );
         assert_eq!(
             trace_from_result(&trace2),
-            ExecutionTrace::from(vec![scalar_number(24)]),
+            ExecutionTrace::from(vec![unused_number(24)]),
         );
     }
 
@@ -571,11 +571,11 @@ mod tests {
 
         assert_eq!(
             trace_from_result(&trace1),
-            ExecutionTrace::from(vec![scalar_number(42)]),
+            ExecutionTrace::from(vec![unused_number(42)]),
         );
         assert_eq!(
             trace_from_result(&trace2),
-            ExecutionTrace::from(vec![scalar_number(24)]),
+            ExecutionTrace::from(vec![unused_number(24)]),
         );
     }
 
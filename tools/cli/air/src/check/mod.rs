@@ -0,0 +1,41 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use air::interpreter_data::InterpreterData;
+use anyhow::{Context, Result};
+use clap::Parser;
+
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[clap(about = "Check structural consistency of AquaVM interpreter data")]
+pub(crate) struct Args {
+    input: Option<PathBuf>,
+}
+
+pub(crate) fn check(args: Args) -> Result<()> {
+    let data = crate::data::read_data(args.input.as_deref()).context("failed to read the input")?;
+    let data = InterpreterData::try_from_slice(&data).context("failed to parse the data")?;
+
+    let diagnostics = data.check_sanity();
+    for diagnostic in &diagnostics {
+        println!("{diagnostic}");
+    }
+
+    if !diagnostics.is_empty() {
+        anyhow::bail!("data is malformed");
+    }
+    Ok(())
+}
@@ -0,0 +1,35 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use air::interpreter_data::InterpreterData;
+use std::{io, path::Path};
+
+/// Reads interpreter data from the file or from stdin, if no file is provided.
+pub(crate) fn read_data(input: Option<&Path>) -> io::Result<Vec<u8>> {
+    use std::io::Read;
+
+    let data = match input {
+        Some(in_path) => std::fs::read(in_path)?,
+        None => {
+            let mut buffer = vec![];
+            let mut stdin = io::stdin().lock();
+
+            stdin.read_to_end(&mut buffer)?;
+            buffer
+        }
+    };
+
+    Ok(data)
+}
@@ -27,6 +27,9 @@
 )]
 
 mod beautify;
+mod check;
+mod data;
+mod migrate;
 mod trace;
 
 use clap::Parser;
@@ -42,6 +45,10 @@ struct Cli {
 enum Subcommand {
     #[clap(alias = "b")]
     Beautify(self::beautify::Args),
+    #[clap(alias = "c")]
+    Check(self::check::Args),
+    #[clap(alias = "m")]
+    Migrate(self::migrate::Args),
     #[clap(alias = "r")]
     Run(self::trace::run::Args),
     #[clap(alias = "s")]
@@ -54,5 +61,7 @@ fn main() -> anyhow::Result<()> {
         Subcommand::Run(args) => self::trace::run::run(args),
         Subcommand::Stats(args) => self::trace::stats::stats(args),
         Subcommand::Beautify(args) => self::beautify::beautify(args),
+        Subcommand::Check(args) => self::check::check(args),
+        Subcommand::Migrate(args) => self::migrate::migrate(args),
     }
 }
@@ -0,0 +1,63 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use air::interpreter_data::InterpreterData;
+use air_interpreter_interface::DataFormat;
+use anyhow::{Context, Result};
+use clap::Parser;
+
+use std::{io, path::PathBuf};
+
+#[derive(Parser)]
+#[clap(about = "Migrate AquaVM interpreter data of an older version to the current one")]
+pub(crate) struct Args {
+    #[clap(
+        long,
+        default_value = "json",
+        help = "Format of migrated data: json or cbor"
+    )]
+    data_format: DataFormat,
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+    input: Option<PathBuf>,
+}
+
+fn write_data(args: &Args, data: &[u8]) -> Result<()> {
+    use std::io::Write;
+
+    match &args.output {
+        Some(out_path) => std::fs::write(out_path, data)?,
+        None => io::stdout().lock().write_all(data)?,
+    }
+
+    Ok(())
+}
+
+pub(crate) fn migrate(args: Args) -> Result<()> {
+    let data = crate::data::read_data(args.input.as_deref()).context("failed to read the input")?;
+    let versions =
+        InterpreterData::try_get_versions(&data).context("failed to get the data version")?;
+    let data = InterpreterData::try_from_slice(&data).context("failed to migrate the data")?;
+    eprintln!(
+        "data is migrated from {} to {} version",
+        versions.data_version,
+        air::interpreter_data::data_version(),
+    );
+
+    let data = data
+        .serialize(args.data_format)
+        .context("failed to serialize the data")?;
+    write_data(&args, &data).context("failed to write the output")
+}
@@ -21,7 +21,11 @@ mod runner;
 mod wasm;
 
 use self::runner::AirRunner;
+use air_interpreter_cid::CidHashAlgorithm;
+use air_interpreter_interface::DataFormat;
 use avm_interface::CallResults;
+use avm_interface::RunOptions;
+use avm_interface::TracingParameters;
 
 use anyhow::Context as _;
 use clap::{Parser, Subcommand};
@@ -52,6 +56,40 @@ pub(crate) struct Args {
     repeat: Option<u32>,
     #[clap(long, help = "Output JSON tracing info")]
     json: bool,
+    #[clap(long, help = "default: particle timestamp")]
+    current_timestamp: Option<u64>,
+    #[clap(
+        long,
+        default_value = "json",
+        help = "Format of produced data: json or cbor"
+    )]
+    data_format: DataFormat,
+    #[clap(
+        long,
+        default_value = "0",
+        help = "Compress produced data not smaller than this size in bytes, 0 disables compression"
+    )]
+    compression_threshold: u32,
+    #[clap(long, help = "Don't verify CIDs of values in the current data")]
+    skip_cid_verification: bool,
+    #[clap(
+        long,
+        default_value = "sha2-256",
+        help = "Hash algorithm of produced CIDs: sha2-256 or blake3-256"
+    )]
+    cid_hash_algorithm: CidHashAlgorithm,
+    #[clap(
+        long = "signing-key",
+        help = "File with a raw 32 bytes ed25519 secret key of the current peer to sign produced states"
+    )]
+    signing_key_path: Option<PathBuf>,
+    #[clap(long, help = "Reject states of peers that don't sign them")]
+    require_signatures: bool,
+    #[clap(
+        long,
+        help = "Print a JSON report about where states of the result trace came from"
+    )]
+    track_merge_provenance: bool,
 
     #[clap(subcommand)]
     source: Source,
@@ -86,8 +124,10 @@ pub(crate) fn run(args: Args) -> anyhow::Result<()> {
         Source::PlainData(raw) => data::plain::load(raw)?,
     };
     let particle = execution_data.particle;
+    let current_timestamp = args.current_timestamp.unwrap_or(particle.timestamp);
 
     let call_results = read_call_results(args.call_results_path.as_deref())?;
+    let signing_key = read_signing_key(args.signing_key_path.as_deref())?;
 
     let repeat = args.repeat.unwrap_or(1);
     for _ in 0..repeat {
@@ -96,17 +136,33 @@ pub(crate) fn run(args: Args) -> anyhow::Result<()> {
                 execution_data.air_script.clone(),
                 execution_data.prev_data.clone().into(),
                 execution_data.current_data.clone().into(),
-                particle.init_peer_id.clone().into_owned(),
-                particle.timestamp,
-                particle.ttl,
-                particle.current_peer_id.clone().into(),
+                RunOptions {
+                    init_peer_id: particle.init_peer_id.to_string(),
+                    current_peer_id: particle.current_peer_id.to_string(),
+                    particle_id: particle.particle_id.to_string(),
+                    timestamp: particle.timestamp,
+                    ttl: particle.ttl,
+                    current_timestamp,
+                    data_format: args.data_format,
+                    compression_threshold: args.compression_threshold,
+                    skip_cid_verification: args.skip_cid_verification,
+                    cid_hash_algorithm: args.cid_hash_algorithm,
+                    signing_key: signing_key.clone(),
+                    require_signatures: args.require_signatures,
+                    track_merge_provenance: args.track_merge_provenance,
+                },
                 call_results.clone(),
-                args.tracing_params.clone(),
-                tracing_json,
+                TracingParameters {
+                    params: args.tracing_params.clone(),
+                    output_mode: tracing_json,
+                },
             )
             .context("Failed to execute the script")?;
         if args.repeat.is_none() {
             println!("{result:?}");
+            if args.track_merge_provenance {
+                println!("{}", String::from_utf8_lossy(&result.merge_provenance));
+            }
         }
     }
 
@@ -163,6 +219,16 @@ fn read_call_results(call_results_path: Option<&Path>) -> anyhow::Result<CallRes
     }
 }
 
+// an absent key disables signing
+fn read_signing_key(signing_key_path: Option<&Path>) -> anyhow::Result<Vec<u8>> {
+    match signing_key_path {
+        None => Ok(vec![]),
+        Some(signing_key_path) => {
+            std::fs::read(signing_key_path).context("failed to read signing key")
+        }
+    }
+}
+
 fn load_data(data_path: &Path) -> anyhow::Result<String> {
     Ok(std::fs::read_to_string(data_path)?)
 }
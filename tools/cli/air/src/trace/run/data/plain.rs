@@ -28,6 +28,8 @@ const DEFAULT_DATA: &str = "";
 pub(crate) struct PlainDataArgs {
     #[clap(long, default_value = "some_id")]
     init_peer_id: String,
+    #[clap(long, default_value = "")]
+    particle_id: String,
     #[clap(long, help = "default: current time")]
     timestamp: Option<u64>,
     #[clap(long, help = "default: max possible ttl")]
@@ -57,11 +59,12 @@ pub(crate) fn load(args: &PlainDataArgs) -> anyhow::Result<ExecutionData<'_>> {
     let timestamp = args.timestamp.unwrap_or_else(unix_timestamp_now);
     let ttl = args.ttl.unwrap_or(u32::MAX);
     let init_peer_id = &args.init_peer_id;
+    let particle_id = &args.particle_id;
     let current_peer_id = &args.current_peer_id;
 
     let particle = ParticleParameters::new(
         init_peer_id.into(),
-        "".into(),
+        particle_id.into(),
         timestamp,
         ttl,
         current_peer_id.into(),
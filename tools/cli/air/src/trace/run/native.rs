@@ -17,6 +17,8 @@
 use super::runner::AirRunner;
 use air_interpreter_interface::RunParameters;
 use avm_interface::raw_outcome::RawAVMOutcome;
+use avm_interface::RunOptions;
+use avm_interface::TracingParameters;
 
 struct NativeAvmRunner {}
 
@@ -26,14 +28,10 @@ impl AirRunner for NativeAvmRunner {
         air: String,
         prev_data: Vec<u8>,
         data: Vec<u8>,
-        init_peer_id: String,
-        timestamp: u64,
-        ttl: u32,
-        current_peer_id: String,
+        run_options: RunOptions,
         call_results: avm_interface::CallResults,
         // We use externally configured logger.
-        _tracing_params: String,
-        _tracing_output_mode: u8,
+        _tracing_parameters: TracingParameters,
     ) -> anyhow::Result<RawAVMOutcome> {
         use avm_interface::into_raw_result;
 
@@ -45,15 +43,11 @@ impl AirRunner for NativeAvmRunner {
             air,
             prev_data,
             data,
-            RunParameters {
-                init_peer_id,
-                current_peer_id,
-                timestamp,
-                ttl,
-            },
+            RunParameters::from(run_options),
             raw_call_results,
         );
-        let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+        let mut outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+        outcome.merge_provenance = air::take_merge_provenance();
 
         Ok(outcome)
     }
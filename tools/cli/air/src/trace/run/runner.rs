@@ -16,20 +16,17 @@
 
 use avm_interface::raw_outcome::RawAVMOutcome;
 use avm_interface::CallResults;
+use avm_interface::RunOptions;
+use avm_interface::TracingParameters;
 
 pub(crate) trait AirRunner {
-    #[allow(clippy::too_many_arguments)]
     fn call_tracing(
         &mut self,
         air: String,
         prev_data: Vec<u8>,
         data: Vec<u8>,
-        init_peer_id: String,
-        timestamp: u64,
-        ttl: u32,
-        current_peer_id: String,
+        run_options: RunOptions,
         call_results: CallResults,
-        tracing_params: String,
-        tracing_output_mode: u8,
+        tracing_parameters: TracingParameters,
     ) -> anyhow::Result<RawAVMOutcome>;
 }
@@ -15,6 +15,8 @@
  */
 use super::runner::AirRunner;
 use air_test_utils::avm_runner::AVMRunner;
+use avm_interface::RunOptions;
+use avm_interface::TracingParameters;
 use std::path::Path;
 
 pub(crate) struct WasmAvmRunner(AVMRunner);
@@ -25,25 +27,17 @@ impl AirRunner for WasmAvmRunner {
         air: String,
         prev_data: Vec<u8>,
         data: Vec<u8>,
-        init_peer_id: String,
-        timestamp: u64,
-        ttl: u32,
-        current_peer_id: String,
+        run_options: RunOptions,
         call_results: avm_interface::CallResults,
-        tracing_params: String,
-        tracing_output_mode: u8,
+        tracing_parameters: TracingParameters,
     ) -> anyhow::Result<avm_interface::raw_outcome::RawAVMOutcome> {
         Ok(self.0.call_tracing(
             air,
             prev_data,
             data,
-            init_peer_id,
-            timestamp,
-            ttl,
-            current_peer_id,
+            run_options,
             call_results,
-            tracing_params,
-            tracing_output_mode,
+            tracing_parameters,
         )?)
     }
 }